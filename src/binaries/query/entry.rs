@@ -33,6 +33,7 @@ use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeAutoIngestService;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
@@ -232,6 +233,9 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Pipe auto ingestion.
+    PipeAutoIngestService::start(conf);
+
     // Print information to users.
     println!("Databend Query");
     println!();
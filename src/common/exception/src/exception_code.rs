@@ -150,6 +150,13 @@ build_exceptions! {
     UnknownCatalogType(1120),
     UnmatchMaskPolicyReturnType(1121),
     Timeout(1122),
+    UnknownRowAccessPolicy(1123),
+    UnknownSnapshotTag(1124),
+    ColumnReferencedByConstraint(1125),
+    // A recursive cte doesn't terminate within `max_cte_recursive_depth` iterations.
+    RecursiveCteDepthExceeded(1126),
+    // The rows deduplicated by a `UNION` recursive cte exceed `max_memory_usage`.
+    RecursiveCteMemoryExceeded(1127),
 
     // Data Related Errors
 
@@ -173,6 +180,12 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolated is used when the rows written into a table
+    /// don't satisfy a CHECK constraint of the table.
+    CheckConstraintViolated(1304),
+    /// UniqueKeyViolated is used when the rows written into a table
+    /// duplicate an enforced PRIMARY KEY or UNIQUE constraint of the table.
+    UniqueKeyViolated(1305),
 
     // License related errors starts here
 
@@ -193,6 +206,7 @@ build_exceptions! {
     // Index related errors.
     UnsupportedIndex(1601),
     RefreshIndexError(1602),
+    IndexOptionInvalid(1603),
 
     // Cloud control error codes
     CloudControlConnectError(1701),
@@ -205,7 +219,11 @@ build_exceptions! {
     // Tantivy errors.
     TantivyError(1901),
     TantivyOpenReadError(1902),
-    TantivyQueryParserError(1903)
+    TantivyQueryParserError(1903),
+
+    // Script errors.
+    ScriptSemanticError(1951),
+    ScriptExecutionError(1952)
 }
 
 // Meta service errors [2001, 3000].
@@ -244,6 +262,10 @@ build_exceptions! {
     RoleAlreadyExists(2216),
     IllegalRole(2217),
     IllegalUser(2218),
+    UnknownWorkloadGroup(2219),
+    WorkloadGroupAlreadyExists(2220),
+    IllegalWorkloadGroup(2221),
+    WorkloadGroupIsUsedByUser(2222),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -273,6 +295,10 @@ build_exceptions! {
     CatalogNotFound(2320),
     /// data mask error codes
     DatamaskAlreadyExists(2321),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2322),
+    /// snapshot tag error codes
+    SnapshotTagAlreadyExists(2323),
 
 
     // Cluster error codes.
@@ -342,6 +368,10 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Pipe error codes.
+    UnknownPipe(2740),
+    PipeAlreadyExists(2741),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
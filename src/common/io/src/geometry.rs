@@ -12,13 +12,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use geo::Geometry;
+use geozero::geojson::GeoJson;
+use geozero::wkb::Ewkb;
+use geozero::wkb::FromWkb;
+use geozero::wkb::WkbDialect;
+use geozero::wkt::Ewkt;
 use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
 use geozero::ToWkb;
+use geozero::ToWkt;
 use wkt::TryFromWkt;
 
+// The flag of the EWKB geometry type which indicates the SRID follows the type.
+const GEO_TYPE_ID_MASK: u32 = 0x2000_0000;
+
+/// The text or binary representation of the geometry values in the outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeometryDataType {
+    WKB,
+    WKT,
+    EWKB,
+    EWKT,
+    #[default]
+    GEOJSON,
+}
+
+impl FromStr for GeometryDataType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_uppercase().as_str() {
+            "WKB" => Ok(GeometryDataType::WKB),
+            "WKT" => Ok(GeometryDataType::WKT),
+            "EWKB" => Ok(GeometryDataType::EWKB),
+            "EWKT" => Ok(GeometryDataType::EWKT),
+            "GEOJSON" => Ok(GeometryDataType::GEOJSON),
+            _ => Err(ErrorCode::GeometryError(format!(
+                "Invalid geometry format '{}', valid values are WKB, WKT, EWKB, EWKT and GeoJSON",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for GeometryDataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeometryDataType::WKB => write!(f, "WKB"),
+            GeometryDataType::WKT => write!(f, "WKT"),
+            GeometryDataType::EWKB => write!(f, "EWKB"),
+            GeometryDataType::EWKT => write!(f, "EWKT"),
+            GeometryDataType::GEOJSON => write!(f, "GeoJSON"),
+        }
+    }
+}
+
 pub fn parse_to_ewkb(buf: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
     let wkt = std::str::from_utf8(buf).map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
     let input_wkt = wkt.trim().to_ascii_uppercase();
@@ -41,3 +97,89 @@ pub fn parse_to_ewkb(buf: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
     geom.to_ewkb(CoordDimensions::xy(), parsed_srid)
         .map_err(ErrorCode::from)
 }
+
+/// Parses the geometry from any of the supported input representations:
+/// GeoJSON, hex encoded WKB or EWKB, and WKT or EWKT.
+pub fn parse_any_to_ewkb(buf: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
+    let input = std::str::from_utf8(buf)
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))?
+        .trim();
+    if input.starts_with('{') {
+        parse_geojson_to_ewkb(input, srid)
+    } else if !input.is_empty()
+        && input.len() % 2 == 0
+        && input.bytes().all(|b| b.is_ascii_hexdigit())
+    {
+        let wkb = hex::decode(input).map_err(|e| ErrorCode::GeometryError(e.to_string()))?;
+        parse_wkb_to_ewkb(&wkb, srid)
+    } else {
+        parse_to_ewkb(input.as_bytes(), srid)
+    }
+}
+
+/// Converts the WKB or EWKB to EWKB, the `srid` overrides the SRID of the input.
+pub fn parse_wkb_to_ewkb(wkb: &[u8], srid: Option<i32>) -> Result<Vec<u8>> {
+    let (geom, parsed_srid) = ewkb_to_geo(wkb)?;
+    geo_to_ewkb(geom, srid.or(parsed_srid))
+}
+
+pub fn parse_geojson_to_ewkb(json: &str, srid: Option<i32>) -> Result<Vec<u8>> {
+    let geom = GeoJson(json).to_geo()?;
+    geo_to_ewkb(geom, srid)
+}
+
+pub fn ewkb_to_geo(ewkb: &[u8]) -> Result<(Geometry<f64>, Option<i32>)> {
+    let srid = read_ewkb_srid(ewkb)?;
+    let geom = Ewkb(ewkb.to_vec()).to_geo()?;
+    Ok((geom, srid))
+}
+
+pub fn geo_to_ewkb(geom: Geometry<f64>, srid: Option<i32>) -> Result<Vec<u8>> {
+    geom.to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(ErrorCode::from)
+}
+
+/// Converts the EWKB to WKB, the SRID is dropped.
+pub fn ewkb_to_wkb(ewkb: &[u8]) -> Result<Vec<u8>> {
+    let (geom, _) = ewkb_to_geo(ewkb)?;
+    geom.to_wkb(CoordDimensions::xy()).map_err(ErrorCode::from)
+}
+
+/// Reads the SRID from the header of the EWKB, returns `None` for WKB and the
+/// EWKB without SRID.
+pub fn read_ewkb_srid(ewkb: &[u8]) -> Result<Option<i32>> {
+    if ewkb.len() < 5 {
+        return Err(ErrorCode::GeometryError("Invalid EWKB, too short"));
+    }
+    let is_little_endian = ewkb[0] != 0;
+    let read_u32 = |bytes: [u8; 4]| {
+        if is_little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }
+    };
+    let type_id = read_u32(ewkb[1..5].try_into().unwrap());
+    if type_id & GEO_TYPE_ID_MASK == 0 {
+        return Ok(None);
+    }
+    if ewkb.len() < 9 {
+        return Err(ErrorCode::GeometryError("Invalid EWKB, missing SRID"));
+    }
+    Ok(Some(read_u32(ewkb[5..9].try_into().unwrap()) as i32))
+}
+
+/// Formats the EWKB geometry value in the given representation, the binary
+/// representations are hex encoded.
+pub fn geometry_format(ewkb: &[u8], format: GeometryDataType) -> Result<String> {
+    match format {
+        GeometryDataType::WKB => Ok(hex::encode_upper(ewkb_to_wkb(ewkb)?)),
+        GeometryDataType::EWKB => Ok(hex::encode_upper(ewkb)),
+        GeometryDataType::WKT => Ok(Ewkb(ewkb.to_vec()).to_wkt()?),
+        GeometryDataType::EWKT => {
+            let mut cursor = std::io::Cursor::new(ewkb);
+            Ok(Ewkt::from_wkb(&mut cursor, WkbDialect::Ewkb)?.0)
+        }
+        GeometryDataType::GEOJSON => Ok(Ewkb(ewkb.to_vec()).to_json()?),
+    }
+}
@@ -29,6 +29,7 @@ pub enum Feature {
     VirtualColumn,
     BackgroundService,
     DataMask,
+    RowAccessPolicy,
     AggregateIndex,
     InvertedIndex,
     ComputedColumn,
@@ -57,6 +58,9 @@ impl Display for Feature {
             Feature::DataMask => {
                 write!(f, "data_mask")
             }
+            Feature::RowAccessPolicy => {
+                write!(f, "row_access_policy")
+            }
             Feature::AggregateIndex => {
                 write!(f, "aggregate_index")
             }
@@ -0,0 +1,378 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Hierarchical Navigable Small World graph, as described in
+//! "Efficient and robust approximate nearest neighbor search using
+//! Hierarchical Navigable Small World graphs" (Malkov & Yashunin).
+
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+pub const DEFAULT_HNSW_M: usize = 16;
+pub const DEFAULT_HNSW_EF_CONSTRUCTION: usize = 100;
+pub const DEFAULT_HNSW_EF_SEARCH: usize = 64;
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+)]
+pub enum DistanceType {
+    #[default]
+    Cosine,
+    L2,
+}
+
+impl DistanceType {
+    /// Name of the scalar function which computes the same distance.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            DistanceType::Cosine => "cosine_distance",
+            DistanceType::L2 => "l2_distance",
+        }
+    }
+
+    /// The vectors must have the same length, it is checked when they are added to the index.
+    #[inline]
+    pub fn distance(&self, from: &[f32], to: &[f32]) -> f32 {
+        match self {
+            DistanceType::Cosine => {
+                let mut dot = 0.0;
+                let mut aa_sum = 0.0;
+                let mut bb_sum = 0.0;
+                for (a, b) in from.iter().zip(to.iter()) {
+                    dot += a * b;
+                    aa_sum += a * a;
+                    bb_sum += b * b;
+                }
+                1.0 - dot / (aa_sum.sqrt() * bb_sum.sqrt())
+            }
+            DistanceType::L2 => from
+                .iter()
+                .zip(to.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+}
+
+impl FromStr for DistanceType {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(DistanceType::Cosine),
+            "l2" => Ok(DistanceType::L2),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unsupported vector distance type: {}, expect cosine or l2",
+                s
+            ))),
+        }
+    }
+}
+
+impl Display for DistanceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceType::Cosine => write!(f, "cosine"),
+            DistanceType::L2 => write!(f, "l2"),
+        }
+    }
+}
+
+/// A node id paired with its distance to the query, ordered by the distance.
+#[derive(Clone, Copy, Debug)]
+struct Neighbour {
+    distance: f32,
+    id: u32,
+}
+
+impl PartialEq for Neighbour {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Neighbour {}
+
+impl PartialOrd for Neighbour {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Neighbour {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.id.cmp(&other.id))
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
+pub struct HnswIndex {
+    distance_type: DistanceType,
+    m: usize,
+    ef_construction: usize,
+    dimension: usize,
+    // The row id of each node.
+    row_ids: Vec<u32>,
+    // The vectors of the nodes, stored continuously.
+    vectors: Vec<f32>,
+    // The neighbours of each node on each layer, a node lives on
+    // layers `0..=level` where `level` is randomly chosen on insertion.
+    neighbours: Vec<Vec<Vec<u32>>>,
+    entry_point: Option<u32>,
+    max_level: usize,
+    #[borsh(skip)]
+    rng: Option<SmallRng>,
+}
+
+impl HnswIndex {
+    pub fn new(distance_type: DistanceType, m: usize, ef_construction: usize) -> Self {
+        HnswIndex {
+            distance_type,
+            m: m.max(2),
+            ef_construction: ef_construction.max(1),
+            dimension: 0,
+            row_ids: vec![],
+            vectors: vec![],
+            neighbours: vec![],
+            entry_point: None,
+            max_level: 0,
+            rng: None,
+        }
+    }
+
+    pub fn distance_type(&self) -> DistanceType {
+        self.distance_type
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn len(&self) -> usize {
+        self.row_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.row_ids.is_empty()
+    }
+
+    pub fn insert(&mut self, row_id: u32, vector: &[f32]) -> Result<()> {
+        if self.is_empty() {
+            if vector.is_empty() {
+                return Err(ErrorCode::InvalidArgument(
+                    "Can't build vector index on empty vectors",
+                ));
+            }
+            self.dimension = vector.len();
+        } else if vector.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                vector.len(),
+                self.dimension,
+            )));
+        }
+
+        let id = self.row_ids.len() as u32;
+        let level = self.random_level();
+        self.row_ids.push(row_id);
+        self.vectors.extend_from_slice(vector);
+        self.neighbours.push(vec![vec![]; level + 1]);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(id);
+            self.max_level = level;
+            return Ok(());
+        };
+
+        // Greedy search on the layers above the level of the new node.
+        let mut entry_points = vec![self.neighbour(vector, entry_point)];
+        for layer in (level + 1..=self.max_level).rev() {
+            entry_points = self.search_layer(vector, &entry_points, 1, layer);
+        }
+
+        for layer in (0..=level.min(self.max_level)).rev() {
+            let candidates = self.search_layer(vector, &entry_points, self.ef_construction, layer);
+            let max_neighbours = self.max_neighbours(layer);
+            let selected = candidates
+                .iter()
+                .take(self.m)
+                .map(|n| n.id)
+                .collect::<Vec<_>>();
+
+            for neighbour in selected.iter() {
+                let neighbours = &mut self.neighbours[*neighbour as usize][layer];
+                neighbours.push(id);
+                if neighbours.len() > max_neighbours {
+                    self.shrink_neighbours(*neighbour, layer, max_neighbours);
+                }
+            }
+            self.neighbours[id as usize][layer] = selected;
+            entry_points = candidates;
+        }
+
+        if level > self.max_level {
+            self.entry_point = Some(id);
+            self.max_level = level;
+        }
+        Ok(())
+    }
+
+    /// Returns the row ids and distances of the approximate `k` nearest
+    /// vectors to the query, ordered by the distance.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        let Some(entry_point) = self.entry_point else {
+            return Ok(vec![]);
+        };
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+
+        let mut entry_points = vec![self.neighbour(query, entry_point)];
+        for layer in (1..=self.max_level).rev() {
+            entry_points = self.search_layer(query, &entry_points, 1, layer);
+        }
+        let result = self.search_layer(query, &entry_points, ef.max(k), 0);
+        Ok(result
+            .into_iter()
+            .take(k)
+            .map(|n| (self.row_ids[n.id as usize], n.distance))
+            .collect())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        borsh::to_vec(self).map_err(|e| ErrorCode::Internal(e.to_string()))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        borsh::from_slice(bytes).map_err(|e| ErrorCode::Internal(e.to_string()))
+    }
+
+    fn vector(&self, id: u32) -> &[f32] {
+        let start = id as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    fn neighbour(&self, query: &[f32], id: u32) -> Neighbour {
+        Neighbour {
+            distance: self.distance_type.distance(query, self.vector(id)),
+            id,
+        }
+    }
+
+    fn max_neighbours(&self, layer: usize) -> usize {
+        if layer == 0 { self.m * 2 } else { self.m }
+    }
+
+    fn random_level(&mut self) -> usize {
+        // Seeded so that the same input always builds the same graph.
+        let rng = self
+            .rng
+            .get_or_insert_with(|| SmallRng::seed_from_u64(0x5eed));
+        let uniform: f64 = rng.gen_range(f64::EPSILON..1.0);
+        let level_factor = 1.0 / (self.m as f64).ln();
+        (-uniform.ln() * level_factor).floor() as usize
+    }
+
+    fn shrink_neighbours(&mut self, id: u32, layer: usize, max_neighbours: usize) {
+        let vector = self.vector(id);
+        let mut neighbours = self.neighbours[id as usize][layer]
+            .iter()
+            .map(|n| Neighbour {
+                distance: self.distance_type.distance(vector, self.vector(*n)),
+                id: *n,
+            })
+            .collect::<Vec<_>>();
+        neighbours.sort();
+        self.neighbours[id as usize][layer] = neighbours
+            .into_iter()
+            .take(max_neighbours)
+            .map(|n| n.id)
+            .collect();
+    }
+
+    /// Returns the `ef` nearest nodes to the query on the layer, ordered by the distance.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[Neighbour],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Neighbour> {
+        let mut visited = vec![false; self.row_ids.len()];
+        let mut candidates = BinaryHeap::with_capacity(ef);
+        let mut result = BinaryHeap::with_capacity(ef + 1);
+        for entry_point in entry_points {
+            visited[entry_point.id as usize] = true;
+            candidates.push(Reverse(*entry_point));
+            result.push(*entry_point);
+        }
+        while result.len() > ef {
+            result.pop();
+        }
+
+        while let Some(Reverse(candidate)) = candidates.pop() {
+            let furthest = result.peek().map(|n| n.distance).unwrap_or(f32::MAX);
+            if candidate.distance > furthest && result.len() >= ef {
+                break;
+            }
+            let Some(neighbours) = self.neighbours[candidate.id as usize].get(layer) else {
+                continue;
+            };
+            for id in neighbours {
+                if visited[*id as usize] {
+                    continue;
+                }
+                visited[*id as usize] = true;
+                let neighbour = self.neighbour(query, *id);
+                let furthest = result.peek().map(|n| n.distance).unwrap_or(f32::MAX);
+                if result.len() < ef || neighbour.distance < furthest {
+                    candidates.push(Reverse(neighbour));
+                    result.push(neighbour);
+                    if result.len() > ef {
+                        result.pop();
+                    }
+                }
+            }
+        }
+        result.into_sorted_vec()
+    }
+}
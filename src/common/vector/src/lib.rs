@@ -13,8 +13,14 @@
 // limitations under the License.
 
 mod distance;
+mod hnsw;
 
 pub use distance::cosine_distance;
 pub use distance::cosine_distance_64;
 pub use distance::l2_distance;
 pub use distance::l2_distance_64;
+pub use hnsw::DistanceType;
+pub use hnsw::HnswIndex;
+pub use hnsw::DEFAULT_HNSW_EF_CONSTRUCTION;
+pub use hnsw::DEFAULT_HNSW_EF_SEARCH;
+pub use hnsw::DEFAULT_HNSW_M;
@@ -0,0 +1,109 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::cosine_distance;
+use databend_common_vector::l2_distance;
+use databend_common_vector::DistanceType;
+use databend_common_vector::HnswIndex;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+fn random_vectors(num: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut rng = SmallRng::seed_from_u64(42);
+    (0..num)
+        .map(|_| (0..dimension).map(|_| rng.gen_range(-1.0..1.0)).collect())
+        .collect()
+}
+
+#[test]
+fn test_hnsw_search() {
+    let vectors = random_vectors(2000, 16);
+    let queries = random_vectors(20, 16);
+
+    for distance_type in [DistanceType::Cosine, DistanceType::L2] {
+        let mut index = HnswIndex::new(distance_type, 16, 100);
+        for (row_id, vector) in vectors.iter().enumerate() {
+            index.insert(row_id as u32, vector).unwrap();
+        }
+        assert_eq!(index.len(), 2000);
+        assert_eq!(index.dimension(), 16);
+
+        let k = 10;
+        let mut hits = 0;
+        for query in queries.iter() {
+            let mut expected = vectors
+                .iter()
+                .enumerate()
+                .map(|(row_id, vector)| {
+                    let distance = match distance_type {
+                        DistanceType::Cosine => cosine_distance(query, vector).unwrap(),
+                        DistanceType::L2 => l2_distance(query, vector).unwrap(),
+                    };
+                    (row_id as u32, distance)
+                })
+                .collect::<Vec<_>>();
+            expected.sort_by(|a, b| a.1.total_cmp(&b.1));
+            expected.truncate(k);
+
+            let result = index.search(query, k, 64).unwrap();
+            assert_eq!(result.len(), k);
+            // The result is ordered by the distance.
+            assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+            hits += result
+                .iter()
+                .filter(|(row_id, _)| expected.iter().any(|(id, _)| id == row_id))
+                .count();
+        }
+        let recall = hits as f64 / (k * queries.len()) as f64;
+        assert!(recall >= 0.9, "recall of {} is {}", distance_type, recall);
+    }
+}
+
+#[test]
+fn test_hnsw_serialization() {
+    let vectors = random_vectors(100, 4);
+    let mut index = HnswIndex::new(DistanceType::L2, 8, 50);
+    for (row_id, vector) in vectors.iter().enumerate() {
+        index.insert(row_id as u32 * 2, vector).unwrap();
+    }
+
+    let bytes = index.to_bytes().unwrap();
+    let index2 = HnswIndex::from_bytes(&bytes).unwrap();
+    assert_eq!(index2.distance_type(), DistanceType::L2);
+    assert_eq!(index2.len(), 100);
+
+    let result = index.search(&vectors[3], 5, 16).unwrap();
+    let result2 = index2.search(&vectors[3], 5, 16).unwrap();
+    assert_eq!(result, result2);
+    // The vector itself is the nearest one.
+    assert_eq!(result[0].0, 6);
+}
+
+#[test]
+fn test_hnsw_invalid_vector() {
+    let mut index = HnswIndex::new(DistanceType::Cosine, 16, 100);
+    assert!(index.search(&[1.0, 2.0], 3, 16).unwrap().is_empty());
+    assert!(index.insert(0, &[]).is_err());
+
+    index.insert(0, &[1.0, 2.0, 3.0]).unwrap();
+    assert!(index.insert(1, &[1.0, 2.0]).is_err());
+    assert!(index.search(&[1.0, 2.0], 3, 16).is_err());
+
+    assert_eq!(
+        "COSINE".parse::<DistanceType>().unwrap(),
+        DistanceType::Cosine
+    );
+    assert!("dot".parse::<DistanceType>().is_err());
+}
@@ -26,6 +26,8 @@ mod data_mask_api_impl;
 pub mod kv_app_error;
 pub mod kv_pb_api;
 pub mod reply;
+mod row_access_policy_api;
+mod row_access_policy_api_impl;
 mod schema_api;
 mod schema_api_impl;
 mod schema_api_test_suite;
@@ -41,6 +43,7 @@ pub mod crud;
 pub use background_api::BackgroundApi;
 pub use background_api_test_suite::BackgroundApiTestSuite;
 pub use data_mask_api::DatamaskApi;
+pub use row_access_policy_api::RowAccessPolicyApi;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
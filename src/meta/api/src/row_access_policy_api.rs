@@ -0,0 +1,40 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+
+use crate::kv_app_error::KVAppError;
+
+#[async_trait::async_trait]
+pub trait RowAccessPolicyApi: Send + Sync {
+    async fn create_row_access_policy(
+        &self,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<CreateRowAccessPolicyReply, KVAppError>;
+
+    async fn drop_row_access_policy(
+        &self,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<DropRowAccessPolicyReply, KVAppError>;
+
+    async fn get_row_access_policy(
+        &self,
+        req: GetRowAccessPolicyReq,
+    ) -> Result<GetRowAccessPolicyReply, KVAppError>;
+}
@@ -0,0 +1,361 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+
+use databend_common_meta_app::app_error::AppError;
+use databend_common_meta_app::app_error::RowAccessPolicyAlreadyExists;
+use databend_common_meta_app::app_error::UnknownRowAccessPolicy;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::DropRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReply;
+use databend_common_meta_app::row_access_policy::GetRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyId;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableId;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_types::ConditionResult::Eq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use log::debug;
+use minitrace::func_name;
+
+use crate::fetch_id;
+use crate::get_pb_value;
+use crate::get_u64_value;
+use crate::kv_app_error::KVAppError;
+use crate::row_access_policy_api::RowAccessPolicyApi;
+use crate::send_txn;
+use crate::serialize_struct;
+use crate::serialize_u64;
+use crate::txn_backoff::txn_backoff;
+use crate::txn_cond_seq;
+use crate::txn_op_del;
+use crate::txn_op_put;
+
+/// RowAccessPolicyApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls RowAccessPolicyApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> RowAccessPolicyApi for KV {
+    async fn create_row_access_policy(
+        &self,
+        req: CreateRowAccessPolicyReq,
+    ) -> Result<CreateRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let mut trials = txn_backoff(None, func_name!());
+        let id = loop {
+            trials.next().unwrap()?.await;
+
+            // Get policy by name to ensure absence
+            let (seq, id) = get_u64_value(self, name_key).await?;
+            debug!(seq = seq, id = id, name_key :? =(name_key); "create_row_access_policy");
+
+            let mut condition = vec![];
+            let mut if_then = vec![];
+
+            if seq > 0 {
+                match req.create_option {
+                    CreateOption::Create => {
+                        return Err(KVAppError::AppError(
+                            AppError::RowAccessPolicyAlreadyExists(
+                                RowAccessPolicyAlreadyExists::new(
+                                    &name_key.name,
+                                    format!("create row access policy: {}", req.name),
+                                ),
+                            ),
+                        ));
+                    }
+                    CreateOption::CreateIfNotExists => {
+                        return Ok(CreateRowAccessPolicyReply { id });
+                    }
+                    CreateOption::CreateOrReplace => {
+                        construct_drop_row_access_policy_operations(
+                            self,
+                            name_key,
+                            false,
+                            false,
+                            func_name!(),
+                            &mut condition,
+                            &mut if_then,
+                        )
+                        .await?;
+                    }
+                };
+            };
+
+            // Create row access policy by inserting these record:
+            // name -> id
+            // id -> policy
+            // policy name -> table id list
+            //
+            // When a policy is replaced, the tables it is attached to are kept,
+            // thus the table id list is only initialized for a new policy.
+
+            let id = fetch_id(self, IdGenerator::row_access_policy_id()).await?;
+            let id_key = RowAccessPolicyId { id };
+            let id_list_key = RowAccessPolicyTableIdListKey {
+                tenant: name_key.tenant.clone(),
+                name: name_key.name.clone(),
+            };
+
+            debug!(
+                id :? =(&id_key),
+                name_key :? =(name_key);
+                "new row access policy id"
+            );
+
+            {
+                let meta: RowAccessPolicyMeta = req.clone().into();
+                condition.push(txn_cond_seq(name_key, Eq, seq));
+                if_then.extend(vec![
+                    txn_op_put(name_key, serialize_u64(id)?), // name -> policy_id
+                    txn_op_put(&id_key, serialize_struct(&meta)?), // id -> meta
+                ]);
+                if seq == 0 {
+                    let id_list = RowAccessPolicyTableIdList::default();
+                    // policy name -> id_list
+                    if_then.push(txn_op_put(&id_list_key, serialize_struct(&id_list)?));
+                }
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name :? =(name_key),
+                    id :? =(&id_key),
+                    succ = succ;
+                    "create_row_access_policy"
+                );
+
+                if succ {
+                    break id;
+                }
+            }
+        };
+
+        Ok(CreateRowAccessPolicyReply { id })
+    }
+
+    async fn drop_row_access_policy(
+        &self,
+        req: DropRowAccessPolicyReq,
+    ) -> Result<DropRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let mut condition = vec![];
+            let mut if_then = vec![];
+
+            construct_drop_row_access_policy_operations(
+                self,
+                name_key,
+                req.if_exists,
+                true,
+                func_name!(),
+                &mut condition,
+                &mut if_then,
+            )
+            .await?;
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                succ = succ;
+                "drop_row_access_policy"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropRowAccessPolicyReply {})
+    }
+
+    async fn get_row_access_policy(
+        &self,
+        req: GetRowAccessPolicyReq,
+    ) -> Result<GetRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "RowAccessPolicyApi: {}", func_name!());
+
+        let name_key = &req.name;
+
+        let (_id_seq, _id, _policy_seq, policy) = get_row_access_policy_or_err(
+            self,
+            name_key,
+            format!("get_row_access_policy: {}", name_key),
+        )
+        .await?;
+
+        Ok(GetRowAccessPolicyReply { policy })
+    }
+}
+
+/// Returns (id_seq, id, policy_seq, policy)
+async fn get_row_access_policy_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(u64, u64, u64, RowAccessPolicyMeta), KVAppError> {
+    let (id_seq, id) = get_u64_value(kv_api, name_key).await?;
+    row_access_policy_has_to_exist(id_seq, name_key, &msg)?;
+
+    let id_key = RowAccessPolicyId { id };
+
+    let (policy_seq, policy) = get_pb_value(kv_api, &id_key).await?;
+    row_access_policy_has_to_exist(policy_seq, name_key, msg)?;
+
+    Ok((
+        id_seq,
+        id,
+        policy_seq,
+        // Safe unwrap(): policy_seq > 0 implies policy is not None.
+        policy.unwrap(),
+    ))
+}
+
+/// Return OK if a row access policy exists by checking the seq.
+///
+/// Otherwise returns UnknownRowAccessPolicy error
+pub fn row_access_policy_has_to_exist(
+    seq: u64,
+    name_ident: &RowAccessPolicyNameIdent,
+    msg: impl Display,
+) -> Result<(), KVAppError> {
+    if seq == 0 {
+        debug!(seq = seq, name_ident :? =(name_ident); "row access policy does not exist");
+
+        Err(KVAppError::AppError(AppError::UnknownRowAccessPolicy(
+            UnknownRowAccessPolicy::new(&name_ident.name, format!("{}: {}", msg, name_ident)),
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+async fn clear_table_row_access_policy(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_ident: &RowAccessPolicyNameIdent,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let id_list_key = RowAccessPolicyTableIdListKey {
+        tenant: name_ident.tenant.clone(),
+        name: name_ident.name.clone(),
+    };
+    let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+        get_pb_value(kv_api, &id_list_key).await?;
+    if let Some(id_list) = id_list_opt {
+        condition.push(txn_cond_seq(&id_list_key, Eq, id_list_seq));
+        if_then.push(txn_op_del(&id_list_key));
+
+        // remove row access policy from table meta
+        for table_id in id_list.id_list.into_iter() {
+            let tbid = TableId { table_id };
+
+            let (tb_meta_seq, table_meta_opt): (_, Option<TableMeta>) =
+                get_pb_value(kv_api, &tbid).await?;
+            if let Some(mut table_meta) = table_meta_opt {
+                if table_meta
+                    .row_access_policy
+                    .as_ref()
+                    .is_some_and(|p| p.policy == name_ident.name)
+                {
+                    table_meta.row_access_policy = None;
+
+                    condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                    if_then.push(txn_op_put(&tbid, serialize_struct(&table_meta)?));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn construct_drop_row_access_policy_operations(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_key: &RowAccessPolicyNameIdent,
+    drop_if_exists: bool,
+    if_delete: bool,
+    ctx: &str,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> Result<(), KVAppError> {
+    let result = get_row_access_policy_or_err(
+        kv_api,
+        name_key,
+        format!("drop_row_access_policy: {}", name_key),
+    )
+    .await;
+
+    let (id_seq, id, policy_seq) = match result {
+        Ok((id_seq, id, policy_seq, _)) => (id_seq, id, policy_seq),
+        Err(err) => {
+            if let KVAppError::AppError(AppError::UnknownRowAccessPolicy(_)) = err {
+                if drop_if_exists {
+                    return Ok(());
+                }
+            }
+
+            return Err(err);
+        }
+    };
+    let id_key = RowAccessPolicyId { id };
+
+    condition.push(txn_cond_seq(&id_key, Eq, policy_seq));
+    if_then.push(txn_op_del(&id_key));
+
+    if if_delete {
+        condition.push(txn_cond_seq(name_key, Eq, id_seq));
+        if_then.push(txn_op_del(name_key));
+        clear_table_row_access_policy(kv_api, name_key, condition, if_then).await?;
+    }
+
+    debug!(
+        name :? =(name_key),
+        id :? =(&RowAccessPolicyId { id }),
+        ctx = ctx;
+        "construct_drop_row_access_policy_operations"
+    );
+
+    Ok(())
+}
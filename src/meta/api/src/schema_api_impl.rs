@@ -35,6 +35,7 @@ use databend_common_meta_app::app_error::DuplicatedUpsertFiles;
 use databend_common_meta_app::app_error::GetIndexWithDropTime;
 use databend_common_meta_app::app_error::IndexAlreadyExists;
 use databend_common_meta_app::app_error::MultiStmtTxnCommitFailed;
+use databend_common_meta_app::app_error::PipeAlreadyExists;
 use databend_common_meta_app::app_error::ShareHasNoGrantedPrivilege;
 use databend_common_meta_app::app_error::StreamAlreadyExists;
 use databend_common_meta_app::app_error::StreamVersionMismatched;
@@ -59,6 +60,8 @@ use databend_common_meta_app::app_error::WrongShareObject;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdListKey;
 use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListKey;
 use databend_common_meta_app::schema::CatalogId;
 use databend_common_meta_app::schema::CatalogIdToName;
 use databend_common_meta_app::schema::CatalogInfo;
@@ -76,6 +79,8 @@ use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableIndexReply;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
@@ -100,6 +105,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableIndexReply;
 use databend_common_meta_app::schema::DropTableIndexReq;
@@ -116,6 +123,8 @@ use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::GetLVTReply;
 use databend_common_meta_app::schema::GetLVTReq;
+use databend_common_meta_app::schema::GetPipeReply;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::GetTableReq;
@@ -133,10 +142,13 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListTableReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::PipeNameIdent;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
@@ -146,6 +158,9 @@ use databend_common_meta_app::schema::SetLVTReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -158,6 +173,7 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::schema::TruncateTableReply;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReply;
@@ -167,6 +183,8 @@ use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeReply;
+use databend_common_meta_app::schema::UpdatePipeReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -237,6 +255,7 @@ use crate::util::deserialize_id_get_response;
 use crate::util::deserialize_struct_get_response;
 use crate::util::deserialize_u64;
 use crate::util::get_index_metas_by_ids;
+use crate::util::get_pipe_or_err;
 use crate::util::get_table_by_id_or_err;
 use crate::util::get_table_names_by_ids;
 use crate::util::get_virtual_column_by_id_or_err;
@@ -1485,6 +1504,181 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         Ok(virtual_column_list)
     }
 
+    #[minitrace::trace]
+    async fn create_pipe(&self, req: CreatePipeReq) -> Result<CreatePipeReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, old_pipe_opt): (_, Option<PipeMeta>) =
+                get_pb_value(self, &req.name_ident).await?;
+
+            if old_pipe_opt.is_some() {
+                match req.create_option {
+                    CreateOption::Create => {
+                        return Err(KVAppError::AppError(AppError::PipeAlreadyExists(
+                            PipeAlreadyExists::new(
+                                &req.name_ident.pipe_name,
+                                format!("create pipe: {}", req.name_ident),
+                            ),
+                        )));
+                    }
+                    CreateOption::CreateIfNotExists => {
+                        return Ok(CreatePipeReply {});
+                    }
+                    CreateOption::CreateOrReplace => {}
+                }
+            }
+
+            // Create pipe by inserting this record:
+            // (tenant, pipe_name) -> pipe_meta
+            {
+                let condition = vec![txn_cond_seq(&req.name_ident, Eq, seq)];
+                let if_then = vec![txn_op_put(&req.name_ident, serialize_struct(&req.meta)?)];
+
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name_ident :? =(&req.name_ident),
+                    succ = succ;
+                    "create_pipe"
+                );
+
+                if succ {
+                    break;
+                }
+            }
+        }
+
+        Ok(CreatePipeReply {})
+    }
+
+    #[minitrace::trace]
+    async fn drop_pipe(&self, req: DropPipeReq) -> Result<DropPipeReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let seq = match get_pipe_or_err(self, &req.name_ident, func_name!()).await {
+                Ok((seq, _)) => seq,
+                Err(err) => {
+                    if req.if_exists {
+                        return Ok(DropPipeReply {});
+                    }
+                    return Err(err);
+                }
+            };
+
+            // Drop pipe by deleting this record:
+            // (tenant, pipe_name) -> pipe_meta
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&req.name_ident, Eq, seq)],
+                if_then: vec![txn_op_del(&req.name_ident)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name_ident :? =(&req.name_ident),
+                succ = succ;
+                "drop_pipe"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(DropPipeReply {})
+    }
+
+    #[minitrace::trace]
+    async fn get_pipe(&self, req: GetPipeReq) -> Result<GetPipeReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let (_, meta) = get_pipe_or_err(self, &req.name_ident, func_name!()).await?;
+
+        Ok(GetPipeReply { meta })
+    }
+
+    #[minitrace::trace]
+    async fn update_pipe(&self, req: UpdatePipeReq) -> Result<UpdatePipeReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (seq, mut meta) = match get_pipe_or_err(self, &req.name_ident, func_name!()).await {
+                Ok((seq, meta)) => (seq, meta),
+                Err(err) => {
+                    if req.if_exists {
+                        return Ok(UpdatePipeReply {});
+                    }
+                    return Err(err);
+                }
+            };
+
+            if let Some(execution_paused) = req.execution_paused {
+                meta.execution_paused = execution_paused;
+            }
+            if let Some(comment) = &req.comment {
+                meta.comment = comment.clone();
+            }
+            meta.updated_on = Some(Utc::now());
+
+            let txn_req = TxnRequest {
+                condition: vec![txn_cond_seq(&req.name_ident, Eq, seq)],
+                if_then: vec![txn_op_put(&req.name_ident, serialize_struct(&meta)?)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name_ident :? =(&req.name_ident),
+                succ = succ;
+                "update_pipe"
+            );
+
+            if succ {
+                break;
+            }
+        }
+
+        Ok(UpdatePipeReply {})
+    }
+
+    #[minitrace::trace]
+    async fn list_pipes(&self, req: ListPipesReq) -> Result<Vec<PipeMeta>, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+
+        // Get pipes list by `prefix_list` "<prefix>/<tenant>"
+        let prefix_key = kvapi::KeyBuilder::new_prefixed(PipeNameIdent::PREFIX)
+            .push_str(&req.tenant)
+            .done();
+
+        let list = self.prefix_list_kv(&prefix_key).await?;
+        let mut pipes = Vec::with_capacity(list.len());
+        for (_, seq) in list.iter() {
+            let pipe_meta: PipeMeta = deserialize_struct(&seq.data)?;
+            pipes.push(pipe_meta);
+        }
+
+        Ok(pipes)
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn create_table(&self, req: CreateTableReq) -> Result<CreateTableReply, KVAppError> {
@@ -3221,6 +3415,99 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                get_pb_value(self, &tbid).await?;
+
+            debug!(ident :% =(&tbid); "set_table_row_access_policy");
+
+            if tb_meta_seq == 0 || table_meta.is_none() {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            }
+            if req_seq.match_seq(tb_meta_seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        tb_meta_seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let table_meta = table_meta.unwrap();
+            let old_policy = table_meta
+                .row_access_policy
+                .as_ref()
+                .map(|p| p.policy.clone());
+
+            let mut new_table_meta = table_meta;
+            match &req.action {
+                SetTableRowAccessPolicyAction::Set(policy, columns) => {
+                    new_table_meta.row_access_policy = Some(TableRowAccessPolicy {
+                        policy: policy.clone(),
+                        columns: columns.clone(),
+                    });
+                }
+                SetTableRowAccessPolicyAction::Unset(_) => {
+                    new_table_meta.row_access_policy = None;
+                }
+            }
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, tb_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+                ],
+                else_then: vec![],
+            };
+
+            update_row_access_policy(
+                self,
+                &req.action,
+                old_policy,
+                &mut txn_req,
+                &req.tenant,
+                req.table_id,
+            )
+            .await?;
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id :? =(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {
+                    share_table_info: get_share_table_info_map(self, &new_table_meta).await?,
+                });
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn create_table_index(
@@ -3278,6 +3565,9 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
             let index = TableIndex {
                 name: req.name.clone(),
                 column_ids: req.column_ids.clone(),
+                index_type: req.index_type,
+                options: req.options.clone(),
+                version: req.version.clone(),
             };
             indexes.insert(req.name.clone(), index);
 
@@ -5572,6 +5862,52 @@ async fn update_mask_policy(
     Ok(())
 }
 
+/// Maintain the table id lists of the row access policies touched by `action`,
+/// `old_policy` is the policy attached to the table before the action.
+async fn update_row_access_policy(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    action: &SetTableRowAccessPolicyAction,
+    old_policy: Option<String>,
+    txn_req: &mut TxnRequest,
+    tenant: &str,
+    table_id: u64,
+) -> Result<(), KVAppError> {
+    let (add, remove) = match action {
+        SetTableRowAccessPolicyAction::Set(policy, _) => {
+            if old_policy.as_ref() == Some(policy) {
+                return Ok(());
+            }
+            (Some(policy.clone()), old_policy)
+        }
+        SetTableRowAccessPolicyAction::Unset(policy) => (None, Some(policy.clone())),
+    };
+
+    for (name, insert) in add
+        .into_iter()
+        .map(|n| (n, true))
+        .chain(remove.into_iter().map(|n| (n, false)))
+    {
+        let key = RowAccessPolicyTableIdListKey::new(tenant, name);
+        let (id_list_seq, id_list_opt): (_, Option<RowAccessPolicyTableIdList>) =
+            get_pb_value(kv_api, &key).await?;
+
+        if let Some(mut id_list) = id_list_opt {
+            if insert {
+                id_list.id_list.insert(table_id);
+            } else {
+                id_list.id_list.remove(&table_id);
+            }
+
+            txn_req.condition.push(txn_cond_seq(&key, Eq, id_list_seq));
+            txn_req
+                .if_then
+                .push(txn_op_put(&key, serialize_struct(&id_list)?));
+        }
+    }
+
+    Ok(())
+}
+
 /// Return OK if a table lock exists by checking the seq.
 ///
 /// Otherwise returns TableLockExpired error
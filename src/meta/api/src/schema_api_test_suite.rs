@@ -99,6 +99,7 @@ use databend_common_meta_app::schema::TableIdList;
 use databend_common_meta_app::schema::TableIdListKey;
 use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_meta_app::schema::TableMeta;
@@ -1435,6 +1436,7 @@ impl SchemaApiTestSuite {
                         bucket: "bucket".to_string(),
                         ..Default::default()
                     })),
+                    rest: None,
                 }),
                 created_on: Utc::now(),
             },
@@ -5795,6 +5797,9 @@ impl SchemaApiTestSuite {
                 table_id,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
+                index_type: TableIndexType::Inverted,
+                options: BTreeMap::new(),
+                version: "".to_string(),
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_ok());
@@ -5804,6 +5809,9 @@ impl SchemaApiTestSuite {
                 table_id,
                 name: index_name_2.clone(),
                 column_ids: index_column_ids_2.clone(),
+                index_type: TableIndexType::Inverted,
+                options: BTreeMap::new(),
+                version: "".to_string(),
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_ok());
@@ -5816,6 +5824,9 @@ impl SchemaApiTestSuite {
                 table_id,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
+                index_type: TableIndexType::Inverted,
+                options: BTreeMap::new(),
+                version: "".to_string(),
             };
 
             let res = mt.create_table_index(req).await;
@@ -5833,6 +5844,9 @@ impl SchemaApiTestSuite {
                 table_id,
                 name: index_name_1.clone(),
                 column_ids: index_column_ids_1.clone(),
+                index_type: TableIndexType::Inverted,
+                options: BTreeMap::new(),
+                version: "".to_string(),
             };
 
             let res = mt.create_table_index(req).await;
@@ -5846,6 +5860,9 @@ impl SchemaApiTestSuite {
                 table_id,
                 name: index_name_3.clone(),
                 column_ids: index_column_ids_3.clone(),
+                index_type: TableIndexType::Inverted,
+                options: BTreeMap::new(),
+                version: "".to_string(),
             };
             let res = mt.create_table_index(req).await;
             assert!(res.is_err());
@@ -22,6 +22,7 @@ use databend_common_meta_app::app_error::AppError;
 use databend_common_meta_app::app_error::ShareHasNoGrantedDatabase;
 use databend_common_meta_app::app_error::UnknownDatabase;
 use databend_common_meta_app::app_error::UnknownDatabaseId;
+use databend_common_meta_app::app_error::UnknownPipe;
 use databend_common_meta_app::app_error::UnknownShare;
 use databend_common_meta_app::app_error::UnknownShareAccounts;
 use databend_common_meta_app::app_error::UnknownShareEndpoint;
@@ -41,6 +42,8 @@ use databend_common_meta_app::schema::DatabaseNameIdent;
 use databend_common_meta_app::schema::DatabaseType;
 use databend_common_meta_app::schema::IndexId;
 use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::PipeNameIdent;
 use databend_common_meta_app::schema::TableId;
 use databend_common_meta_app::schema::TableIdToName;
 use databend_common_meta_app::schema::TableIdent;
@@ -1245,3 +1248,28 @@ pub async fn get_virtual_column_by_id_or_err(
 
     Ok((seq, virtual_column_meta))
 }
+
+/// Get pipe meta by name, returns (seq, pipe_meta).
+///
+/// Returns UnknownPipe error if the pipe does not exist.
+pub async fn get_pipe_or_err(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    name_ident: &PipeNameIdent,
+    ctx: impl Display + Copy,
+) -> Result<(u64, PipeMeta), KVAppError> {
+    let (seq, pipe_meta): (_, Option<PipeMeta>) = get_pb_value(kv_api, name_ident).await?;
+    let Some(pipe_meta) = pipe_meta else {
+        return Err(KVAppError::AppError(AppError::UnknownPipe(
+            UnknownPipe::new(&name_ident.pipe_name, format!("get pipe: {}", name_ident)),
+        )));
+    };
+
+    debug!(
+        ident :% =(name_ident),
+        pipe_meta :? =(&pipe_meta);
+        "{}",
+        ctx
+    );
+
+    Ok((seq, pipe_meta))
+}
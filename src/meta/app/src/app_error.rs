@@ -74,6 +74,22 @@ impl DatamaskAlreadyExists {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("RowAccessPolicyAlreadyExists: `{name}` while `{context}`")]
+pub struct RowAccessPolicyAlreadyExists {
+    name: String,
+    context: String,
+}
+
+impl RowAccessPolicyAlreadyExists {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("BackgroundJobAlreadyExists: `{name}` while `{context}`")]
 pub struct BackgroundJobAlreadyExists {
@@ -398,6 +414,22 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error("UnknownRowAccessPolicy: `{name}` while `{context}`")]
+pub struct UnknownRowAccessPolicy {
+    name: String,
+    context: String,
+}
+
+impl UnknownRowAccessPolicy {
+    pub fn new(name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownBackgroundJob: `{name}` while `{context}`")]
 pub struct UnknownBackgroundJob {
@@ -885,6 +917,38 @@ impl VirtualColumnNotFound {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("PipeAlreadyExists: `{pipe_name}` while `{context}`")]
+pub struct PipeAlreadyExists {
+    pipe_name: String,
+    context: String,
+}
+
+impl PipeAlreadyExists {
+    pub fn new(pipe_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            pipe_name: pipe_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("UnknownPipe: `{pipe_name}` while `{context}`")]
+pub struct UnknownPipe {
+    pipe_name: String,
+    context: String,
+}
+
+impl UnknownPipe {
+    pub fn new(pipe_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            pipe_name: pipe_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 /// Application error.
 ///
 /// The application does not get expected result but there is nothing wrong with meta-service.
@@ -1020,6 +1084,12 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDatamask(#[from] UnknownDatamask),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] RowAccessPolicyAlreadyExists),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownRowAccessPolicy),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] BackgroundJobAlreadyExists),
 
@@ -1047,6 +1117,12 @@ pub enum AppError {
     #[error(transparent)]
     UnknownStreamId(#[from] UnknownStreamId),
 
+    #[error(transparent)]
+    PipeAlreadyExists(#[from] PipeAlreadyExists),
+
+    #[error(transparent)]
+    UnknownPipe(#[from] UnknownPipe),
+
     #[error(transparent)]
     MultiStatementTxnCommitFailed(#[from] MultiStmtTxnCommitFailed),
 }
@@ -1121,6 +1197,18 @@ impl AppErrorMessage for StreamVersionMismatched {}
 
 impl AppErrorMessage for UnknownStreamId {}
 
+impl AppErrorMessage for PipeAlreadyExists {
+    fn message(&self) -> String {
+        format!("Pipe '{}' already exists", self.pipe_name)
+    }
+}
+
+impl AppErrorMessage for UnknownPipe {
+    fn message(&self) -> String {
+        format!("Unknown pipe '{}'", self.pipe_name)
+    }
+}
+
 impl AppErrorMessage for MultiStmtTxnCommitFailed {}
 
 impl AppErrorMessage for DuplicatedUpsertFiles {}
@@ -1344,6 +1432,18 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyAlreadyExists {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' already exists", self.name)
+    }
+}
+
+impl AppErrorMessage for UnknownRowAccessPolicy {
+    fn message(&self) -> String {
+        format!("Row access policy '{}' does not exists", self.name)
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1418,6 +1518,8 @@ impl From<AppError> for ErrorCode {
                 ErrorCode::StreamVersionMismatched(err.message())
             }
             AppError::UnknownStreamId(err) => ErrorCode::UnknownStreamId(err.message()),
+            AppError::PipeAlreadyExists(err) => ErrorCode::PipeAlreadyExists(err.message()),
+            AppError::UnknownPipe(err) => ErrorCode::UnknownPipe(err.message()),
             AppError::ShareAlreadyExists(err) => ErrorCode::ShareAlreadyExists(err.message()),
             AppError::UnknownShare(err) => ErrorCode::UnknownShare(err.message()),
             AppError::UnknownShareId(err) => ErrorCode::UnknownShareId(err.message()),
@@ -1456,6 +1558,12 @@ impl From<AppError> for ErrorCode {
             AppError::GetIndexWithDropTIme(err) => ErrorCode::GetIndexWithDropTime(err.message()),
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDatamask(err) => ErrorCode::UnknownDatamask(err.message()),
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
 
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
@@ -27,6 +27,7 @@ pub(crate) const ID_GEN_SHARE: &str = "share_id";
 pub(crate) const ID_GEN_SHARE_ENDPOINT: &str = "share_endpoint_id";
 
 pub(crate) const ID_GEN_DATA_MASK: &str = "data_mask";
+pub(crate) const ID_GEN_ROW_ACCESS_POLICY: &str = "row_access_policy";
 pub(crate) const ID_GEN_BACKGROUND_JOB: &str = "background_job";
 
 /// Key for resource id generator
@@ -79,6 +80,12 @@ impl IdGenerator {
         }
     }
 
+    pub fn row_access_policy_id() -> Self {
+        Self {
+            resource: ID_GEN_ROW_ACCESS_POLICY.to_string(),
+        }
+    }
+
     pub fn table_lock_id() -> Self {
         Self {
             resource: ID_GEN_TABLE_LOCK.to_string(),
@@ -194,6 +201,15 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        {
+            let g1 = IdGenerator::row_access_policy_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/row_access_policy", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         {
             let g1 = IdGenerator::table_lock_id();
             let k = g1.to_string_key();
@@ -112,6 +112,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Orc(OrcFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -123,6 +125,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
         }
     }
 
@@ -140,6 +144,10 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -155,6 +163,10 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            // Avro object container files carry their own block codec.
+            FileFormatParams::Avro(_) => StageFileCompression::None,
+            // ORC compresses the streams inside each stripe.
+            FileFormatParams::Orc(_) => StageFileCompression::None,
         }
     }
 
@@ -205,6 +217,13 @@ impl FileFormatParams {
                     missing_field_as.as_deref(),
                 )?)
             }
+            StageFileFormatType::Avro => {
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    missing_field_as.as_deref(),
+                )?)
+            }
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams::default()),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -653,6 +672,36 @@ impl ParquetFileFormatParams {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub missing_field_as: NullAs,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(missing_field_as: Option<&str>) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self { missing_field_as })
+    }
+
+    pub fn downcast_unchecked(params: &FileFormatParams) -> &AvroFileFormatParams {
+        match params {
+            FileFormatParams::Avro(p) => p,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Default for AvroFileFormatParams {
+    fn default() -> Self {
+        AvroFileFormatParams {
+            missing_field_as: NullAs::Error,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -718,6 +767,16 @@ impl Display for FileFormatParams {
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO MISSING_FIELD_AS = {}",
+                    params.missing_field_as
+                )
+            }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
         }
     }
 }
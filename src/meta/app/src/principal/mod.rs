@@ -33,6 +33,7 @@ mod user_quota;
 mod user_setting;
 mod user_stage;
 mod user_stage_file_ident;
+mod workload_group;
 
 pub mod connection_ident;
 pub mod network_policy_ident;
@@ -40,6 +41,7 @@ pub mod password_policy_ident;
 pub mod user_defined_file_format_ident;
 pub mod user_setting_ident;
 pub mod user_stage_ident;
+pub mod workload_group_ident;
 
 pub use connection::*;
 pub use file_format::*;
@@ -81,3 +83,5 @@ pub use user_setting_ident::SettingIdent;
 pub use user_stage::*;
 pub use user_stage_file_ident::StageFileIdent;
 pub use user_stage_ident::StageIdent;
+pub use workload_group::WorkloadGroup;
+pub use workload_group_ident::WorkloadGroupIdent;
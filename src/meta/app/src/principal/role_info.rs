@@ -28,6 +28,9 @@ pub struct RoleInfo {
     pub name: String,
 
     pub grants: UserGrantSet,
+
+    /// The workload group the queries of the role run in, if the user is not bound to one.
+    pub workload_group: Option<String>,
 }
 
 /// Error when ser/de RoleInfo
@@ -42,6 +45,7 @@ impl RoleInfo {
         Self {
             name: name.to_string(),
             grants: UserGrantSet::empty(),
+            workload_group: None,
         }
     }
 
@@ -164,6 +164,8 @@ pub struct UserOption {
     network_policy: Option<String>,
 
     password_policy: Option<String>,
+
+    workload_group: Option<String>,
 }
 
 impl UserOption {
@@ -173,6 +175,7 @@ impl UserOption {
             default_role: None,
             network_policy: None,
             password_policy: None,
+            workload_group: None,
         }
     }
 
@@ -200,6 +203,11 @@ impl UserOption {
         self
     }
 
+    pub fn with_workload_group(mut self, workload_group: Option<String>) -> Self {
+        self.workload_group = workload_group;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -221,6 +229,10 @@ impl UserOption {
         self.password_policy.as_ref()
     }
 
+    pub fn workload_group(&self) -> Option<&String> {
+        self.workload_group.as_ref()
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -233,6 +245,10 @@ impl UserOption {
         self.password_policy = password_policy;
     }
 
+    pub fn set_workload_group(&mut self, workload_group: Option<String>) {
+        self.workload_group = workload_group;
+    }
+
     pub fn set_all_flag(&mut self) {
         self.flags = BitFlags::all();
     }
@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A workload group isolates the resource usage of the queries issued by
+/// the users bound to it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// Percentage of the cpu time of all the cores of a node the queries of the group
+    /// may use, in [1, 100], 0 means unlimited.
+    pub cpu_quota: u64,
+    /// Max bytes of memory used by the running queries of the group, 0 means unlimited.
+    pub memory_quota: u64,
+    /// Max number of queries of the group running at the same time, 0 means unlimited.
+    pub max_concurrency: u64,
+    /// Seconds a query may wait for a slot of the group, 0 means no timeout.
+    pub queue_timeout: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
@@ -70,7 +70,24 @@ pub struct HiveCatalogOption {
 /// Option for creating a iceberg catalog
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct IcebergCatalogOption {
+    /// Storage of the iceberg warehouse.
+    ///
+    /// It's `StorageParams::None` for a REST catalog created without a storage connection.
     pub storage_params: Box<StorageParams>,
+    /// Set if databases and tables are served by an Iceberg REST catalog,
+    /// instead of being discovered from the directories under `storage_params`.
+    pub rest: Option<IcebergRestCatalogOption>,
+}
+
+/// Option for an iceberg catalog served by the Iceberg REST catalog protocol
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IcebergRestCatalogOption {
+    /// Base uri of the REST catalog service, e.g. `http://127.0.0.1:8181`
+    pub uri: String,
+    /// Warehouse identifier sent to the service, empty if not specified.
+    pub warehouse: String,
+    /// Bearer token used to authenticate requests, empty if not specified.
+    pub token: String,
 }
 
 /// Same as `CatalogNameIdent`, but with `serde` support,
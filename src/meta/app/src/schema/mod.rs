@@ -21,6 +21,7 @@ mod index;
 mod least_visible_time;
 mod lock;
 mod ownership;
+mod pipe;
 mod table;
 mod virtual_column;
 pub use catalog::*;
@@ -63,6 +64,17 @@ pub use lock::LockMeta;
 pub use lock::LockType;
 pub use lock::TableLockKey;
 pub use ownership::Ownership;
+pub use pipe::CreatePipeReply;
+pub use pipe::CreatePipeReq;
+pub use pipe::DropPipeReply;
+pub use pipe::DropPipeReq;
+pub use pipe::GetPipeReply;
+pub use pipe::GetPipeReq;
+pub use pipe::ListPipesReq;
+pub use pipe::PipeMeta;
+pub use pipe::PipeNameIdent;
+pub use pipe::UpdatePipeReply;
+pub use pipe::UpdatePipeReq;
 pub use table::CountTablesKey;
 pub use table::CountTablesReply;
 pub use table::CountTablesReq;
@@ -91,6 +103,9 @@ pub use table::RenameTableReq;
 pub use table::SetTableColumnMaskPolicyAction;
 pub use table::SetTableColumnMaskPolicyReply;
 pub use table::SetTableColumnMaskPolicyReq;
+pub use table::SetTableRowAccessPolicyAction;
+pub use table::SetTableRowAccessPolicyReply;
+pub use table::SetTableRowAccessPolicyReq;
 pub use table::TableCopiedFileInfo;
 pub use table::TableCopiedFileNameIdent;
 pub use table::TableId;
@@ -99,10 +114,12 @@ pub use table::TableIdListKey;
 pub use table::TableIdToName;
 pub use table::TableIdent;
 pub use table::TableIndex;
+pub use table::TableIndexType;
 pub use table::TableInfo;
 pub use table::TableInfoFilter;
 pub use table::TableMeta;
 pub use table::TableNameIdent;
+pub use table::TableRowAccessPolicy;
 pub use table::TableStatistics;
 pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
@@ -0,0 +1,229 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use super::CreateOption;
+use crate::principal::UserIdentity;
+
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct PipeNameIdent {
+    pub tenant: String,
+    pub pipe_name: String,
+}
+
+impl PipeNameIdent {
+    pub fn new(tenant: impl Into<String>, pipe_name: impl Into<String>) -> PipeNameIdent {
+        PipeNameIdent {
+            tenant: tenant.into(),
+            pipe_name: pipe_name.into(),
+        }
+    }
+
+    pub fn pipe_name(&self) -> String {
+        self.pipe_name.clone()
+    }
+}
+
+impl Display for PipeNameIdent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'.'{}'", self.tenant, self.pipe_name)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeMeta {
+    pub name: String,
+    /// The `COPY INTO <table>` statement run by the pipe,
+    /// the table name is fully qualified when the pipe is created.
+    pub copy_stmt: String,
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    /// The role owning the pipe, the auto ingestion runs with its privileges.
+    pub owner: Option<String>,
+    /// The user who created the pipe, the auto ingestion runs as this user.
+    pub creator: Option<UserIdentity>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: Option<DateTime<Utc>>,
+}
+
+impl Default for PipeMeta {
+    fn default() -> Self {
+        PipeMeta {
+            name: "".to_string(),
+            copy_stmt: "".to_string(),
+            auto_ingest: false,
+            execution_paused: false,
+            comment: "".to_string(),
+            owner: None,
+            creator: None,
+            created_on: Utc::now(),
+            updated_on: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipeReq {
+    pub create_option: CreateOption,
+    pub name_ident: PipeNameIdent,
+    pub meta: PipeMeta,
+}
+
+impl Display for CreatePipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "create_pipe({:?}):{}={:?}",
+            self.create_option, self.name_ident, self.meta
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipeReply {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipeReq {
+    pub if_exists: bool,
+    pub name_ident: PipeNameIdent,
+}
+
+impl Display for DropPipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "drop_pipe(if_exists={}):{}",
+            self.if_exists, self.name_ident
+        )
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DropPipeReply {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetPipeReq {
+    pub name_ident: PipeNameIdent,
+}
+
+impl GetPipeReq {
+    pub fn new(tenant: impl Into<String>, pipe_name: impl Into<String>) -> GetPipeReq {
+        GetPipeReq {
+            name_ident: PipeNameIdent::new(tenant, pipe_name),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GetPipeReply {
+    pub meta: PipeMeta,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdatePipeReq {
+    pub if_exists: bool,
+    pub name_ident: PipeNameIdent,
+    pub execution_paused: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl Display for UpdatePipeReq {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "update_pipe:{}", self.name_ident)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdatePipeReply {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ListPipesReq {
+    pub tenant: String,
+}
+
+impl ListPipesReq {
+    pub fn new(tenant: impl Into<String>) -> ListPipesReq {
+        ListPipesReq {
+            tenant: tenant.into(),
+        }
+    }
+}
+
+mod kvapi_key_impl {
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::schema::PipeMeta;
+    use crate::schema::PipeNameIdent;
+    use crate::tenant::Tenant;
+
+    /// <prefix>/<tenant>/<pipe_name>
+    impl kvapi::Key for PipeNameIdent {
+        const PREFIX: &'static str = "__fd_pipe";
+
+        type ValueType = PipeMeta;
+
+        /// It belongs to a tenant
+        fn parent(&self) -> Option<String> {
+            Some(Tenant::new(&self.tenant).to_string_key())
+        }
+
+        fn to_string_key(&self) -> String {
+            kvapi::KeyBuilder::new_prefixed(Self::PREFIX)
+                .push_str(&self.tenant)
+                .push_str(&self.pipe_name)
+                .done()
+        }
+
+        fn from_str_key(s: &str) -> Result<Self, kvapi::KeyError> {
+            let mut p = kvapi::KeyParser::new_prefixed(s, Self::PREFIX)?;
+
+            let tenant = p.next_str()?;
+            let pipe_name = p.next_str()?;
+            p.done()?;
+
+            Ok(PipeNameIdent { tenant, pipe_name })
+        }
+    }
+
+    impl kvapi::Value for PipeMeta {
+        fn dependency_keys(&self) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::schema::PipeNameIdent;
+
+    #[test]
+    fn test_pipe_name_ident() {
+        let ident = PipeNameIdent::new("test", "pipe1");
+
+        assert_eq!(ident.to_string_key(), "__fd_pipe/test/pipe1");
+        assert_eq!(
+            ident,
+            PipeNameIdent::from_str_key("__fd_pipe/test/pipe1").unwrap()
+        );
+    }
+}
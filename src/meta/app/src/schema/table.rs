@@ -268,12 +268,53 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    pub row_access_policy: Option<TableRowAccessPolicy>,
+}
+
+/// The row access policy attached to a table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    // The columns passed to the policy arguments, in order.
+    pub columns: Vec<String>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum TableIndexType {
+    #[default]
+    Inverted = 0,
+    Vector = 1,
+}
+
+impl Display for TableIndexType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TableIndexType::Inverted => write!(f, "INVERTED"),
+            TableIndexType::Vector => write!(f, "VECTOR"),
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TableIndex {
     pub name: String,
     pub column_ids: Vec<u32>,
+    pub index_type: TableIndexType,
+    // The options of the index, e.g. the distance type of a vector index.
+    pub options: BTreeMap<String, String>,
+    // Changed every time the index is created, index files written by
+    // a previous version of the index are ignored.
+    pub version: String,
 }
 
 impl TableMeta {
@@ -389,6 +430,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            row_access_policy: None,
         }
     }
 }
@@ -725,6 +767,27 @@ pub struct SetTableColumnMaskPolicyReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy name, columns passed to the policy
+    Set(String, Vec<String>),
+    // prev policy name
+    Unset(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: String,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
@@ -741,6 +804,9 @@ pub struct CreateTableIndexReq {
     pub table_id: u64,
     pub name: String,
     pub column_ids: Vec<u32>,
+    pub index_type: TableIndexType,
+    pub options: BTreeMap<String, String>,
+    pub version: String,
 }
 
 impl Display for CreateTableIndexReq {
@@ -21,6 +21,7 @@ use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_protos::pb;
 
@@ -30,6 +31,32 @@ use crate::Incompatible;
 use crate::MIN_READER_VER;
 use crate::VER;
 
+impl FromToProto for IcebergRestCatalogOption {
+    type PB = pb::IcebergRestCatalogOption;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::IcebergRestCatalogOption) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            uri: p.uri,
+            warehouse: p.warehouse,
+            token: p.token,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::IcebergRestCatalogOption, Incompatible> {
+        Ok(pb::IcebergRestCatalogOption {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            uri: self.uri.clone(),
+            warehouse: self.warehouse.clone(),
+            token: self.token.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::CatalogNameIdent {
     type PB = pb::CatalogNameIdent;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -81,16 +108,28 @@ impl FromToProto for mt::CatalogMeta {
                 pb::catalog_option::CatalogOption::Hive(v) => {
                     CatalogOption::Hive(HiveCatalogOption {
                         address: v.address,
-                        storage_params: v.storage_params.map(StorageParams::from_pb).transpose()?.map(Box::new),
+                        storage_params: v
+                            .storage_params
+                            .map(StorageParams::from_pb)
+                            .transpose()?
+                            .map(Box::new),
                     })
                 }
                 pb::catalog_option::CatalogOption::Iceberg(v) => {
-                    CatalogOption::Iceberg(IcebergCatalogOption {
-                        storage_params: Box::new(StorageParams::from_pb(
-                            v.storage_params.ok_or_else(|| Incompatible {
+                    let rest = v.rest.map(IcebergRestCatalogOption::from_pb).transpose()?;
+                    let storage_params = match (v.storage_params, &rest) {
+                        (Some(sp), _) => StorageParams::from_pb(sp)?,
+                        // A REST catalog is allowed to be created without storage connection.
+                        (None, Some(_)) => StorageParams::None,
+                        (None, None) => {
+                            return Err(Incompatible {
                                 reason: "CatalogMeta.option.catalog_option.iceberg.StorageParams is None".to_string(),
-                            })?,
-                        )?),
+                            });
+                        }
+                    };
+                    CatalogOption::Iceberg(IcebergCatalogOption {
+                        storage_params: Box::new(storage_params),
+                        rest,
                     })
                 }
             },
@@ -125,7 +164,11 @@ impl FromToProto for mt::CatalogMeta {
                         pb::IcebergCatalogOption {
                             ver: VER,
                             min_reader_ver: MIN_READER_VER,
-                            storage_params: Some(v.storage_params.to_pb()?),
+                            storage_params: match v.storage_params.as_ref() {
+                                StorageParams::None => None,
+                                sp => Some(sp.to_pb()?),
+                            },
+                            rest: v.rest.map(|v| v.to_pb()).transpose()?,
                         },
                     )),
                 }),
@@ -75,7 +75,9 @@ mod least_visible_time_from_to_protobuf_impl;
 mod lock_from_to_protobuf_impl;
 mod owner_from_to_protobuf_impl;
 mod ownership_from_to_protobuf_impl;
+mod pipe_from_to_protobuf_impl;
 mod role_from_to_protobuf_impl;
+mod row_access_policy_from_to_protobuf_impl;
 mod schema_from_to_protobuf_impl;
 mod share_from_to_protobuf_impl;
 mod stage_from_to_protobuf_impl;
@@ -40,6 +40,7 @@ impl FromToProto for mt::principal::RoleInfo {
                     reason: "RoleInfo.grants cannot be None".to_string(),
                 }
             })?)?,
+            workload_group: p.workload_group,
         })
     }
 
@@ -49,6 +50,7 @@ impl FromToProto for mt::principal::RoleInfo {
             min_reader_ver: MIN_READER_VER,
             name: self.name.clone(),
             grants: Some(mt::principal::UserGrantSet::to_pb(&self.grants)?),
+            workload_group: self.workload_group.clone(),
         })
     }
 }
@@ -258,6 +258,7 @@ impl FromToProto for ex::TableDataType {
                     }
                     Dt24::VariantT(_) => ex::TableDataType::Variant,
                     Dt24::GeometryT(_) => ex::TableDataType::Geometry,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
                     Dt24::DecimalT(x) => {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
@@ -290,6 +291,7 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Timestamp => new_pb_dt24(Dt24::TimestampT(pb::Empty {})),
             TableDataType::Date => new_pb_dt24(Dt24::DateT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
             TableDataType::Nullable(v) => {
                 let x = v.to_pb()?;
                 new_pb_dt24(Dt24::NullableT(Box::new(x)))
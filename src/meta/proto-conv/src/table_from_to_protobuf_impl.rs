@@ -25,6 +25,7 @@ use databend_common_expression as ex;
 use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_protos::pb;
+use num::FromPrimitive;
 
 use crate::reader_check_msg;
 use crate::FromToProto;
@@ -224,6 +225,10 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            row_access_policy: p
+                .row_access_policy
+                .map(mt::TableRowAccessPolicy::from_pb)
+                .transpose()?,
         };
         Ok(v)
     }
@@ -265,6 +270,11 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            row_access_policy: self
+                .row_access_policy
+                .as_ref()
+                .map(|p| p.to_pb())
+                .transpose()?,
         };
         Ok(p)
     }
@@ -338,6 +348,11 @@ impl FromToProto for mt::TableIndex {
         let v = Self {
             name: p.name,
             column_ids: p.column_ids,
+            index_type: FromPrimitive::from_i32(p.index_type).ok_or_else(|| Incompatible {
+                reason: format!("invalid TableIndexType: {}", p.index_type),
+            })?,
+            options: p.options,
+            version: p.version,
         };
         Ok(v)
     }
@@ -348,6 +363,35 @@ impl FromToProto for mt::TableIndex {
             min_reader_ver: MIN_READER_VER,
             name: self.name.clone(),
             column_ids: self.column_ids.clone(),
+            index_type: self.index_type as i32,
+            options: self.options.clone(),
+            version: self.version.clone(),
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
         };
         Ok(p)
     }
@@ -100,7 +100,8 @@ impl FromToProto for mt::principal::UserOption {
             .with_flags(flags)
             .with_default_role(p.default_role)
             .with_network_policy(p.network_policy)
-            .with_password_policy(p.password_policy))
+            .with_password_policy(p.password_policy)
+            .with_workload_group(p.workload_group))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -111,6 +112,7 @@ impl FromToProto for mt::principal::UserOption {
             default_role: self.default_role().cloned(),
             network_policy: self.network_policy().cloned(),
             password_policy: self.password_policy().cloned(),
+            workload_group: self.workload_group().cloned(),
         })
     }
 }
@@ -505,3 +507,45 @@ impl FromToProto for mt::principal::PasswordPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name.clone(),
+            cpu_quota: p.cpu_quota,
+            memory_quota: p.memory_quota,
+            max_concurrency: p.max_concurrency,
+            queue_timeout: p.queue_timeout,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            cpu_quota: self.cpu_quota,
+            memory_quota: self.memory_quota,
+            max_concurrency: self.max_concurrency,
+            queue_timeout: self.queue_timeout,
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
@@ -114,6 +114,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (82, "2024-03-08: Add: table.inverted_index"),
     (83, "2024-03-14: Add: null_if in user.proto/NDJSONFileFormatParams"),
     (84, "2024-03-21: Rename: background.proto/BackgroundJobIdent to BackgroundTaskCreator"),
+    (85, "2024-03-26: Add: pipe.proto/PipeMeta"),
+    (86, "2024-03-28: Add: file_format.proto/AvroFileFormatParams"),
+    (87, "2024-03-29: Add: file_format.proto/OrcFileFormatParams"),
+    (88, "2024-04-02: Add: datatype.proto/DataType Interval type"),
+    (89, "2024-04-08: Add: table.proto/TableIndex add index_type, options and version"),
+    (90, "2024-04-12: Add: row_access_policy.proto/RowAccessPolicyMeta, table.proto/TableMeta add row_access_policy"),
+    (91, "2024-04-15: Add: user.proto/WorkloadGroup, user.proto/UserOption add workload_group"),
+    (92, "2024-04-18: Add: catalog.proto/IcebergCatalogOption add rest"),
+    (93, "2024-04-19: Add: role.proto/RoleInfo add workload_group"),
+    (94, "2024-04-22: Add: pipe.proto/PipeMeta add creator"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
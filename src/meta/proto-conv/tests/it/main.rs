@@ -87,3 +87,13 @@ mod v081_udf_script;
 mod v082_table_index;
 mod v083_ndjson_format_params;
 mod v084_background_task_creator;
+mod v085_pipe;
+mod v086_avro_format_params;
+mod v087_orc_format_params;
+mod v088_interval_datatype;
+mod v089_table_index_type;
+mod v090_row_access_policy;
+mod v091_workload_group;
+mod v092_iceberg_rest_catalog;
+mod v093_role_workload_group;
+mod v094_pipe_creator;
@@ -214,6 +214,7 @@ fn new_table_meta() -> mt::TableMeta {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        row_access_policy: None,
     }
 }
 
@@ -323,6 +324,7 @@ fn new_catalog_meta() -> databend_common_meta_app::schema::CatalogMeta {
                     ..Default::default()
                 },
             )),
+            rest: None,
         }),
         created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
     }
@@ -150,6 +150,7 @@ fn test_decode_v33_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: None,
         indexes: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -101,6 +101,7 @@ fn test_decode_v44_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_load_old(func_name!(), bytes.as_slice(), 44, want())?;
@@ -88,6 +88,7 @@ fn test_decode_v76_role() -> anyhow::Result<()> {
             ],
             HashSet::new(),
         ),
+        workload_group: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), role_info_v76.as_slice(), 76, want())?;
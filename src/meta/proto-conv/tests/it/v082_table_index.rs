@@ -93,8 +93,12 @@ fn test_decode_v82_table_meta() -> anyhow::Result<()> {
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {s("idx1") => mt::TableIndex {
             name: "idx1".to_string(),
-            column_ids: vec![1, 2]
+            column_ids: vec![1, 2],
+            index_type: mt::TableIndexType::Inverted,
+            options: btreemap! {},
+            version: "".to_string(),
         }},
+        row_access_policy: None,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v82.as_slice(), 82, want())?;
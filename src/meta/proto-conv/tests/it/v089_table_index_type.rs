@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_meta_app::schema as mt;
+use maplit::btreemap;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v89_table_index() -> anyhow::Result<()> {
+    let table_index_v89 = vec![
+        10, 4, 105, 100, 120, 49, 18, 2, 1, 2, 24, 1, 34, 18, 10, 8, 100, 105, 115, 116, 97, 110,
+        99, 101, 18, 6, 99, 111, 115, 105, 110, 101, 42, 2, 118, 49, 160, 6, 89, 168, 6, 24,
+    ];
+
+    let want = || mt::TableIndex {
+        name: "idx1".to_string(),
+        column_ids: vec![1, 2],
+        index_type: mt::TableIndexType::Vector,
+        options: btreemap! {"distance".to_string() => "cosine".to_string()},
+        version: "v1".to_string(),
+    };
+    common::test_load_old(func_name!(), table_index_v89.as_slice(), 89, want())?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
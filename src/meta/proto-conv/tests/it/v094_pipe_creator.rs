@@ -0,0 +1,60 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::schema::PipeMeta;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v94_pipe_creator() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 5, 112, 105, 112, 101, 49, 18, 37, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 100, 101,
+        102, 97, 117, 108, 116, 46, 100, 101, 102, 97, 117, 108, 116, 46, 116, 49, 32, 70, 82, 79,
+        77, 32, 64, 115, 49, 32, 1, 42, 18, 108, 111, 97, 100, 32, 102, 105, 108, 101, 115, 32,
+        102, 114, 111, 109, 32, 115, 49, 50, 13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109,
+        105, 110, 58, 23, 50, 48, 50, 52, 45, 48, 51, 45, 50, 54, 32, 48, 49, 58, 50, 54, 58, 48,
+        57, 32, 85, 84, 67, 66, 23, 50, 48, 50, 52, 45, 48, 51, 45, 50, 54, 32, 48, 50, 58, 50, 54,
+        58, 48, 57, 32, 85, 84, 67, 74, 15, 10, 4, 114, 111, 111, 116, 18, 1, 37, 160, 6, 94, 168,
+        6, 24, 160, 6, 94, 168, 6, 24,
+    ];
+
+    let want = || PipeMeta {
+        name: "pipe1".to_string(),
+        copy_stmt: "COPY INTO default.default.t1 FROM @s1".to_string(),
+        auto_ingest: false,
+        execution_paused: true,
+        comment: "load files from s1".to_string(),
+        owner: Some("account_admin".to_string()),
+        creator: Some(UserIdentity::new("root", "%")),
+        created_on: DateTime::<Utc>::from_timestamp(1711416369, 0).unwrap(),
+        updated_on: Some(DateTime::<Utc>::from_timestamp(1711419969, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 94, want())
+}
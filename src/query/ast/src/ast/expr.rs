@@ -739,6 +739,7 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Interval,
     Binary,
     String,
     Array(Box<TypeName>),
@@ -824,6 +825,9 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
             TypeName::Binary => {
                 write!(f, "BINARY")?;
             }
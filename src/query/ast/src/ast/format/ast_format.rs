@@ -1213,6 +1213,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let options_node = FormatTreeNode::with_children(options_format_ctx, options_children);
             children.push(options_node);
         }
+        if let Some(clone_from) = &stmt.clone_from {
+            let mut clone_children = Vec::new();
+            self.visit_database_ref(&clone_from.database.catalog, &clone_from.database.database);
+            clone_children.push(self.children.pop().unwrap());
+            if let Some(travel_point) = &clone_from.travel_point {
+                self.visit_time_travel_point(travel_point);
+                clone_children.push(self.children.pop().unwrap());
+            }
+            let clone_name = "CloneDatabase".to_string();
+            let clone_format_ctx =
+                AstFormatContext::with_children(clone_name, clone_children.len());
+            let clone_node = FormatTreeNode::with_children(clone_format_ctx, clone_children);
+            children.push(clone_node);
+        }
         let name = "CreateDatabase".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1416,12 +1430,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("TableConstraint {}", constraint);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1439,6 +1458,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                let mut children = Vec::new();
+                self.visit_table_ref(catalog, database, table);
+                children.push(self.children.pop().unwrap());
+                if let Some(travel_point) = travel_point {
+                    self.visit_time_travel_point(travel_point);
+                    children.push(self.children.pop().unwrap());
+                }
+                let name = "CloneTable".to_string();
+                let format_ctx = AstFormatContext::with_children(name, children.len());
+                let node = FormatTreeNode::with_children(format_ctx, children);
+                self.children.push(node);
+            }
         }
     }
 
@@ -1588,6 +1625,43 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action Add row access policy {} on ({})",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action Drop row access policy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::CreateTag {
+                tag, travel_point, ..
+            } => {
+                let action_name = format!("Action Create tag {}", tag);
+                match travel_point {
+                    Some(point) => {
+                        self.visit_time_travel_point(point);
+                        let point_node = self.children.pop().unwrap();
+                        let action_format_ctx = AstFormatContext::with_children(action_name, 1);
+                        FormatTreeNode::with_children(action_format_ctx, vec![point_node])
+                    }
+                    None => FormatTreeNode::new(AstFormatContext::new(action_name)),
+                }
+            }
+            AlterTableAction::DropTag { tag, .. } => {
+                let action_name = format!("Action Drop tag {}", tag);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1897,6 +1971,59 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_vector_index(&mut self, stmt: &'ast CreateVectorIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index_name);
+        children.push(self.children.pop().unwrap());
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        children.push(self.children.pop().unwrap());
+        self.visit_identifier(&stmt.column);
+        let column_child = self.children.pop().unwrap();
+        let column_ctx = AstFormatContext::with_children("Column".to_string(), 1);
+        children.push(FormatTreeNode::with_children(column_ctx, vec![
+            column_child,
+        ]));
+        if !stmt.index_options.is_empty() {
+            let options_children = stmt
+                .index_options
+                .iter()
+                .map(|(k, v)| FormatTreeNode::new(AstFormatContext::new(format!("{k} = {v}"))))
+                .collect::<Vec<_>>();
+            let options_ctx =
+                AstFormatContext::with_children("Options".to_string(), options_children.len());
+            children.push(FormatTreeNode::with_children(options_ctx, options_children));
+        }
+
+        let name = "CreateVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_vector_index(&mut self, stmt: &'ast DropVectorIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_vector_index(&mut self, stmt: &'ast RefreshVectorIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "RefreshVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2102,6 +2229,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_alter_role(&mut self, stmt: &'ast AlterRoleStmt) {
+        let role_name = format!("Role {}", stmt.role_name);
+        let role_format_ctx = AstFormatContext::new(role_name);
+        let role_child = FormatTreeNode::new(role_format_ctx);
+        let action_format_ctx = AstFormatContext::new(format!("Action {}", stmt.action));
+        let action_child = FormatTreeNode::new(action_format_ctx);
+
+        let name = "AlterRole".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![role_child, action_child]);
+        self.children.push(node);
+    }
+
     fn visit_drop_role(&mut self, _if_exists: bool, role_name: &'ast str) {
         let role_name = format!("Role {}", role_name);
         let role_format_ctx = AstFormatContext::new(role_name);
@@ -2761,6 +2901,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2851,6 +3021,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
 
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -3122,6 +3322,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 alias,
                 travel_point,
                 since_point,
+                sample,
                 pivot,
                 unpivot,
             } => {
@@ -3137,6 +3338,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 }
                 name.push_str(&table.to_string());
 
+                if let Some(sample) = sample {
+                    name.push(' ');
+                    name.push_str(&sample.to_string());
+                }
+
                 if let Some(pivot) = pivot {
                     name.push(' ');
                     name.push_str(&pivot.to_string());
@@ -3243,6 +3449,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 let mut children = Vec::new();
                 if let Some(files) = &options.files {
@@ -3254,6 +3461,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     let pattern = format!("pattern = {}", pattern);
                     children.push(FormatTreeNode::new(AstFormatContext::new(pattern)))
                 }
+                if let Some(sample) = sample {
+                    children.push(FormatTreeNode::new(AstFormatContext::new(
+                        sample.to_string(),
+                    )))
+                }
                 let stage_name = format!("Stage {:?}", location);
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
@@ -3286,6 +3498,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let node = FormatTreeNode::with_children(format_ctx, vec![child]);
                 self.children.push(node);
             }
+            TimeTravelPoint::Tag(tag) => {
+                let name = format!("Tag {}", tag);
+                let format_ctx = AstFormatContext::new(name);
+                let node = FormatTreeNode::new(format_ctx);
+                self.children.push(node);
+            }
         }
     }
 
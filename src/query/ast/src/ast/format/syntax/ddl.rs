@@ -114,11 +114,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -140,6 +145,30 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::space().append(RcDoc::text(format!("AT {travel_point}")))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -224,6 +253,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::RevertTo { point } => match point {
             TimeTravelPoint::Snapshot(sid) => RcDoc::text(format!(" AT (SNAPSHOT => {sid})")),
             TimeTravelPoint::Timestamp(ts) => RcDoc::text(format!(" AT (TIMESTAMP => {ts})")),
+            TimeTravelPoint::Tag(tag) => RcDoc::text(format!(" AT (TAG => {tag})")),
         },
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
@@ -233,6 +263,41 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(interweave_comma(
+                columns
+                    .into_iter()
+                    .map(|column| RcDoc::text(column.to_string())),
+            ))),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
+        AlterTableAction::CreateTag {
+            if_not_exists,
+            tag,
+            travel_point,
+        } => RcDoc::line()
+            .append(RcDoc::text("CREATE TAG "))
+            .append(if if_not_exists {
+                RcDoc::text("IF NOT EXISTS ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(tag.to_string()))
+            .append(if let Some(point) = travel_point {
+                RcDoc::text(format!(" AT {point}"))
+            } else {
+                RcDoc::nil()
+            }),
+        AlterTableAction::DropTag { if_exists, tag } => RcDoc::line()
+            .append(RcDoc::text("DROP TAG "))
+            .append(if if_exists {
+                RcDoc::text("IF EXISTS ")
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(tag.to_string())),
     }
 }
 
@@ -321,6 +321,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             alias,
             travel_point,
             since_point,
+            sample,
             pivot,
             unpivot,
         } => if let Some(catalog) = catalog {
@@ -348,6 +349,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
             RcDoc::text(format!(" AT (TIMESTAMP => {ts})"))
+        } else if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+            RcDoc::text(format!(" AT (TAG => {tag})"))
         } else {
             RcDoc::nil()
         })
@@ -355,6 +358,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" SINCE (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = since_point {
             RcDoc::text(format!(" SINCE (TIMESTAMP => {ts})"))
+        } else if let Some(TimeTravelPoint::Tag(tag)) = since_point {
+            RcDoc::text(format!(" SINCE (TAG => {tag})"))
         } else {
             RcDoc::nil()
         })
@@ -362,6 +367,11 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             RcDoc::text(format!(" AS {alias}"))
         } else {
             RcDoc::nil()
+        })
+        .append(if let Some(sample) = sample {
+            RcDoc::text(format!(" {sample}"))
+        } else {
+            RcDoc::nil()
         }),
         TableReference::Subquery {
             span: _,
@@ -430,8 +440,17 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsof => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(match &join.match_condition {
+                Some(match_condition) => RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION("))
+                    .append(pretty_expr(*match_condition.clone()))
+                    .append(RcDoc::text(")")),
+                None => RcDoc::nil(),
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -450,12 +469,18 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             location,
             options,
             alias,
+            sample,
         } => RcDoc::text(location.to_string())
             .append(options.to_string())
             .append(if let Some(a) = alias {
                 RcDoc::text(format!(" AS {a}"))
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(sample) = sample {
+                RcDoc::text(format!(" {sample}"))
+            } else {
+                RcDoc::nil()
             }),
     }
 }
@@ -491,11 +491,49 @@ impl Display for Indirection {
     }
 }
 
+/// `TABLESAMPLE [BLOCK | ROW] (probability) [REPEATABLE (seed)]`
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct SampleConfig {
+    #[drive(skip)]
+    pub level: SampleLevel,
+    /// The percentage of the blocks or rows to be sampled, in the range of [0, 100].
+    #[drive(skip)]
+    pub probability: f64,
+    #[drive(skip)]
+    pub seed: Option<u64>,
+}
+
+impl Display for SampleConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TABLESAMPLE {} ({})", self.level, self.probability)?;
+        if let Some(seed) = self.seed {
+            write!(f, " REPEATABLE ({seed})")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
+impl Display for SampleLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SampleLevel::Row => write!(f, "ROW"),
+            SampleLevel::Block => write!(f, "BLOCK"),
+        }
+    }
+}
+
 /// Time Travel specification
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum TimeTravelPoint {
     Snapshot(#[drive(skip)] String),
     Timestamp(Box<Expr>),
+    Tag(Identifier),
 }
 
 impl Display for TimeTravelPoint {
@@ -507,6 +545,9 @@ impl Display for TimeTravelPoint {
             TimeTravelPoint::Timestamp(ts) => {
                 write!(f, "(TIMESTAMP => {ts})")?;
             }
+            TimeTravelPoint::Tag(tag) => {
+                write!(f, "(TAG => {tag})")?;
+            }
         }
 
         Ok(())
@@ -562,6 +603,7 @@ pub enum TableReference {
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
         since_point: Option<TimeTravelPoint>,
+        sample: Option<SampleConfig>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
@@ -598,6 +640,7 @@ pub enum TableReference {
         location: FileLocation,
         options: SelectStageOptions,
         alias: Option<TableAlias>,
+        sample: Option<SampleConfig>,
     },
 }
 
@@ -635,6 +678,7 @@ impl Display for TableReference {
                 alias,
                 travel_point,
                 since_point,
+                sample,
                 pivot,
                 unpivot,
             } => {
@@ -651,6 +695,10 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(tag)) = travel_point {
+                    write!(f, " AT (TAG => {tag})")?;
+                }
+
                 if let Some(TimeTravelPoint::Snapshot(sid)) = since_point {
                     write!(f, " SINCE (SNAPSHOT => {sid})")?;
                 }
@@ -659,9 +707,16 @@ impl Display for TableReference {
                     write!(f, " SINCE (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(TimeTravelPoint::Tag(tag)) = since_point {
+                    write!(f, " SINCE (TAG => {tag})")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
                 if let Some(pivot) = pivot {
                     write!(f, " {pivot}")?;
                 }
@@ -744,8 +799,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -763,6 +827,7 @@ impl Display for TableReference {
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 write!(f, "{location}")?;
                 if !options.is_empty() {
@@ -771,6 +836,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
             }
         }
         Ok(())
@@ -799,6 +867,8 @@ impl Display for TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    /// `MATCH_CONDITION(expr)` of the ASOF joins.
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -816,6 +886,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // ASOF joins match each left row with the nearest right row by the `MATCH_CONDITION`
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
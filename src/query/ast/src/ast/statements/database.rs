@@ -24,6 +24,7 @@ use crate::ast::statements::show::ShowLimit;
 use crate::ast::write_dot_separated_list;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
+use crate::ast::TimeTravelPoint;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowDatabasesStmt {
@@ -66,7 +67,7 @@ impl Display for ShowCreateDatabaseStmt {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateDatabaseStmt {
     #[drive(skip)]
     pub create_option: CreateOption,
@@ -75,6 +76,7 @@ pub struct CreateDatabaseStmt {
     pub options: Vec<SQLProperty>,
     #[drive(skip)]
     pub from_share: Option<ShareNameIdent>,
+    pub clone_from: Option<CloneDatabaseSource>,
 }
 
 impl Display for CreateDatabaseStmt {
@@ -100,12 +102,31 @@ impl Display for CreateDatabaseStmt {
                 from_share.tenant, from_share.share_name
             )?;
         }
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " {clone_from}")?;
+        }
 
         // TODO(leiysky): display rest information
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CloneDatabaseSource {
+    pub database: DatabaseRef,
+    pub travel_point: Option<TimeTravelPoint>,
+}
+
+impl Display for CloneDatabaseSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CLONE {}", self.database)?;
+        if let Some(point) = &self.travel_point {
+            write!(f, " AT {point}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct DropDatabaseStmt {
     #[drive(skip)]
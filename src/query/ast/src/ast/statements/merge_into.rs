@@ -277,6 +277,7 @@ impl MergeSource {
                 alias: alias.clone(),
                 travel_point: None,
                 since_point: None,
+                sample: None,
                 pivot: None,
                 unpivot: None,
             },
@@ -33,6 +33,7 @@ mod password_policy;
 mod pipe;
 mod presign;
 mod replace;
+mod row_access_policy;
 mod script;
 mod share;
 mod show;
@@ -47,6 +48,7 @@ mod update;
 mod user;
 mod view;
 mod virtual_column;
+mod workload_group;
 
 pub use call::*;
 pub use catalog::*;
@@ -69,6 +71,7 @@ pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use script::*;
 pub use share::*;
 pub use show::*;
@@ -83,3 +86,4 @@ pub use update::*;
 pub use user::*;
 pub use view::*;
 pub use virtual_column::*;
+pub use workload_group::*;
@@ -16,6 +16,8 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use databend_common_exception::Span;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
 
 use crate::ast::Expr;
 use crate::ast::Identifier;
@@ -25,6 +27,18 @@ use crate::ast::TypeName;
 
 const INDENT_DEPTH: usize = 4;
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ExecuteImmediateStmt {
+    #[drive(skip)]
+    pub script: String,
+}
+
+impl Display for ExecuteImmediateStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EXECUTE IMMEDIATE $${}$$", self.script)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateStoredProceduer {
     pub or_replace: bool,
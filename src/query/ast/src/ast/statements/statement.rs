@@ -169,6 +169,9 @@ pub enum Statement {
     CreateInvertedIndex(CreateInvertedIndexStmt),
     DropInvertedIndex(DropInvertedIndexStmt),
     RefreshInvertedIndex(RefreshInvertedIndexStmt),
+    CreateVectorIndex(CreateVectorIndexStmt),
+    DropVectorIndex(DropVectorIndexStmt),
+    RefreshVectorIndex(RefreshVectorIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -194,6 +197,7 @@ pub enum Statement {
         #[drive(skip)]
         role_name: String,
     },
+    AlterRole(AlterRoleStmt),
     DropRole {
         #[drive(skip)]
         if_exists: bool,
@@ -283,6 +287,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -299,6 +308,11 @@ pub enum Statement {
         show_options: Option<ShowOptions>,
     },
 
+    // workload group
+    CreateWorkloadGroup(CreateWorkloadGroupStmt),
+    AlterWorkloadGroup(AlterWorkloadGroupStmt),
+    DropWorkloadGroup(DropWorkloadGroupStmt),
+
     // tasks
     CreateTask(CreateTaskStmt),
     AlterTask(AlterTaskStmt),
@@ -323,6 +337,9 @@ pub enum Statement {
     AlterNotification(AlterNotificationStmt),
     DropNotification(DropNotificationStmt),
     DescribeNotification(DescribeNotificationStmt),
+
+    // Scripts
+    ExecuteImmediate(ExecuteImmediateStmt),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -555,6 +572,9 @@ impl Display for Statement {
             Statement::CreateInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropInvertedIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshInvertedIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropVectorIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshVectorIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -581,6 +601,7 @@ impl Display for Statement {
                 }
                 write!(f, " '{role}'")?;
             }
+            Statement::AlterRole(stmt) => write!(f, "{stmt}")?,
             Statement::DropRole {
                 if_exists,
                 role_name: role,
@@ -678,6 +699,9 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -687,6 +711,9 @@ impl Display for Statement {
             Statement::AlterPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropPasswordPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescPasswordPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterWorkloadGroup(stmt) => write!(f, "{stmt}")?,
+            Statement::DropWorkloadGroup(stmt) => write!(f, "{stmt}")?,
             Statement::ShowPasswordPolicies { show_options } => {
                 write!(f, "SHOW PASSWORD POLICIES")?;
                 if let Some(show_options) = show_options {
@@ -714,6 +741,7 @@ impl Display for Statement {
             Statement::AlterNotification(stmt) => write!(f, "{stmt}")?,
             Statement::DropNotification(stmt) => write!(f, "{stmt}")?,
             Statement::DescribeNotification(stmt) => write!(f, "{stmt}")?,
+            Statement::ExecuteImmediate(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
@@ -234,20 +234,30 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -258,6 +268,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(point) = travel_point {
+                    write!(f, " AT {point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -389,6 +412,24 @@ pub enum AlterTableAction {
         #[drive(skip)]
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
+    CreateTag {
+        #[drive(skip)]
+        if_not_exists: bool,
+        tag: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTag {
+        #[drive(skip)]
+        if_exists: bool,
+        tag: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -444,6 +485,35 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
+            AlterTableAction::CreateTag {
+                if_not_exists,
+                tag,
+                travel_point,
+            } => {
+                write!(f, "CREATE TAG ")?;
+                if *if_not_exists {
+                    write!(f, "IF NOT EXISTS ")?;
+                }
+                write!(f, "{tag}")?;
+                if let Some(point) = travel_point {
+                    write!(f, " AT {point}")?;
+                }
+            }
+            AlterTableAction::DropTag { if_exists, tag } => {
+                write!(f, "DROP TAG ")?;
+                if *if_exists {
+                    write!(f, "IF EXISTS ")?;
+                }
+                write!(f, "{tag}")?;
+            }
         };
         Ok(())
     }
@@ -828,6 +898,67 @@ impl Display for ColumnDefinition {
     }
 }
 
+/// A table level constraint, e.g. `CONSTRAINT c1 CHECK (a > 0)` or `PRIMARY KEY (a, b)`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct TableConstraint {
+    pub name: Option<Identifier>,
+    pub kind: TableConstraintKind,
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum TableConstraintKind {
+    Check(Expr),
+    PrimaryKey {
+        columns: Vec<Identifier>,
+        #[drive(skip)]
+        enforced: bool,
+    },
+    Unique {
+        columns: Vec<Identifier>,
+        #[drive(skip)]
+        enforced: bool,
+    },
+}
+
+impl Display for TableConstraintKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraintKind::Check(expr) => write!(f, "CHECK ({expr})"),
+            TableConstraintKind::PrimaryKey { columns, enforced } => {
+                write!(f, "PRIMARY KEY ")?;
+                write_key_columns(f, columns, *enforced)
+            }
+            TableConstraintKind::Unique { columns, enforced } => {
+                write!(f, "UNIQUE ")?;
+                write_key_columns(f, columns, *enforced)
+            }
+        }
+    }
+}
+
+fn write_key_columns(
+    f: &mut Formatter,
+    columns: &[Identifier],
+    enforced: bool,
+) -> std::fmt::Result {
+    write!(f, "(")?;
+    write_comma_separated_list(f, columns)?;
+    write!(f, ")")?;
+    if enforced {
+        write!(f, " ENFORCED")?;
+    }
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -86,14 +86,15 @@ impl Display for UDFDefinition {
                 code,
                 handler,
                 language,
-                runtime_version: _,
+                runtime_version,
             } => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, arg_types)?;
-                write!(
-                    f,
-                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = '{handler}' AS $${code}$$"
-                )?;
+                write!(f, ") RETURNS {return_type} LANGUAGE {language}")?;
+                if !runtime_version.is_empty() {
+                    write!(f, " RUNTIME_VERSION = '{runtime_version}'")?;
+                }
+                write!(f, " HANDLER = '{handler}' AS $${code}$$")?;
             }
         }
         Ok(())
@@ -109,6 +109,40 @@ impl Display for AlterUserStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct AlterRoleStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub role_name: String,
+    pub action: AlterRoleAction,
+}
+
+impl Display for AlterRoleStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ALTER ROLE")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " '{}' {}", self.role_name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AlterRoleAction {
+    SetWorkloadGroup(#[drive(skip)] String),
+    UnsetWorkloadGroup,
+}
+
+impl Display for AlterRoleAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterRoleAction::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            AlterRoleAction::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct GrantStmt {
     pub source: AccountMgrSource,
@@ -182,6 +216,8 @@ pub enum UserOptionItem {
     UnsetNetworkPolicy,
     SetPasswordPolicy(#[drive(skip)] String),
     UnsetPasswordPolicy,
+    SetWorkloadGroup(#[drive(skip)] String),
+    UnsetWorkloadGroup,
 }
 
 impl UserOptionItem {
@@ -195,6 +231,8 @@ impl UserOptionItem {
             Self::UnsetNetworkPolicy => option.set_network_policy(None),
             Self::SetPasswordPolicy(v) => option.set_password_policy(Some(v.clone())),
             Self::UnsetPasswordPolicy => option.set_password_policy(None),
+            Self::SetWorkloadGroup(v) => option.set_workload_group(Some(v.clone())),
+            Self::UnsetWorkloadGroup => option.set_workload_group(None),
         }
     }
 }
@@ -265,6 +303,8 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
             UserOptionItem::SetPasswordPolicy(v) => write!(f, "SET PASSWORD POLICY = '{}'", v),
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
+            UserOptionItem::SetWorkloadGroup(v) => write!(f, "SET WORKLOAD GROUP = '{}'", v),
+            UserOptionItem::UnsetWorkloadGroup => write!(f, "UNSET WORKLOAD GROUP"),
         }
     }
 }
@@ -0,0 +1,159 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateWorkloadGroupStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+    #[drive(skip)]
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "WORKLOAD GROUP ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if !self.options.is_empty() {
+            write!(f, " WITH {}", self.options)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct AlterWorkloadGroupStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} SET {}", self.name, self.options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropWorkloadGroupStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    #[drive(skip)]
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Drive, DriveMut)]
+pub struct WorkloadGroupOptions {
+    #[drive(skip)]
+    pub cpu_quota: Option<u64>,
+    #[drive(skip)]
+    pub memory_quota: Option<u64>,
+    #[drive(skip)]
+    pub max_concurrency: Option<u64>,
+    #[drive(skip)]
+    pub queue_timeout: Option<u64>,
+    #[drive(skip)]
+    pub comment: Option<String>,
+}
+
+impl WorkloadGroupOptions {
+    pub fn is_empty(&self) -> bool {
+        self.cpu_quota.is_none()
+            && self.memory_quota.is_none()
+            && self.max_concurrency.is_none()
+            && self.queue_timeout.is_none()
+            && self.comment.is_none()
+    }
+
+    pub fn from_items(items: Vec<WorkloadGroupOption>) -> Self {
+        let mut options = WorkloadGroupOptions::default();
+        for item in items {
+            match item {
+                WorkloadGroupOption::CpuQuota(v) => options.cpu_quota = Some(v),
+                WorkloadGroupOption::MemoryQuota(v) => options.memory_quota = Some(v),
+                WorkloadGroupOption::MaxConcurrency(v) => options.max_concurrency = Some(v),
+                WorkloadGroupOption::QueueTimeout(v) => options.queue_timeout = Some(v),
+                WorkloadGroupOption::Comment(v) => options.comment = Some(v),
+            }
+        }
+        options
+    }
+}
+
+impl Display for WorkloadGroupOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut options = Vec::new();
+        if let Some(cpu_quota) = self.cpu_quota {
+            options.push(format!("CPU_QUOTA = {}", cpu_quota));
+        }
+        if let Some(memory_quota) = self.memory_quota {
+            options.push(format!("MEMORY_QUOTA = {}", memory_quota));
+        }
+        if let Some(max_concurrency) = self.max_concurrency {
+            options.push(format!("MAX_CONCURRENCY = {}", max_concurrency));
+        }
+        if let Some(queue_timeout) = self.queue_timeout {
+            options.push(format!("QUEUE_TIMEOUT = {}", queue_timeout));
+        }
+        if let Some(comment) = &self.comment {
+            options.push(format!("COMMENT = '{}'", comment));
+        }
+        write!(f, "{}", options.join(", "))
+    }
+}
+
+/// A single `key = value` item in `CREATE WORKLOAD GROUP ... WITH` or `ALTER WORKLOAD GROUP ... SET`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkloadGroupOption {
+    CpuQuota(u64),
+    MemoryQuota(u64),
+    MaxConcurrency(u64),
+    QueueTimeout(u64),
+    Comment(String),
+}
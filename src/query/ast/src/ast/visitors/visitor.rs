@@ -595,6 +595,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_refresh_inverted_index(&mut self, _stmt: &'ast RefreshInvertedIndexStmt) {}
 
+    fn visit_create_vector_index(&mut self, _stmt: &'ast CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &'ast DropVectorIndexStmt) {}
+
+    fn visit_refresh_vector_index(&mut self, _stmt: &'ast RefreshVectorIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -617,6 +623,8 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_create_role(&mut self, _if_not_exists: bool, _role_name: &'ast str) {}
 
+    fn visit_alter_role(&mut self, _stmt: &'ast AlterRoleStmt) {}
+
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &'ast str) {}
 
     fn visit_grant(&mut self, _grant: &'ast GrantStmt) {}
@@ -687,6 +695,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -707,6 +721,12 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &'ast CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &'ast AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &'ast DropWorkloadGroupStmt) {}
+
     fn visit_create_task(&mut self, _stmt: &'ast CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &'ast DropTaskStmt) {}
@@ -822,6 +842,7 @@ pub trait Visitor<'ast>: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -829,6 +850,9 @@ pub trait Visitor<'ast>: Sized {
         walk_table_reference(self, right);
 
         walk_join_condition(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
     fn visit_window_definition(&mut self, window_definition: &'ast WindowDefinition) {
         walk_window_definition(self, window_definition);
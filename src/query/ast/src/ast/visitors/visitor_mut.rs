@@ -608,6 +608,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_refresh_inverted_index(&mut self, _stmt: &mut RefreshInvertedIndexStmt) {}
 
+    fn visit_create_vector_index(&mut self, _stmt: &mut CreateVectorIndexStmt) {}
+
+    fn visit_drop_vector_index(&mut self, _stmt: &mut DropVectorIndexStmt) {}
+
+    fn visit_refresh_vector_index(&mut self, _stmt: &mut RefreshVectorIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -630,6 +636,8 @@ pub trait VisitorMut: Sized {
 
     fn visit_create_role(&mut self, _if_not_exists: bool, _role_name: &mut String) {}
 
+    fn visit_alter_role(&mut self, _stmt: &mut AlterRoleStmt) {}
+
     fn visit_drop_role(&mut self, _if_exists: bool, _role_name: &mut String) {}
 
     fn visit_grant(&mut self, _grant: &mut GrantStmt) {}
@@ -700,6 +708,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
@@ -720,6 +734,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_password_policies(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_create_workload_group(&mut self, _stmt: &mut CreateWorkloadGroupStmt) {}
+
+    fn visit_alter_workload_group(&mut self, _stmt: &mut AlterWorkloadGroupStmt) {}
+
+    fn visit_drop_workload_group(&mut self, _stmt: &mut DropWorkloadGroupStmt) {}
+
     fn visit_create_task(&mut self, _stmt: &mut CreateTaskStmt) {}
 
     fn visit_drop_task(&mut self, _stmt: &mut DropTaskStmt) {}
@@ -837,6 +857,7 @@ pub trait VisitorMut: Sized {
             left,
             right,
             condition,
+            match_condition,
             ..
         } = join;
 
@@ -844,6 +865,9 @@ pub trait VisitorMut: Sized {
         self.visit_table_reference(right);
 
         walk_join_condition_mut(self, condition);
+        if let Some(match_condition) = match_condition {
+            self.visit_expr(match_condition);
+        }
     }
 
     fn visit_create_connection(&mut self, _stmt: &mut CreateConnectionStmt) {}
@@ -310,6 +310,7 @@ pub fn walk_time_travel_point<'a, V: Visitor<'a>>(visitor: &mut V, time: &'a Tim
     match time {
         TimeTravelPoint::Snapshot(_) => {}
         TimeTravelPoint::Timestamp(expr) => visitor.visit_expr(expr),
+        TimeTravelPoint::Tag(tag) => visitor.visit_identifier(tag),
     }
 }
 
@@ -464,6 +465,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateInvertedIndex(stmt) => visitor.visit_create_inverted_index(stmt),
         Statement::DropInvertedIndex(stmt) => visitor.visit_drop_inverted_index(stmt),
         Statement::RefreshInvertedIndex(stmt) => visitor.visit_refresh_inverted_index(stmt),
+        Statement::CreateVectorIndex(stmt) => visitor.visit_create_vector_index(stmt),
+        Statement::DropVectorIndex(stmt) => visitor.visit_drop_vector_index(stmt),
+        Statement::RefreshVectorIndex(stmt) => visitor.visit_refresh_vector_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -478,6 +482,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             if_not_exists,
             role_name,
         } => visitor.visit_create_role(*if_not_exists, role_name),
+        Statement::AlterRole(stmt) => visitor.visit_alter_role(stmt),
         Statement::DropRole {
             if_exists,
             role_name,
@@ -530,6 +535,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
         Statement::AttachTable(_) => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
@@ -543,6 +551,9 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::ShowPasswordPolicies { show_options } => {
             visitor.visit_show_password_policies(show_options)
         }
+        Statement::CreateWorkloadGroup(stmt) => visitor.visit_create_workload_group(stmt),
+        Statement::AlterWorkloadGroup(stmt) => visitor.visit_alter_workload_group(stmt),
+        Statement::DropWorkloadGroup(stmt) => visitor.visit_drop_workload_group(stmt),
         Statement::CreateTask(stmt) => visitor.visit_create_task(stmt),
         Statement::ExecuteTask(stmt) => visitor.visit_execute_task(stmt),
         Statement::DropTask(stmt) => visitor.visit_drop_task(stmt),
@@ -561,6 +572,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::AlterNotification(stmt) => visitor.visit_alter_notification(stmt),
         Statement::DropNotification(stmt) => visitor.visit_drop_notification(stmt),
         Statement::DescribeNotification(stmt) => visitor.visit_describe_notification(stmt),
+        Statement::ExecuteImmediate(_) => {}
         Statement::Begin => {}
         Statement::Commit => {}
         Statement::Abort => {}
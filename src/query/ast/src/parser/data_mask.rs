@@ -17,6 +17,7 @@ use nom::combinator::map;
 use crate::ast::DataMaskArg;
 use crate::ast::DataMaskPolicy;
 use crate::ast::Expr;
+use crate::ast::RowAccessPolicy;
 use crate::ast::TypeName;
 use crate::parser::common::*;
 use crate::parser::expr::*;
@@ -77,3 +78,14 @@ pub fn data_mask_policy(i: Input) -> IResult<DataMaskPolicy> {
         },
     )(i)
 }
+
+pub fn row_access_policy(i: Input) -> IResult<RowAccessPolicy> {
+    map(
+        rule! { #data_mask_args ~ RETURNS ~ BOOLEAN ~ "->" ~ #data_mask_body ~ ( COMMENT ~ "=" ~ #literal_string)? },
+        |(args, _, _, _, body, comment_opt)| RowAccessPolicy {
+            args,
+            body,
+            comment: comment_opt.map(|opt| opt.2),
+        },
+    )(i)
+}
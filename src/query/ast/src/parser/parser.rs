@@ -20,6 +20,7 @@ use super::statement::insert_stmt;
 use super::statement::replace_stmt;
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::ScriptStatement;
 use crate::ast::Statement;
 use crate::parser::common::comma_separated_list0;
 use crate::parser::common::comma_separated_list1;
@@ -31,6 +32,7 @@ use crate::parser::expr::expr;
 use crate::parser::expr::values_with_placeholder;
 use crate::parser::input::Dialect;
 use crate::parser::input::Input;
+use crate::parser::script::script_block;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
@@ -81,6 +83,11 @@ pub fn parse_expr(tokens: &[Token], dialect: Dialect) -> Result<Expr> {
     run_parser(tokens, dialect, ParseMode::Default, false, expr)
 }
 
+/// Parse a script, `:<name>` in the script is parsed as a variable hole.
+pub fn parse_script(tokens: &[Token], dialect: Dialect) -> Result<Vec<ScriptStatement>> {
+    run_parser(tokens, dialect, ParseMode::Template, false, script_block)
+}
+
 pub fn parse_comma_separated_exprs(tokens: &[Token], dialect: Dialect) -> Result<Vec<Expr>> {
     run_parser(tokens, dialect, ParseMode::Default, true, |i| {
         comma_separated_list0(expr)(i)
@@ -508,12 +508,60 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         rule! { "(" ~ TIMESTAMP ~ "=>" ~ #expr ~ ")" },
         |(_, _, _, e, _)| TimeTravelPoint::Timestamp(Box::new(e)),
     );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, tag, _)| TimeTravelPoint::Tag(tag),
+    );
 
     rule!(
-        #at_snapshot | #at_timestamp
+        #at_snapshot | #at_timestamp | #at_tag
+    )(i)
+}
+
+// (TABLESAMPLE | SAMPLE) [BLOCK | ROW] (probability) [REPEATABLE (seed)]
+pub fn table_sample(i: Input) -> IResult<SampleConfig> {
+    let level = alt((
+        value(SampleLevel::Block, rule! { BLOCK }),
+        value(SampleLevel::Row, rule! { ROW }),
+    ));
+    let probability = map_res(rule! { LiteralInteger | LiteralFloat }, |token| {
+        let probability =
+            fast_float::parse::<f64, _>(token.text()).map_err(|e| nom::Err::Failure(e.into()))?;
+        if !(0.0..=100.0).contains(&probability) {
+            return Err(nom::Err::Failure(ErrorKind::Other(
+                "sample probability must be in the range of [0, 100]",
+            )));
+        }
+        Ok(probability)
+    });
+    map(
+        rule! {
+            (TABLESAMPLE | SAMPLE) ~ #level? ~ "(" ~ #probability ~ ^")"
+            ~ (REPEATABLE ~ ^"(" ~ ^#literal_u64 ~ ^")")?
+        },
+        |(_, level, _, probability, _, seed)| SampleConfig {
+            level: level.unwrap_or(SampleLevel::Row),
+            probability,
+            seed: seed.map(|(_, _, seed, _)| seed),
+        },
     )(i)
 }
 
+// The sample clause is tried before the alias, so that `t SAMPLE (10)`
+// will not take `SAMPLE` as the alias of the table.
+fn table_alias_and_sample(i: Input) -> IResult<(Option<TableAlias>, Option<SampleConfig>)> {
+    alt((
+        map(
+            rule! { #table_sample ~ #table_alias? },
+            |(sample, alias)| (alias, Some(sample)),
+        ),
+        map(
+            rule! { #table_alias? ~ #table_sample? },
+            |(alias, sample)| (alias, sample),
+        ),
+    ))(i)
+}
+
 pub fn alias_name(i: Input) -> IResult<Identifier> {
     let short_alias = map(
         rule! {
@@ -570,6 +618,8 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
         value(JoinOperator::RightOuter, rule! { RIGHT ~ OUTER? }),
         value(JoinOperator::FullOuter, rule! { FULL ~ OUTER? }),
         value(JoinOperator::CrossJoin, rule! { CROSS }),
+        value(JoinOperator::LeftAsof, rule! { ASOF ~ LEFT }),
+        value(JoinOperator::Asof, rule! { ASOF }),
     ))(i)
 }
 
@@ -627,6 +677,7 @@ pub enum TableReferenceElement {
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
         since_point: Option<TimeTravelPoint>,
+        sample: Option<SampleConfig>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
@@ -652,11 +703,14 @@ pub enum TableReferenceElement {
     },
     // ON expr | USING (ident, ...)
     JoinCondition(JoinCondition),
+    // MATCH_CONDITION(expr)
+    MatchCondition(Expr),
     Group(TableReference),
     Stage {
         location: FileLocation,
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
+        sample: Option<SampleConfig>,
     },
 }
 
@@ -685,9 +739,16 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?  ~ (SINCE ~ ^#travel_point)? ~ #table_alias? ~ #pivot? ~ #unpivot?
+            #dot_separated_idents_1_to_3 ~ (AT ~ ^#travel_point)?  ~ (SINCE ~ ^#travel_point)? ~ #table_alias_and_sample ~ #pivot? ~ #unpivot?
         },
-        |((catalog, database, table), travel_point_opt, since_point_opt, alias, pivot, unpivot)| {
+        |(
+            (catalog, database, table),
+            travel_point_opt,
+            since_point_opt,
+            (alias, sample),
+            pivot,
+            unpivot,
+        )| {
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -695,6 +756,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 alias,
                 travel_point: travel_point_opt.map(|p| p.1),
                 since_point: since_point_opt.map(|p| p.1),
+                sample,
                 pivot: pivot.map(Box::new),
                 unpivot: unpivot.map(Box::new),
             }
@@ -721,6 +783,12 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
         },
         |(_, _, idents, _)| TableReferenceElement::JoinCondition(JoinCondition::Using(idents)),
     );
+    let match_condition = map(
+        rule! {
+            MATCH_CONDITION ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableReferenceElement::MatchCondition(expr),
+    );
     let table_function = map(
         rule! {
             LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
@@ -751,9 +819,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_stage = map(
         rule! {
-            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ #table_alias?
+            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ #table_alias_and_sample
         },
-        |(location, options, alias)| {
+        |(location, options, (alias, sample))| {
             let options = options
                 .map(|(_, options, _)| options.into_iter().map(|(option, _)| option).collect())
                 .unwrap_or_default();
@@ -761,12 +829,14 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 location,
                 alias,
                 options,
+                sample,
             }
         },
     );
 
     let (rest, (span, elem)) = consumed(rule! {
         #aliased_stage
+        | #match_condition
         | #table_function
         | #aliased_table
         | #subquery
@@ -790,7 +860,8 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
     fn query(&mut self, input: &Self::Input) -> Result<Affix, &'static str> {
         let affix = match &input.elem {
             TableReferenceElement::Join { .. } => Affix::Infix(Precedence(10), Associativity::Left),
-            TableReferenceElement::JoinCondition(..) => Affix::Postfix(Precedence(5)),
+            TableReferenceElement::JoinCondition(..)
+            | TableReferenceElement::MatchCondition(..) => Affix::Postfix(Precedence(5)),
             _ => Affix::Nilfix,
         };
         Ok(affix)
@@ -806,6 +877,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 alias,
                 travel_point,
                 since_point,
+                sample,
                 pivot,
                 unpivot,
             } => TableReference::Table {
@@ -816,6 +888,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 alias,
                 travel_point,
                 since_point,
+                sample,
                 pivot,
                 unpivot,
             },
@@ -862,6 +935,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 let options = SelectStageOptions::from(options);
                 TableReference::Location {
@@ -869,6 +943,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     location,
                     options,
                     alias,
+                    sample,
                 }
             }
             _ => unreachable!(),
@@ -894,6 +969,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     join: Join {
                         op,
                         condition,
+                        match_condition: None,
                         left: Box::new(lhs),
                         right: Box::new(rhs),
                     },
@@ -932,6 +1008,24 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 },
                 _ => Err("join condition must apply to a join"),
             },
+            TableReferenceElement::MatchCondition(expr) => match &mut lhs {
+                TableReference::Join {
+                    join:
+                        Join {
+                            op: JoinOperator::Asof | JoinOperator::LeftAsof,
+                            match_condition,
+                            ..
+                        },
+                    ..
+                } => match match_condition {
+                    None => {
+                        *match_condition = Some(Box::new(expr));
+                        Ok(lhs)
+                    }
+                    Some(_) => Err("match condition already set"),
+                },
+                _ => Err("match condition must apply to an ASOF join"),
+            },
             _ => unreachable!(),
         }
     }
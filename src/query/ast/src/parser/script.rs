@@ -24,6 +24,21 @@ use crate::parser::statement::*;
 use crate::parser::token::*;
 use crate::rule;
 
+/// A script is a list of statements, optionally wrapped in `BEGIN ... END`.
+pub fn script_block(i: Input) -> IResult<Vec<ScriptStatement>> {
+    let begin_end_block = map(
+        rule! {
+            BEGIN ~ #semicolon_terminated_list1(script_stmt) ~ END ~ ";"?
+        },
+        |(_, body, _, _)| body,
+    );
+
+    rule!(
+        #begin_end_block
+        | #semicolon_terminated_list1(script_stmt)
+    )(i)
+}
+
 pub fn script_stmt(i: Input) -> IResult<ScriptStatement> {
     let let_query_stmt = map(
         consumed(rule! {
@@ -35,6 +35,7 @@ use crate::parser::common::*;
 use crate::parser::copy::copy_into;
 use crate::parser::copy::copy_into_table;
 use crate::parser::data_mask::data_mask_policy;
+use crate::parser::data_mask::row_access_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::input::Input;
@@ -57,6 +58,7 @@ pub enum ShowGrantOption {
 pub enum CreateDatabaseOption {
     DatabaseEngine(DatabaseEngine),
     FromShare(ShareNameIdent),
+    Clone(CloneDatabaseSource),
 }
 
 pub fn statement_body(i: Input) -> IResult<Statement> {
@@ -475,6 +477,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         engine: Some(engine),
                         options: vec![],
                         from_share: None,
+                        clone_from: None,
                     })
                 }
                 Some(CreateDatabaseOption::FromShare(share_name)) => {
@@ -484,6 +487,17 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         engine: None,
                         options: vec![],
                         from_share: Some(share_name),
+                        clone_from: None,
+                    })
+                }
+                Some(CreateDatabaseOption::Clone(clone_from)) => {
+                    Statement::CreateDatabase(CreateDatabaseStmt {
+                        create_option,
+                        database,
+                        engine: None,
+                        options: vec![],
+                        from_share: None,
+                        clone_from: Some(clone_from),
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -492,6 +506,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     engine: None,
                     options: vec![],
                     from_share: None,
+                    clone_from: None,
                 }),
             };
 
@@ -1066,6 +1081,80 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let create_vector_index = map_res(
+        rule! {
+            CREATE
+            ~ ( OR ~ ^REPLACE )?
+            ~ VECTOR ~ INDEX
+            ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#ident ~ ^")"
+            ~ USING ~ ^HNSW
+            ~ ( "(" ~ #set_table_option ~ ^")" )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            _,
+            _,
+            opt_index_options,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                create_option,
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                index_options: opt_index_options
+                    .map(|(_, options, _)| options)
+                    .unwrap_or_default(),
+            }))
+        },
+    );
+
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropVectorIndex(DropVectorIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
+    let refresh_vector_index = map(
+        rule! {
+            REFRESH ~ VECTOR ~ INDEX ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, index_name, _, (catalog, database, table))| {
+            Statement::RefreshVectorIndex(RefreshVectorIndexStmt {
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let create_virtual_column = map_res(
         rule! {
             CREATE
@@ -1233,6 +1322,18 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             role_name,
         },
     );
+    let alter_role = map(
+        rule! {
+            ALTER ~ ROLE ~ ( IF ~ ^EXISTS )? ~ #role_name ~ #alter_role_action
+        },
+        |(_, _, opt_if_exists, role_name, action)| {
+            Statement::AlterRole(AlterRoleStmt {
+                if_exists: opt_if_exists.is_some(),
+                role_name,
+                action,
+            })
+        },
+    );
     let drop_role = map(
         rule! {
             DROP ~ ROLE ~ ( IF ~ ^EXISTS )? ~ #role_name
@@ -1716,6 +1817,45 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, opt_or_replace, _, _, _, opt_if_not_exists, name, policy)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateRowAccessPolicyStmt {
+                create_option,
+                name: name.to_string(),
+                policy,
+            };
+            Ok(Statement::CreateRowAccessPolicy(stmt))
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            let stmt = DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropRowAccessPolicy(stmt)
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ ^POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map_res(
         rule! {
             CREATE ~  ( OR ~ ^REPLACE )? ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -1879,6 +2019,51 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ ( WITH ~ ^#comma_separated_list1(workload_group_option) )?
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, name, opt_options)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateWorkloadGroupStmt {
+                create_option,
+                name: name.to_string(),
+                options: WorkloadGroupOptions::from_items(
+                    opt_options.map(|(_, items)| items).unwrap_or_default(),
+                ),
+            };
+            Ok(Statement::CreateWorkloadGroup(stmt))
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+             ~ SET ~ ^#comma_separated_list1(workload_group_option)
+        },
+        |(_, _, _, opt_if_exists, name, _, items)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                options: WorkloadGroupOptions::from_items(items),
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -2016,6 +2201,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let execute_immediate = map(
+        rule! {
+            EXECUTE ~ IMMEDIATE ~ #code_string
+        },
+        |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
+    );
+
     let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
     let commit = value(Statement::Commit, rule! { COMMIT });
     let abort = value(Statement::Abort, rule! { ABORT | ROLLBACK });
@@ -2047,7 +2239,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / row access policy / workload group
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -2059,6 +2251,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_row_access_policy: "`CREATE [OR REPLACE] ROW ACCESS POLICY [IF NOT EXISTS] policy_name AS (arg1 arg_type1 [, ...]) RETURNS BOOLEAN -> <expr>`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] policy_name`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY policy_name`"
+            | #create_workload_group: "`CREATE [OR REPLACE] WORKLOAD GROUP [IF NOT EXISTS] name [WITH CPU_QUOTA = <u64_literal>, ... , COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name SET CPU_QUOTA = <u64_literal>, ... , COMMENT = '<string_literal>'`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
         ),
         rule!(
             #insert_stmt(false) : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -2110,6 +2308,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_inverted_index: "`CREATE [OR REPLACE] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>, ...)`"
             | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
             | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> ON [<database>.]<table> [LIMIT <limit>]`"
+            | #create_vector_index: "`CREATE [OR REPLACE] VECTOR INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>) USING HNSW [(<option> = <value>, ...)]`"
+            | #drop_vector_index: "`DROP VECTOR INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
+            | #refresh_vector_index: "`REFRESH VECTOR INDEX <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -2125,6 +2326,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_user : "`DROP USER [IF EXISTS] '<username>'@'hostname'`"
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
+            | #alter_role : "`ALTER ROLE [IF EXISTS] <role_name> (SET WORKLOAD GROUP = '<group_name>' | UNSET WORKLOAD GROUP)`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
@@ -2227,6 +2429,7 @@ AS
         | #drop_connection: "`DROP CONNECTION [IF EXISTS] <connection_name>`"
         | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
         | #show_connections: "`SHOW CONNECTIONS`"
+        | #execute_immediate : "`EXECUTE IMMEDIATE $$ <script> $$`"
         ),
     ))(i)
 }
@@ -2595,6 +2798,45 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     Ok((i, def))
 }
 
+pub fn table_constraint(i: Input) -> IResult<TableConstraint> {
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableConstraintKind::Check(expr),
+    );
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ ENFORCED?
+        },
+        |(_, _, _, columns, _, opt_enforced)| TableConstraintKind::PrimaryKey {
+            columns,
+            enforced: opt_enforced.is_some(),
+        },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ ENFORCED?
+        },
+        |(_, _, columns, _, opt_enforced)| TableConstraintKind::Unique {
+            columns,
+            enforced: opt_enforced.is_some(),
+        },
+    );
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )?
+            ~ ( #check | #primary_key | #unique )
+            : "`[CONSTRAINT <name>] {CHECK (<expr>) | PRIMARY KEY (<column>, ...) [ENFORCED] | UNIQUE (<column>, ...) [ENFORCED]}`"
+        },
+        |(opt_name, kind)| TableConstraint {
+            name: opt_name.map(|(_, name)| name),
+            kind,
+        },
+    )(i)
+}
+
 pub fn role_name(i: Input) -> IResult<String> {
     let role_ident = map(
         rule! {
@@ -2906,11 +3148,31 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    #[derive(Clone)]
+    enum TableElement {
+        Column(ColumnDefinition),
+        Constraint(TableConstraint),
+    }
+
+    let element = alt((
+        map(table_constraint, TableElement::Constraint),
+        map(column_def, TableElement::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -2922,10 +3184,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ ^#dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -3117,6 +3391,44 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ^ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
+    let create_tag = map(
+        rule! {
+            CREATE ~ TAG ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, _, opt_if_not_exists, tag, opt_travel_point)| AlterTableAction::CreateTag {
+            if_not_exists: opt_if_not_exists.is_some(),
+            tag,
+            travel_point: opt_travel_point.map(|(_, point)| point),
+        },
+    );
+
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, tag)| AlterTableAction::DropTag {
+            if_exists: opt_if_exists.is_some(),
+            tag,
+        },
+    );
+
     let revert_table = map(
         rule! {
             FLASHBACK ~ TO ~ #travel_point
@@ -3136,7 +3448,11 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
+        | #add_row_access_policy
         | #add_column
+        | #drop_row_access_policy
+        | #create_tag
+        | #drop_tag
         | #drop_column
         | #modify_column
         | #recluster_table
@@ -3619,9 +3935,22 @@ pub fn create_database_option(i: Input) -> IResult<CreateDatabaseOption> {
         },
     );
 
+    let clone_from = map(
+        rule! {
+            CLONE ~ ^#database_ref ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, database, opt_travel_point)| {
+            CreateDatabaseOption::Clone(CloneDatabaseSource {
+                database,
+                travel_point: opt_travel_point.map(|(_, point)| point),
+            })
+        },
+    );
+
     rule!(
         #create_db_engine
         | #share_from
+        | #clone_from
     )(i)
 }
 
@@ -3669,6 +3998,18 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetPasswordPolicy,
     );
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| UserOptionItem::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
+    );
 
     rule!(
         #tenant_setting
@@ -3678,6 +4019,8 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #unset_network_policy
         | #set_password_policy
         | #unset_password_policy
+        | #set_workload_group
+        | #unset_workload_group
     )(i)
 }
 
@@ -3746,6 +4089,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
             }),
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         },
@@ -3765,6 +4109,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             alias: None,
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         },
@@ -3825,19 +4170,34 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
             "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
             ~ RETURNS ~ #udf_arg_type
             ~ LANGUAGE ~ #ident
+            ~ ( RUNTIME_VERSION ~ ^"=" ~ ^#literal_string )?
             ~ HANDLER ~ ^"=" ~ ^#literal_string
             ~ AS ~ ^#code_string
         },
-        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, code)| {
+        |(
+            _,
+            arg_types,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            runtime_version,
+            _,
+            _,
+            handler,
+            _,
+            code,
+        )| {
             UDFDefinition::UDFScript {
                 arg_types,
                 return_type,
                 code,
                 handler,
                 language: language.to_string(),
-                // TODO inject runtime_version by user
-                // Now we use fixed runtime version
-                runtime_version: "".to_string(),
+                runtime_version: runtime_version
+                    .map(|(_, _, version)| version)
+                    .unwrap_or_default(),
             }
         },
     );
@@ -3845,7 +4205,7 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     rule!(
         #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
-        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <language_codes>"
+        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> [RUNTIME_VERSION=<version>] HANDLER=<handler> AS <language_codes>"
     )(i)
 }
 
@@ -3904,6 +4264,48 @@ pub fn password_set_options(i: Input) -> IResult<PasswordSetOptions> {
     )(i)
 }
 
+pub fn alter_role_action(i: Input) -> IResult<AlterRoleAction> {
+    let set_workload_group = map(
+        rule! {
+            SET ~ WORKLOAD ~ ^GROUP ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, _, group)| AlterRoleAction::SetWorkloadGroup(group),
+    );
+    let unset_workload_group = map(
+        rule! {
+            UNSET ~ WORKLOAD ~ ^GROUP
+        },
+        |(_, _, _)| AlterRoleAction::UnsetWorkloadGroup,
+    );
+
+    rule!(
+        #set_workload_group
+        | #unset_workload_group
+    )(i)
+}
+
+pub fn workload_group_option(i: Input) -> IResult<WorkloadGroupOption> {
+    alt((
+        map(rule! { CPU_QUOTA ~ ^Eq ~ ^#literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::CpuQuota(v)
+        }),
+        map(rule! { MEMORY_QUOTA ~ ^Eq ~ ^#literal_u64 }, |(_, _, v)| {
+            WorkloadGroupOption::MemoryQuota(v)
+        }),
+        map(
+            rule! { MAX_CONCURRENCY ~ ^Eq ~ ^#literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::MaxConcurrency(v),
+        ),
+        map(
+            rule! { QUEUE_TIMEOUT ~ ^Eq ~ ^#literal_u64 },
+            |(_, _, v)| WorkloadGroupOption::QueueTimeout(v),
+        ),
+        map(rule! { COMMENT ~ ^Eq ~ ^#literal_string }, |(_, _, v)| {
+            WorkloadGroupOption::Comment(v)
+        }),
+    ))(i)
+}
+
 pub fn password_unset_options(i: Input) -> IResult<PasswordUnSetOptions> {
     map(
         rule! {
@@ -320,6 +320,8 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ACCOUNT", ignore(ascii_case))]
     ACCOUNT,
     #[token("ALL", ignore(ascii_case))]
@@ -362,6 +364,8 @@ pub enum TokenKind {
     ASC,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ASYNC", ignore(ascii_case))]
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
@@ -384,6 +388,8 @@ pub enum TokenKind {
     TINYBLOB,
     #[token("BLOB", ignore(ascii_case))]
     BLOB,
+    #[token("BLOCK", ignore(ascii_case))]
+    BLOCK,
     #[token("BINARY_FORMAT", ignore(ascii_case))]
     BINARY_FORMAT,
     #[token("BITMAP", ignore(ascii_case))]
@@ -414,6 +420,8 @@ pub enum TokenKind {
     CATALOGS,
     #[token("CENTURY", ignore(ascii_case))]
     CENTURY,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -436,8 +444,12 @@ pub enum TokenKind {
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
     COLUMNS,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
     #[token("CHARACTER", ignore(ascii_case))]
     CHARACTER,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONFLICT", ignore(ascii_case))]
     CONFLICT,
     #[token("COMPRESSION", ignore(ascii_case))]
@@ -448,6 +460,8 @@ pub enum TokenKind {
     COPY,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
+    #[token("CPU_QUOTA", ignore(ascii_case))]
+    CPU_QUOTA,
     #[token("CREATE", ignore(ascii_case))]
     CREATE,
     #[token("CREDENTIALS", ignore(ascii_case))]
@@ -536,6 +550,8 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -630,6 +646,8 @@ pub enum TokenKind {
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
     HIVE,
+    #[token("HNSW", ignore(ascii_case))]
+    HNSW,
     #[token("HOUR", ignore(ascii_case))]
     HOUR,
     #[token("HOURS", ignore(ascii_case))]
@@ -644,6 +662,8 @@ pub enum TokenKind {
     IDENTIFIER,
     #[token("IF", ignore(ascii_case))]
     IF,
+    #[token("IMMEDIATE", ignore(ascii_case))]
+    IMMEDIATE,
     #[token("IN", ignore(ascii_case))]
     IN,
     #[token("INDEX", ignore(ascii_case))]
@@ -725,12 +745,16 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MEMORY_QUOTA", ignore(ascii_case))]
+    MEMORY_QUOTA,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -841,6 +865,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -871,6 +897,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -887,6 +915,8 @@ pub enum TokenKind {
     GRANT,
     #[token("REPEAT", ignore(ascii_case))]
     REPEAT,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
     #[token("PRECEDING", ignore(ascii_case))]
@@ -895,6 +925,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
@@ -915,6 +947,8 @@ pub enum TokenKind {
     RESULTSET,
     #[token("RUN", ignore(ascii_case))]
     RUN,
+    #[token("RUNTIME_VERSION", ignore(ascii_case))]
+    RUNTIME_VERSION,
     #[token("GRANTS", ignore(ascii_case))]
     GRANTS,
     #[token("RIGHT", ignore(ascii_case))]
@@ -925,6 +959,8 @@ pub enum TokenKind {
     RAW,
     #[token("OPTIMIZED", ignore(ascii_case))]
     OPTIMIZED,
+    #[token("SAMPLE", ignore(ascii_case))]
+    SAMPLE,
     #[token("SCHEMA", ignore(ascii_case))]
     SCHEMA,
     #[token("SCHEMAS", ignore(ascii_case))]
@@ -1017,6 +1053,10 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TEXT", ignore(ascii_case))]
     TEXT,
     #[token("LONGTEXT", ignore(ascii_case))]
@@ -1079,6 +1119,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1107,6 +1149,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
     #[token("VIEW", ignore(ascii_case))]
@@ -1125,6 +1169,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1482,6 +1528,7 @@ impl TokenKind {
             | TokenKind::FUNCTION
             | TokenKind::ASC
             | TokenKind::ANTI
+            | TokenKind::ASOF
             // | TokenKind::ASYMMETRIC
             // | TokenKind::AUTHORIZATION
             // | TokenKind::BINARY
@@ -1546,7 +1593,7 @@ impl TokenKind {
             | TokenKind::SEMI
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRANSACTION
@@ -1573,6 +1620,7 @@ impl TokenKind {
             // | TokenKind::ISNULL
             | TokenKind::LIMIT
             | TokenKind::FORMAT
+            | TokenKind::MATCH_CONDITION
             // | TokenKind::NOTNULL
             | TokenKind::OFFSET
             | TokenKind::ON
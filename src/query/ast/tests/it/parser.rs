@@ -119,6 +119,7 @@ fn test_statement() {
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
+        r#"CREATE OR REPLACE VECTOR INDEX idx3 ON t1 (a) USING HNSW (distance = 'cosine', m = 16);"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create or replace table a (c decimal(38))"#,
@@ -130,6 +131,8 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table a.b clone c.d at (snapshot => '9828b23f74664ff3806f44bbc1925ea5');"#,
+        r#"create table t (a int not null, b int, constraint ck_b check (b > 0), primary key (a) enforced, unique (b));"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/'
              connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900')
@@ -144,6 +147,7 @@ fn test_statement() {
         r#"create database ctl.t engine = Default;"#,
         r#"create database t engine = Default;"#,
         r#"create database t FROM SHARE a.s;"#,
+        r#"create database t clone s;"#,
         r#"CREATE TABLE `t3`(a int not null, b int not null, c int not null) bloom_index_columns='a,b,c' COMPRESSION='zstd' STORAGE_FORMAT='native';"#,
         r#"create or replace database a;"#,
         r#"drop database ctl.t;"#,
@@ -181,6 +185,9 @@ fn test_statement() {
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1, TENANTSETTING;"#,
         r#"ALTER USER u1 WITH SET NETWORK POLICY = 'policy1';"#,
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
+        r#"ALTER USER u1 WITH SET WORKLOAD GROUP = 'etl';"#,
+        r#"ALTER ROLE etl_role SET WORKLOAD GROUP = 'etl';"#,
+        r#"ALTER ROLE IF EXISTS 'etl_role' UNSET WORKLOAD GROUP;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"DROP database if exists db1;"#,
@@ -539,6 +546,18 @@ fn test_statement() {
         r#"CREATE OR REPLACE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
         r#"DROP MASKING POLICY IF EXISTS email_mask"#,
+        r#"CREATE ROW ACCESS POLICY rap AS (region STRING) RETURNS BOOLEAN -> region = 'east'"#,
+        r#"DESC ROW ACCESS POLICY rap"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS rap"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY rap ON (region, dept);"#,
+        r#"ALTER TABLE t DROP ROW ACCESS POLICY rap;"#,
+        r#"ALTER TABLE t CREATE TAG IF NOT EXISTS v1 AT (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5');"#,
+        r#"ALTER TABLE t CREATE TAG v2 AT (TAG => v1);"#,
+        r#"ALTER TABLE t DROP TAG IF EXISTS v1;"#,
+        r#"CREATE WORKLOAD GROUP etl WITH cpu_quota = 30, memory_quota = 1073741824, max_concurrency = 10, queue_timeout = 60"#,
+        r#"CREATE OR REPLACE WORKLOAD GROUP etl"#,
+        r#"ALTER WORKLOAD GROUP IF EXISTS etl SET max_concurrency = 20, comment = 'nightly jobs'"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl"#,
         r#"CREATE VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"CREATE OR REPLACE VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
         r#"ALTER VIRTUAL COLUMN (a['k1']['k2'], b[0][1]) FOR t"#,
@@ -607,6 +626,7 @@ fn test_statement() {
         r#"DROP TASK MyTask1"#,
         r#"SHOW TASKS"#,
         r#"EXECUTE TASK MyTask"#,
+        r#"EXECUTE IMMEDIATE $$ BEGIN RETURN 1; END; $$"#,
         r#"DESC TASK MyTask"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3'"#,
         r#"CREATE CONNECTION IF NOT EXISTS my_conn STORAGE_TYPE='s3' any_arg='any_value'"#,
@@ -656,6 +676,7 @@ $$
 def addone_py(i):
   return i+1
 $$;"#,
+        "create function addone_v(int) returns int language python runtime_version = '3.12' handler = 'addone_py' as $$def addone_py(i): return i+1$$;",
         "DROP FUNCTION binary_reverse;",
         "DROP FUNCTION isnotempty;",
     ];
@@ -799,6 +820,9 @@ fn test_query() {
         r#"select * from customer inner join orders on a = b limit 2 offset 3"#,
         r#"select * from customer natural full join orders"#,
         r#"select * from customer natural join orders left outer join detail using (id)"#,
+        r#"select * from t1 asof join t2 match_condition(a >= b) on c = d"#,
+        r#"select * from t tablesample block (10) repeatable (1)"#,
+        r#"select * from t1 as a sample (0.1)"#,
         r#"with t2(tt) as (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2(tt) as materialized (select a from t) select t2.tt from t2  where t2.tt > 1"#,
         r#"with t2 as (select a from t) select t2.a from t2  where t2.a > 1"#,
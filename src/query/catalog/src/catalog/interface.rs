@@ -27,6 +27,8 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableIndexReply;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
@@ -38,6 +40,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableIndexReply;
 use databend_common_meta_app::schema::DropTableIndexReq;
@@ -50,6 +54,8 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::GcDroppedTableResp;
 use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
+use databend_common_meta_app::schema::GetPipeReply;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::IndexMeta;
@@ -58,15 +64,19 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -79,6 +89,8 @@ use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeReply;
+use databend_common_meta_app::schema::UpdatePipeReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -166,6 +178,26 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: ListVirtualColumnsReq,
     ) -> Result<Vec<VirtualColumnMeta>>;
 
+    async fn create_pipe(&self, _req: CreatePipeReq) -> Result<CreatePipeReply> {
+        Err(ErrorCode::Unimplemented("'create_pipe' not implemented"))
+    }
+
+    async fn drop_pipe(&self, _req: DropPipeReq) -> Result<DropPipeReply> {
+        Err(ErrorCode::Unimplemented("'drop_pipe' not implemented"))
+    }
+
+    async fn get_pipe(&self, _req: GetPipeReq) -> Result<GetPipeReply> {
+        Err(ErrorCode::Unimplemented("'get_pipe' not implemented"))
+    }
+
+    async fn update_pipe(&self, _req: UpdatePipeReq) -> Result<UpdatePipeReply> {
+        Err(ErrorCode::Unimplemented("'update_pipe' not implemented"))
+    }
+
+    async fn list_pipes(&self, _req: ListPipesReq) -> Result<Vec<PipeMeta>> {
+        Err(ErrorCode::Unimplemented("'list_pipes' not implemented"))
+    }
+
     #[async_backtrace::framed]
     async fn exists_database(&self, tenant: &str, db_name: &str) -> Result<bool> {
         match self.get_database(tenant, db_name).await {
@@ -280,6 +312,11 @@ pub trait Catalog: DynClone + Send + Sync + Debug {
         req: SetTableColumnMaskPolicyReq,
     ) -> Result<SetTableColumnMaskPolicyReply>;
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply>;
+
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<CreateTableIndexReply>;
 
     async fn drop_table_index(&self, req: DropTableIndexReq) -> Result<DropTableIndexReply>;
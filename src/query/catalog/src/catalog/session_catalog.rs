@@ -26,6 +26,8 @@ use databend_common_meta_app::schema::CreateIndexReply;
 use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableIndexReply;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
@@ -37,6 +39,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableIndexReply;
 use databend_common_meta_app::schema::DropTableIndexReq;
@@ -49,6 +53,8 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::GcDroppedTableResp;
 use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
+use databend_common_meta_app::schema::GetPipeReply;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::IndexMeta;
@@ -57,15 +63,19 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -78,6 +88,8 @@ use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeReply;
+use databend_common_meta_app::schema::UpdatePipeReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -205,6 +217,26 @@ impl Catalog for SessionCatalog {
         self.inner.list_virtual_columns(req).await
     }
 
+    async fn create_pipe(&self, req: CreatePipeReq) -> Result<CreatePipeReply> {
+        self.inner.create_pipe(req).await
+    }
+
+    async fn drop_pipe(&self, req: DropPipeReq) -> Result<DropPipeReply> {
+        self.inner.drop_pipe(req).await
+    }
+
+    async fn get_pipe(&self, req: GetPipeReq) -> Result<GetPipeReply> {
+        self.inner.get_pipe(req).await
+    }
+
+    async fn update_pipe(&self, req: UpdatePipeReq) -> Result<UpdatePipeReply> {
+        self.inner.update_pipe(req).await
+    }
+
+    async fn list_pipes(&self, req: ListPipesReq) -> Result<Vec<PipeMeta>> {
+        self.inner.list_pipes(req).await
+    }
+
     async fn rename_database(&self, req: RenameDatabaseReq) -> Result<RenameDatabaseReply> {
         self.inner.rename_database(req).await
     }
@@ -376,6 +408,13 @@ impl Catalog for SessionCatalog {
         self.inner.set_table_column_mask_policy(req).await
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        self.inner.set_table_row_access_policy(req).await
+    }
+
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<CreateTableIndexReply> {
         self.inner.create_table_index(req).await
     }
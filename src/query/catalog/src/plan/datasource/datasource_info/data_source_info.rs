@@ -17,6 +17,7 @@ use std::sync::Arc;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableInfo;
 
+use crate::plan::OrcTableInfo;
 use crate::plan::ParquetTableInfo;
 use crate::plan::ResultScanTableInfo;
 use crate::plan::StageTableInfo;
@@ -29,6 +30,8 @@ pub enum DataSourceInfo {
     StageSource(StageTableInfo),
     // stage source with parquet format used for select.
     ParquetSource(ParquetTableInfo),
+    // stage source with orc format used for select.
+    OrcSource(OrcTableInfo),
     // Table Function Result_Scan
     ResultScanSource(ResultScanTableInfo),
 }
@@ -39,6 +42,7 @@ impl DataSourceInfo {
             DataSourceInfo::TableSource(table_info) => table_info.schema(),
             DataSourceInfo::StageSource(table_info) => table_info.schema(),
             DataSourceInfo::ParquetSource(table_info) => table_info.schema(),
+            DataSourceInfo::OrcSource(table_info) => table_info.schema(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.schema(),
         }
     }
@@ -48,6 +52,7 @@ impl DataSourceInfo {
             DataSourceInfo::TableSource(table_info) => table_info.desc.clone(),
             DataSourceInfo::StageSource(table_info) => table_info.desc(),
             DataSourceInfo::ParquetSource(table_info) => table_info.desc(),
+            DataSourceInfo::OrcSource(table_info) => table_info.desc(),
             DataSourceInfo::ResultScanSource(table_info) => table_info.desc(),
         }
     }
@@ -13,12 +13,14 @@
 // limitations under the License.
 
 mod data_source_info;
+mod orc;
 mod parquet;
 mod parquet_read_options;
 mod result_scan;
 mod stage;
 
 pub use data_source_info::DataSourceInfo;
+pub use orc::OrcTableInfo;
 pub use parquet::FullParquetMeta;
 pub use parquet::ParquetTableInfo;
 pub use parquet_read_options::ParquetReadOptions;
@@ -13,8 +13,11 @@
 // limitations under the License.
 
 use std::fmt::Debug;
+use std::hash::Hasher;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::F32;
 use databend_common_expression::DataSchema;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::Scalar;
@@ -22,6 +25,7 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::ChangeType;
+use siphasher::sip::SipHasher13;
 
 use super::AggIndexInfo;
 use crate::plan::Projection;
@@ -77,6 +81,76 @@ pub struct InvertedIndexInfo {
     pub query_text: String,
 }
 
+/// Nearest-neighbour search on the vector column of the table, generated from
+/// `ORDER BY <distance>(col, <query>) LIMIT k` if the column has a vector index.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    pub index_name: String,
+    pub index_version: String,
+    /// Name of the vector column in the table schema.
+    pub column_name: String,
+    pub query: Vec<F32>,
+    /// The number of the nearest rows to be searched.
+    pub limit: usize,
+}
+
+/// Sampling of the table, generated from `TABLESAMPLE`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SampleConfig {
+    pub level: SampleLevel,
+    /// The probability of a block (or row) to be sampled, in the range of [0, 1].
+    pub probability: f64,
+    /// The seed of `REPEATABLE`, the sampling is random if it is not set.
+    pub seed: Option<u64>,
+}
+
+// The probability is checked when binding, it can't be NaN.
+impl Eq for SampleConfig {}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleLevel {
+    Row,
+    Block,
+}
+
+impl SampleConfig {
+    /// Whether the part identified by `key`, such as the location of a block or a file,
+    /// is kept by the block sampling. With a seed, the result is stable for the same key.
+    pub fn sample_part(&self, key: &str) -> bool {
+        match self.seed {
+            Some(seed) => stable_fraction(seed, key, None) < self.probability,
+            None => rand::random::<f64>() < self.probability,
+        }
+    }
+
+    /// Whether the row at `offset` of the block identified by `key` is kept by the row sampling.
+    /// With a seed, the result is stable for the same row no matter which processor reads it.
+    pub fn sample_row(&self, key: &str, offset: usize) -> bool {
+        match self.seed {
+            Some(seed) => stable_fraction(seed, key, Some(offset as u64)) < self.probability,
+            None => rand::random::<f64>() < self.probability,
+        }
+    }
+
+    /// Sample the rows randomly, returns the bitmap of the selected rows.
+    pub fn sample_random_rows(&self, num_rows: usize) -> Bitmap {
+        (0..num_rows)
+            .map(|_| rand::random::<f64>() < self.probability)
+            .collect()
+    }
+}
+
+/// Map the seed, key and offset to a fraction in [0, 1]. The hash function is fixed,
+/// so `REPEATABLE` samples the same data across queries, processes and releases.
+fn stable_fraction(seed: u64, key: &str, offset: Option<u64>) -> f64 {
+    let mut hasher = SipHasher13::new_with_keys(seed, 0);
+    hasher.write(key.as_bytes());
+    if let Some(offset) = offset {
+        hasher.write(&offset.to_le_bytes());
+    }
+    hasher.finish() as f64 / u64::MAX as f64
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -106,6 +180,10 @@ pub struct PushDownInfo {
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
     pub inverted_index: Option<InvertedIndexInfo>,
+    /// Optional vector index information, used to prune the blocks.
+    pub vector_index: Option<VectorIndexInfo>,
+    /// Optional sampling of the table.
+    pub sample: Option<SampleConfig>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
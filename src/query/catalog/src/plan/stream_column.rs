@@ -93,6 +93,9 @@ impl StreamTablePart {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub struct StreamColumnMeta {
     pub block_id: i128,
+    /// The row numbers of the rows in the origin block, `None` means the rows are
+    /// read from the beginning of the block without any filtering.
+    pub offsets: Option<Vec<usize>>,
     pub inner: Option<BlockMetaInfoPtr>,
 }
 
@@ -123,6 +126,17 @@ impl StreamColumnMeta {
             },
         )))
     }
+
+    pub fn build_origin_block_row_num(&self, num_rows: usize) -> BlockEntry {
+        match &self.offsets {
+            Some(offsets) => {
+                debug_assert_eq!(offsets.len(), num_rows);
+                let row_nums = offsets.iter().map(|i| *i as u64).collect();
+                wrap_origin_block_row_num(row_nums)
+            }
+            None => build_origin_block_row_num(num_rows),
+        }
+    }
 }
 
 pub fn build_origin_block_row_num(num_rows: usize) -> BlockEntry {
@@ -130,6 +144,10 @@ pub fn build_origin_block_row_num(num_rows: usize) -> BlockEntry {
     for i in 0..num_rows {
         row_ids.push(i as u64);
     }
+    wrap_origin_block_row_num(row_ids)
+}
+
+fn wrap_origin_block_row_num(row_ids: Vec<u64>) -> BlockEntry {
     let column = Value::Column(UInt64Type::from_data(row_ids));
 
     BlockEntry::new(
@@ -216,7 +234,7 @@ impl StreamColumn {
                 )))),
                 meta.build_origin_block_id(),
             ),
-            StreamColumnType::OriginRowNum => build_origin_block_row_num(num_rows),
+            StreamColumnType::OriginRowNum => meta.build_origin_block_row_num(num_rows),
         }
     }
 }
@@ -245,5 +263,9 @@ pub fn gen_mutation_stream_meta(
     path: &str,
 ) -> Result<StreamColumnMeta> {
     let block_id = block_id_from_location(path)?;
-    Ok(StreamColumnMeta { block_id, inner })
+    Ok(StreamColumnMeta {
+        block_id,
+        offsets: None,
+        inner,
+    })
 }
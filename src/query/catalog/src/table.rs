@@ -154,6 +154,18 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// Whether the table engine can sample the blocks (or files, row groups) when reading.
+    /// For other engines, `TABLESAMPLE BLOCK` falls back to sampling the rows.
+    fn support_block_sample(&self) -> bool {
+        false
+    }
+
+    /// Whether the table engine samples the rows by their positions when reading, so that
+    /// `TABLESAMPLE ROW ... REPEATABLE` selects the same rows no matter how the data is read.
+    fn support_row_sample(&self) -> bool {
+        false
+    }
+
     #[async_backtrace::framed]
     async fn alter_table_cluster_keys(
         &self,
@@ -433,6 +445,8 @@ impl<T: ?Sized> TableExt for T where T: Table {}
 pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
+    /// The snapshot pinned by a named tag of the table.
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone, Default)]
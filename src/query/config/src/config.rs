@@ -1402,6 +1402,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_running_queries: u64,
 
+    /// The interval in seconds to load the new files of the pipes with AUTO_INGEST,
+    /// 0 disables the auto ingestion.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub pipe_auto_ingest_interval_secs: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1650,6 +1655,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE")]
     pub udf_server_allow_list: Vec<String>,
 
+    /// A list of modules that python script udfs are allowed to import,
+    /// the sandboxed python runtime only provides a limited set of modules like `math` and `re`.
+    #[clap(long, value_name = "VALUE")]
+    pub python_udf_allow_imports: Vec<String>,
+
     #[clap(long)]
     pub cloud_control_grpc_server_address: Option<String>,
 
@@ -1683,6 +1693,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            pipe_auto_ingest_interval_secs: self.pipe_auto_ingest_interval_secs,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1737,6 +1748,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             openai_api_version: self.openai_api_version,
             enable_udf_server: self.enable_udf_server,
             udf_server_allow_list: self.udf_server_allow_list,
+            python_udf_allow_imports: self.python_udf_allow_imports,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
             settings: self
@@ -1763,6 +1775,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            pipe_auto_ingest_interval_secs: inner.pipe_auto_ingest_interval_secs,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1834,6 +1847,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             openai_api_embedding_model: inner.openai_api_embedding_model,
             enable_udf_server: inner.enable_udf_server,
             udf_server_allow_list: inner.udf_server_allow_list,
+            python_udf_allow_imports: inner.python_udf_allow_imports,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
             settings: HashMap::new(),
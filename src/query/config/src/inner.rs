@@ -162,6 +162,8 @@ pub struct QueryConfig {
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    /// The interval in seconds to load the new files of the pipes with AUTO_INGEST.
+    pub pipe_auto_ingest_interval_secs: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -228,6 +230,7 @@ pub struct QueryConfig {
 
     pub enable_udf_server: bool,
     pub udf_server_allow_list: Vec<String>,
+    pub python_udf_allow_imports: Vec<String>,
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
@@ -248,6 +251,7 @@ impl Default for QueryConfig {
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 0,
+            pipe_auto_ingest_interval_secs: 60,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -299,6 +303,7 @@ impl Default for QueryConfig {
             openai_api_embedding_model: "text-embedding-ada-002".to_string(),
             enable_udf_server: false,
             udf_server_allow_list: Vec::new(),
+            python_udf_allow_imports: Vec::new(),
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
             data_retention_time_in_days_max: 90,
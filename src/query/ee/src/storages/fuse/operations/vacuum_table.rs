@@ -59,7 +59,8 @@ impl SnapshotReferencedFiles {
     }
 }
 
-// return all the segment\block\index files referenced by current snapshot.
+// return all the segment\block\index files referenced by current snapshot,
+// and the files of this table referenced by the tables cloned from it.
 #[async_backtrace::framed]
 pub async fn get_snapshot_referenced_files(
     fuse_table: &FuseTable,
@@ -129,10 +130,18 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // 3. Files shared with the cloned tables are still referenced.
+    let retained = fuse_table.get_retained_locations(ctx).await?;
+    segments.extend(retained.segments);
+    blocks.extend(retained.locations.block_location);
+    blocks_index.extend(retained.locations.bloom_location);
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -143,7 +152,14 @@ async fn get_orphan_files_to_be_purged(
     referenced_files: HashSet<String>,
     retention_time: DateTime<Utc>,
 ) -> Result<Vec<String>> {
-    let files_to_be_purged = match referenced_files.iter().next().cloned() {
+    // A cloned table may reference the files of its source tables, only the
+    // files under the prefix of the table itself can be purged.
+    let table_prefix = format!("{}/", fuse_table.meta_location_generator().prefix());
+    let files_to_be_purged = match referenced_files
+        .iter()
+        .find(|location| location.starts_with(&table_prefix))
+        .cloned()
+    {
         Some(location) => {
             let prefix = SnapshotsIO::get_s3_prefix_from_file(&location);
             if let Some(prefix) = prefix {
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_storages_fuse::io::read::load_inverted_index_info;
 use databend_common_storages_fuse::io::read::InvertedIndexReader;
 use databend_common_storages_fuse::FuseTable;
@@ -56,6 +59,9 @@ async fn test_fuse_do_refresh_inverted_index() -> Result<()> {
         table_id,
         name: index_name.clone(),
         column_ids: vec![0, 1],
+        index_type: TableIndexType::Inverted,
+        options: BTreeMap::new(),
+        version: "".to_string(),
     };
 
     let res = handler.do_create_table_index(catalog, req).await;
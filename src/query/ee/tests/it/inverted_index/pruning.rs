@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Engine;
@@ -31,6 +32,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storages_fuse::pruning::create_segment_location_vector;
@@ -411,6 +413,9 @@ async fn test_block_pruner() -> Result<()> {
         table_id,
         name: index_name.clone(),
         column_ids: vec![1, 2],
+        index_type: TableIndexType::Inverted,
+        options: BTreeMap::new(),
+        version: "".to_string(),
     };
 
     let res = handler.do_create_table_index(catalog, req).await;
@@ -17,6 +17,7 @@ use ordered_float::OrderedFloat;
 
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::months_days_micros;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BinaryType;
@@ -25,6 +26,7 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -68,6 +70,7 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
         DataType::Boolean => combine_group_hash_type_column::<IS_FIRST, BooleanType>(c, values),
         DataType::Timestamp => combine_group_hash_type_column::<IS_FIRST, TimestampType>(c, values),
         DataType::Date => combine_group_hash_type_column::<IS_FIRST, DateType>(c, values),
+        DataType::Interval => combine_group_hash_type_column::<IS_FIRST, IntervalType>(c, values),
         DataType::Binary => combine_group_hash_string_column::<IS_FIRST, BinaryType>(c, values),
         DataType::String => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
         DataType::Bitmap => combine_group_hash_string_column::<IS_FIRST, BitmapType>(c, values),
@@ -220,6 +223,12 @@ impl AggHash for i128 {
     }
 }
 
+impl AggHash for months_days_micros {
+    fn agg_hash(&self) -> u64 {
+        self.0.agg_hash()
+    }
+}
+
 impl AggHash for i256 {
     fn agg_hash(&self) -> u64 {
         self.to_le_bytes().agg_hash()
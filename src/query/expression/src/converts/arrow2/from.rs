@@ -16,8 +16,10 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use databend_common_arrow::arrow::datatypes::Field as ArrowField;
+use databend_common_arrow::arrow::datatypes::IntervalUnit;
 use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::arrow::datatypes::TimeUnit;
+use databend_common_arrow::arrow::types::months_days_ns;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
@@ -29,6 +31,7 @@ use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::DataType;
@@ -122,6 +125,7 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
 
         ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
         ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => TableDataType::Interval,
         ArrowDataType::Map(f, _) => {
             let inner_ty = arrow_type_to_table_type(&f.data_type, f.is_nullable)?;
             TableDataType::Map(Box::new(inner_ty))
@@ -520,6 +524,33 @@ impl Column {
                         .values()
                         .clone(),
                 ),
+                (DataType::Interval, ArrowDataType::Interval(IntervalUnit::MonthDayNano)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<months_days_ns>>()
+                        .expect(
+                            "fail to read `Interval` from arrow: array should be `PrimitiveArray<months_days_ns>`",
+                        )
+                        .values()
+                        .iter()
+                        .map(|v| months_days_micros::new(v.months(), v.days(), v.ns() / 1000))
+                        .collect();
+                    Column::Interval(values)
+                }
+                // The intervals in storage are the packed `i128`, see `storage_table_schema`.
+                (DataType::Interval, ArrowDataType::Decimal(_, _)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `Interval` from arrow: array should be `PrimitiveArray<i128>`",
+                        )
+                        .values()
+                        .clone();
+                    Column::Interval(unsafe {
+                        std::mem::transmute::<Buffer<i128>, Buffer<months_days_micros>>(values)
+                    })
+                }
                 (
                     DataType::Variant,
                     ArrowDataType::Extension(name, box ArrowDataType::Binary, None),
@@ -16,28 +16,38 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use databend_common_arrow::arrow::datatypes::Field as ArrowField;
+use databend_common_arrow::arrow::datatypes::IntervalUnit;
 use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::arrow::datatypes::TimeUnit;
 use databend_common_arrow::arrow::offset::OffsetsBuffer;
+use databend_common_arrow::arrow::types::months_days_ns;
 
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOMETRY;
 use super::ARROW_EXT_TYPE_VARIANT;
+use crate::types::array::ArrayColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
+use crate::types::nullable::NullableColumn;
+use crate::types::DataType;
 use crate::types::DecimalDataType;
+use crate::types::DecimalSize;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
 use crate::types::F32;
 use crate::types::F64;
 use crate::with_number_type;
+use crate::BlockEntry;
 use crate::Column;
+use crate::DataBlock;
 use crate::DataField;
 use crate::DataSchema;
 use crate::TableDataType;
 use crate::TableField;
 use crate::TableSchema;
+use crate::Value;
 
 impl From<&TableSchema> for ArrowSchema {
     fn from(schema: &TableSchema) -> Self {
@@ -79,6 +89,101 @@ impl From<&DataField> for ArrowField {
     }
 }
 
+/// The schema of the blocks written by the storage formats.
+///
+/// Neither the parquet writers nor the native format can write arrow's month-day-nano
+/// intervals, so an interval is stored as its packed `i128` in a `Decimal(38, 0)` column,
+/// which `Column::from_arrow` reads back as an interval.
+pub fn storage_table_schema(schema: &TableSchema) -> TableSchema {
+    let mut schema = schema.clone();
+    for field in schema.fields.iter_mut() {
+        field.data_type = storage_table_type(&field.data_type);
+    }
+    schema
+}
+
+/// Converts the interval columns of the block to the columns of [`storage_table_schema`].
+pub fn storage_data_block(block: &DataBlock) -> DataBlock {
+    let num_rows = block.num_rows();
+    let columns = block
+        .columns()
+        .iter()
+        .map(|entry| {
+            if !contains_interval(&entry.data_type) {
+                return entry.clone();
+            }
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column = storage_column(column);
+            BlockEntry::new(column.data_type(), Value::Column(column))
+        })
+        .collect();
+    DataBlock::new(columns, num_rows)
+}
+
+fn contains_interval(ty: &DataType) -> bool {
+    match ty {
+        DataType::Interval => true,
+        DataType::Nullable(ty) | DataType::Array(ty) | DataType::Map(ty) => contains_interval(ty),
+        DataType::Tuple(tys) => tys.iter().any(contains_interval),
+        _ => false,
+    }
+}
+
+fn storage_table_type(ty: &TableDataType) -> TableDataType {
+    match ty {
+        TableDataType::Interval => {
+            TableDataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                precision: 38,
+                scale: 0,
+            }))
+        }
+        TableDataType::Nullable(ty) => TableDataType::Nullable(Box::new(storage_table_type(ty))),
+        TableDataType::Array(ty) => TableDataType::Array(Box::new(storage_table_type(ty))),
+        TableDataType::Map(ty) => TableDataType::Map(Box::new(storage_table_type(ty))),
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => TableDataType::Tuple {
+            fields_name: fields_name.clone(),
+            fields_type: fields_type.iter().map(storage_table_type).collect(),
+        },
+        ty => ty.clone(),
+    }
+}
+
+fn storage_column(column: Column) -> Column {
+    match column {
+        Column::Interval(col) => {
+            let values =
+                unsafe { std::mem::transmute::<Buffer<months_days_micros>, Buffer<i128>>(col) };
+            Column::Decimal(DecimalColumn::Decimal128(values, DecimalSize {
+                precision: 38,
+                scale: 0,
+            }))
+        }
+        Column::Nullable(box NullableColumn { column, validity }) => {
+            Column::Nullable(Box::new(NullableColumn {
+                column: storage_column(column),
+                validity,
+            }))
+        }
+        Column::Array(box ArrayColumn { values, offsets }) => {
+            Column::Array(Box::new(ArrayColumn {
+                values: storage_column(values),
+                offsets,
+            }))
+        }
+        Column::Map(box ArrayColumn { values, offsets }) => Column::Map(Box::new(ArrayColumn {
+            values: storage_column(values),
+            offsets,
+        })),
+        Column::Tuple(fields) => Column::Tuple(fields.into_iter().map(storage_column).collect()),
+        column => column,
+    }
+}
+
 // Note: Arrow's data type is not nullable, so we need to explicitly
 // add nullable information to Arrow's field afterwards.
 fn table_type_to_arrow_type(
@@ -112,6 +217,7 @@ fn table_type_to_arrow_type(
         }
         TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
         TableDataType::Date => ArrowDataType::Date32,
+        TableDataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
         TableDataType::Nullable(ty) => {
             table_type_to_arrow_type(ty.as_ref(), true, ignore_inside_nullable)
         }
@@ -354,6 +460,25 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => {
+                // The microseconds saturate at the range of the nanoseconds.
+                let values: Buffer<months_days_ns> = col
+                    .iter()
+                    .map(|v| {
+                        months_days_ns::new(
+                            v.months(),
+                            v.days(),
+                            v.microseconds().saturating_mul(1000),
+                        )
+                    })
+                    .collect();
+                Box::new(
+                    databend_common_arrow::arrow::array::PrimitiveArray::<months_days_ns>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
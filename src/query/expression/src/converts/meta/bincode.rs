@@ -151,7 +151,7 @@ impl From<Scalar> for LegacyScalar {
             Scalar::Timestamp(ts) => LegacyScalar::Timestamp(ts),
             Scalar::Date(date) => LegacyScalar::Date(date),
             Scalar::Boolean(b) => LegacyScalar::Boolean(b),
-            Scalar::Binary(_) | Scalar::Geometry(_) => unreachable!(),
+            Scalar::Binary(_) | Scalar::Interval(_) | Scalar::Geometry(_) => unreachable!(),
             Scalar::String(string) => LegacyScalar::String(string.as_bytes().to_vec()),
             Scalar::Array(column) => LegacyScalar::Array(column.into()),
             Scalar::Map(column) => LegacyScalar::Map(column.into()),
@@ -171,7 +171,7 @@ impl From<Column> for LegacyColumn {
             Column::Number(num_col) => LegacyColumn::Number(num_col),
             Column::Decimal(dec_col) => LegacyColumn::Decimal(dec_col),
             Column::Boolean(bmp) => LegacyColumn::Boolean(bmp),
-            Column::Binary(_) | Column::Geometry(_) => unreachable!(),
+            Column::Binary(_) | Column::Interval(_) | Column::Geometry(_) => unreachable!(),
             Column::String(str_col) => LegacyColumn::String(str_col.into()),
             Column::Timestamp(buf) => LegacyColumn::Timestamp(buf),
             Column::Date(buf) => LegacyColumn::Date(buf),
@@ -77,6 +77,7 @@ impl TryFrom<Scalar> for IndexScalar {
             Scalar::Array(_)
             | Scalar::Map(_)
             | Scalar::Bitmap(_)
+            | Scalar::Interval(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
             | Scalar::EmptyArray
@@ -282,6 +282,13 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                Column::Interval(builder.into())
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
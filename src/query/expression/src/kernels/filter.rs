@@ -139,6 +139,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
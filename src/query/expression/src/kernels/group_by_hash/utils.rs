@@ -90,6 +90,7 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Interval(v) => store_advance::<i128>(&v[row].0, row_space),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
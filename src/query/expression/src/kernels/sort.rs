@@ -24,6 +24,7 @@ use databend_common_arrow::arrow::compute::merge_sort as arrow_merge_sort;
 use databend_common_arrow::arrow::compute::merge_sort::build_comparator_impl;
 use databend_common_arrow::arrow::compute::sort as arrow_sort;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowType;
+use databend_common_arrow::arrow::datatypes::IntervalUnit;
 use databend_common_arrow::arrow::error::Error as ArrowError;
 use databend_common_arrow::arrow::error::Result as ArrowResult;
 use databend_common_exception::ErrorCode;
@@ -228,6 +229,20 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_interval(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Column::from_arrow(left, &DataType::Interval)
+        .unwrap()
+        .as_interval()
+        .cloned()
+        .unwrap();
+    let right = Column::from_arrow(right, &DataType::Interval)
+        .unwrap()
+        .as_interval()
+        .cloned()
+        .unwrap();
+    Ok(Box::new(move |i, j| left[i].cmp(&right[j])))
+}
+
 fn compare_null() -> ArrowResult<DynComparator> {
     Ok(Box::new(move |_, _| Ordering::Equal))
 }
@@ -263,6 +278,7 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
             ))),
         },
         ArrowType::Null => compare_null(),
+        ArrowType::Interval(IntervalUnit::MonthDayNano) => compare_interval(left, right),
         ArrowType::Decimal256(_, _) => compare_decimal256(left, right),
         _ => arrow_ord::build_compare(left, right),
     }
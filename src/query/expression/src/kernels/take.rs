@@ -142,6 +142,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(indices.len() + 1);
                 offsets.push(0);
@@ -136,6 +136,10 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -111,6 +111,10 @@ impl Column {
                 let d = Self::take_ranges_primitive_types(column, ranges, num_rows);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
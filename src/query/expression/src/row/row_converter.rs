@@ -24,6 +24,7 @@ use super::variable;
 use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::months_days_micros;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
@@ -123,6 +124,9 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += months_days_micros::ENCODED_LEN as u64),
                 DataType::Binary => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -254,6 +258,7 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
             out,
@@ -189,6 +189,7 @@ pub enum TableDataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
@@ -1174,6 +1175,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -1410,6 +1412,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -23,6 +23,7 @@ pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
 pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
@@ -52,6 +53,7 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -80,6 +82,7 @@ pub enum DataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Interval,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
@@ -138,6 +141,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry => false,
@@ -161,6 +165,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
@@ -0,0 +1,481 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+pub const DAYS_IN_A_MONTH: i32 = 30;
+pub const MONTHS_IN_A_YEAR: i32 = 12;
+
+/// An interval of months, days and microseconds, packed into an `i128` with the months in the
+/// highest 32 bits, the days in the next 32 bits and the microseconds in the lowest 64 bits.
+///
+/// The three parts are kept apart as a month does not have a fixed number of days and a day
+/// does not have a fixed number of microseconds across daylight saving changes.
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct months_days_micros(pub i128);
+
+impl months_days_micros {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        let months = (months as i128) << 96;
+        let days = ((days as u32) as i128) << 64;
+        let microseconds = (microseconds as u64) as i128;
+        months_days_micros(months | days | microseconds)
+    }
+
+    #[inline]
+    pub fn months(&self) -> i32 {
+        (self.0 >> 96) as i32
+    }
+
+    #[inline]
+    pub fn days(&self) -> i32 {
+        (self.0 >> 64) as i32
+    }
+
+    #[inline]
+    pub fn microseconds(&self) -> i64 {
+        self.0 as i64
+    }
+
+    /// The length of the interval in microseconds, counting a month as 30 days and a day
+    /// as 24 hours. Only used to order the intervals.
+    pub fn total_micros(&self) -> i128 {
+        let days = self.months() as i128 * DAYS_IN_A_MONTH as i128 + self.days() as i128;
+        days * MICROS_IN_A_DAY as i128 + self.microseconds() as i128
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_add(other.months())?,
+            self.days().checked_add(other.days())?,
+            self.microseconds().checked_add(other.microseconds())?,
+        ))
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self::new(
+            self.months().checked_neg()?,
+            self.days().checked_neg()?,
+            self.microseconds().checked_neg()?,
+        ))
+    }
+
+    /// Move each whole 24 hours of the microseconds into the days.
+    pub fn justify_hours(&self) -> Option<Self> {
+        let days = self.microseconds() / MICROS_IN_A_DAY;
+        let microseconds = self.microseconds() % MICROS_IN_A_DAY;
+        let days = self.days().checked_add(i32::try_from(days).ok()?)?;
+        let (days, microseconds) = align_sign(days as i64, microseconds, MICROS_IN_A_DAY);
+        Some(Self::new(self.months(), days as i32, microseconds))
+    }
+
+    /// Move each whole 30 days of the days into the months.
+    pub fn justify_days(&self) -> Option<Self> {
+        let months = self.days() / DAYS_IN_A_MONTH;
+        let days = self.days() % DAYS_IN_A_MONTH;
+        let months = self.months().checked_add(months)?;
+        let (months, days) = align_sign(months as i64, days as i64, DAYS_IN_A_MONTH as i64);
+        Some(Self::new(months as i32, days as i32, self.microseconds()))
+    }
+
+    /// Justify the hours and then the days, so that all the parts have the same sign.
+    pub fn justify_interval(&self) -> Option<Self> {
+        let mut days = self.days() as i64 + self.microseconds() / MICROS_IN_A_DAY;
+        let mut micros = self.microseconds() % MICROS_IN_A_DAY;
+        let mut months = self.months() as i64 + days / DAYS_IN_A_MONTH as i64;
+        days %= DAYS_IN_A_MONTH as i64;
+
+        if months > 0 && (days < 0 || (days == 0 && micros < 0)) {
+            days += DAYS_IN_A_MONTH as i64;
+            months -= 1;
+        } else if months < 0 && (days > 0 || (days == 0 && micros > 0)) {
+            days -= DAYS_IN_A_MONTH as i64;
+            months += 1;
+        }
+        (days, micros) = align_sign(days, micros, MICROS_IN_A_DAY);
+
+        Some(Self::new(
+            i32::try_from(months).ok()?,
+            i32::try_from(days).ok()?,
+            micros,
+        ))
+    }
+}
+
+// Borrow one unit from the higher part if the two parts have different signs.
+fn align_sign(high: i64, low: i64, unit: i64) -> (i64, i64) {
+    if high > 0 && low < 0 {
+        (high - 1, low + unit)
+    } else if high < 0 && low > 0 {
+        (high + 1, low - unit)
+    } else {
+        (high, low)
+    }
+}
+
+impl PartialOrd for months_days_micros {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for months_days_micros {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl Display for months_days_micros {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let years = self.months() / MONTHS_IN_A_YEAR;
+        let months = self.months() % MONTHS_IN_A_YEAR;
+        let days = self.days();
+        let micros = self.microseconds();
+
+        let mut parts = vec![];
+        for (value, unit) in [(years, "year"), (months, "month"), (days, "day")] {
+            if value != 0 {
+                let plural = if value.abs() == 1 { "" } else { "s" };
+                parts.push(format!("{value} {unit}{plural}"));
+            }
+        }
+        if micros != 0 || parts.is_empty() {
+            let sign = if micros < 0 { "-" } else { "" };
+            let micros = micros.unsigned_abs();
+            let hours = micros / MICROS_IN_AN_HOUR as u64;
+            let minutes = micros % MICROS_IN_AN_HOUR as u64 / MICROS_IN_A_MINUTE as u64;
+            let seconds = micros % MICROS_IN_A_MINUTE as u64 / MICROS_IN_A_SEC as u64;
+            let fraction = micros % MICROS_IN_A_SEC as u64;
+            let mut time = format!("{sign}{hours:02}:{minutes:02}:{seconds:02}");
+            if fraction != 0 {
+                time.push_str(&format!(".{fraction:06}"));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = months_days_micros;
+    type ScalarRef<'a> = months_days_micros;
+    type Column = Buffer<months_days_micros>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, months_days_micros>>;
+    type ColumnBuilder = Vec<months_days_micros>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: months_days_micros) -> months_days_micros {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        scalar.as_interval().cloned()
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        col.as_interval().cloned()
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Option<Ordering> {
+        Some(lhs.cmp(&rhs))
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval from a string like `1 year 2 months 3 days 04:05:06.789`.
+///
+/// Each part is a number followed by a unit, the time part `[-]hh:mm:ss[.ffffff]` can be used
+/// instead of the hour, minute and second parts, and a trailing `ago` negates the interval.
+pub fn string_to_interval(s: &str) -> Result<months_days_micros, String> {
+    let err = || format!("invalid interval: '{s}'");
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return Err(err());
+    }
+
+    let (tokens, ago) = match tokens.last() {
+        Some(last) if last.eq_ignore_ascii_case("ago") => (&tokens[..tokens.len() - 1], true),
+        _ => (&tokens[..], false),
+    };
+
+    let mut months: i64 = 0;
+    let mut days: i64 = 0;
+    let mut micros: i128 = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        if token.contains(':') {
+            micros += parse_time(token).ok_or_else(err)? as i128;
+            i += 1;
+            continue;
+        }
+
+        // The unit may follow the number directly, like `3days`.
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = if split < token.len() {
+            (&token[..split], &token[split..])
+        } else {
+            i += 1;
+            (token, *tokens.get(i).ok_or_else(err)?)
+        };
+        i += 1;
+
+        let number = number.parse::<f64>().map_err(|_| err())?;
+        let unit = unit.to_ascii_lowercase();
+        let unit = unit.trim_end_matches(',');
+        match unit {
+            "millennium" | "millennia" | "millenniums" => months += whole(number * 12000.0)?,
+            "century" | "centuries" => months += whole(number * 1200.0)?,
+            "decade" | "decades" => months += whole(number * 120.0)?,
+            "year" | "years" | "y" | "yr" | "yrs" => months += whole(number * 12.0)?,
+            "quarter" | "quarters" => months += whole(number * 3.0)?,
+            "month" | "months" | "mon" | "mons" => months += whole(number)?,
+            "week" | "weeks" | "w" => days += whole(number * 7.0)?,
+            "day" | "days" | "d" => days += whole(number)?,
+            "hour" | "hours" | "h" | "hr" | "hrs" => {
+                micros += (number * MICROS_IN_AN_HOUR as f64).round() as i128
+            }
+            "minute" | "minutes" | "m" | "min" | "mins" => {
+                micros += (number * MICROS_IN_A_MINUTE as f64).round() as i128
+            }
+            "second" | "seconds" | "s" | "sec" | "secs" => {
+                micros += (number * MICROS_IN_A_SEC as f64).round() as i128
+            }
+            "millisecond" | "milliseconds" | "ms" => micros += (number * 1000.0).round() as i128,
+            "microsecond" | "microseconds" | "us" => micros += number.round() as i128,
+            _ => return Err(err()),
+        }
+    }
+
+    let months = i32::try_from(months).map_err(|_| err())?;
+    let days = i32::try_from(days).map_err(|_| err())?;
+    let micros = i64::try_from(micros).map_err(|_| err())?;
+    let interval = months_days_micros::new(months, days, micros);
+    if ago {
+        interval.checked_neg().ok_or_else(err)
+    } else {
+        Ok(interval)
+    }
+}
+
+fn whole(number: f64) -> Result<i64, String> {
+    if number.fract() != 0.0 || !number.is_finite() {
+        return Err(format!(
+            "interval part {number} must be a whole number of its unit"
+        ));
+    }
+    Ok(number as i64)
+}
+
+// Parse `[-]hh:mm[:ss[.ffffff]]` into microseconds.
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours.checked_mul(MICROS_IN_AN_HOUR)?
+        + minutes * MICROS_IN_A_MINUTE
+        + (seconds * MICROS_IN_A_SEC as f64).round() as i64;
+    Some(if negative { -micros } else { micros })
+}
+
+#[inline]
+pub fn interval_to_string(interval: months_days_micros) -> impl Display {
+    interval
+}
@@ -38,6 +38,7 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Decimal(_)
         | DataType::Timestamp
         | DataType::Date
+        | DataType::Interval
         | DataType::Bitmap
         | DataType::Geometry
         | DataType::Generic(_) => false,
@@ -76,6 +77,7 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::Decimal(_)
         | ScalarRef::Timestamp(_)
         | ScalarRef::Date(_)
+        | ScalarRef::Interval(_)
         | ScalarRef::Boolean(_)
         | ScalarRef::Binary(_)
         | ScalarRef::String(_)
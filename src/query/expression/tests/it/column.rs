@@ -13,11 +13,21 @@
 // limitations under the License.
 
 use databend_common_arrow::arrow::array::new_empty_array;
+use databend_common_arrow::arrow::array::PrimitiveArray;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use databend_common_arrow::arrow::datatypes::IntervalUnit;
+use databend_common_arrow::arrow::types::months_days_ns;
 use databend_common_exception::Result;
+use databend_common_expression::converts::arrow2::storage_data_block;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockEntry;
 use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Value;
 
 #[test]
 fn test_from_arrow_extension_to_column() -> Result<()> {
@@ -30,3 +40,56 @@ fn test_from_arrow_extension_to_column() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_interval_to_arrow() -> Result<()> {
+    let values = vec![
+        months_days_micros::new(1, 2, 3),
+        months_days_micros::new(-14, 0, -86_400_000_000),
+    ];
+    let col = IntervalType::from_data(values.clone());
+
+    let arrow_col = col.as_arrow();
+    assert_eq!(
+        arrow_col.data_type(),
+        &ArrowDataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    let arrow_values = arrow_col
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap()
+        .values();
+    assert_eq!(arrow_values[0], months_days_ns::new(1, 2, 3_000));
+    assert_eq!(
+        arrow_values[1],
+        months_days_ns::new(-14, 0, -86_400_000_000_000)
+    );
+    assert_eq!(
+        Column::from_arrow(arrow_col.as_ref(), &DataType::Interval)?,
+        col
+    );
+
+    // The storage formats keep the interval as the packed `i128`.
+    let block = DataBlock::new(
+        vec![BlockEntry::new(
+            DataType::Interval,
+            Value::Column(col.clone()),
+        )],
+        values.len(),
+    );
+    let storage_col = storage_data_block(&block).columns()[0]
+        .value
+        .as_column()
+        .unwrap()
+        .as_arrow();
+    assert!(matches!(
+        storage_col.data_type(),
+        ArrowDataType::Decimal(38, 0)
+    ));
+    assert_eq!(
+        Column::from_arrow(storage_col.as_ref(), &DataType::Interval)?,
+        col
+    );
+
+    Ok(())
+}
@@ -0,0 +1,515 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+
+use apache_avro::schema::Schema;
+use apache_avro::types::Value;
+use chrono_tz::Tz;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::serialize::read_decimal_with_size;
+use databend_common_expression::serialize::uniform_date;
+use databend_common_expression::types::array::ArrayColumnBuilder;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::date::check_date;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalColumnBuilder;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::ColumnBuilder;
+use databend_common_io::cursor_ext::BufferReadDateTimeExt;
+use databend_common_io::cursor_ext::DateTimeResType;
+use lexical_core::FromLexical;
+use num::bigint::Sign;
+use num::BigInt;
+use num_traits::NumCast;
+
+use crate::FieldDecoder;
+use crate::FileFormatOptionsExt;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+static NULL_SCHEMA: Schema = Schema::Null;
+
+/// Decodes values of an Avro object container file into columns.
+///
+/// The writer schema is passed along with each value, it is needed to
+/// resolve union branches and the scale of decimals.
+pub struct FieldAvroDecoder {
+    timezone: Tz,
+    pub ident_case_sensitive: bool,
+    pub is_select: bool,
+    is_rounding_mode: bool,
+}
+
+impl FieldDecoder for FieldAvroDecoder {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl FieldAvroDecoder {
+    pub fn create(options: &FileFormatOptionsExt, rounding_mode: bool) -> Self {
+        FieldAvroDecoder {
+            timezone: options.timezone,
+            ident_case_sensitive: options.ident_case_sensitive,
+            is_select: options.is_select,
+            is_rounding_mode: rounding_mode,
+        }
+    }
+
+    pub fn read_field(
+        &self,
+        column: &mut ColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        if let Value::Union(index, value) = value {
+            let schema = match schema {
+                Schema::Union(union) => union.variants().get(*index as usize).unwrap_or(schema),
+                _ => schema,
+            };
+            return self.read_field(column, value, schema);
+        }
+        match column {
+            ColumnBuilder::Null { len } => {
+                *len += 1;
+                Ok(())
+            }
+            ColumnBuilder::Nullable(c) => self.read_nullable(c, value, schema),
+            ColumnBuilder::Boolean(c) => self.read_bool(c, value),
+            ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumnBuilder::NUM_TYPE(c) => {
+                    if NUM_TYPE::FLOATING {
+                        self.read_float(c, value)
+                    } else {
+                        self.read_int(c, value)
+                    }
+                }
+            }),
+            ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
+                DecimalColumnBuilder::DECIMAL_TYPE(c, size) => {
+                    self.read_decimal(c, *size, value, schema)
+                }
+            }),
+            ColumnBuilder::Date(c) => self.read_date(c, value),
+            ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
+            ColumnBuilder::String(c) => self.read_string(c, value),
+            ColumnBuilder::Array(c) => self.read_array(c, value, schema),
+            ColumnBuilder::Map(c) => self.read_map(c, value, schema),
+            ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value, schema),
+            ColumnBuilder::Variant(c) => self.read_variant(c, value, schema),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Unsupported column type {} for avro value",
+                column.data_type()
+            ))),
+        }
+    }
+
+    fn read_nullable(
+        &self,
+        column: &mut NullableColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match value {
+            Value::Null => {
+                column.push_null();
+            }
+            other => {
+                self.read_field(&mut column.builder, other, schema)?;
+                column.validity.push(true);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_bool(&self, column: &mut MutableBitmap, value: &Value) -> Result<()> {
+        match value {
+            Value::Boolean(v) => column.push(*v),
+            _ => return Err(ErrorCode::BadBytes("Incorrect boolean value")),
+        }
+        Ok(())
+    }
+
+    fn read_int<T>(&self, column: &mut Vec<T>, value: &Value) -> Result<()>
+    where
+        T: Number + From<T::Native>,
+        T::Native: FromLexical + NumCast,
+    {
+        let new_val: Option<T::Native> = match value {
+            Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => num_traits::cast::cast(*v),
+            Value::Long(v)
+            | Value::TimeMicros(v)
+            | Value::TimestampMillis(v)
+            | Value::TimestampMicros(v)
+            | Value::LocalTimestampMillis(v)
+            | Value::LocalTimestampMicros(v) => num_traits::cast::cast(*v),
+            Value::Float(v) => self.cast_float(*v as f64),
+            Value::Double(v) => self.cast_float(*v),
+            _ => {
+                return Err(ErrorCode::BadBytes("Incorrect avro value, must be number"));
+            }
+        };
+        match new_val {
+            Some(v) => {
+                column.push(v.into());
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Incorrect avro number {:?}",
+                value
+            ))),
+        }
+    }
+
+    fn cast_float<N: NumCast>(&self, v: f64) -> Option<N> {
+        if self.is_rounding_mode {
+            num_traits::cast::cast(v.round())
+        } else {
+            num_traits::cast::cast(v)
+        }
+    }
+
+    fn read_float<T>(&self, column: &mut Vec<T>, value: &Value) -> Result<()>
+    where
+        T: Number + From<T::Native>,
+        T::Native: FromLexical + NumCast,
+    {
+        let new_val: Option<T::Native> = match value {
+            Value::Float(v) => num_traits::cast::cast(*v),
+            Value::Double(v) => num_traits::cast::cast(*v),
+            Value::Int(v) => num_traits::cast::cast(*v),
+            Value::Long(v) => num_traits::cast::cast(*v),
+            _ => {
+                return Err(ErrorCode::BadBytes("Incorrect avro value, must be number"));
+            }
+        };
+        match new_val {
+            Some(v) => {
+                column.push(v.into());
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Incorrect avro number {:?}",
+                value
+            ))),
+        }
+    }
+
+    fn read_decimal<D: Decimal>(
+        &self,
+        column: &mut Vec<D>,
+        size: DecimalSize,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let text = match value {
+            Value::Decimal(_) => decimal_to_string(value, schema)?,
+            Value::Int(v) => v.to_string(),
+            Value::Long(v) => v.to_string(),
+            Value::Float(v) => v.to_string(),
+            Value::Double(v) => v.to_string(),
+            Value::String(v) => v.clone(),
+            _ => {
+                return Err(ErrorCode::BadBytes("Incorrect avro value, must be decimal"));
+            }
+        };
+        let (n, _) =
+            read_decimal_with_size::<D>(text.as_bytes(), size, true, self.is_rounding_mode)?;
+        column.push(n);
+        Ok(())
+    }
+
+    fn read_string(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(s) | Value::Enum(_, s) => column.put_str(s.as_str()),
+            Value::Uuid(u) => column.put_str(&u.to_string()),
+            Value::Bytes(b) | Value::Fixed(_, b) => {
+                let s = std::str::from_utf8(b).map_err(|_| {
+                    ErrorCode::BadBytes("Incorrect avro value, bytes are not valid utf8")
+                })?;
+                column.put_str(s);
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes("Incorrect avro value, must be string"));
+            }
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::Bytes(b) | Value::Fixed(_, b) => column.put_slice(b),
+            Value::String(s) => column.put_slice(s.as_bytes()),
+            _ => {
+                return Err(ErrorCode::BadBytes("Incorrect avro value, must be bytes"));
+            }
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
+        let days = match value {
+            Value::Date(v) | Value::Int(v) => *v as i64,
+            Value::Long(v) => *v,
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) => {
+                v.div_euclid(MICROS_PER_DAY)
+            }
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => {
+                v.div_euclid(MICROS_PER_DAY / 1000)
+            }
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                let date = reader.read_date_text(&self.timezone)?;
+                uniform_date(date) as i64
+            }
+            _ => return Err(ErrorCode::BadBytes("Incorrect date value")),
+        };
+        column.push(check_date(days)?);
+        Ok(())
+    }
+
+    fn read_timestamp(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        let micros = match value {
+            Value::TimestampMicros(v) | Value::LocalTimestampMicros(v) | Value::Long(v) => *v,
+            Value::TimestampMillis(v) | Value::LocalTimestampMillis(v) => {
+                v.checked_mul(1000).ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("Timestamp millis {v} is out of range"))
+                })?
+            }
+            Value::Date(v) => *v as i64 * MICROS_PER_DAY,
+            Value::String(v) => {
+                let mut reader = Cursor::new(v.as_bytes());
+                match reader.read_timestamp_text(&self.timezone, false)? {
+                    DateTimeResType::Datetime(ts) => ts.timestamp_micros(),
+                    _ => unreachable!(),
+                }
+            }
+            _ => return Err(ErrorCode::BadBytes("Incorrect timestamp value")),
+        };
+        column.push(check_timestamp(micros)?);
+        Ok(())
+    }
+
+    fn read_variant(
+        &self,
+        column: &mut BinaryColumnBuilder,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        let json = to_json_value(value, schema)?;
+        jsonb::Value::from(&json).write_to_vec(&mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_array(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match value {
+            Value::Array(vals) => {
+                let item_schema = match schema {
+                    Schema::Array(items) => items.as_ref(),
+                    _ => &NULL_SCHEMA,
+                };
+                for val in vals {
+                    self.read_field(&mut column.builder, val, item_schema)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be array")),
+        }
+    }
+
+    fn read_map(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        const KEY: usize = 0;
+        const VALUE: usize = 1;
+        let map_builder = column.builder.as_tuple_mut().unwrap();
+        match value {
+            Value::Map(obj) => {
+                let value_schema = match schema {
+                    Schema::Map(values) => values.as_ref(),
+                    _ => &NULL_SCHEMA,
+                };
+                // keep the output stable, the entries of avro map are not ordered.
+                let mut entries = obj.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                for (key, val) in entries {
+                    let key = Value::String(key.to_string());
+                    self.read_field(&mut map_builder[KEY], &key, &Schema::String)?;
+                    self.read_field(&mut map_builder[VALUE], val, value_schema)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be map")),
+        }
+    }
+
+    fn read_tuple(
+        &self,
+        fields: &mut [ColumnBuilder],
+        value: &Value,
+        schema: &Schema,
+    ) -> Result<()> {
+        match value {
+            Value::Record(record) => {
+                if fields.len() != record.len() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Incorrect avro value, expect {} values, but get {} values",
+                        fields.len(),
+                        record.len()
+                    )));
+                }
+                for (i, (field, (_, val))) in fields.iter_mut().zip(record.iter()).enumerate() {
+                    let field_schema = match schema {
+                        Schema::Record(r) => &r.fields[i].schema,
+                        _ => &NULL_SCHEMA,
+                    };
+                    self.read_field(field, val, field_schema)?;
+                }
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect avro value, must be record")),
+        }
+    }
+}
+
+/// Render an avro decimal as text, the scale only lives in the writer schema.
+fn decimal_to_string(value: &Value, schema: &Schema) -> Result<String> {
+    let (Value::Decimal(decimal), Schema::Decimal(decimal_schema)) = (value, schema) else {
+        return Err(ErrorCode::BadBytes(
+            "Incorrect avro value, decimal without a decimal schema",
+        ));
+    };
+    let bytes: Vec<u8> = decimal
+        .try_into()
+        .map_err(|e| ErrorCode::BadBytes(format!("Incorrect avro decimal: {e}")))?;
+    let unscaled = BigInt::from_signed_bytes_be(&bytes);
+    let scale = decimal_schema.scale;
+    let digits = unscaled.magnitude().to_string();
+    let sign = if unscaled.sign() == Sign::Minus {
+        "-"
+    } else {
+        ""
+    };
+    if scale == 0 {
+        return Ok(format!("{sign}{digits}"));
+    }
+    let digits = if digits.len() <= scale {
+        format!("{}{digits}", "0".repeat(scale - digits.len() + 1))
+    } else {
+        digits
+    };
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+    Ok(format!("{sign}{int_part}.{frac_part}"))
+}
+
+/// Convert an avro value to json, used when the target column is a variant.
+fn to_json_value(value: &Value, schema: &Schema) -> Result<serde_json::Value> {
+    use serde_json::Value as Json;
+    Ok(match value {
+        Value::Null => Json::Null,
+        Value::Boolean(v) => Json::Bool(*v),
+        Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => Json::from(*v),
+        Value::Long(v)
+        | Value::TimeMicros(v)
+        | Value::TimestampMillis(v)
+        | Value::TimestampMicros(v)
+        | Value::LocalTimestampMillis(v)
+        | Value::LocalTimestampMicros(v) => Json::from(*v),
+        Value::Float(v) => Json::from(*v as f64),
+        Value::Double(v) => Json::from(*v),
+        Value::String(v) | Value::Enum(_, v) => Json::String(v.clone()),
+        Value::Uuid(v) => Json::String(v.to_string()),
+        Value::Bytes(v) | Value::Fixed(_, v) => Json::String(hex::encode_upper(v)),
+        Value::Decimal(_) => {
+            let text = decimal_to_string(value, schema)?;
+            match text.parse::<serde_json::Number>() {
+                Ok(n) => Json::Number(n),
+                Err(_) => Json::String(text),
+            }
+        }
+        Value::Union(index, value) => {
+            let schema = match schema {
+                Schema::Union(union) => union.variants().get(*index as usize).unwrap_or(schema),
+                _ => schema,
+            };
+            to_json_value(value, schema)?
+        }
+        Value::Array(items) => {
+            let item_schema = match schema {
+                Schema::Array(items) => items.as_ref(),
+                _ => &NULL_SCHEMA,
+            };
+            Json::Array(
+                items
+                    .iter()
+                    .map(|v| to_json_value(v, item_schema))
+                    .collect::<Result<_>>()?,
+            )
+        }
+        Value::Map(entries) => {
+            let value_schema = match schema {
+                Schema::Map(values) => values.as_ref(),
+                _ => &NULL_SCHEMA,
+            };
+            let mut obj = serde_json::Map::with_capacity(entries.len());
+            for (k, v) in entries {
+                obj.insert(k.clone(), to_json_value(v, value_schema)?);
+            }
+            Json::Object(obj)
+        }
+        Value::Record(fields) => {
+            let mut obj = serde_json::Map::with_capacity(fields.len());
+            for (i, (k, v)) in fields.iter().enumerate() {
+                let field_schema = match schema {
+                    Schema::Record(r) => &r.fields[i].schema,
+                    _ => &NULL_SCHEMA,
+                };
+                obj.insert(k.clone(), to_json_value(v, field_schema)?);
+            }
+            Json::Object(obj)
+        }
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Unsupported avro value {:?}",
+                other
+            )));
+        }
+    })
+}
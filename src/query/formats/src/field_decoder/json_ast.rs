@@ -27,6 +27,8 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
@@ -38,8 +40,8 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
+use databend_common_io::parse_any_to_ewkb;
 use databend_common_io::parse_bitmap;
-use databend_common_io::parse_to_ewkb;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use num_traits::NumCast;
@@ -93,6 +95,7 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
             ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
@@ -243,6 +246,16 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_interval(v)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
     fn read_date(&self, column: &mut Vec<i32>, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
@@ -330,7 +343,13 @@ impl FieldJsonAstDecoder {
     fn read_geometry(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
-                let geom = parse_to_ewkb(v.as_bytes(), None)?;
+                let geom = parse_any_to_ewkb(v.as_bytes(), None)?;
+                column.put_slice(&geom);
+                column.commit_row();
+                Ok(())
+            }
+            Value::Object(_) => {
+                let geom = parse_any_to_ewkb(value.to_string().as_bytes(), None)?;
                 column.put_slice(&geom);
                 column.commit_row();
                 Ok(())
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod fast_values;
 mod json_ast;
 mod nested;
@@ -19,6 +20,7 @@ mod separated_text;
 
 use std::any::Any;
 
+pub use avro::FieldAvroDecoder;
 pub use fast_values::FastFieldDecoderValues;
 pub use fast_values::FastValuesDecodeFallback;
 pub use fast_values::FastValuesDecoder;
@@ -27,6 +27,8 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
 use databend_common_expression::types::AnyType;
@@ -48,8 +50,8 @@ use databend_common_io::cursor_ext::read_num_text_exact;
 use databend_common_io::cursor_ext::BufferReadDateTimeExt;
 use databend_common_io::cursor_ext::DateTimeResType;
 use databend_common_io::cursor_ext::ReadBytesExt;
+use databend_common_io::parse_any_to_ewkb;
 use databend_common_io::parse_bitmap;
-use databend_common_io::parse_to_ewkb;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_meta_app::principal::TsvFileFormatParams;
 use databend_common_meta_app::principal::XmlFileFormatParams;
@@ -166,6 +168,7 @@ impl SeparatedTextDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
@@ -280,6 +283,11 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<months_days_micros>, data: &[u8]) -> Result<()> {
+        column.push(string_to_interval(&String::from_utf8_lossy(data))?);
+        Ok(())
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
         let ts = if !data.contains(&b'-') {
             read_num_text_exact(data)?
@@ -332,7 +340,7 @@ impl SeparatedTextDecoder {
     }
 
     fn read_geometry(&self, column: &mut BinaryColumnBuilder, data: &[u8]) -> Result<()> {
-        let geom = parse_to_ewkb(data, None)?;
+        let geom = parse_any_to_ewkb(data, None)?;
         column.put_slice(geom.as_bytes());
         column.commit_row();
         Ok(())
@@ -21,10 +21,9 @@ use databend_common_io::constants::INF_BYTES_LOWER;
 use databend_common_io::constants::NULL_BYTES_ESCAPE;
 use databend_common_io::constants::TRUE_BYTES_LOWER;
 use databend_common_io::constants::TRUE_BYTES_NUM;
+use databend_common_io::geometry_format;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_meta_app::principal::TsvFileFormatParams;
-use geozero::wkb::Ewkb;
-use geozero::ToWkt;
 
 use crate::binary::encode_binary;
 use crate::field_encoder::write_tsv_escaped_string;
@@ -89,6 +88,7 @@ impl FieldEncoderCSV {
                     inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                     timezone: options_ext.timezone,
                     binary_format: params.binary_format,
+                    geometry_format: options_ext.geometry_format,
                 },
                 quote_char: 0, // not used
             },
@@ -110,6 +110,7 @@ impl FieldEncoderCSV {
                     inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                     timezone: options_ext.timezone,
                     binary_format: Default::default(),
+                    geometry_format: options_ext.geometry_format,
                 },
                 quote_char: 0, // not used
             },
@@ -133,7 +134,11 @@ impl FieldEncoderCSV {
                 self.string_formatter.write_string(buf.as_bytes(), out_buf);
             }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) | Column::Variant(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..)
+            | Column::Variant(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
@@ -141,7 +146,8 @@ impl FieldEncoderCSV {
 
             Column::Geometry(g) => {
                 let buf = unsafe { g.index_unchecked(row_index) };
-                let geom = Ewkb(buf.to_vec()).to_ewkt(None).unwrap();
+                let geom =
+                    geometry_format(buf, self.simple.common_settings.geometry_format).unwrap();
                 self.string_formatter.write_string(geom.as_bytes(), out_buf);
             }
 
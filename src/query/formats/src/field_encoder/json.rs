@@ -19,8 +19,8 @@ use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_LOWER;
 use databend_common_io::constants::NULL_BYTES_LOWER;
 use databend_common_io::constants::TRUE_BYTES_LOWER;
-use geozero::wkb::Ewkb;
-use geozero::ToJson;
+use databend_common_io::geometry_format;
+use databend_common_io::GeometryDataType;
 
 use crate::field_encoder::helpers::write_json_string;
 use crate::field_encoder::FieldEncoderValues;
@@ -45,6 +45,7 @@ impl FieldEncoderJSON {
                     null_bytes: NULL_BYTES_LOWER.as_bytes().to_vec(),
                     timezone: options.timezone,
                     binary_format: Default::default(),
+                    geometry_format: options.geometry_format,
                 },
                 quote_char: 0,
             },
@@ -68,7 +69,10 @@ impl FieldEncoderJSON {
                 self.write_string(buf.as_bytes(), out_buf);
             }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.write_string(&buf, out_buf);
@@ -80,7 +84,13 @@ impl FieldEncoderJSON {
             }
             Column::Geometry(c) => {
                 let v = unsafe { c.index_unchecked(row_index) };
-                out_buf.extend_from_slice(Ewkb(v.to_vec()).to_json().unwrap().as_bytes())
+                let format = self.simple.common_settings.geometry_format;
+                let geom = geometry_format(v, format).unwrap();
+                // GeoJSON is written as a JSON object, other formats as a string.
+                match format {
+                    GeometryDataType::GEOJSON => out_buf.extend_from_slice(geom.as_bytes()),
+                    _ => self.write_string(geom.as_bytes(), out_buf),
+                }
             }
 
             Column::Array(box c) => self.write_array(c, row_index, out_buf),
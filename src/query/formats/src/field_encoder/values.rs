@@ -19,6 +19,8 @@ use databend_common_expression::types::array::ArrayColumn;
 use databend_common_expression::types::binary::BinaryColumn;
 use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::interval::interval_to_string;
+use databend_common_expression::types::interval::months_days_micros;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::timestamp::timestamp_to_string;
@@ -32,9 +34,8 @@ use databend_common_io::constants::NAN_BYTES_LOWER;
 use databend_common_io::constants::NAN_BYTES_SNAKE;
 use databend_common_io::constants::NULL_BYTES_UPPER;
 use databend_common_io::constants::TRUE_BYTES_NUM;
-use geozero::wkb::FromWkb;
-use geozero::wkb::WkbDialect;
-use geozero::wkt::Ewkt;
+use databend_common_io::geometry_format;
+use databend_common_io::GeometryDataType;
 use lexical_core::ToLexical;
 use micromarshal::Marshal;
 use micromarshal::Unmarshal;
@@ -61,12 +62,13 @@ impl FieldEncoderValues {
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options.timezone,
                 binary_format: Default::default(),
+                geometry_format: options.geometry_format,
             },
             quote_char: b'\'',
         }
     }
 
-    pub fn create_for_http_handler(timezone: Tz) -> Self {
+    pub fn create_for_http_handler(timezone: Tz, geometry_format: GeometryDataType) -> Self {
         FieldEncoderValues {
             common_settings: OutputCommonSettings {
                 true_bytes: TRUE_BYTES_NUM.as_bytes().to_vec(),
@@ -76,6 +78,7 @@ impl FieldEncoderValues {
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone,
                 binary_format: Default::default(),
+                geometry_format,
             },
             quote_char: b'\'',
         }
@@ -85,7 +88,7 @@ impl FieldEncoderValues {
     // mysql python client will decode to python float, which is printed as 'nan' and 'inf'
     // so we still use 'nan' and 'inf' in logic test.
     // https://github.com/datafuselabs/databend/discussions/8941
-    pub fn create_for_mysql_handler(timezone: Tz) -> Self {
+    pub fn create_for_mysql_handler(timezone: Tz, geometry_format: GeometryDataType) -> Self {
         FieldEncoderValues {
             common_settings: OutputCommonSettings {
                 true_bytes: TRUE_BYTES_NUM.as_bytes().to_vec(),
@@ -95,6 +98,7 @@ impl FieldEncoderValues {
                 inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
                 timezone,
                 binary_format: Default::default(),
+                geometry_format,
             },
             quote_char: b'\'',
         }
@@ -132,6 +136,7 @@ impl FieldEncoderValues {
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
             Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
@@ -254,6 +259,18 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<months_days_micros>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = interval_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
     fn write_timestamp(
         &self,
         column: &Buffer<i64>,
@@ -296,9 +313,8 @@ impl FieldEncoderValues {
         in_nested: bool,
     ) {
         let v = unsafe { column.index_unchecked(row_index) };
-        let mut data_cursor = std::io::Cursor::new(v);
-        let s = Ewkt::from_wkb(&mut data_cursor, WkbDialect::Ewkb).unwrap();
-        self.write_string_inner(s.0.as_bytes(), out_buf, in_nested);
+        let s = geometry_format(v, self.common_settings().geometry_format).unwrap();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
     fn write_array<T: ValueType>(
@@ -16,6 +16,7 @@ use chrono_tz::Tz;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_common_io::GeometryDataType;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_settings::Settings;
@@ -44,6 +45,7 @@ pub struct FileFormatOptionsExt {
     pub json_strings: bool,
     pub disable_variant_check: bool,
     pub timezone: Tz,
+    pub geometry_format: GeometryDataType,
     pub is_select: bool,
     pub is_clickhouse: bool,
     pub is_rounding_mode: bool,
@@ -55,6 +57,7 @@ impl FileFormatOptionsExt {
         is_select: bool,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let geometry_format = parse_geometry_format(settings)?;
         let numeric_cast_option = settings
             .get_numeric_cast_option()
             .unwrap_or("rounding".to_string());
@@ -67,6 +70,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            geometry_format,
             is_select,
             is_clickhouse: false,
             is_rounding_mode,
@@ -79,6 +83,7 @@ impl FileFormatOptionsExt {
         settings: &Settings,
     ) -> Result<FileFormatOptionsExt> {
         let timezone = parse_timezone(settings)?;
+        let geometry_format = parse_geometry_format(settings)?;
         let mut options = FileFormatOptionsExt {
             ident_case_sensitive: settings.get_unquoted_ident_case_sensitive()?,
             headers: 0,
@@ -86,6 +91,7 @@ impl FileFormatOptionsExt {
             json_strings: false,
             disable_variant_check: false,
             timezone,
+            geometry_format,
             is_select: false,
             is_clickhouse: true,
             is_rounding_mode: true,
@@ -206,3 +212,7 @@ pub fn parse_timezone(settings: &Settings) -> Result<Tz> {
     tz.parse::<Tz>()
         .map_err(|_| ErrorCode::InvalidTimezone("Timezone has been checked and should be valid"))
 }
+
+pub fn parse_geometry_format(settings: &Settings) -> Result<GeometryDataType> {
+    settings.get_geometry_output_format()?.parse()
+}
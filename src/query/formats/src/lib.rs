@@ -29,6 +29,7 @@ pub mod output_format;
 pub use clickhouse::ClickhouseFormatType;
 pub use delimiter::RecordDelimiter;
 pub use field_decoder::*;
+pub use file_format_type::parse_geometry_format;
 pub use file_format_type::parse_timezone;
 pub use file_format_type::FileFormatOptionsExt;
 pub use file_format_type::FileFormatTypeExt;
@@ -17,9 +17,9 @@ use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
+use databend_common_io::geometry_format;
 use databend_common_io::prelude::FormatSettings;
-use geozero::wkb::Ewkb;
-use geozero::ToJson;
+use databend_common_io::GeometryDataType;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
@@ -44,6 +44,7 @@ impl JSONOutputFormat {
             rows: 0,
             format_settings: FormatSettings {
                 timezone: options.timezone,
+                geometry_format: options.geometry_format,
             },
         }
     }
@@ -98,6 +99,7 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(v.to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
@@ -144,10 +146,12 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             b.into()
         }
         ScalarRef::Geometry(x) => {
-            let geom = Ewkb(x.to_vec())
-                .to_json()
-                .expect("failed to convert ewkb to json");
-            jsonb::from_slice(geom.as_bytes()).unwrap().into()
+            let geom = geometry_format(x, format.geometry_format)
+                .expect("failed to format the geometry value");
+            match format.geometry_format {
+                GeometryDataType::GEOJSON => jsonb::from_slice(geom.as_bytes()).unwrap().into(),
+                _ => JsonValue::String(geom),
+            }
         }
     }
 }
@@ -12,10 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataType;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
+use databend_common_io::ewkb_to_wkb;
+use databend_common_io::geometry_format;
+use databend_common_io::GeometryDataType;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_table_meta::table::TableCompression;
 
@@ -25,13 +39,15 @@ use crate::FileFormatOptionsExt;
 #[derive(Default)]
 pub struct ParquetOutputFormat {
     schema: TableSchemaRef,
+    geometry_format: GeometryDataType,
     data_blocks: Vec<DataBlock>,
 }
 
 impl ParquetOutputFormat {
-    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+    pub fn create(schema: TableSchemaRef, options: &FileFormatOptionsExt) -> Self {
         Self {
-            schema,
+            schema: geometry_output_schema(schema, options.geometry_format),
+            geometry_format: options.geometry_format,
             data_blocks: vec![],
         }
     }
@@ -39,7 +55,8 @@ impl ParquetOutputFormat {
 
 impl OutputFormat for ParquetOutputFormat {
     fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
-        self.data_blocks.push(block.clone());
+        let block = geometry_output_block(block, &self.schema, self.geometry_format)?;
+        self.data_blocks.push(block);
         Ok(vec![])
     }
 
@@ -63,3 +80,83 @@ impl OutputFormat for ParquetOutputFormat {
         Ok(buf)
     }
 }
+
+// The type of the top level geometry columns in the output file. EWKB is the
+// representation in memory, so the column is kept as it is.
+fn geometry_output_type(format: GeometryDataType) -> Option<TableDataType> {
+    match format {
+        GeometryDataType::EWKB => None,
+        GeometryDataType::WKB => Some(TableDataType::Binary),
+        GeometryDataType::WKT | GeometryDataType::EWKT | GeometryDataType::GEOJSON => {
+            Some(TableDataType::String)
+        }
+    }
+}
+
+fn geometry_output_schema(schema: TableSchemaRef, format: GeometryDataType) -> TableSchemaRef {
+    let Some(output_type) = geometry_output_type(format) else {
+        return schema;
+    };
+    if !schema
+        .fields()
+        .iter()
+        .any(|f| f.data_type().remove_nullable() == TableDataType::Geometry)
+    {
+        return schema;
+    }
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| match f.data_type() {
+            TableDataType::Geometry => TableField::new(f.name(), output_type.clone()),
+            TableDataType::Nullable(box TableDataType::Geometry) => {
+                TableField::new(f.name(), output_type.wrap_nullable())
+            }
+            _ => f.clone(),
+        })
+        .collect();
+    Arc::new(TableSchema::new(fields))
+}
+
+fn geometry_output_block(
+    block: &DataBlock,
+    schema: &TableSchemaRef,
+    format: GeometryDataType,
+) -> Result<DataBlock> {
+    if geometry_output_type(format).is_none() {
+        return Ok(block.clone());
+    }
+    let num_rows = block.num_rows();
+    let mut entries = Vec::with_capacity(block.num_columns());
+    for (entry, field) in block.columns().iter().zip(schema.fields()) {
+        if entry.data_type.remove_nullable() != DataType::Geometry {
+            entries.push(entry.clone());
+            continue;
+        }
+        let data_type = DataType::from(field.data_type());
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let column = convert_geometry_column(&column, &data_type, format)?;
+        entries.push(BlockEntry::new(data_type, Value::Column(column)));
+    }
+    Ok(DataBlock::new(entries, num_rows))
+}
+
+fn convert_geometry_column(
+    column: &Column,
+    data_type: &DataType,
+    format: GeometryDataType,
+) -> Result<Column> {
+    let mut builder = ColumnBuilder::with_capacity(data_type, column.len());
+    for value in column.iter() {
+        match value {
+            ScalarRef::Geometry(ewkb) => match format {
+                GeometryDataType::WKB => builder.push(ScalarRef::Binary(&ewkb_to_wkb(ewkb)?)),
+                _ => builder.push(ScalarRef::String(&geometry_format(ewkb, format)?)),
+            },
+            _ => builder.push(ScalarRef::Null),
+        }
+    }
+    Ok(builder.build())
+}
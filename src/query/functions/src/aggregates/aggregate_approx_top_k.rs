@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::btree_map::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::ops::AddAssign;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_unary::UnaryState;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+use crate::with_simple_no_number_mapped_type;
+
+const DEFAULT_K: u64 = 1;
+const DEFAULT_COUNTERS: u64 = 10000;
+
+struct ApproxTopKData {
+    pub k: u64,
+    pub counters: u64,
+    pub data_type: DataType,
+}
+
+impl FunctionData for ApproxTopKData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Frequency map bounded by the number of `counters`.
+///
+/// Once the map holds more than twice as many distinct values as `counters`,
+/// only the `counters` most frequent values are kept. The result is exact as long
+/// as the number of distinct values does not exceed `counters`.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    pub counters: u64,
+    pub frequency_map: BTreeMap<T::Scalar, u64>,
+}
+
+impl<T> Default for ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        ApproxTopKState::<T> {
+            counters: 0,
+            frequency_map: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> ApproxTopKState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    /// Returns the values ordered by descending frequency, ties are broken by the value.
+    fn sorted_entries(&mut self) -> Vec<(T::Scalar, u64)> {
+        let mut entries = std::mem::take(&mut self.frequency_map)
+            .into_iter()
+            .collect::<Vec<_>>();
+        // `sort_by` is stable and the map is already ordered by value.
+        entries.sort_by(|(_, l), (_, r)| r.cmp(l));
+        entries
+    }
+
+    fn shrink_if_needed(&mut self) {
+        if self.counters == 0 || (self.frequency_map.len() as u64) <= self.counters * 2 {
+            return;
+        }
+        let mut entries = self.sorted_entries();
+        entries.truncate(self.counters as usize);
+        self.frequency_map = entries.into_iter().collect();
+    }
+}
+
+impl<T> UnaryState<T, AnyType> for ApproxTopKState<T>
+where
+    T: ValueType + Sync + Send,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize + Sync + Send,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if self.counters == 0 {
+            let top_k_data = unsafe {
+                function_data
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref_unchecked::<ApproxTopKData>()
+            };
+            self.counters = top_k_data.counters;
+        }
+
+        let other = T::to_owned_scalar(other);
+        match self.frequency_map.entry(other) {
+            Entry::Occupied(o) => *o.into_mut() += 1,
+            Entry::Vacant(v) => {
+                v.insert(1);
+            }
+        };
+        self.shrink_if_needed();
+
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.counters = self.counters.max(rhs.counters);
+        for (key, value) in rhs.frequency_map.iter() {
+            match self.frequency_map.get_mut(key) {
+                Some(entry) => entry.add_assign(value),
+                None => {
+                    self.frequency_map.insert(key.clone(), *value);
+                }
+            }
+        }
+        self.shrink_if_needed();
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let top_k_data = unsafe {
+            function_data
+                .unwrap()
+                .as_any()
+                .downcast_ref_unchecked::<ApproxTopKData>()
+        };
+
+        let mut entries = self.sorted_entries();
+        entries.truncate(top_k_data.k as usize);
+
+        let tuple_type = DataType::Tuple(vec![
+            top_k_data.data_type.clone(),
+            DataType::Number(NumberDataType::UInt64),
+        ]);
+        let mut tuple_builder = ColumnBuilder::with_capacity(&tuple_type, entries.len());
+        for (value, count) in entries {
+            let value = T::upcast_scalar(value);
+            tuple_builder.push(ScalarRef::Tuple(vec![
+                value.as_ref(),
+                ScalarRef::Number(NumberScalar::UInt64(count)),
+            ]));
+        }
+        builder.push(ScalarRef::Array(tuple_builder.build()));
+
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 3))?;
+
+    let data_type = arguments[0].clone();
+    let (k, counters) = get_top_k_params(&params, display_name)?;
+    let return_type = DataType::Array(Box::new(DataType::Tuple(vec![
+        data_type.clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])));
+    let function_data = ApproxTopKData {
+        k,
+        counters,
+        data_type: data_type.clone(),
+    };
+
+    with_simple_no_number_mapped_type!(|T| match data_type {
+        DataType::T => {
+            let func = AggregateUnaryFunction::<ApproxTopKState<T>, T, AnyType>::try_create(
+                display_name,
+                return_type,
+                params,
+                data_type.clone(),
+            )
+            .with_function_data(Box::new(function_data))
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        DataType::Number(num_type) => {
+            with_number_mapped_type!(|NUM| match num_type {
+                NumberDataType::NUM => {
+                    let func = AggregateUnaryFunction::<
+                        ApproxTopKState<NumberType<NUM>>,
+                        NumberType<NUM>,
+                        AnyType,
+                    >::try_create(
+                        display_name, return_type, params, data_type.clone()
+                    )
+                    .with_function_data(Box::new(function_data))
+                    .with_need_drop(true);
+                    Ok(Arc::new(func))
+                }
+            })
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        ))),
+    })
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
+
+/// Parses `k` and `counters` from the params, `counters` can't be less than `k`.
+fn get_top_k_params(params: &[Scalar], display_name: &str) -> Result<(u64, u64)> {
+    let mut values = Vec::with_capacity(params.len());
+    for param in params {
+        match param {
+            Scalar::Number(number) => match number.integer_to_i128() {
+                Some(number) if number > 0 => values.push(number as u64),
+                _ => {
+                    return Err(ErrorCode::BadDataValueType(format!(
+                        "The params of aggregate function {} must be positive int",
+                        display_name
+                    )));
+                }
+            },
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "The params of aggregate function {} must be positive int",
+                    display_name
+                )));
+            }
+        }
+    }
+
+    match values.as_slice() {
+        [] => Ok((DEFAULT_K, DEFAULT_COUNTERS)),
+        [k] => Ok((*k, DEFAULT_COUNTERS.max(*k))),
+        [k, counters] if counters >= k => Ok((*k, *counters)),
+        [_, _] => Err(ErrorCode::BadArguments(format!(
+            "The counters of aggregate function {} can't be less than k",
+            display_name
+        ))),
+        _ => Err(ErrorCode::NumberArgumentsNotMatch(format!(
+            "{} expect to have at most two params, but got {}",
+            display_name,
+            values.len()
+        ))),
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::Scalar;
+use num_traits::PrimInt;
+
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunctionRef;
+
+pub trait BitwiseOp: Send + Sync + 'static {
+    /// The identity element of the operator, used as the initial value of the state.
+    fn identity<T: PrimInt>() -> T;
+
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T;
+}
+
+struct BitAndOp;
+
+impl BitwiseOp for BitAndOp {
+    fn identity<T: PrimInt>() -> T {
+        !T::zero()
+    }
+
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs & rhs
+    }
+}
+
+struct BitOrOp;
+
+impl BitwiseOp for BitOrOp {
+    fn identity<T: PrimInt>() -> T {
+        T::zero()
+    }
+
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs | rhs
+    }
+}
+
+struct BitXorOp;
+
+impl BitwiseOp for BitXorOp {
+    fn identity<T: PrimInt>() -> T {
+        T::zero()
+    }
+
+    fn apply<T: PrimInt>(lhs: T, rhs: T) -> T {
+        lhs ^ rhs
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct BitwiseState<T, O>
+where T: Number + PrimInt + BorshSerialize + BorshDeserialize
+{
+    pub value: T,
+    #[borsh(skip)]
+    _o: PhantomData<O>,
+}
+
+impl<T, O> Default for BitwiseState<T, O>
+where
+    T: Number + PrimInt + BorshSerialize + BorshDeserialize,
+    O: BitwiseOp,
+{
+    fn default() -> Self {
+        Self {
+            value: O::identity(),
+            _o: PhantomData,
+        }
+    }
+}
+
+impl<T, O> UnaryState<NumberType<T>, NumberType<T>> for BitwiseState<T, O>
+where
+    T: Number + PrimInt + BorshSerialize + BorshDeserialize,
+    O: BitwiseOp,
+{
+    fn add(&mut self, other: T, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.value = O::apply(self.value, other);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.value = O::apply(self.value, rhs.value);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<T>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_bitwise_function<O: BitwiseOp>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    with_integer_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            AggregateUnaryFunction::<
+                BitwiseState<NUM_TYPE, O>,
+                NumberType<NUM_TYPE>,
+                NumberType<NUM_TYPE>,
+            >::try_create_unary(
+                display_name,
+                arguments[0].clone(),
+                params,
+                arguments[0].clone(),
+            )
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_bit_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BitAndOp>,
+    ))
+}
+
+pub fn aggregate_bit_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BitOrOp>,
+    ))
+}
+
+pub fn aggregate_bit_xor_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BitXorOp>,
+    ))
+}
@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
+
+use super::AggregateUnaryFunction;
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunctionRef;
+
+/// State of `bool_and` (`IS_AND = true`) and `bool_or` (`IS_AND = false`).
+/// The initial value is the identity of the operator, so empty states can be merged freely.
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct BoolAndOrState<const IS_AND: bool> {
+    pub value: bool,
+}
+
+impl<const IS_AND: bool> Default for BoolAndOrState<IS_AND> {
+    fn default() -> Self {
+        Self { value: IS_AND }
+    }
+}
+
+impl<const IS_AND: bool> UnaryState<BooleanType, BooleanType> for BoolAndOrState<IS_AND> {
+    fn add(&mut self, other: bool, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        if IS_AND {
+            self.value &= other;
+        } else {
+            self.value |= other;
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value, None)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut MutableBitmap,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_bool_function<const IS_AND: bool>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    match &arguments[0] {
+        DataType::Boolean => AggregateUnaryFunction::<
+            BoolAndOrState<IS_AND>,
+            BooleanType,
+            BooleanType,
+        >::try_create_unary(
+            display_name,
+            DataType::Boolean,
+            params,
+            arguments[0].clone(),
+        ),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    }
+}
+
+pub fn aggregate_bool_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<true>))
+}
+
+pub fn aggregate_bool_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<false>))
+}
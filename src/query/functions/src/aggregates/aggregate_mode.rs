@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::btree_map::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::ops::AddAssign;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::Scalar;
+use ethnum::i256;
+
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_unary::UnaryState;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+use crate::with_simple_no_number_mapped_type;
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ModeState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    pub frequency_map: BTreeMap<T::Scalar, u64>,
+}
+
+impl<T> Default for ModeState<T>
+where
+    T: ValueType,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize,
+{
+    fn default() -> Self {
+        ModeState::<T> {
+            frequency_map: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> UnaryState<T, T> for ModeState<T>
+where
+    T: ValueType + Sync + Send,
+    T::Scalar: Ord + BorshSerialize + BorshDeserialize + Sync + Send,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let other = T::to_owned_scalar(other);
+        match self.frequency_map.entry(other) {
+            Entry::Occupied(o) => *o.into_mut() += 1,
+            Entry::Vacant(v) => {
+                v.insert(1);
+            }
+        };
+
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        for (key, value) in rhs.frequency_map.iter() {
+            match self.frequency_map.get_mut(key) {
+                Some(entry) => entry.add_assign(value),
+                None => {
+                    self.frequency_map.insert(key.clone(), *value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut T::ColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        // If several values have the same frequency, the smallest one is returned.
+        let mut mode: Option<(&T::Scalar, u64)> = None;
+        for (key, value) in self.frequency_map.iter() {
+            match mode {
+                Some((_, max)) if *value <= max => {}
+                _ => mode = Some((key, *value)),
+            }
+        }
+
+        match mode {
+            Some((key, _)) => T::push_item(builder, T::to_scalar_ref(key)),
+            None => T::push_default(builder),
+        }
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_mode_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let data_type = arguments[0].clone();
+    with_simple_no_number_mapped_type!(|T| match data_type {
+        DataType::T => {
+            let func = AggregateUnaryFunction::<ModeState<T>, T, T>::try_create(
+                display_name,
+                data_type.clone(),
+                params,
+                data_type,
+            )
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        DataType::Number(num_type) => {
+            with_number_mapped_type!(|NUM| match num_type {
+                NumberDataType::NUM => {
+                    let func = AggregateUnaryFunction::<
+                        ModeState<NumberType<NUM>>,
+                        NumberType<NUM>,
+                        NumberType<NUM>,
+                    >::try_create(
+                        display_name, data_type.clone(), params, data_type
+                    )
+                    .with_need_drop(true);
+                    Ok(Arc::new(func))
+                }
+            })
+        }
+        DataType::Decimal(DecimalDataType::Decimal128(_)) => {
+            let func = AggregateUnaryFunction::<
+                ModeState<DecimalType<i128>>,
+                DecimalType<i128>,
+                DecimalType<i128>,
+            >::try_create(display_name, data_type.clone(), params, data_type)
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        DataType::Decimal(DecimalDataType::Decimal256(_)) => {
+            let func = AggregateUnaryFunction::<
+                ModeState<DecimalType<i256>>,
+                DecimalType<i256>,
+                DecimalType<i256>,
+            >::try_create(display_name, data_type.clone(), params, data_type)
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        ))),
+    })
+}
+
+pub fn aggregate_mode_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_mode_function))
+}
@@ -0,0 +1,421 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use num_traits::AsPrimitive;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Shared state of `corr` and the `regr_*` family.
+///
+/// The first argument is the dependent variable `y` and the second one is the
+/// independent variable `x`, the same as PostgreSQL and Snowflake.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct AggregateRegressionState {
+    pub count: u64,
+    pub y_mean: f64,
+    pub x_mean: f64,
+    // sum((y - y_mean)^2)
+    pub y_m2: f64,
+    // sum((x - x_mean)^2)
+    pub x_m2: f64,
+    // sum((y - y_mean) * (x - x_mean))
+    pub co_moments: f64,
+}
+
+// Same approach as `AggregateCovarianceState`, see formula III.9 and III.6 in
+// "Numerically Stable, Single-Pass, Parallel Statistics Algorithms" (J. Bennett et al.),
+// extended with the second moments of both variables.
+impl AggregateRegressionState {
+    #[inline(always)]
+    fn add(&mut self, y: f64, x: f64) {
+        let y_delta = y - self.y_mean;
+        let x_delta = x - self.x_mean;
+
+        self.count += 1;
+        let new_y_mean = self.y_mean + y_delta / self.count as f64;
+        let new_x_mean = self.x_mean + x_delta / self.count as f64;
+
+        self.y_m2 += y_delta * (y - new_y_mean);
+        self.x_m2 += x_delta * (x - new_x_mean);
+        self.co_moments += (y - new_y_mean) * x_delta;
+        self.y_mean = new_y_mean;
+        self.x_mean = new_x_mean;
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        let total = self.count + other.count;
+        if total == 0 {
+            return;
+        }
+
+        let factor = self.count as f64 * other.count as f64 / total as f64;
+        let y_delta = other.y_mean - self.y_mean;
+        let x_delta = other.x_mean - self.x_mean;
+
+        self.y_m2 += other.y_m2 + y_delta * y_delta * factor;
+        self.x_m2 += other.x_m2 + x_delta * x_delta * factor;
+        self.co_moments += other.co_moments + y_delta * x_delta * factor;
+        self.y_mean += y_delta * other.count as f64 / total as f64;
+        self.x_mean += x_delta * other.count as f64 / total as f64;
+        self.count = total;
+    }
+
+    #[inline(always)]
+    fn slope(&self) -> Option<f64> {
+        if self.count == 0 || self.x_m2 == 0.0 {
+            None
+        } else {
+            Some(self.co_moments / self.x_m2)
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateRegressionFunction<T0, T1, R> {
+    display_name: String,
+    _t0: PhantomData<T0>,
+    _t1: PhantomData<T1>,
+    _r: PhantomData<R>,
+}
+
+impl<T0, T1, R> AggregateFunction for AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(R::return_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write_state(AggregateRegressionState::default());
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateRegressionState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let y = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let x = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        match validity {
+            Some(bitmap) => {
+                y.iter()
+                    .zip(x.iter())
+                    .zip(bitmap.iter())
+                    .for_each(|((y_val, x_val), valid)| {
+                        if valid {
+                            state.add(y_val.as_(), x_val.as_());
+                        }
+                    });
+            }
+            None => {
+                y.iter().zip(x.iter()).for_each(|(y_val, x_val)| {
+                    state.add(y_val.as_(), x_val.as_());
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let y = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let x = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        y.iter()
+            .zip(x.iter())
+            .zip(places.iter())
+            .for_each(|((y_val, x_val), place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateRegressionState>();
+                state.add(y_val.as_(), x_val.as_());
+            });
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let y = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let x = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        let y_val = unsafe { y.get_unchecked(row) };
+        let x_val = unsafe { x.get_unchecked(row) };
+
+        let state = place.get::<AggregateRegressionState>();
+        state.add(y_val.as_(), x_val.as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let rhs: AggregateRegressionState = borsh_deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let other = rhs.get::<AggregateRegressionState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        builder.push(R::apply(state).as_ref());
+        Ok(())
+    }
+}
+
+impl<T0, T1, R> fmt::Display for AggregateRegressionFunction<T0, T1, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T0, T1, R> AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    pub fn try_create(
+        display_name: &str,
+        _arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _t0: PhantomData,
+            _t1: PhantomData,
+            _r: PhantomData,
+        }))
+    }
+}
+
+pub fn try_create_aggregate_regression<R: AggregateRegression>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    with_number_mapped_type!(|NUM_TYPE0| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE0) =>
+            with_number_mapped_type!(|NUM_TYPE1| match &arguments[1] {
+                DataType::Number(NumberDataType::NUM_TYPE1) => {
+                    return AggregateRegressionFunction::<NUM_TYPE0, NUM_TYPE1, R>::try_create(
+                        display_name,
+                        arguments,
+                    );
+                }
+                _ => (),
+            }),
+        _ => (),
+    });
+
+    Err(ErrorCode::BadDataValueType(format!(
+        "Expected number data type, but got {:?}",
+        arguments
+    )))
+}
+
+pub trait AggregateRegression: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn return_type() -> DataType {
+        DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64)))
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar;
+}
+
+fn float64_or_null(value: Option<f64>) -> Scalar {
+    match value {
+        Some(v) if v.is_finite() => Scalar::Number(NumberScalar::Float64(v.into())),
+        _ => Scalar::Null,
+    }
+}
+
+// Pearson correlation coefficient
+struct AggregateCorrImpl;
+
+impl AggregateRegression for AggregateCorrImpl {
+    fn name() -> &'static str {
+        "AggregateCorrFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar {
+        if state.count == 0 || state.x_m2 == 0.0 || state.y_m2 == 0.0 {
+            return Scalar::Null;
+        }
+        float64_or_null(Some(
+            state.co_moments / (state.x_m2.sqrt() * state.y_m2.sqrt()),
+        ))
+    }
+}
+
+// Slope of the least-squares-fit linear equation
+struct AggregateRegrSlopeImpl;
+
+impl AggregateRegression for AggregateRegrSlopeImpl {
+    fn name() -> &'static str {
+        "AggregateRegrSlopeFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar {
+        float64_or_null(state.slope())
+    }
+}
+
+// Y-intercept of the least-squares-fit linear equation
+struct AggregateRegrInterceptImpl;
+
+impl AggregateRegression for AggregateRegrInterceptImpl {
+    fn name() -> &'static str {
+        "AggregateRegrInterceptFunction"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar {
+        float64_or_null(
+            state
+                .slope()
+                .map(|slope| state.y_mean - slope * state.x_mean),
+        )
+    }
+}
+
+// Square of the correlation coefficient
+struct AggregateRegrR2Impl;
+
+impl AggregateRegression for AggregateRegrR2Impl {
+    fn name() -> &'static str {
+        "AggregateRegrR2Function"
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar {
+        if state.count == 0 || state.x_m2 == 0.0 {
+            return Scalar::Null;
+        }
+        if state.y_m2 == 0.0 {
+            return float64_or_null(Some(1.0));
+        }
+        float64_or_null(Some(
+            (state.co_moments * state.co_moments) / (state.x_m2 * state.y_m2),
+        ))
+    }
+}
+
+// Number of rows in which both arguments are not null
+struct AggregateRegrCountImpl;
+
+impl AggregateRegression for AggregateRegrCountImpl {
+    fn name() -> &'static str {
+        "AggregateRegrCountFunction"
+    }
+
+    fn return_type() -> DataType {
+        DataType::Number(NumberDataType::UInt64)
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Scalar {
+        Scalar::Number(NumberScalar::UInt64(state.count))
+    }
+}
+
+pub fn aggregate_corr_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_regression::<AggregateCorrImpl>,
+    ))
+}
+
+pub fn aggregate_regr_slope_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_regression::<AggregateRegrSlopeImpl>,
+    ))
+}
+
+pub fn aggregate_regr_intercept_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_regression::<AggregateRegrInterceptImpl>,
+    ))
+}
+
+pub fn aggregate_regr_r2_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_regression::<AggregateRegrR2Impl>,
+    ))
+}
+
+pub fn aggregate_regr_count_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        is_decomposable: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_regression::<AggregateRegrCountImpl>),
+        features,
+    )
+}
@@ -37,18 +37,30 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use crate::aggregates::aggregate_approx_top_k_function_desc;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_bit_and_function_desc;
+use crate::aggregates::aggregate_bit_or_function_desc;
+use crate::aggregates::aggregate_bit_xor_function_desc;
+use crate::aggregates::aggregate_bool_and_function_desc;
+use crate::aggregates::aggregate_bool_or_function_desc;
+use crate::aggregates::aggregate_corr_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_median_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_mode_function_desc;
 use crate::aggregates::aggregate_quantile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_regr_count_function_desc;
+use crate::aggregates::aggregate_regr_intercept_function_desc;
+use crate::aggregates::aggregate_regr_r2_function_desc;
+use crate::aggregates::aggregate_regr_slope_function_desc;
 use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
@@ -72,6 +84,11 @@ impl Aggregators {
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("corr", aggregate_corr_function_desc());
+        factory.register("regr_slope", aggregate_regr_slope_function_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_function_desc());
+        factory.register("regr_r2", aggregate_regr_r2_function_desc());
+        factory.register("regr_count", aggregate_regr_count_function_desc());
         factory.register("stddev_samp", aggregate_stddev_samp_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_samp_function_desc());
@@ -137,6 +154,14 @@ impl Aggregators {
         );
 
         factory.register("histogram", aggregate_histogram_function_desc());
+
+        factory.register("bool_and", aggregate_bool_and_function_desc());
+        factory.register("bool_or", aggregate_bool_or_function_desc());
+        factory.register("bit_and", aggregate_bit_and_function_desc());
+        factory.register("bit_or", aggregate_bit_or_function_desc());
+        factory.register("bit_xor", aggregate_bit_xor_function_desc());
+        factory.register("mode", aggregate_mode_function_desc());
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
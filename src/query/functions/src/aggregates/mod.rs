@@ -18,11 +18,14 @@ mod aggregate_function_factory;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
 mod aggregate_avg;
 mod aggregate_bitmap;
+mod aggregate_bitwise;
+mod aggregate_bool;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_combinator_state;
@@ -31,11 +34,13 @@ mod aggregate_distinct_state;
 mod aggregate_histogram;
 mod aggregate_kurtosis;
 mod aggregate_min_max_any;
+mod aggregate_mode;
 mod aggregate_null_result;
 mod aggregate_quantile_cont;
 mod aggregate_quantile_disc;
 mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
+mod aggregate_regression;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_skewness;
@@ -48,9 +53,12 @@ mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
+pub use aggregate_bitwise::*;
+pub use aggregate_bool::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
@@ -60,11 +68,13 @@ pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_histogram::*;
 pub use aggregate_kurtosis::*;
 pub use aggregate_min_max_any::*;
+pub use aggregate_mode::*;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_quantile_cont::*;
 pub use aggregate_quantile_disc::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
+pub use aggregate_regression::*;
 pub use aggregate_retention::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
@@ -73,6 +73,7 @@ pub const GENERAL_CAST_RULES: AutoCastRules = &[
     (DataType::String, DataType::Binary),
     (DataType::String, DataType::Timestamp),
     (DataType::String, DataType::Date),
+    (DataType::String, DataType::Interval),
     (DataType::String, DataType::Boolean),
     (DataType::Date, DataType::Timestamp),
     (
@@ -27,6 +27,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::EmptyArrayType;
 use databend_common_expression::types::GenericType;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -58,6 +59,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_interval_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -169,6 +171,39 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
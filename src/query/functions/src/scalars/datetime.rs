@@ -27,6 +27,8 @@ use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::date::DATE_MAX;
 use databend_common_expression::types::date::DATE_MIN;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::MICROS_IN_A_DAY;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::Int64Type;
@@ -44,6 +46,7 @@ use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -1206,23 +1209,19 @@ fn register_timestamp_add_sub(registry: &mut FunctionRegistry) {
         ),
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    // The difference of two timestamps is kept in days and microseconds, the same as
+    // `justify_hours`, as a month does not have a fixed number of days.
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
-        |_, lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
+        |_, _, _| FunctionDomain::Full,
+        |a, b, _| {
+            let micros = a - b;
+            months_days_micros::new(
+                0,
+                (micros / MICROS_IN_A_DAY) as i32,
+                micros % MICROS_IN_A_DAY,
+            )
         },
-        |a, b, _| a - b,
     );
 
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Float64Type, _, _>(
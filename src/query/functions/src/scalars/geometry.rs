@@ -12,22 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::f64::consts::PI;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_expression::types::geometry::GeometryType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
+use databend_common_io::ewkb_to_geo;
+use databend_common_io::ewkb_to_wkb;
+use databend_common_io::geo_to_ewkb;
+use databend_common_io::geometry_format;
+use databend_common_io::parse_any_to_ewkb;
+use databend_common_io::parse_geojson_to_ewkb;
 use databend_common_io::parse_to_ewkb;
+use databend_common_io::parse_wkb_to_ewkb;
+use databend_common_io::read_ewkb_srid;
+use databend_common_io::GeometryDataType;
+use geo::coord;
+use geo::Area;
+use geo::Contains;
+use geo::EuclideanDistance;
+use geo::EuclideanLength;
 use geo::Geometry;
+use geo::Intersects;
+use geo::LineString;
 use geo::Point;
-use geozero::wkb::Ewkb;
+use geo::Polygon;
+use geo::Within;
 use geozero::CoordDimensions;
 use geozero::ToWkb;
-use geozero::ToWkt;
+use jsonb::parse_value;
 
 // const GEO_TYPE_ID_MASK: u32 = 0x2000_0000;
 
@@ -40,8 +65,15 @@ pub fn register(registry: &mut FunctionRegistry) {
         "st_geomfromewkt",
         "st_geometryfromtext",
         "st_geomfromtext",
-        "to_geometry",
     ]);
+    registry.register_aliases("st_geometryfromwkb", &[
+        "st_geomfromwkb",
+        "st_geometryfromewkb",
+        "st_geomfromewkb",
+    ]);
+    registry.register_aliases("st_geometryfromgeojson", &["st_geomfromgeojson"]);
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
+    registry.register_aliases("st_aswkt", &["st_astext"]);
 
     // functions
     registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
@@ -127,6 +159,367 @@ pub fn register(registry: &mut FunctionRegistry) {
         }),
     );
 
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_any_to_ewkb(s.as_bytes(), None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, Int32Type, GeometryType, _, _>(
+        "to_geometry",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, Int32Type, GeometryType>(
+            |s, srid, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match parse_any_to_ewkb(s.as_bytes(), Some(srid)) {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|wkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_wkb_to_ewkb(wkb, None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, GeometryType>(|json, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_geojson_to_ewkb(&jsonb::to_string(json), None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geometryfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, GeometryType>(|wkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_wkb_to_ewkb(wkb, None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<BinaryType, Int32Type, GeometryType, _, _>(
+        "st_geometryfromwkb",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<BinaryType, Int32Type, GeometryType>(
+            |wkb, srid, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match parse_wkb_to_ewkb(wkb, Some(srid)) {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    // The hex encoded WKB or EWKB.
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geometryfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match hex::decode(s)
+                .map_err(|e| ErrorCode::GeometryError(e.to_string()))
+                .and_then(|wkb| parse_wkb_to_ewkb(&wkb, None))
+            {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, Int32Type, GeometryType, _, _>(
+        "st_geometryfromwkb",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, Int32Type, GeometryType>(
+            |s, srid, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match hex::decode(s)
+                    .map_err(|e| ErrorCode::GeometryError(e.to_string()))
+                    .and_then(|wkb| parse_wkb_to_ewkb(&wkb, Some(srid)))
+                {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geometryfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|json, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_geojson_to_ewkb(json, None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<VariantType, GeometryType, _, _>(
+        "st_geometryfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<VariantType, GeometryType>(|json, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match parse_geojson_to_ewkb(&jsonb::to_string(json), None) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match ewkb_to_wkb(ewkb) {
+                Ok(data) => builder.put_slice(data.as_slice()),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_asewkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, BinaryType>(|ewkb, builder, _| {
+            builder.put_slice(ewkb);
+            builder.commit_row();
+        }),
+    );
+
+    register_geometry_to_string(registry, "st_aswkt", GeometryDataType::WKT);
+    register_geometry_to_string(registry, "st_asewkt", GeometryDataType::EWKT);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, VariantType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            let json = geometry_format(ewkb, GeometryDataType::GEOJSON).and_then(|json| {
+                parse_value(json.as_bytes()).map_err(|e| ErrorCode::GeometryError(e.to_string()))
+            });
+            match json {
+                Ok(value) => value.write_to_vec(&mut builder.data),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, Int32Type, _, _>(
+        "st_srid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, Int32Type>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(0);
+                    return;
+                }
+            }
+            match read_ewkb_srid(ewkb) {
+                Ok(srid) => builder.push(srid.unwrap_or(0)),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(0);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, Int32Type, GeometryType, _, _>(
+        "st_setsrid",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, Int32Type, GeometryType>(
+            |ewkb, srid, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.commit_row();
+                        return;
+                    }
+                }
+                match parse_wkb_to_ewkb(ewkb, Some(srid)) {
+                    Ok(data) => builder.put_slice(data.as_slice()),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    register_geometry_predicate(registry, "st_contains", |l, r| l.contains(r));
+    register_geometry_predicate(registry, "st_intersects", |l, r| l.intersects(r));
+    register_geometry_predicate(registry, "st_within", |l, r| l.is_within(r));
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l, r, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match geometry_pair(l, r) {
+                    Ok((l, r)) => builder.push(l.euclidean_distance(&r) <= distance.0),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, GeometryType, NumberType<F64>>(
+                |l, r, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.push(F64::from(0.0));
+                            return;
+                        }
+                    }
+                    match geometry_pair(l, r) {
+                        Ok((l, r)) => builder.push(F64::from(l.euclidean_distance(&r))),
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e.to_string());
+                            builder.push(F64::from(0.0));
+                        }
+                    }
+                },
+            ),
+        );
+
+    register_geometry_measurement(registry, "st_area", |geom| geom.unsigned_area());
+    register_geometry_measurement(registry, "st_length", geometry_length);
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_buffer",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |ewkb, distance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+                    let result = ewkb_to_geo(ewkb).and_then(|(geom, srid)| {
+                        let buffer = geometry_buffer(&geom, distance.0)?;
+                        geo_to_ewkb(buffer, srid)
+                    });
+                    match result {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
     // registry.register_passthrough_nullable_2_arg::<GeometryType, Int32Type, GeometryType, _, _>(
     //     "st_transform",
     //     |_, _, _| FunctionDomain::MayThrow,
@@ -227,6 +620,138 @@ pub fn register(registry: &mut FunctionRegistry) {
     // );
 }
 
+fn register_geometry_to_string(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    format: GeometryDataType,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(move |ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+            match geometry_format(ewkb, format) {
+                Ok(data) => builder.put_str(&data),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+}
+
+fn register_geometry_predicate(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    predicate: fn(&Geometry, &Geometry) -> bool,
+) {
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        name,
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            move |l, r, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                match geometry_pair(l, r) {
+                    Ok((l, r)) => builder.push(predicate(&l, &r)),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn register_geometry_measurement(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    measure: fn(&Geometry) -> f64,
+) {
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(move |ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0.0));
+                    return;
+                }
+            }
+            match ewkb_to_geo(ewkb) {
+                Ok((geom, _)) => builder.push(F64::from(measure(&geom))),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            }
+        }),
+    );
+}
+
+// Decodes both geometries, they must be in the same spatial reference system.
+fn geometry_pair(l: &[u8], r: &[u8]) -> Result<(Geometry, Geometry)> {
+    let (l_geom, l_srid) = ewkb_to_geo(l)?;
+    let (r_geom, r_srid) = ewkb_to_geo(r)?;
+    let (l_srid, r_srid) = (l_srid.unwrap_or(0), r_srid.unwrap_or(0));
+    if l_srid != r_srid {
+        return Err(ErrorCode::GeometryError(format!(
+            "Incompatible SRID: {} and {}",
+            l_srid, r_srid
+        )));
+    }
+    Ok((l_geom, r_geom))
+}
+
+// The length of the linear geometries, the area geometries and points have zero length.
+fn geometry_length(geom: &Geometry) -> f64 {
+    match geom {
+        Geometry::Line(line) => line.euclidean_length(),
+        Geometry::LineString(line_string) => line_string.euclidean_length(),
+        Geometry::MultiLineString(lines) => lines.euclidean_length(),
+        Geometry::GeometryCollection(collection) => collection.iter().map(geometry_length).sum(),
+        _ => 0.0,
+    }
+}
+
+// The number of segments used to approximate a quarter circle, the same as PostGIS.
+const BUFFER_QUADRANT_SEGMENTS: usize = 8;
+
+fn geometry_buffer(geom: &Geometry, distance: f64) -> Result<Geometry> {
+    if distance <= 0.0 || !distance.is_finite() {
+        return Err(ErrorCode::GeometryError(format!(
+            "The buffer distance must be a positive number, but got {}",
+            distance
+        )));
+    }
+    let Geometry::Point(point) = geom else {
+        return Err(ErrorCode::GeometryError(
+            "ST_BUFFER only supports the POINT geometry",
+        ));
+    };
+    let segments = BUFFER_QUADRANT_SEGMENTS * 4;
+    let coords = (0..segments)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / segments as f64;
+            coord! { x: point.x() + distance * angle.cos(), y: point.y() + distance * angle.sin() }
+        })
+        .collect::<Vec<_>>();
+    // The exterior ring is closed by `Polygon::new`.
+    Ok(Geometry::Polygon(Polygon::new(
+        LineString::from(coords),
+        vec![],
+    )))
+}
+
 // fn make_crs(srid: i32) -> String {
 //     format!("EPSG:{}", srid)
 // }
@@ -0,0 +1,426 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::interval_to_string;
+use databend_common_expression::types::interval::months_days_micros;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::interval::MICROS_IN_AN_HOUR;
+use databend_common_expression::types::interval::MICROS_IN_A_DAY;
+use databend_common_expression::types::interval::MICROS_IN_A_MINUTE;
+use databend_common_expression::types::interval::MONTHS_IN_A_YEAR;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::F64;
+use databend_common_expression::utils::date_helper::AddDaysImpl;
+use databend_common_expression::utils::date_helper::AddMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+
+    // cast(interval AS string)
+    // to_string(interval)
+    register_interval_to_string(registry);
+
+    // to_[years | quarters | months | weeks | days | hours | minutes | seconds](number)
+    register_number_to_interval(registry);
+
+    // interval [+ | -] interval
+    // [date | timestamp] [+ | -] interval
+    register_interval_add_sub(registry);
+
+    // justify_[hours | days | interval](interval)
+    register_justify_functions(registry);
+
+    // extract([year | quarter | month | day | hour | minute | second] FROM interval)
+    register_extract_functions(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`: {e}"),
+                    );
+                    output.push(months_days_micros::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", interval_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.data, "{}", interval_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    fn register_unit(
+        registry: &mut FunctionRegistry,
+        name: &str,
+        to_interval: fn(i64) -> Option<months_days_micros>,
+    ) {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                match to_interval(val) {
+                    Some(interval) => output.push(interval),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(months_days_micros::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    register_unit(registry, "to_years", |n| {
+        let months = n.checked_mul(MONTHS_IN_A_YEAR as i64)?;
+        Some(months_days_micros::new(months.try_into().ok()?, 0, 0))
+    });
+    register_unit(registry, "to_quarters", |n| {
+        let months = n.checked_mul(3)?;
+        Some(months_days_micros::new(months.try_into().ok()?, 0, 0))
+    });
+    register_unit(registry, "to_months", |n| {
+        Some(months_days_micros::new(n.try_into().ok()?, 0, 0))
+    });
+    register_unit(registry, "to_weeks", |n| {
+        let days = n.checked_mul(7)?;
+        Some(months_days_micros::new(0, days.try_into().ok()?, 0))
+    });
+    register_unit(registry, "to_days", |n| {
+        Some(months_days_micros::new(0, n.try_into().ok()?, 0))
+    });
+    register_unit(registry, "to_hours", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_AN_HOUR)?,
+        ))
+    });
+    register_unit(registry, "to_minutes", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_MINUTE)?,
+        ))
+    });
+    register_unit(registry, "to_seconds", |n| {
+        Some(months_days_micros::new(
+            0,
+            0,
+            n.checked_mul(MICROS_IN_A_SEC)?,
+        ))
+    });
+}
+
+/// Add the interval to the timestamp. The months are added in the calendar of the time zone,
+/// while the days and the microseconds are added as exact durations.
+fn add_interval(ts: i64, interval: months_days_micros, tz: TzLUT) -> Result<i64, String> {
+    let mut ts = ts;
+    if interval.months() != 0 {
+        ts = AddMonthsImpl::eval_timestamp(ts, tz, interval.months())?;
+    }
+    if interval.days() != 0 {
+        ts = AddDaysImpl::eval_timestamp(ts, interval.days())?;
+    }
+    let ts = ts
+        .checked_add(interval.microseconds())
+        .ok_or_else(|| "timestamp out of range".to_string())?;
+    check_timestamp(ts)
+}
+
+fn sub_interval(ts: i64, interval: months_days_micros, tz: TzLUT) -> Result<i64, String> {
+    let interval = interval
+        .checked_neg()
+        .ok_or_else(|| "interval out of range".to_string())?;
+    add_interval(ts, interval, tz)
+}
+
+fn register_interval_add_sub(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(&b) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match b.checked_neg().and_then(|b| a.checked_add(&b)) {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            match a.checked_neg() {
+                Some(v) => output.push(v),
+                None => {
+                    ctx.set_error(output.len(), "interval out of range");
+                    output.push(months_days_micros::default());
+                }
+            }
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match add_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match add_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match sub_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    // Same as PostgreSQL, a date plus or minus an interval is a timestamp.
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                match add_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                match add_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let ts = date as i64 * MICROS_IN_A_DAY;
+                match sub_interval(ts, interval, ctx.func_ctx.tz) {
+                    Ok(v) => output.push(v),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+}
+
+fn register_justify_functions(registry: &mut FunctionRegistry) {
+    fn register_justify(
+        registry: &mut FunctionRegistry,
+        name: &str,
+        justify: fn(&months_days_micros) -> Option<months_days_micros>,
+    ) {
+        registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<IntervalType, IntervalType>(move |val, output, ctx| {
+                match justify(&val) {
+                    Some(interval) => output.push(interval),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(months_days_micros::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    register_justify(registry, "justify_hours", months_days_micros::justify_hours);
+    register_justify(registry, "justify_days", months_days_micros::justify_days);
+    register_justify(
+        registry,
+        "justify_interval",
+        months_days_micros::justify_interval,
+    );
+}
+
+fn register_extract_functions(registry: &mut FunctionRegistry) {
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_year",
+        |_, _| FunctionDomain::Full,
+        |val, _| (val.months() / MONTHS_IN_A_YEAR) as i64,
+    );
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_quarter",
+        |_, _| FunctionDomain::Full,
+        |val, _| (val.months() % MONTHS_IN_A_YEAR / 3 + 1) as i64,
+    );
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_month",
+        |_, _| FunctionDomain::Full,
+        |val, _| (val.months() % MONTHS_IN_A_YEAR) as i64,
+    );
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_day_of_month",
+        |_, _| FunctionDomain::Full,
+        |val, _| val.days() as i64,
+    );
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_hour",
+        |_, _| FunctionDomain::Full,
+        |val, _| val.microseconds() / MICROS_IN_AN_HOUR,
+    );
+    registry.register_1_arg::<IntervalType, Int64Type, _, _>(
+        "to_minute",
+        |_, _| FunctionDomain::Full,
+        |val, _| val.microseconds() % MICROS_IN_AN_HOUR / MICROS_IN_A_MINUTE,
+    );
+    registry.register_1_arg::<IntervalType, Float64Type, _, _>(
+        "to_second",
+        |_, _| FunctionDomain::Full,
+        |val, _| {
+            let micros = val.microseconds() % MICROS_IN_A_MINUTE;
+            F64::from(micros as f64 / MICROS_IN_A_SEC as f64)
+        },
+    );
+}
@@ -28,6 +28,7 @@ mod geo;
 mod geo_h3;
 mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
@@ -64,4 +65,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     vector::register(registry);
     bitmap::register(registry);
     geometry::register(registry);
+    interval::register(registry);
 }
@@ -30,6 +30,7 @@ use itertools::Itertools;
 use roaring::RoaringTreemap;
 
 use super::run_agg_ast;
+use super::simulate_merge_partial_states;
 use super::simulate_two_groups_group_by;
 use super::AggregationSimulator;
 
@@ -51,6 +52,8 @@ fn test_agg() {
     test_agg_arg_max(file, eval_aggr);
     test_agg_covar_samp(file, eval_aggr);
     test_agg_covar_pop(file, eval_aggr);
+    test_agg_regression(file, eval_aggr);
+    test_agg_regression(file, simulate_merge_partial_states);
     test_agg_retention(file, eval_aggr);
     test_agg_stddev(file, eval_aggr);
     test_agg_kurtosis(file, eval_aggr);
@@ -90,6 +93,7 @@ fn test_agg_group_by() {
     test_agg_arg_max(file, simulate_two_groups_group_by);
     test_agg_covar_samp(file, simulate_two_groups_group_by);
     test_agg_covar_pop(file, simulate_two_groups_group_by);
+    test_agg_regression(file, simulate_two_groups_group_by);
     test_agg_retention(file, simulate_two_groups_group_by);
     test_agg_stddev(file, simulate_two_groups_group_by);
     test_agg_kurtosis(file, simulate_two_groups_group_by);
@@ -187,6 +191,13 @@ fn get_example() -> Vec<(&'static str, Column)> {
     ]
 }
 
+fn get_empty_example() -> Vec<(&'static str, Column)> {
+    vec![
+        ("a", Int64Type::from_data(Vec::<i64>::new())),
+        ("b", UInt64Type::from_data(Vec::<u64>::new())),
+    ]
+}
+
 fn test_count(file: &mut impl Write, simulator: impl AggregationSimulator) {
     run_agg_ast(file, "count(1)", get_example().as_slice(), simulator);
     run_agg_ast(file, "count()", get_example().as_slice(), simulator);
@@ -378,6 +389,54 @@ fn test_agg_covar_pop(file: &mut impl Write, simulator: impl AggregationSimulato
     );
 }
 
+fn test_agg_regression(file: &mut impl Write, simulator: impl AggregationSimulator) {
+    run_agg_ast(file, "corr(b, c)", get_example().as_slice(), simulator);
+    run_agg_ast(file, "corr(c, x_null)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "corr(a, all_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "regr_slope(c, b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "regr_intercept(c, b)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(file, "regr_r2(b, c)", get_example().as_slice(), simulator);
+    run_agg_ast(
+        file,
+        "regr_count(a, x_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "regr_count(a, all_null)",
+        get_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "corr(a, b)",
+        get_empty_example().as_slice(),
+        simulator,
+    );
+    run_agg_ast(
+        file,
+        "regr_count(a, b)",
+        get_empty_example().as_slice(),
+        simulator,
+    );
+}
+
 fn test_agg_retention(file: &mut impl Write, simulator: impl AggregationSimulator) {
     run_agg_ast(
         file,
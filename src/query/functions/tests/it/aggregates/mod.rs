@@ -218,3 +218,69 @@ pub fn simulate_two_groups_group_by(
 
     Ok((builder.build(), data_type))
 }
+
+/// Simulate the merge of partial aggregation.
+/// Rows are accumulated into three partial states by ranges, the partial states are
+/// serialized and then merged into the final state.
+///
+/// Example:
+///
+/// If the column is:
+///
+/// ```
+/// let column = vec![1, 2, 3, 4];
+/// ```
+///
+/// then the partial states are accumulated by:
+///
+/// ```
+/// let partial1 = vec![1];
+/// let partial2 = vec![2];
+/// let partial3 = vec![3, 4];
+/// ```
+pub fn simulate_merge_partial_states(
+    name: &str,
+    params: Vec<Scalar>,
+    columns: &[Column],
+    rows: usize,
+) -> databend_common_exception::Result<(Column, DataType)> {
+    const PARTIAL_STATES: usize = 3;
+
+    let factory = AggregateFunctionFactory::instance();
+    let arguments: Vec<DataType> = columns.iter().map(|c| c.data_type()).collect();
+
+    let func = factory.get(name, params, arguments)?;
+    let data_type = func.return_type()?;
+
+    let arena = Bump::new();
+
+    let addr = arena.alloc_layout(func.state_layout());
+    func.init_state(addr.into());
+
+    for i in 0..PARTIAL_STATES {
+        let range = rows * i / PARTIAL_STATES..rows * (i + 1) / PARTIAL_STATES;
+        let cols = columns
+            .iter()
+            .map(|c| c.slice(range.clone()))
+            .collect::<Vec<_>>();
+
+        let partial_addr = arena.alloc_layout(func.state_layout());
+        func.init_state(partial_addr.into());
+        func.accumulate(partial_addr.into(), &cols, None, range.len())?;
+
+        let mut buffer = Vec::new();
+        func.serialize(partial_addr.into(), &mut buffer)?;
+        func.merge(addr.into(), &mut buffer.as_slice())?;
+        if func.need_manual_drop_state() {
+            unsafe { func.drop_state(partial_addr.into()) };
+        }
+    }
+
+    let mut builder = ColumnBuilder::with_capacity(&data_type, 1024);
+    func.merge_result(addr.into(), &mut builder)?;
+    if func.need_manual_drop_state() {
+        unsafe { func.drop_state(addr.into()) };
+    }
+
+    Ok((builder.build(), data_type))
+}
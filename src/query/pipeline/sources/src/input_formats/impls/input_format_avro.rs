@@ -0,0 +1,296 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use apache_avro::schema::RecordSchema;
+use apache_avro::schema::Schema;
+use apache_avro::types::Value;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_formats::FieldAvroDecoder;
+use databend_common_formats::FieldDecoder;
+use databend_common_formats::FileFormatOptionsExt;
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_storage::FileParseError;
+
+use super::input_format_xml::AligningStateWholeFile;
+use crate::input_formats::BlockBuilder;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormatTextBase;
+use crate::input_formats::RowBatch;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatAvro {}
+
+impl InputFormatAvro {
+    pub fn create() -> Self {
+        Self {}
+    }
+
+    /// Infer the table schema from the header of an Avro object container file.
+    pub fn infer_schema(data: &[u8]) -> Result<TableSchema> {
+        let reader = Reader::new(Cursor::new(data))
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to read avro header: {e}")))?;
+        match reader.writer_schema() {
+            Schema::Record(record) => {
+                let mut fields = Vec::with_capacity(record.fields.len());
+                for field in record.fields.iter() {
+                    fields.push(TableField::new(
+                        &field.name,
+                        avro_to_table_type(&field.schema)?,
+                    ));
+                }
+                Ok(TableSchema::new(fields))
+            }
+            other => Err(ErrorCode::BadBytes(format!(
+                "the schema of avro file must be a record, but got {}",
+                other.canonical_form()
+            ))),
+        }
+    }
+
+    fn read_row(
+        field_decoder: &FieldAvroDecoder,
+        record: &[(String, Value)],
+        record_schema: &RecordSchema,
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        default_values: &Option<Vec<Scalar>>,
+        missing_field_as: &NullAs,
+    ) -> std::result::Result<(), FileParseError> {
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            let position = record.iter().position(|(name, _)| {
+                if field_decoder.ident_case_sensitive {
+                    name == field.name()
+                } else {
+                    name.eq_ignore_ascii_case(field.name())
+                }
+            });
+            match position {
+                None => match missing_field_as {
+                    NullAs::Error => {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                    NullAs::Null => {
+                        if field.is_nullable_or_null() {
+                            column.push_default();
+                        } else {
+                            return Err(FileParseError::ColumnMissingError {
+                                column_index,
+                                column_name: field.name().to_owned(),
+                                column_type: field.data_type.to_string(),
+                            });
+                        }
+                    }
+                    NullAs::FieldDefault => {
+                        if let Some(values) = default_values {
+                            column.push(values[column_index].as_ref());
+                        } else {
+                            column.push_default();
+                        }
+                    }
+                },
+                Some(pos) => {
+                    let value = &record[pos].1;
+                    let value_schema = &record_schema.fields[pos].schema;
+                    field_decoder
+                        .read_field(column, value, value_schema)
+                        .map_err(|e| FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.message(),
+                            column_data: format!("{:?}", value),
+                        })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl InputFormatTextBase for InputFormatAvro {
+    type AligningState = AligningStateWholeFile;
+
+    fn format_type() -> StageFileFormatType {
+        StageFileFormatType::Avro
+    }
+
+    fn create_field_decoder(
+        _params: &FileFormatParams,
+        options: &FileFormatOptionsExt,
+    ) -> Arc<dyn FieldDecoder> {
+        Arc::new(FieldAvroDecoder::create(options, options.is_rounding_mode))
+    }
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<Self::AligningState> {
+        AligningStateWholeFile::try_create(ctx, split_info)
+    }
+
+    fn deserialize(builder: &mut BlockBuilder<Self>, batch: RowBatch) -> Result<()> {
+        let field_decoder = builder
+            .field_decoder
+            .as_any()
+            .downcast_ref::<FieldAvroDecoder>()
+            .expect("must success");
+        let columns = &mut builder.mutable_columns;
+        let path = &batch.split_info.file.path;
+        let avro_params = AvroFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
+
+        // the reader decompresses the blocks with the codec from the file header.
+        let reader = Reader::new(Cursor::new(&batch.data)).map_err(|e| avro_error(&e, path, 0))?;
+        let writer_schema = reader.writer_schema().clone();
+        let record_schema = match &writer_schema {
+            Schema::Record(record) => Some(record),
+            _ => None,
+        };
+
+        for (num_rows, value) in reader.enumerate() {
+            let value = value.map_err(|e| avro_error(&e, path, num_rows))?;
+            let res = if field_decoder.is_select {
+                field_decoder
+                    .read_field(&mut columns[0], &value, &writer_schema)
+                    .map_err(|e| FileParseError::InvalidAvroRecord {
+                        message: e.message(),
+                    })
+            } else {
+                match (&value, record_schema) {
+                    (Value::Record(record), Some(record_schema)) => Self::read_row(
+                        field_decoder,
+                        record,
+                        record_schema,
+                        columns,
+                        &builder.ctx.schema,
+                        &builder.ctx.default_values,
+                        &avro_params.missing_field_as,
+                    ),
+                    _ => Err(FileParseError::InvalidAvroRecord {
+                        message: "the schema of avro file must be a record".to_string(),
+                    }),
+                }
+            };
+            if let Err(e) = res {
+                builder.ctx.on_error(
+                    e,
+                    Some((columns, builder.num_rows)),
+                    &mut builder.file_status,
+                    path,
+                    num_rows + batch.start_row_in_split,
+                )?;
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn avro_to_table_type(schema: &Schema) -> Result<TableDataType> {
+    let ty = match schema {
+        Schema::Null => TableDataType::Null,
+        Schema::Boolean => TableDataType::Boolean,
+        Schema::Int | Schema::TimeMillis => TableDataType::Number(NumberDataType::Int32),
+        Schema::Long | Schema::TimeMicros => TableDataType::Number(NumberDataType::Int64),
+        Schema::Float => TableDataType::Number(NumberDataType::Float32),
+        Schema::Double => TableDataType::Number(NumberDataType::Float64),
+        Schema::Bytes | Schema::Fixed(_) | Schema::Duration => TableDataType::Binary,
+        Schema::String | Schema::Enum(_) | Schema::Uuid => TableDataType::String,
+        Schema::Date => TableDataType::Date,
+        Schema::TimestampMillis
+        | Schema::TimestampMicros
+        | Schema::LocalTimestampMillis
+        | Schema::LocalTimestampMicros => TableDataType::Timestamp,
+        Schema::Decimal(decimal) => {
+            let size = DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            };
+            TableDataType::Decimal(DecimalDataType::from_size(size)?)
+        }
+        Schema::Array(items) => TableDataType::Array(Box::new(avro_to_table_type(items)?)),
+        Schema::Map(values) => TableDataType::Map(Box::new(TableDataType::Tuple {
+            fields_name: vec!["key".to_string(), "value".to_string()],
+            fields_type: vec![TableDataType::String, avro_to_table_type(values)?],
+        })),
+        Schema::Record(record) => {
+            let mut fields_name = Vec::with_capacity(record.fields.len());
+            let mut fields_type = Vec::with_capacity(record.fields.len());
+            for field in record.fields.iter() {
+                fields_name.push(field.name.clone());
+                fields_type.push(avro_to_table_type(&field.schema)?);
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            }
+        }
+        Schema::Union(union) => {
+            let variants = union.variants();
+            let non_null = variants
+                .iter()
+                .filter(|v| !matches!(v, Schema::Null))
+                .collect::<Vec<_>>();
+            match non_null.as_slice() {
+                [] => TableDataType::Null,
+                // ["null", T] is how avro spells a nullable T.
+                [inner] if union.is_nullable() => avro_to_table_type(inner)?.wrap_nullable(),
+                [inner] => avro_to_table_type(inner)?,
+                // the other unions can not be mapped to a single type.
+                _ if union.is_nullable() => TableDataType::Variant.wrap_nullable(),
+                _ => TableDataType::Variant,
+            }
+        }
+        // named references are used by recursive types.
+        Schema::Ref { .. } => TableDataType::Variant,
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported avro type {}",
+                other.canonical_form()
+            )));
+        }
+    };
+    Ok(ty)
+}
+
+fn avro_error(e: &apache_avro::Error, path: &str, row: usize) -> ErrorCode {
+    let row = row + 1;
+    let msg = format!("fail to parse AVRO {}:{} {} ", path, row, e);
+
+    ErrorCode::BadBytes(msg)
+}
@@ -12,13 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ColumnBuilder;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_formats::FieldDecoder;
 use databend_common_formats::FileFormatOptionsExt;
@@ -44,34 +47,146 @@ impl InputFormatXML {
     pub fn create() -> Self {
         Self {}
     }
-    fn read_row(
+
+    /// Infer the table schema from the rows of a XML file.
+    ///
+    /// Columns are collected from the attributes and the child elements of the row elements,
+    /// in the order they first appear. Names are matched case-insensitively, like `COPY INTO` does.
+    ///
+    /// `data` may be a prefix of the file, the row cut by the end of the prefix is ignored.
+    pub fn infer_schema(data: &[u8], row_tag: &str, is_prefix: bool) -> Result<TableSchema> {
+        let mut columns: Vec<(String, InferredType)> = vec![];
+        let mut num_rows = 0;
+        let res = Self::parse_rows(data, row_tag, "", |row| {
+            for (name, value) in row {
+                let ty = InferredType::infer(value);
+                match columns
+                    .iter_mut()
+                    .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                {
+                    Some((_, prev)) => prev.merge(ty),
+                    None => {
+                        // a column missing in the former rows is nullable.
+                        let ty = if num_rows > 0 { ty.nullable() } else { ty };
+                        columns.push((name.clone(), ty));
+                    }
+                }
+            }
+            for (name, ty) in columns.iter_mut() {
+                if !row.iter().any(|(n, _)| n.eq_ignore_ascii_case(name)) {
+                    ty.nullable = true;
+                }
+            }
+            num_rows += 1;
+            Ok(num_rows < XML_INFER_MAX_ROWS)
+        });
+        match res {
+            Err(_) if is_prefix && num_rows > 0 => {}
+            res => res?,
+        }
+
+        let fields = columns
+            .into_iter()
+            .map(|(name, ty)| TableField::new(&name, ty.to_table_type()))
+            .collect();
+        Ok(TableSchema::new(fields))
+    }
+
+    /// Parse the rows in `data`, calling `on_row` with the (name, value) pairs of each row.
+    ///
+    /// `on_row` returns false to stop parsing.
+    fn parse_rows<F>(data: &[u8], row_tag: &str, path: &str, mut on_row: F) -> Result<()>
+    where F: FnMut(&[(String, Vec<u8>)]) -> Result<bool> {
+        let row_tag = row_tag.as_bytes();
+        let field_tag = b"field";
+
+        let mut buf = Cursor::new(data);
+        let reader = ParserConfig::new().create_reader(&mut buf);
+
+        // (name, value) of the current row, in the order they appear.
+        let mut cols: Vec<(String, Vec<u8>)> = vec![];
+        // index in `cols` of the field waiting for its value.
+        let mut key = None;
+        let mut has_start_row = false;
+        let mut num_rows = 0usize;
+
+        for e in reader {
+            match e {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let name_byte = name.local_name.as_bytes();
+                    match attributes.is_empty() {
+                        true => {
+                            // Column names as tags and column values as the content of these tags.
+                            if name_byte != row_tag && has_start_row {
+                                cols.push((name.local_name, vec![]));
+                                key = Some(cols.len() - 1);
+                            } else if name_byte == row_tag {
+                                has_start_row = true;
+                            }
+                        }
+                        false => {
+                            // Column name as attributes and column values as attribute values.
+                            if name_byte == row_tag {
+                                for attr in attributes {
+                                    cols.push((attr.name.local_name, attr.value.into_bytes()));
+                                }
+                            } else if name_byte == field_tag {
+                                if attributes.len() > 1 {
+                                    return Err(xml_error(
+                                        &format!(
+                                            "invalid field tag, expect 1 attr, but got {}",
+                                            attributes.len()
+                                        ),
+                                        path,
+                                        num_rows,
+                                    ));
+                                }
+                                let attr = attributes.first().unwrap();
+                                cols.push((attr.value.clone(), vec![]));
+                                key = Some(cols.len() - 1);
+                            }
+                        }
+                    }
+                }
+                Ok(XmlEvent::EndElement { name }) => {
+                    if name.local_name.as_bytes() == row_tag {
+                        let go_on = on_row(&cols)?;
+                        num_rows += 1;
+                        cols.clear();
+                        key = None;
+                        has_start_row = false;
+                        if !go_on {
+                            break;
+                        }
+                    }
+                }
+                Ok(XmlEvent::Characters(v)) => {
+                    if let Some(key) = key.take() {
+                        cols[key].1 = v.into_bytes();
+                    }
+                }
+                Err(e) => {
+                    return Err(xml_error(e.msg(), path, num_rows));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn read_column(
         field_decoder: &SeparatedTextDecoder,
-        row_data: &mut HashMap<String, Vec<u8>>,
-        columns: &mut [ColumnBuilder],
+        column: &mut ColumnBuilder,
+        value: Option<&[u8]>,
+        column_index: usize,
         schema: &TableSchemaRef,
-        ident_case_sensitive: bool,
     ) -> std::result::Result<(), FileParseError> {
-        let raw_data = if !ident_case_sensitive {
-            row_data
-                .drain()
-                .map(|(k, v)| (k.to_lowercase(), v))
-                .collect()
-        } else {
-            row_data.clone()
-        };
-
-        for ((column_index, field), column) in
-            schema.fields().iter().enumerate().zip(columns.iter_mut())
-        {
-            let value = if ident_case_sensitive {
-                raw_data.get(field.name())
-            } else {
-                raw_data.get(&field.name().to_lowercase())
-            };
-            if let Some(value) = value {
-                if value.is_empty() {
-                    column.push_default();
-                } else if let Err(e) = field_decoder.read_field(column, value) {
+        match value {
+            Some(value) if !value.is_empty() => {
+                if let Err(e) = field_decoder.read_field(column, value) {
+                    let field = &schema.fields()[column_index];
                     return Err(FileParseError::ColumnDecodeError {
                         column_index,
                         column_name: field.name().to_string(),
@@ -82,12 +197,156 @@ impl InputFormatXML {
                         ),
                     });
                 }
-            } else {
-                column.push_default();
             }
+            _ => column.push_default(),
         }
         Ok(())
     }
+
+    fn read_row(
+        field_decoder: &SeparatedTextDecoder,
+        row_data: &[(String, Vec<u8>)],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        ident_case_sensitive: bool,
+    ) -> std::result::Result<(), FileParseError> {
+        for ((column_index, field), column) in
+            schema.fields().iter().enumerate().zip(columns.iter_mut())
+        {
+            // the last one wins if a name appears more than once.
+            let value = row_data
+                .iter()
+                .rev()
+                .find(|(name, _)| {
+                    if ident_case_sensitive {
+                        name == field.name()
+                    } else {
+                        name.eq_ignore_ascii_case(field.name())
+                    }
+                })
+                .map(|(_, v)| v.as_slice());
+            Self::read_column(field_decoder, column, value, column_index, schema)?;
+        }
+        Ok(())
+    }
+
+    /// Used by `select $1, $2 from @stage`, `$N` is the N-th name in `names`.
+    ///
+    /// The value is found by name, a row without the element gets NULL.
+    fn read_row_by_position(
+        field_decoder: &SeparatedTextDecoder,
+        row_data: &[(String, Vec<u8>)],
+        columns: &mut [ColumnBuilder],
+        schema: &TableSchemaRef,
+        columns_to_read: &Option<Vec<usize>>,
+        names: &[String],
+    ) -> std::result::Result<(), FileParseError> {
+        for (column_index, column) in columns.iter_mut().enumerate() {
+            if let Some(columns_to_read) = columns_to_read {
+                if !columns_to_read.contains(&column_index) {
+                    column.push_default();
+                    continue;
+                }
+            }
+            let value = names.get(column_index).and_then(|column_name| {
+                row_data
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name.eq_ignore_ascii_case(column_name))
+                    .map(|(_, v)| v.as_slice())
+            });
+            Self::read_column(field_decoder, column, value, column_index, schema)?;
+        }
+        Ok(())
+    }
+
+    /// The names of the columns in a file, in the order they first appear, like `infer_schema`.
+    fn column_names(data: &[u8], row_tag: &str, path: &str) -> Result<Vec<String>> {
+        let mut names: Vec<String> = vec![];
+        Self::parse_rows(data, row_tag, path, |row| {
+            for (name, _) in row {
+                if !names.iter().any(|n| n.eq_ignore_ascii_case(name)) {
+                    names.push(name.clone());
+                }
+            }
+            Ok(true)
+        })?;
+        Ok(names)
+    }
+}
+
+/// The max number of rows read to infer the schema of a XML file.
+const XML_INFER_MAX_ROWS: usize = 1000;
+
+/// Type of a column inferred from the text values.
+#[derive(Clone, Copy)]
+struct InferredType {
+    kind: InferredKind,
+    nullable: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum InferredKind {
+    // only empty values are seen.
+    Unknown,
+    Boolean,
+    Int64,
+    Float64,
+    String,
+}
+
+impl InferredType {
+    fn infer(value: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(value);
+        let text = text.trim();
+        let kind = if text.is_empty() {
+            InferredKind::Unknown
+        } else if text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("false") {
+            InferredKind::Boolean
+        } else if text.parse::<i64>().is_ok() {
+            InferredKind::Int64
+        } else if text.parse::<f64>().is_ok() {
+            InferredKind::Float64
+        } else {
+            InferredKind::String
+        };
+        InferredType {
+            kind,
+            nullable: kind == InferredKind::Unknown,
+        }
+    }
+
+    fn nullable(self) -> Self {
+        InferredType {
+            nullable: true,
+            ..self
+        }
+    }
+
+    fn merge(&mut self, other: InferredType) {
+        self.nullable |= other.nullable;
+        self.kind = match (self.kind, other.kind) {
+            (InferredKind::Unknown, k) | (k, InferredKind::Unknown) => k,
+            (a, b) if a == b => a,
+            (InferredKind::Int64, InferredKind::Float64)
+            | (InferredKind::Float64, InferredKind::Int64) => InferredKind::Float64,
+            _ => InferredKind::String,
+        };
+    }
+
+    fn to_table_type(self) -> TableDataType {
+        let ty = match self.kind {
+            InferredKind::Boolean => TableDataType::Boolean,
+            InferredKind::Int64 => TableDataType::Number(NumberDataType::Int64),
+            InferredKind::Float64 => TableDataType::Number(NumberDataType::Float64),
+            InferredKind::Unknown | InferredKind::String => TableDataType::String,
+        };
+        if self.nullable {
+            ty.wrap_nullable()
+        } else {
+            ty
+        }
+    }
 }
 
 pub struct AligningStateWholeFile {
@@ -97,7 +356,7 @@ pub struct AligningStateWholeFile {
 }
 
 impl AligningStateWholeFile {
-    fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
+    pub fn try_create(_ctx: &Arc<InputContext>, split_info: &Arc<SplitInfo>) -> Result<Self> {
         Ok(Self {
             split_info: split_info.clone(),
             bufs: vec![],
@@ -158,121 +417,56 @@ impl InputFormatTextBase for InputFormatXML {
             .as_any()
             .downcast_ref::<SeparatedTextDecoder>()
             .expect("must success");
-        let columns = &mut builder.mutable_columns;
-
         let path = &batch.split_info.file.path;
         let xml_params = XmlFileFormatParams::downcast_unchecked(&builder.ctx.file_format_params);
-        let row_tag = xml_params.row_tag.as_bytes().to_vec();
-        let field_tag = vec![b'f', b'i', b'e', b'l', b'd'];
-
-        let mut buf = Cursor::new(&batch.data);
-        let num_fields = builder.ctx.schema.fields().len();
-        let reader = ParserConfig::new().create_reader(&mut buf);
+        let is_select = builder.ctx.file_format_options_ext.is_select;
 
-        let mut rows_to_skip = 0;
-
-        let mut cols = HashMap::with_capacity(num_fields);
+        let names = if is_select {
+            Self::column_names(&batch.data, &xml_params.row_tag, path)?
+        } else {
+            vec![]
+        };
 
-        let mut key = None;
-        let mut has_start_row = false;
         // for deal with on_error mode
         let mut num_rows = 0usize;
-
-        for e in reader {
-            if rows_to_skip != 0 {
-                match e {
-                    Ok(XmlEvent::EndElement { name }) => {
-                        // Arrived one row end and skip.
-                        if name.local_name.into_bytes().eq(&row_tag) {
-                            rows_to_skip -= 1;
-                        }
-                    }
-                    Err(e) => {
-                        return Err(xml_error(e.msg(), path, num_rows));
-                    }
-                    _ => {}
-                }
+        Self::parse_rows(&batch.data, &xml_params.row_tag, path, |row| {
+            let columns = &mut builder.mutable_columns;
+            let res = if is_select {
+                Self::read_row_by_position(
+                    field_decoder,
+                    row,
+                    columns,
+                    &builder.ctx.schema,
+                    &builder.projection,
+                    &names,
+                )
             } else {
-                match e {
-                    Ok(XmlEvent::StartElement {
-                        name, attributes, ..
-                    }) => {
-                        let name_byte = name.local_name.clone().into_bytes();
-                        match attributes.is_empty() {
-                            true => {
-                                // Column names as tags and column values as the content of these tags.
-                                if !name_byte.eq(&row_tag) && has_start_row {
-                                    key = Some(name.local_name);
-                                } else if name_byte.eq(&row_tag) {
-                                    has_start_row = true;
-                                }
-                            }
-                            false => {
-                                // Column name as attributes and column values as attribute values.
-                                if name_byte.eq(&row_tag) {
-                                    for attr in attributes {
-                                        let key = attr.name.local_name;
-                                        let value = attr.value;
-                                        cols.insert(key, value.into_bytes());
-                                    }
-                                } else if name_byte.eq(&field_tag) {
-                                    if attributes.len() > 1 {
-                                        return Err(xml_error(
-                                            &format!(
-                                                "invalid field tag, expect 1 attr, but got {}",
-                                                attributes.len()
-                                            ),
-                                            path,
-                                            num_rows,
-                                        ));
-                                    }
-                                    let attr = attributes.first().unwrap();
-                                    key = Some(attr.value.clone());
-                                }
-                            }
-                        }
-                    }
-                    Ok(XmlEvent::EndElement { name }) => {
-                        if name.local_name.into_bytes().eq(&row_tag) {
-                            if let Err(e) = Self::read_row(
-                                field_decoder,
-                                &mut cols,
-                                columns,
-                                &builder.ctx.schema,
-                                builder.ident_case_sensitive,
-                            ) {
-                                builder
-                                    .ctx
-                                    .on_error(
-                                        e,
-                                        Some((columns, builder.num_rows)),
-                                        &mut builder.file_status,
-                                        path,
-                                        num_rows + batch.start_row_in_split,
-                                    )
-                                    .map_err(|e| xml_error(&e.message(), path, num_rows))?;
-                            } else {
-                                builder.num_rows += 1;
-                                builder.file_status.num_rows_loaded += 1;
-                            }
-                            num_rows += 1;
-                            cols.clear();
-                            has_start_row = false;
-                        }
-                    }
-                    Ok(XmlEvent::Characters(v)) => {
-                        if let Some(key) = key.take() {
-                            cols.insert(key, v.into_bytes());
-                        }
-                    }
-                    Err(e) => {
-                        return Err(xml_error(e.msg(), path, num_rows));
-                    }
-                    _ => {}
-                }
+                Self::read_row(
+                    field_decoder,
+                    row,
+                    columns,
+                    &builder.ctx.schema,
+                    builder.ident_case_sensitive,
+                )
+            };
+            if let Err(e) = res {
+                builder
+                    .ctx
+                    .on_error(
+                        e,
+                        Some((columns, builder.num_rows)),
+                        &mut builder.file_status,
+                        path,
+                        num_rows + batch.start_row_in_split,
+                    )
+                    .map_err(|e| xml_error(&e.message(), path, num_rows))?;
+            } else {
+                builder.num_rows += 1;
+                builder.file_status.num_rows_loaded += 1;
             }
-        }
-        Ok(())
+            num_rows += 1;
+            Ok(true)
+        })
     }
 }
 
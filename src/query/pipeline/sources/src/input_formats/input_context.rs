@@ -45,6 +45,7 @@ use databend_common_storage::FileParseError;
 use databend_common_storage::FileStatus;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
 use crate::input_formats::impls::InputFormatParquet;
@@ -157,6 +158,7 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro::create())),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
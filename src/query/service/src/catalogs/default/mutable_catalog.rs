@@ -31,6 +31,8 @@ use databend_common_meta_app::schema::CreateIndexReq;
 use databend_common_meta_app::schema::CreateLockRevReply;
 use databend_common_meta_app::schema::CreateLockRevReq;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreatePipeReply;
+use databend_common_meta_app::schema::CreatePipeReq;
 use databend_common_meta_app::schema::CreateTableIndexReply;
 use databend_common_meta_app::schema::CreateTableIndexReq;
 use databend_common_meta_app::schema::CreateTableReply;
@@ -47,6 +49,8 @@ use databend_common_meta_app::schema::DropDatabaseReply;
 use databend_common_meta_app::schema::DropDatabaseReq;
 use databend_common_meta_app::schema::DropIndexReply;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::DropPipeReply;
+use databend_common_meta_app::schema::DropPipeReq;
 use databend_common_meta_app::schema::DropTableByIdReq;
 use databend_common_meta_app::schema::DropTableIndexReply;
 use databend_common_meta_app::schema::DropTableIndexReq;
@@ -60,6 +64,8 @@ use databend_common_meta_app::schema::GcDroppedTableResp;
 use databend_common_meta_app::schema::GetDatabaseReq;
 use databend_common_meta_app::schema::GetIndexReply;
 use databend_common_meta_app::schema::GetIndexReq;
+use databend_common_meta_app::schema::GetPipeReply;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_app::schema::GetTableCopiedFileReply;
 use databend_common_meta_app::schema::GetTableCopiedFileReq;
 use databend_common_meta_app::schema::IndexMeta;
@@ -69,15 +75,19 @@ use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::schema::ListLockRevReq;
 use databend_common_meta_app::schema::ListLocksReq;
+use databend_common_meta_app::schema::ListPipesReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_app::schema::LockInfo;
 use databend_common_meta_app::schema::LockMeta;
+use databend_common_meta_app::schema::PipeMeta;
 use databend_common_meta_app::schema::RenameDatabaseReply;
 use databend_common_meta_app::schema::RenameDatabaseReq;
 use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -90,6 +100,8 @@ use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
 use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdatePipeReply;
+use databend_common_meta_app::schema::UpdatePipeReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -336,6 +348,31 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.list_virtual_columns(req).await?)
     }
 
+    #[async_backtrace::framed]
+    async fn create_pipe(&self, req: CreatePipeReq) -> Result<CreatePipeReply> {
+        Ok(self.ctx.meta.create_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn drop_pipe(&self, req: DropPipeReq) -> Result<DropPipeReply> {
+        Ok(self.ctx.meta.drop_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn get_pipe(&self, req: GetPipeReq) -> Result<GetPipeReply> {
+        Ok(self.ctx.meta.get_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn update_pipe(&self, req: UpdatePipeReq) -> Result<UpdatePipeReply> {
+        Ok(self.ctx.meta.update_pipe(req).await?)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_pipes(&self, req: ListPipesReq) -> Result<Vec<PipeMeta>> {
+        Ok(self.ctx.meta.list_pipes(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn undrop_database(&self, req: UndropDatabaseReq) -> Result<UndropDatabaseReply> {
         let res = self.ctx.meta.undrop_database(req).await?;
@@ -524,6 +561,13 @@ impl Catalog for MutableCatalog {
         Ok(self.ctx.meta.set_table_column_mask_policy(req).await?)
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        Ok(self.ctx.meta.set_table_row_access_policy(req).await?)
+    }
+
     #[async_backtrace::framed]
     async fn get_table_copied_file_info(
         &self,
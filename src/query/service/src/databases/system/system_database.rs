@@ -44,6 +44,7 @@ use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
+use databend_common_storages_system::PipesTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::ProcessorProfileTable;
 use databend_common_storages_system::QueriesQueueTable;
@@ -63,6 +64,7 @@ use databend_common_storages_system::TracingTable;
 use databend_common_storages_system::UserFunctionsTable;
 use databend_common_storages_system::UsersTable;
 use databend_common_storages_system::VirtualColumnsTable;
+use databend_common_storages_system::WorkloadGroupsTable;
 
 use crate::catalogs::InMemoryMetas;
 use crate::databases::Database;
@@ -136,6 +138,8 @@ impl SystemDatabase {
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
+            PipesTable::create(sys_db_meta.next_table_id()),
+            WorkloadGroupsTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
@@ -43,6 +43,7 @@ use crate::locks::LockManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::sessions::WorkloadGroupManager;
 
 pub struct GlobalServices;
 
@@ -97,6 +98,7 @@ impl GlobalServices {
         }
 
         QueriesQueueManager::init(config.query.max_running_queries as usize)?;
+        WorkloadGroupManager::init()?;
         HttpQueryManager::init(config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
@@ -73,10 +73,12 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Database.
                 | Plan::CreateDatabase(_)
+                | Plan::CloneDatabase(_)
                 | Plan::DropDatabase(_)
 
                 // Table.
                 | Plan::CreateTable(_)
+                | Plan::CloneTable(_)
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
@@ -91,6 +93,7 @@ impl AccessChecker for ManagementModeAccess {
                 // Roles.
                 | Plan::ShowRoles(_)
                 | Plan::CreateRole(_)
+                | Plan::AlterRole(_)
                 | Plan::DropRole(_)
 
                 // Privilege.
@@ -109,6 +112,10 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreatePasswordPolicy(_)
                 | Plan::AlterPasswordPolicy(_)
                 | Plan::DropPasswordPolicy(_)
+                // Workload group.
+                | Plan::CreateWorkloadGroup(_)
+                | Plan::AlterWorkloadGroup(_)
+                | Plan::DropWorkloadGroup(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -27,6 +27,7 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserGrantSet;
 use databend_common_meta_app::principal::UserPrivilegeSet;
 use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::GetPipeReq;
 use databend_common_meta_types::NonEmptyString;
 use databend_common_sql::optimizer::get_udf_names;
 use databend_common_sql::plans::InsertInputSource;
@@ -454,6 +455,32 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    // A pipe can be altered, dropped and described by the roles owning it, other roles
+    // need the global SUPER privilege.
+    async fn validate_pipe_access(&self, tenant: &str, name: &str) -> Result<()> {
+        let catalog = self.ctx.get_default_catalog()?;
+        let pipe = match catalog
+            .get_pipe(GetPipeReq::new(tenant.to_string(), name))
+            .await
+        {
+            Ok(reply) => reply.meta,
+            // let the interpreter report the unknown pipe (or skip it with IF EXISTS)
+            Err(e) if e.code() == ErrorCode::UNKNOWN_PIPE => return Ok(()),
+            Err(e) => return Err(e.add_message("error on validating access")),
+        };
+
+        if let Some(owner) = &pipe.owner {
+            let session = self.ctx.get_current_session();
+            let roles = session.get_all_effective_roles().await?;
+            if roles.iter().any(|role| &role.name == owner) {
+                return Ok(());
+            }
+        }
+
+        self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
+            .await
+    }
+
     async fn convert_to_id(
         &self,
         tenant: &str,
@@ -595,6 +622,9 @@ impl AccessChecker for PrivilegeAccess {
                             DataSourceInfo::ParquetSource(stage_info) => {
                                 self.validate_stage_access(&stage_info.stage_info, UserPrivilegeType::Read).await?;
                             }
+                            DataSourceInfo::OrcSource(stage_info) => {
+                                self.validate_stage_access(&stage_info.stage_info, UserPrivilegeType::Read).await?;
+                            }
                             DataSourceInfo::TableSource(_) | DataSourceInfo::ResultScanSource(_) => {}
                         }
                     }
@@ -621,6 +651,11 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::CreateDatabase])
                     .await?;
             }
+            Plan::CloneDatabase(plan) => {
+                self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::CreateDatabase])
+                    .await?;
+                self.validate_db_access(&plan.source_catalog, &plan.source_database, vec![UserPrivilegeType::Select], false).await?;
+            }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop], plan.if_exists).await?;
             }
@@ -683,6 +718,10 @@ impl AccessChecker for PrivilegeAccess {
                     self.check(ctx, query).await?;
                 }
             }
+            Plan::CloneTable(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?;
+                self.validate_table_access(&plan.source_catalog, &plan.source_database, &plan.source_table, vec![UserPrivilegeType::Select], false).await?;
+            }
             Plan::DropTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Drop], plan.if_exists).await?;
             }
@@ -700,6 +739,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetOptions(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
+            Plan::AlterTableTag(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
             Plan::AddTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
@@ -712,6 +754,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
+            Plan::AlterTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
+            }
             Plan::AlterTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Alter], false).await?
             }
@@ -767,7 +812,13 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Replace(plan) => {
                 //plan.delete_when is Expr no need to check privileges.
-                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete], false).await?;
+                // INSERT into a table with an enforced key never deletes the existing rows.
+                let target_table_privileges = if plan.error_on_conflict {
+                    vec![UserPrivilegeType::Insert]
+                } else {
+                    vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete]
+                };
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, target_table_privileges, false).await?;
                 match &plan.source {
                     InsertInputSource::SelectPlan(plan) => {
                         self.check(ctx, plan).await?;
@@ -950,6 +1001,19 @@ impl AccessChecker for PrivilegeAccess {
                     self.check(ctx, query).await?;
                 }
             }
+            Plan::CreatePipe(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create], false).await?;
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, vec![UserPrivilegeType::Insert], false).await?;
+            }
+            Plan::AlterPipe(plan) => {
+                self.validate_pipe_access(&plan.tenant, &plan.name).await?;
+            }
+            Plan::DropPipe(plan) => {
+                self.validate_pipe_access(&plan.tenant, &plan.name).await?;
+            }
+            Plan::DescPipe(plan) => {
+                self.validate_pipe_access(&plan.tenant, &plan.name).await?;
+            }
             Plan::CopyIntoLocation(plan) => {
                 self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
                 let from = plan.from.clone();
@@ -982,6 +1046,10 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
+            | Plan::AlterRole(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -1002,7 +1070,8 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
-            Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_) => {
+            Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_)
+            | Plan::CreateRowAccessPolicy(_) | Plan::DropRowAccessPolicy(_) => {
                 self.validate_access(
                     &GrantObject::Global,
                     vec![UserPrivilegeType::CreateDataMask],
@@ -1026,9 +1095,12 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
             Plan::Begin => {}
             Plan::Commit => {}
             Plan::Abort => {}
+            // The statements in the script are checked when they are executed.
+            Plan::ExecuteImmediate(_) => {}
         }
 
         Ok(())
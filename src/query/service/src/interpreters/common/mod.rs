@@ -26,6 +26,7 @@ pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
 pub use stream::build_update_stream_meta_seq;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_constraints;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
 use databend_common_sql::parse_computed_expr;
+use databend_storages_common_table_meta::table::TableConstraints;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +58,33 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Check if the column is referenced by the constraints of the table,
+/// `schema` is the table schema after the column is altered.
+pub fn check_referenced_constraints(
+    ctx: Arc<dyn TableContext>,
+    options: &BTreeMap<String, String>,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    let constraints = TableConstraints::from_options(options)?;
+    if let Some(name) = constraints.unique_key_of_column(column) {
+        return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+            "column `{}` is referenced by constraint `{}`",
+            column, name
+        )));
+    }
+    for (name, expr) in constraints.checks.iter() {
+        let valid = match parse_computed_expr(ctx.clone(), schema.clone(), expr) {
+            Ok(expr) => expr.data_type().remove_nullable() == DataType::Boolean,
+            Err(_) => false,
+        };
+        if !valid {
+            return Err(ErrorCode::ColumnReferencedByConstraint(format!(
+                "column `{}` is referenced by constraint `{}`",
+                column, name
+            )));
+        }
+    }
+    Ok(())
+}
@@ -23,6 +23,7 @@ use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::CatalogMeta;
 use databend_common_meta_app::schema::CatalogNameIdent;
 use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_sql::plans::CreateCatalogPlan;
 use databend_common_storages_fuse::TableContext;
 use log::debug;
@@ -59,11 +60,21 @@ impl Interpreter for CreateCatalogInterpreter {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "create_catalog_execute");
 
         if let CatalogOption::Iceberg(opt) = &self.plan.meta.catalog_option {
-            if !opt.storage_params.is_secure() && !GlobalConfig::instance().storage.allow_insecure {
+            let allow_insecure = GlobalConfig::instance().storage.allow_insecure;
+            // Rest catalogs are allowed to be created without storage connection.
+            let has_storage = !matches!(opt.storage_params.as_ref(), StorageParams::None);
+            if has_storage && !opt.storage_params.is_secure() && !allow_insecure {
                 return Err(ErrorCode::CatalogNotSupported(
                     "Accessing insecure storage in not allowed by configuration",
                 ));
             }
+            if let Some(rest) = &opt.rest {
+                if !rest.uri.starts_with("https://") && !allow_insecure {
+                    return Err(ErrorCode::CatalogNotSupported(
+                        "Accessing insecure iceberg rest catalog is not allowed by configuration",
+                    ));
+                }
+            }
         }
 
         let catalog_manager = CatalogManager::instance();
@@ -68,10 +68,19 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                     op.storage_params.unwrap_or(Box::new(StorageParams::None))
                 ),
             ),
-            CatalogOption::Iceberg(op) => (
-                String::from("iceberg"),
-                format!("STORAGE PARAMS\n{}", op.storage_params),
-            ),
+            CatalogOption::Iceberg(op) => match op.rest {
+                Some(rest) => (
+                    String::from("iceberg"),
+                    format!(
+                        "REST URI\n{}\nWAREHOUSE\n{}\nSTORAGE PARAMS\n{}",
+                        rest.uri, rest.warehouse, op.storage_params
+                    ),
+                ),
+                None => (
+                    String::from("iceberg"),
+                    format!("STORAGE PARAMS\n{}", op.storage_params),
+                ),
+            },
         };
 
         let block = DataBlock::new(
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::CloneDatabasePlan;
+use log::info;
+
+use super::interpreter_table_clone::clone_table;
+use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CloneDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneDatabasePlan,
+}
+
+impl CloneDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneDatabasePlan) -> Result<Self> {
+        Ok(CloneDatabaseInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "CloneDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let create = &self.plan.create;
+        let catalog = self.ctx.get_catalog(&create.catalog).await?;
+        if create.create_option == CreateOption::CreateIfNotExists
+            && catalog
+                .exists_database(tenant.as_str(), &create.database)
+                .await?
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let source_catalog = self.ctx.get_catalog(&self.plan.source_catalog).await?;
+        let tables = source_catalog
+            .list_tables(tenant.as_str(), &self.plan.source_database)
+            .await?;
+
+        CreateDatabaseInterpreter::try_create(self.ctx.clone(), create.clone())?
+            .execute2()
+            .await?;
+
+        // Only the FUSE tables are cloned, views and the tables of other engines
+        // may reference data that is not owned by the database.
+        for table in tables {
+            let table_info = table.get_table_info();
+            if table.engine() != "FUSE" {
+                info!(
+                    "clone database {}, ignore table {} of engine {}",
+                    create.database,
+                    table_info.desc,
+                    table.engine()
+                );
+                continue;
+            }
+
+            match clone_table(
+                &self.ctx,
+                table.clone(),
+                self.plan.navigation.as_ref(),
+                CreateOption::Create,
+                &create.catalog,
+                &create.database,
+                &table_info.name,
+            )
+            .await
+            {
+                // The table has no data at the given point.
+                Err(e) if e.code() == ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND => {
+                    info!(
+                        "clone database {}, ignore table {}: {}",
+                        create.database,
+                        table_info.desc,
+                        e.message()
+                    );
+                }
+                Err(e) => return Err(e),
+                Ok(_) => {}
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -56,6 +56,7 @@ use databend_common_sql::Visibility;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::operations::TruncateMode;
 use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_iceberg::IcebergTable;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use futures_util::TryStreamExt;
 use log::debug;
@@ -85,6 +86,39 @@ impl DeleteInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: DeletePlan) -> Result<Self> {
         Ok(DeleteInterpreter { ctx, plan })
     }
+
+    /// Delete by rewriting the data files of the iceberg table (copy-on-write).
+    #[async_backtrace::framed]
+    async fn delete_iceberg_table(&self, table: &IcebergTable) -> Result<PipelineBuildResult> {
+        if !self.plan.subquery_desc.is_empty() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Delete with subquery is not supported for the iceberg table '{}'",
+                table.name(),
+            )));
+        }
+
+        let filters = match &self.plan.selection {
+            Some(scalar) => {
+                let filters = create_push_down_filters(scalar)?;
+                let expr = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+                if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+                    return Err(ErrorCode::Unimplemented(
+                        "Delete must have deterministic predicate",
+                    ));
+                }
+                Some(filters)
+            }
+            None => None,
+        };
+
+        let deleted_rows = table.delete(self.ctx.clone(), filters).await?;
+        let progress_values = ProgressValues {
+            rows: deleted_rows as usize,
+            bytes: 0,
+        };
+        self.ctx.get_write_progress().incr(&progress_values);
+        Ok(PipelineBuildResult::create())
+    }
 }
 
 #[async_trait::async_trait]
@@ -119,6 +153,13 @@ impl Interpreter for DeleteInterpreter {
         let table_lock = LockManager::create_table_lock(tbl.get_table_info().clone())?;
         let lock_guard = table_lock.try_lock(self.ctx.clone()).await?;
 
+        // The iceberg table is rewritten in place, the lock is held until the commit is done.
+        if let Some(iceberg_table) = tbl.as_any().downcast_ref::<IcebergTable>() {
+            let res = self.delete_iceberg_table(iceberg_table).await;
+            drop(lock_guard);
+            return res;
+        }
+
         // refresh table.
         let tbl = tbl.refresh(self.ctx.as_ref()).await?;
 
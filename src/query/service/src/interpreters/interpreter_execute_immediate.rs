@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::ExecuteImmediatePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::script::compile;
+use crate::script::ScriptExecutor;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct ExecuteImmediateInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ExecuteImmediatePlan,
+}
+
+impl ExecuteImmediateInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ExecuteImmediatePlan) -> Result<Self> {
+        Ok(ExecuteImmediateInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ExecuteImmediateInterpreter {
+    fn name(&self) -> &str {
+        "ExecuteImmediateInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let code = compile(&self.plan.script)?;
+        let mut executor = ScriptExecutor::try_create(self.ctx.clone(), code)?;
+        let result = match executor.run().await? {
+            Some(value) => value.to_text(executor.tz())?,
+            None => None,
+        };
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_opt_data(vec![result]),
+        ])])
+    }
+}
@@ -40,6 +40,7 @@ use crate::interpreters::interpreter_connection_drop::DropConnectionInterpreter;
 use crate::interpreters::interpreter_connection_show::ShowConnectionsInterpreter;
 use crate::interpreters::interpreter_copy_into_location::CopyIntoLocationInterpreter;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
+use crate::interpreters::interpreter_execute_immediate::ExecuteImmediateInterpreter;
 use crate::interpreters::interpreter_file_format_create::CreateFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_drop::DropFileFormatInterpreter;
 use crate::interpreters::interpreter_file_format_show::ShowFileFormatsInterpreter;
@@ -47,6 +48,10 @@ use crate::interpreters::interpreter_notification_alter::AlterNotificationInterp
 use crate::interpreters::interpreter_notification_create::CreateNotificationInterpreter;
 use crate::interpreters::interpreter_notification_desc::DescNotificationInterpreter;
 use crate::interpreters::interpreter_notification_drop::DropNotificationInterpreter;
+use crate::interpreters::interpreter_pipe_alter::AlterPipeInterpreter;
+use crate::interpreters::interpreter_pipe_create::CreatePipeInterpreter;
+use crate::interpreters::interpreter_pipe_desc::DescPipeInterpreter;
+use crate::interpreters::interpreter_pipe_drop::DropPipeInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
@@ -164,6 +169,9 @@ impl InterpreterFactory {
             Plan::CreateDatabase(create_database) => Ok(Arc::new(
                 CreateDatabaseInterpreter::try_create(ctx, *create_database.clone())?,
             )),
+            Plan::CloneDatabase(clone_database) => Ok(Arc::new(
+                CloneDatabaseInterpreter::try_create(ctx, *clone_database.clone())?,
+            )),
             Plan::DropDatabase(drop_database) => Ok(Arc::new(DropDatabaseInterpreter::try_create(
                 ctx,
                 *drop_database.clone(),
@@ -188,6 +196,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_table.clone(),
             )?)),
+            Plan::CloneTable(clone_table) => Ok(Arc::new(CloneTableInterpreter::try_create(
+                ctx,
+                *clone_table.clone(),
+            )?)),
             Plan::DropTable(drop_table) => Ok(Arc::new(DropTableInterpreter::try_create(
                 ctx,
                 *drop_table.clone(),
@@ -204,6 +216,9 @@ impl InterpreterFactory {
                 ctx,
                 *set_options.clone(),
             )?)),
+            Plan::AlterTableTag(alter_table_tag) => Ok(Arc::new(
+                AlterTableTagInterpreter::try_create(ctx, *alter_table_tag.clone())?,
+            )),
             Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
                 RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
             )),
@@ -350,6 +365,10 @@ impl InterpreterFactory {
                 ctx,
                 *create_role.clone(),
             )?)),
+            Plan::AlterRole(alter_role) => Ok(Arc::new(AlterRoleInterpreter::try_create(
+                ctx,
+                *alter_role.clone(),
+            )?)),
             Plan::DropRole(drop_role) => Ok(Arc::new(DropRoleInterpreter::try_create(
                 ctx,
                 *drop_role.clone(),
@@ -492,6 +511,18 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterTableRowAccessPolicy(p) => Ok(Arc::new(
+                AlterTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
@@ -525,6 +556,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -574,6 +616,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ExecuteImmediate(p) => Ok(Arc::new(ExecuteImmediateInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
         }
     }
 }
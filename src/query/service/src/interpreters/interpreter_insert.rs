@@ -36,6 +36,7 @@ use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::HookOperator;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
+use crate::locks::LockManager;
 use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
@@ -97,6 +98,15 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        // The tables committed by the files in their location, e.g. iceberg and delta,
+        // serialize the commits by the table lock.
+        let lock_guard = if LockManager::is_external_table(table.get_table_info()) {
+            let table_lock = LockManager::create_table_lock(table.get_table_info().clone())?;
+            table_lock.try_lock(self.ctx.clone()).await?
+        } else {
+            None
+        };
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -272,6 +282,7 @@ impl Interpreter for InsertInterpreter {
                     hook_operator.execute(&mut build_res.main_pipeline).await;
                 }
 
+                build_res.main_pipeline.add_lock_guard(lock_guard);
                 return Ok(build_res);
             }
         };
@@ -307,6 +318,7 @@ impl Interpreter for InsertInterpreter {
             hook_operator.execute(&mut build_res.main_pipeline).await;
         }
 
+        build_res.main_pipeline.add_lock_guard(lock_guard);
         Ok(build_res)
     }
 }
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::PipeNameIdent;
+use databend_common_meta_app::schema::UpdatePipeReq;
+use databend_common_sql::plans::AlterPipePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+}
+
+impl AlterPipeInterpreter {
+    fn build_request(&self) -> UpdatePipeReq {
+        let plan = self.plan.clone();
+        UpdatePipeReq {
+            if_exists: plan.if_exists,
+            name_ident: PipeNameIdent::new(plan.tenant, plan.name),
+            execution_paused: plan.execution_paused,
+            comment: plan.comment,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_default_catalog()?;
+        catalog.update_pipe(self.build_request()).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreatePipeReq;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::PipeNameIdent;
+use databend_common_sql::plans::CreatePipePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreatePipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreatePipePlan,
+}
+
+impl CreatePipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePipePlan) -> Result<Self> {
+        Ok(CreatePipeInterpreter { ctx, plan })
+    }
+}
+
+impl CreatePipeInterpreter {
+    fn build_request(&self) -> Result<CreatePipeReq> {
+        let plan = self.plan.clone();
+        let create_option = if plan.if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+        Ok(CreatePipeReq {
+            create_option,
+            name_ident: PipeNameIdent::new(plan.tenant, plan.name.clone()),
+            meta: PipeMeta {
+                name: plan.name,
+                copy_stmt: plan.copy_stmt,
+                auto_ingest: plan.auto_ingest,
+                execution_paused: false,
+                comment: plan.comment,
+                owner: self.ctx.get_current_role().map(|role| role.name),
+                creator: Some(self.ctx.get_current_user()?.identity()),
+                created_on: Utc::now(),
+                updated_on: None,
+            },
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePipeInterpreter {
+    fn name(&self) -> &str {
+        "CreatePipeInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_default_catalog()?;
+        catalog.create_pipe(self.build_request()?).await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
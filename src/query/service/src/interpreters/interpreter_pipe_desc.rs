@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::GetPipeReq;
+use databend_common_sql::plans::DescPipePlan;
+use databend_common_storages_system::parse_pipes_to_datablock;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescPipePlan,
+}
+
+impl DescPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescPipePlan) -> Result<Self> {
+        Ok(DescPipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescPipeInterpreter {
+    fn name(&self) -> &str {
+        "DescPipeInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let catalog = self.ctx.get_default_catalog()?;
+        let reply = catalog
+            .get_pipe(GetPipeReq::new(plan.tenant, plan.name))
+            .await?;
+        let result = parse_pipes_to_datablock(vec![reply.meta])?;
+        PipelineBuildResult::from_blocks(vec![result])
+    }
+}
@@ -167,8 +167,10 @@ impl ReplaceInterpreter {
 
         let is_multi_node = !self.ctx.get_cluster().is_empty();
         let is_value_source = matches!(self.plan.source, InsertInputSource::Values(_));
+        // the duplicated keys among the input rows are only detected in a single node
         let is_distributed = is_multi_node
             && !is_value_source
+            && !plan.error_on_conflict
             && self.ctx.get_settings().get_enable_distributed_replace()?;
         let table_is_empty = base_snapshot.segments.is_empty();
         let table_level_range_index = base_snapshot.summary.col_stats.clone();
@@ -293,6 +295,7 @@ impl ReplaceInterpreter {
                 table_level_range_index,
                 need_insert: true,
                 delete_when,
+                error_on_conflict: plan.error_on_conflict,
                 plan_id: u32::MAX,
             },
         )));
@@ -311,6 +314,7 @@ impl ReplaceInterpreter {
                 .collect(),
             block_slots: None,
             need_insert: true,
+            error_on_conflict: plan.error_on_conflict,
             plan_id: u32::MAX,
         })));
         if is_distributed {
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::DescRowAccessPolicyPlan;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DescRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescRowAccessPolicyPlan,
+}
+
+impl DescRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DescRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_row_access_policy_handler();
+        let policy = handler
+            .get_row_access_policy(
+                meta_api,
+                self.ctx.get_tenant().to_string(),
+                self.plan.name.clone(),
+            )
+            .await;
+
+        let policy = match policy {
+            Ok(policy) => policy,
+            Err(err) => {
+                warn!("DescRowAccessPolicyInterpreter err: {}", err);
+                if err.code() != ErrorCode::UnknownRowAccessPolicy("").code() {
+                    return Err(err);
+                }
+                return Ok(PipelineBuildResult::create());
+            }
+        };
+
+        let signature = format!(
+            "({})",
+            policy
+                .args
+                .iter()
+                .map(|(arg_name, arg_type)| format!("{} {}", arg_name, arg_type))
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        let blocks = vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![self.plan.name.clone()]),
+            StringType::from_data(vec![policy.create_on.to_string()]),
+            StringType::from_data(vec![signature]),
+            StringType::from_data(vec![policy.body.clone()]),
+            StringType::from_data(vec![policy.comment.clone().unwrap_or_default()]),
+        ])];
+        PipelineBuildResult::from_blocks(blocks)
+    }
+}
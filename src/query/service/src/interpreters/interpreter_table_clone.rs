@@ -0,0 +1,214 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Engine;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_sql::plans::CloneTablePlan;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use log::info;
+use log::warn;
+
+use super::interpreter_table_create::is_valid_create_opt;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CloneTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CloneTablePlan,
+}
+
+impl CloneTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CloneTablePlan) -> Result<Self> {
+        Ok(CloneTableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CloneTableInterpreter {
+    fn name(&self) -> &str {
+        "CloneTableInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let source = self
+            .ctx
+            .get_table(
+                &self.plan.source_catalog,
+                &self.plan.source_database,
+                &self.plan.source_table,
+            )
+            .await?;
+
+        clone_table(
+            &self.ctx,
+            source,
+            self.plan.navigation.as_ref(),
+            self.plan.create_option,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+/// Creates table `catalog.database.table` with the definition of `source`, and shares the
+/// segments of the snapshot of `source` at `navigation` (or the current one) with it.
+#[async_backtrace::framed]
+pub(crate) async fn clone_table(
+    ctx: &Arc<QueryContext>,
+    source: Arc<dyn Table>,
+    navigation: Option<&NavigationPoint>,
+    create_option: CreateOption,
+    catalog_name: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let source = FuseTable::try_from_table(source.as_ref()).map_err(|_| {
+        ErrorCode::TableEngineNotSupported(format!(
+            "Clone table is only supported for FUSE engine, but table {} is of {} engine",
+            source.get_table_info().desc,
+            source.engine()
+        ))
+    })?;
+    let navigated;
+    let source = match navigation {
+        Some(point) => {
+            navigated = source.navigate_to(point).await?;
+            navigated.as_ref()
+        }
+        None => source,
+    };
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    if create_option == CreateOption::CreateIfNotExists
+        && catalog
+            .exists_table(tenant.as_str(), database, table)
+            .await?
+    {
+        return Ok(());
+    }
+
+    // Only the options that the user could specify are inherited, the change tracking
+    // of the source table does not make sense for the cloned one.
+    let db = catalog.get_database(tenant.as_str(), database).await?;
+    let mut options = source
+        .get_table_info()
+        .options()
+        .iter()
+        .filter(|(key, _)| is_valid_create_opt(key) && key.as_str() != OPT_KEY_CHANGE_TRACKING)
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<BTreeMap<_, _>>();
+    options.insert(
+        OPT_KEY_DATABASE_ID.to_owned(),
+        db.get_db_info().ident.db_id.to_string(),
+    );
+    let comment = &source.get_table_info().meta.comment;
+    if !comment.is_empty() {
+        options.insert(OPT_KEY_COMMENT.to_owned(), comment.clone());
+    }
+
+    let create_plan = CreateTablePlan {
+        create_option,
+        tenant: tenant.to_string(),
+        catalog: catalog_name.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+        schema: source.schema(),
+        engine: Engine::Fuse,
+        engine_options: Default::default(),
+        storage_params: None,
+        read_only_attach: false,
+        part_prefix: "".to_string(),
+        options,
+        field_comments: source.field_comments().clone(),
+        cluster_key: source.get_table_info().meta.default_cluster_key.clone(),
+        as_select: None,
+    };
+    CreateTableInterpreter::try_create(ctx.clone(), create_plan)?
+        .execute2()
+        .await?;
+
+    let new_table = catalog.get_table(tenant.as_str(), database, table).await?;
+    info!(
+        "clone table {} from {}",
+        new_table.get_table_info().desc,
+        source.get_table_info().desc
+    );
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let cloned = FuseTable::try_from_table(new_table.as_ref())?
+        .do_clone_from(&table_ctx, source)
+        .await;
+    if let Err(cause) = cloned {
+        // Do not leave an empty table behind if the clone failed.
+        let table_info = new_table.get_table_info();
+        let db_id = db.get_db_info().ident.db_id;
+        let dropped = catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: true,
+                tenant: tenant.to_string(),
+                table_name: table.to_string(),
+                tb_id: table_info.ident.table_id,
+                db_id,
+            })
+            .await;
+        match dropped {
+            Ok(_) => {
+                let owner_object = OwnershipObject::Table {
+                    catalog_name: catalog_name.to_string(),
+                    db_id,
+                    table_id: table_info.ident.table_id,
+                };
+                UserApiProvider::instance()
+                    .role_api(&tenant)
+                    .revoke_ownership(&owner_object)
+                    .await?;
+                RoleCacheManager::instance().invalidate_cache(&tenant);
+            }
+            Err(e) => warn!(
+                "failed to drop table {} after the clone failed. cause {:?}",
+                table_info.desc, e
+            ),
+        }
+        return Err(cause);
+    }
+    Ok(())
+}
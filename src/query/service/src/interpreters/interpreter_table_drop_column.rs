@@ -29,6 +29,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -97,6 +98,15 @@ impl Interpreter for DropTableColumnInterpreter {
                 self.plan.column.as_str(),
             )?;
         }
+        // Check if this column is referenced by constraints.
+        let mut constraint_schema: DataSchema = table_info.schema().into();
+        constraint_schema.drop_column(self.plan.column.as_str())?;
+        check_referenced_constraints(
+            self.ctx.clone(),
+            table_info.options(),
+            Arc::new(constraint_schema),
+            self.plan.column.as_str(),
+        )?;
 
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
@@ -18,9 +18,11 @@ use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::CreateTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::CreateTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
+use uuid::Uuid;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -49,11 +51,6 @@ impl Interpreter for CreateTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let index_name = self.plan.index_name.clone();
         let column_ids = self.plan.column_ids.clone();
         let table_id = self.plan.table_id;
@@ -64,12 +61,29 @@ impl Interpreter for CreateTableIndexInterpreter {
             table_id,
             name: index_name,
             column_ids,
+            index_type: self.plan.index_type,
+            options: self.plan.index_options.clone(),
+            // The index files are located by the version, a new version
+            // makes the files of the replaced index unreachable.
+            version: Uuid::new_v4().simple().to_string(),
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler
-            .do_create_table_index(catalog, create_index_req)
-            .await?;
+        match self.plan.index_type {
+            TableIndexType::Inverted => {
+                let license_manager = get_license_manager();
+                license_manager
+                    .manager
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+                let handler = get_inverted_index_handler();
+                let _ = handler
+                    .do_create_table_index(catalog, create_index_req)
+                    .await?;
+            }
+            TableIndexType::Vector => {
+                let _ = catalog.create_table_index(create_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::DropTableIndexReq;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_sql::plans::DropTableIndexPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_enterprise_inverted_index::get_inverted_index_handler;
@@ -49,11 +50,6 @@ impl Interpreter for DropTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let index_name = self.plan.index_name.clone();
         let table_id = self.plan.table_id;
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
@@ -64,8 +60,20 @@ impl Interpreter for DropTableIndexInterpreter {
             name: index_name,
         };
 
-        let handler = get_inverted_index_handler();
-        let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+        match self.plan.index_type {
+            TableIndexType::Inverted => {
+                let license_manager = get_license_manager();
+                license_manager
+                    .manager
+                    .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
+                let handler = get_inverted_index_handler();
+                let _ = handler.do_drop_table_index(catalog, drop_index_req).await?;
+            }
+            TableIndexType::Vector => {
+                let _ = catalog.drop_table_index(drop_index_req).await?;
+            }
+        }
 
         Ok(PipelineBuildResult::create())
     }
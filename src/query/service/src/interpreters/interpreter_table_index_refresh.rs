@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::RefreshTableIndexPlan;
@@ -56,11 +57,6 @@ impl Interpreter for RefreshTableIndexInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
-
         let index_name = self.plan.index_name.clone();
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
@@ -74,10 +70,30 @@ impl Interpreter for RefreshTableIndexInterpreter {
         let table_meta = &table.get_table_info().meta;
         let Some(index) = table_meta.indexes.get(&index_name) else {
             return Err(ErrorCode::RefreshIndexError(format!(
-                "Inverted index {} does not exist",
-                index_name
+                "{} index {} does not exist",
+                self.plan.index_type, index_name
             )));
         };
+        if index.index_type != self.plan.index_type {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Index {} is not a {} index",
+                index_name, self.plan.index_type
+            )));
+        }
+        if index.index_type == TableIndexType::Vector {
+            // The vector index files are located by the blocks, the snapshot is not changed.
+            let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+            fuse_table
+                .do_refresh_vector_index(self.ctx.clone(), index)
+                .await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::InvertedIndex)?;
+
         let mut index_fields = Vec::with_capacity(index.column_ids.len());
         for column_id in &index.column_ids {
             for field in &table_meta.schema.fields {
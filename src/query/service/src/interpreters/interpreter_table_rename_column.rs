@@ -29,6 +29,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_constraints;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -105,6 +106,16 @@ impl Interpreter for RenameTableColumnInterpreter {
                     self.plan.old_column.as_str(),
                 )?;
             }
+            // Check if old column is referenced by constraints.
+            let mut constraint_schema: DataSchema = table_info.schema().into();
+            let index = constraint_schema.index_of(self.plan.old_column.as_str())?;
+            constraint_schema.rename_field(index, self.plan.new_column.as_str());
+            check_referenced_constraints(
+                self.ctx.clone(),
+                table_info.options(),
+                Arc::new(constraint_schema),
+                self.plan.old_column.as_str(),
+            )?;
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterTableRowAccessPolicyAction;
+use databend_common_sql::plans::AlterTableRowAccessPolicyPlan;
+use databend_common_storages_share::save_share_table_info;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableRowAccessPolicyPlan,
+}
+
+impl AlterTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AlterTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+
+        let engine = table.engine();
+        if matches!(engine, VIEW_ENGINE | STREAM_ENGINE) {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support alter",
+                &self.plan.database, &self.plan.table, engine
+            )));
+        }
+        let table_info = table.get_table_info();
+        if table_info.db_type != DatabaseType::NormalDB {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} doesn't support alter",
+                &self.plan.database, &self.plan.table
+            )));
+        }
+
+        let prev_policy = table_info
+            .meta
+            .row_access_policy
+            .as_ref()
+            .map(|p| p.policy.clone());
+
+        let action = match &self.plan.action {
+            AlterTableRowAccessPolicyAction::Add { policy, columns } => {
+                if let Some(prev_policy) = prev_policy {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Table {}.{} already has row access policy '{}', drop it first",
+                        &self.plan.database, &self.plan.table, prev_policy
+                    )));
+                }
+
+                let meta_api = UserApiProvider::instance().get_meta_store_client();
+                let handler = get_row_access_policy_handler();
+                let policy_meta = handler
+                    .get_row_access_policy(meta_api, tenant.to_string(), policy.clone())
+                    .await?;
+                if policy_meta.args.len() != columns.len() {
+                    return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                        "Row access policy '{}' expects {} columns, but got {}",
+                        policy,
+                        policy_meta.args.len(),
+                        columns.len()
+                    )));
+                }
+
+                let schema = table.schema();
+                for column in columns {
+                    if schema.column_with_name(column).is_none() {
+                        return Err(ErrorCode::UnknownColumn(format!(
+                            "Cannot find column {}",
+                            column
+                        )));
+                    }
+                }
+                SetTableRowAccessPolicyAction::Set(policy.clone(), columns.clone())
+            }
+            AlterTableRowAccessPolicyAction::Drop { policy } => match prev_policy {
+                Some(prev_policy) if &prev_policy == policy => {
+                    SetTableRowAccessPolicyAction::Unset(prev_policy)
+                }
+                _ => {
+                    return Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                        "Row access policy '{}' is not attached to table {}.{}",
+                        policy, &self.plan.database, &self.plan.table
+                    )));
+                }
+            },
+        };
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: tenant.to_string(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action,
+        };
+        let res = catalog.set_table_row_access_policy(req).await?;
+
+        if let Some(share_table_info) = res.share_table_info {
+            save_share_table_info(
+                tenant.as_str(),
+                self.ctx.get_data_operator()?.operator(),
+                share_table_info,
+            )
+            .await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -29,6 +29,7 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
+use databend_storages_common_table_meta::table::TableConstraints;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
@@ -141,6 +142,26 @@ impl ShowCreateTableInterpreter {
 
                 columns.push(column);
             }
+            // Append constraints.
+            let constraints = TableConstraints::from_options(table.options())?;
+            for (name, expr) in constraints.checks.iter() {
+                columns.push(format!("  CONSTRAINT `{}` CHECK ({})", name, expr));
+            }
+            for (name, key) in constraints.unique_keys.iter() {
+                let key_columns = key
+                    .columns
+                    .iter()
+                    .map(|column| format!("`{}`", column))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                columns.push(format!(
+                    "  CONSTRAINT `{}` {} ({}){}",
+                    name,
+                    if key.primary { "PRIMARY KEY" } else { "UNIQUE" },
+                    key_columns,
+                    if key.enforced { " ENFORCED" } else { "" }
+                ));
+            }
             // Format is:
             //  (
             //      x,
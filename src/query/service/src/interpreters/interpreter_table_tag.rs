@@ -0,0 +1,119 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AlterTableTagAction;
+use databend_common_sql::plans::AlterTableTagPlan;
+use databend_common_storages_fuse::operations::encode_snapshot_tags;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTableTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTableTagPlan,
+}
+
+impl AlterTableTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTableTagPlan) -> Result<Self> {
+        Ok(AlterTableTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTableTagInterpreter {
+    fn name(&self) -> &str {
+        "AlterTableTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} engine is {} that doesn't support tag",
+                &self.plan.database,
+                &self.plan.table,
+                table.engine()
+            ))
+        })?;
+
+        let mut tags = fuse_table.snapshot_tags()?;
+        match &self.plan.action {
+            AlterTableTagAction::Create {
+                tag,
+                if_not_exists,
+                navigation,
+            } => {
+                if tags.contains_key(tag) {
+                    if *if_not_exists {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    return Err(ErrorCode::SnapshotTagAlreadyExists(format!(
+                        "Tag '{}' of table {}.{} already exists",
+                        tag, &self.plan.database, &self.plan.table
+                    )));
+                }
+                let snapshot_tag = fuse_table.new_snapshot_tag(navigation.as_ref()).await?;
+                tags.insert(tag.clone(), snapshot_tag);
+            }
+            AlterTableTagAction::Drop { tag, if_exists } => {
+                if tags.remove(tag).is_none() {
+                    if *if_exists {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    return Err(ErrorCode::UnknownSnapshotTag(format!(
+                        "Unknown tag '{}' of table {}.{}",
+                        tag, &self.plan.database, &self.plan.table
+                    )));
+                }
+            }
+        }
+
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_SNAPSHOT_TAGS.to_string(),
+                encode_snapshot_tags(&tags)?,
+            )]),
+        };
+        catalog
+            .upsert_table_option(tenant.as_str(), &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
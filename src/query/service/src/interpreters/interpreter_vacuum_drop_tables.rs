@@ -14,6 +14,7 @@
 
 use std::cmp::min;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use chrono::Duration;
@@ -31,7 +32,9 @@ use databend_common_meta_app::schema::GcDroppedTableReq;
 use databend_common_meta_app::schema::ListDroppedTableReq;
 use databend_common_meta_app::schema::TableInfoFilter;
 use databend_common_sql::plans::VacuumDropTablePlan;
+use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::get_vacuum_handler;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use log::info;
 
 use crate::interpreters::Interpreter;
@@ -160,10 +163,44 @@ impl Interpreter for VacuumDropTablesInterpreter {
             .filter(|tbl| !tbl.as_ref().is_read_only())
             .collect::<Vec<_>>();
 
+        // The data of a dropped table can not be purged while it is still referenced
+        // by the tables cloned from it, keep both the data and the meta of it.
+        let mut vacuum_tables = Vec::with_capacity(tables.len());
+        let mut retained_table_ids = HashSet::new();
+        let mut retained_db_ids = HashSet::new();
+        for table in tables {
+            if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+                let cloned_table_ids = fuse_table
+                    .referencing_cloned_table_ids(catalog.as_ref())
+                    .await?;
+                if !cloned_table_ids.is_empty() {
+                    info!(
+                        "vacuum drop table from db {:?}, skip table {} referenced by cloned tables {:?}",
+                        self.plan.database,
+                        table.get_table_info().desc,
+                        cloned_table_ids
+                    );
+                    retained_table_ids.insert(table.get_id());
+                    if let Some(db_id) = table.get_table_info().options().get(OPT_KEY_DATABASE_ID) {
+                        retained_db_ids.insert(db_id.parse::<u64>()?);
+                    }
+                    continue;
+                }
+            }
+            vacuum_tables.push(table);
+        }
+        let drop_ids = drop_ids
+            .into_iter()
+            .filter(|drop_id| match drop_id {
+                DroppedId::Db(db_id, _) => !retained_db_ids.contains(db_id),
+                DroppedId::Table(_, table_id, _) => !retained_table_ids.contains(table_id),
+            })
+            .collect::<Vec<_>>();
+
         let handler = get_vacuum_handler();
         let files_opt = handler
             .do_vacuum_drop_tables(
-                tables,
+                vacuum_tables,
                 if self.plan.option.dry_run.is_some() {
                     Some(DRY_RUN_LIMIT)
                 } else {
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_sql::plans::CreateWorkloadGroupPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateWorkloadGroupPlan,
+}
+
+impl CreateWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateWorkloadGroupPlan) -> Result<Self> {
+        Ok(CreateWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "CreateWorkloadGroupInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_workload_group_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let workload_group = WorkloadGroup {
+            name: plan.name,
+            cpu_quota: plan.options.cpu_quota.unwrap_or_default(),
+            memory_quota: plan.options.memory_quota.unwrap_or_default(),
+            max_concurrency: plan.options.max_concurrency.unwrap_or_default(),
+            queue_timeout: plan.options.queue_timeout.unwrap_or_default(),
+            comment: plan.options.comment.unwrap_or_default(),
+            create_on: Utc::now(),
+            update_on: None,
+        };
+        user_mgr
+            .add_workload_group(&tenant, workload_group, &plan.create_option)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
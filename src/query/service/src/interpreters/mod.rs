@@ -31,12 +31,14 @@ mod interpreter_copy_into_table;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_clone;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
+mod interpreter_execute_immediate;
 mod interpreter_explain;
 mod interpreter_factory;
 mod interpreter_file_format_create;
@@ -62,10 +64,15 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_desc;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
 mod interpreter_replace;
+mod interpreter_role_alter;
 mod interpreter_role_create;
 mod interpreter_role_drop;
 mod interpreter_role_grant;
@@ -73,6 +80,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
@@ -92,6 +102,7 @@ mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
+mod interpreter_table_clone;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
@@ -106,8 +117,10 @@ mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
+mod interpreter_table_row_access_policy;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
+mod interpreter_table_tag;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_vacuum;
@@ -142,7 +155,11 @@ mod interpreter_virtual_column_alter;
 mod interpreter_virtual_column_create;
 mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
+mod interpreter_workload_group_alter;
+mod interpreter_workload_group_create;
+mod interpreter_workload_group_drop;
 
+pub use access::Accessor;
 pub use access::ManagementModeAccess;
 pub use common::InterpreterQueryLog;
 pub use hook::HookOperator;
@@ -155,6 +172,7 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_clone::CloneDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
@@ -179,12 +197,16 @@ pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
+pub use interpreter_role_alter::AlterRoleInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
@@ -203,6 +225,7 @@ pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
+pub use interpreter_table_clone::CloneTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
@@ -216,7 +239,9 @@ pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_row_access_policy::AlterTableRowAccessPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
+pub use interpreter_table_tag::AlterTableTagInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
@@ -241,3 +266,6 @@ pub use interpreter_virtual_column_alter::AlterVirtualColumnInterpreter;
 pub use interpreter_virtual_column_create::CreateVirtualColumnInterpreter;
 pub use interpreter_virtual_column_drop::DropVirtualColumnInterpreter;
 pub use interpreter_virtual_column_refresh::RefreshVirtualColumnInterpreter;
+pub use interpreter_workload_group_alter::AlterWorkloadGroupInterpreter;
+pub use interpreter_workload_group_create::CreateWorkloadGroupInterpreter;
+pub use interpreter_workload_group_drop::DropWorkloadGroupInterpreter;
@@ -52,7 +52,9 @@ pub mod local;
 pub mod locks;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
+pub mod script;
 pub mod servers;
 pub mod sessions;
 pub mod spillers;
@@ -0,0 +1,229 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::lock::Lock;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::LockKey;
+use databend_common_meta_app::schema::LockType;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::KeyBuilder;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::KVMeta;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::Operation;
+use databend_common_pipeline_core::LockGuard;
+use databend_common_pipeline_core::UnlockApi;
+use databend_common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+
+use crate::sessions::SessionManager;
+
+const EXTERNAL_TABLE_LOCK_PREFIX: &str = "__fd_external_table_lock";
+
+/// The lock of a table which is committed by the files in its location, e.g. an iceberg table.
+///
+/// The table lock in the meta service is bound to the table id, but an iceberg table has no id
+/// in the meta service and several tables may share a location, so these tables are locked by
+/// a key of their location instead. The key expires after `table_lock_expire_secs`, it's
+/// extended while the lock is held and deleted when the lock guard is dropped.
+///
+/// Unlike the table lock, it's taken even if `enable_table_lock` is disabled: the concurrent
+/// commits of an external table can't be detected, and one of them would be lost.
+pub struct ExternalTableLock {
+    table_info: TableInfo,
+    key: String,
+}
+
+impl ExternalTableLock {
+    pub fn create(table_info: TableInfo) -> Arc<dyn Lock> {
+        let location = match &table_info.meta.storage_params {
+            Some(params) => params.to_string(),
+            None => format!("{}.{}", table_info.catalog(), table_info.desc),
+        };
+        let key = KeyBuilder::new_prefixed(EXTERNAL_TABLE_LOCK_PREFIX)
+            .push_str(&location)
+            .done();
+        Arc::new(ExternalTableLock { table_info, key })
+    }
+}
+
+#[async_trait::async_trait]
+impl Lock for ExternalTableLock {
+    fn lock_type(&self) -> LockType {
+        LockType::TABLE
+    }
+
+    fn gen_lock_key(&self) -> LockKey {
+        LockKey::Table {
+            table_id: self.table_info.ident.table_id,
+        }
+    }
+
+    fn get_catalog(&self) -> &str {
+        self.table_info.catalog()
+    }
+
+    fn get_table_id(&self) -> u64 {
+        self.table_info.ident.table_id
+    }
+
+    fn watch_delete_key(&self, _revision: u64) -> String {
+        self.key.clone()
+    }
+
+    async fn try_lock(&self, ctx: Arc<dyn TableContext>) -> Result<Option<LockGuard>> {
+        let settings = ctx.get_settings();
+        let expire_secs = settings.get_table_lock_expire_secs()?;
+        let acquire_lock_timeout = Duration::from_secs(settings.get_acquire_lock_timeout()?);
+
+        let meta = UserApiProvider::instance().get_meta_store_client();
+        let query_id = ctx.get_id();
+        let start = Instant::now();
+        loop {
+            // The key is only created if it's absent.
+            let req = UpsertKVReq::new(
+                &self.key,
+                MatchSeq::Exact(0),
+                Operation::Update(query_id.clone().into_bytes()),
+                Some(KVMeta::new_expire(expire_at(expire_secs))),
+            );
+            let reply = meta.upsert_kv(req).await?;
+            if let (true, Some(locked)) = (reply.is_changed(), reply.result) {
+                let holder = Arc::new(ExternalLockHolder {
+                    meta,
+                    key: self.key.clone(),
+                    query_id,
+                    seq: locked.seq,
+                    shutdown_flag: AtomicBool::new(false),
+                    shutdown_notify: Notify::new(),
+                });
+                holder.clone().start(expire_secs);
+                return Ok(Some(LockGuard::new(holder, locked.seq)));
+            }
+
+            if start.elapsed() >= acquire_lock_timeout {
+                return Err(ErrorCode::TableAlreadyLocked(
+                    "table is locked by other session, please retry later".to_string(),
+                ));
+            }
+            sleep(Duration::from_millis(200)).await;
+        }
+    }
+}
+
+struct ExternalLockHolder {
+    meta: Arc<MetaStore>,
+    key: String,
+    query_id: String,
+    // The seq of the key when the lock is acquired.
+    seq: u64,
+    shutdown_flag: AtomicBool,
+    shutdown_notify: Notify,
+}
+
+impl ExternalLockHolder {
+    fn start(self: Arc<Self>, expire_secs: u64) {
+        GlobalIORuntime::instance().spawn(self.query_id.clone(), async move {
+            let mut seq = self.seq;
+            let mut notified = Box::pin(self.shutdown_notify.notified());
+            while !self.shutdown_flag.load(Ordering::SeqCst) {
+                let sleep_gap = Box::pin(sleep(Duration::from_millis(expire_secs * 1000 / 3)));
+                match select(notified, sleep_gap).await {
+                    Either::Left((_, _)) => {
+                        // shutdown.
+                        break;
+                    }
+                    Either::Right((_, new_notified)) => {
+                        notified = new_notified;
+                        match self.extend(seq, expire_secs).await {
+                            Ok(new_seq) => seq = new_seq,
+                            Err(e) => {
+                                log::error!(
+                                    "failed to extend the lock {}. cause {:?}",
+                                    self.key,
+                                    e
+                                );
+                                // Force kill the query if extend lock failure.
+                                if let Some(session) =
+                                    SessionManager::instance().get_session_by_id(&self.query_id)
+                                {
+                                    session.force_kill_query(e.clone());
+                                }
+                                return Err(e);
+                            }
+                        }
+                    }
+                }
+            }
+
+            let req = UpsertKVReq::new(&self.key, MatchSeq::Exact(seq), Operation::Delete, None);
+            if let Err(e) = self.meta.upsert_kv(req).await {
+                log::warn!(
+                    "failed to delete the lock {}, it will expire. cause {:?}",
+                    self.key,
+                    e
+                );
+            }
+            Ok::<_, ErrorCode>(())
+        });
+    }
+
+    async fn extend(&self, seq: u64, expire_secs: u64) -> Result<u64> {
+        let req = UpsertKVReq::new(
+            &self.key,
+            MatchSeq::Exact(seq),
+            Operation::Update(self.query_id.clone().into_bytes()),
+            Some(KVMeta::new_expire(expire_at(expire_secs))),
+        );
+        let reply = self.meta.upsert_kv(req).await?;
+        match (reply.is_changed(), reply.result) {
+            (true, Some(extended)) => Ok(extended.seq),
+            _ => Err(ErrorCode::TableLockExpired(
+                "the acquired table lock has been expired".to_string(),
+            )),
+        }
+    }
+}
+
+impl UnlockApi for ExternalLockHolder {
+    fn unlock(&self, _revision: u64) {
+        self.shutdown_flag.store(true, Ordering::SeqCst);
+        self.shutdown_notify.notify_one();
+    }
+}
+
+fn expire_at(expire_secs: u64) -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    now + expire_secs
+}
@@ -35,10 +35,13 @@ use databend_common_metrics::lock::record_acquired_lock_nums;
 use databend_common_metrics::lock::record_created_lock_nums;
 use databend_common_pipeline_core::LockGuard;
 use databend_common_pipeline_core::UnlockApi;
+use databend_common_storages_delta::DELTA_ENGINE;
+use databend_common_storages_iceberg::ICEBERG_ENGINE;
 use databend_common_users::UserApiProvider;
 use futures_util::StreamExt;
 use parking_lot::RwLock;
 
+use crate::locks::external_table_lock::ExternalTableLock;
 use crate::locks::lock_holder::LockHolder;
 use crate::locks::table_lock::TableLock;
 use crate::locks::LockExt;
@@ -72,7 +75,18 @@ impl LockManager {
         GlobalInstance::get()
     }
 
+    /// Whether the table is committed by the files in its location rather than by the meta
+    /// service, e.g. iceberg and delta tables.
+    pub fn is_external_table(table_info: &TableInfo) -> bool {
+        let engine = table_info.engine();
+        engine.eq_ignore_ascii_case(ICEBERG_ENGINE) || engine.eq_ignore_ascii_case(DELTA_ENGINE)
+    }
+
     pub fn create_table_lock(table_info: TableInfo) -> Result<Arc<dyn Lock>> {
+        // The commits of the external tables are serialized by their location.
+        if Self::is_external_table(&table_info) {
+            return Ok(ExternalTableLock::create(table_info));
+        }
         let lock_mgr = LockManager::instance();
         Ok(TableLock::create(lock_mgr, table_info))
     }
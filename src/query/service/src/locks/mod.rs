@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod external_table_lock;
 mod lock_ext;
 mod lock_holder;
 mod lock_manager;
@@ -21,7 +21,9 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
+use databend_storages_common_table_meta::table::TableConstraints;
 
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
@@ -40,6 +42,7 @@ impl PipelineBuilder {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         Self::fill_and_reorder_columns(ctx.clone(), main_pipeline, table.clone(), source_schema)?;
+        Self::check_table_constraints(ctx.clone(), main_pipeline, table.as_ref(), None)?;
 
         table.append_data(ctx.clone(), main_pipeline, append_mode)?;
 
@@ -64,9 +67,37 @@ impl PipelineBuilder {
         append_mode: AppendMode,
     ) -> Result<()> {
         Self::fill_and_reorder_columns(ctx.clone(), main_pipeline, table.clone(), source_schema)?;
+        Self::check_table_constraints(ctx.clone(), main_pipeline, table.as_ref(), None)?;
 
         table.append_data(ctx, main_pipeline, append_mode)?;
 
         Ok(())
     }
+
+    // Validate the CHECK constraints of the table.
+    // ** The block must be in table's schema order without virtual computed columns,
+    // unless the schema of the block is given **
+    pub fn check_table_constraints(
+        ctx: Arc<QueryContext>,
+        pipeline: &mut Pipeline,
+        table: &dyn Table,
+        block_schema: Option<DataSchemaRef>,
+    ) -> Result<()> {
+        let constraints = TableConstraints::from_options(table.options())?;
+        if constraints.checks.is_empty() {
+            return Ok(());
+        }
+
+        let schema = block_schema
+            .unwrap_or_else(|| Arc::new(table.schema().remove_virtual_computed_fields().into()));
+        pipeline.add_transform(|transform_input_port, transform_output_port| {
+            TransformCheckConstraints::try_create(
+                ctx.clone(),
+                transform_input_port,
+                transform_output_port,
+                schema.clone(),
+                &constraints.checks,
+            )
+        })
+    }
 }
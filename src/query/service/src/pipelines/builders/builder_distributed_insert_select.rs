@@ -64,6 +64,12 @@ impl PipelineBuilder {
             table.clone(),
             source_schema.clone(),
         )?;
+        Self::check_table_constraints(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            table.as_ref(),
+            None,
+        )?;
 
         table.append_data(
             self.ctx.clone(),
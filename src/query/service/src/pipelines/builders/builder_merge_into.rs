@@ -50,10 +50,12 @@ use databend_common_storages_fuse::operations::TransformDistributedMergeIntoBloc
 use databend_common_storages_fuse::operations::TransformSerializeBlock;
 use databend_common_storages_fuse::operations::TransformSerializeSegment;
 use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::TableConstraints;
 
 use crate::pipelines::processors::transforms::AccumulateRowNumber;
 use crate::pipelines::processors::transforms::ExtractHashTableByRowNumber;
 use crate::pipelines::processors::transforms::TransformAddComputedColumns;
+use crate::pipelines::processors::transforms::TransformCheckConstraints;
 use crate::pipelines::processors::DeduplicateRowNumber;
 use crate::pipelines::processors::TransformResortAddOnWithoutSourceSchema;
 use crate::pipelines::PipelineBuilder;
@@ -229,6 +231,25 @@ impl PipelineBuilder {
                 self.main_pipeline.add_pipe(builder.finalize());
             }
 
+            // 2.1 check constraints
+            let constraints = TableConstraints::from_options(tbl.options())?;
+            if !constraints.checks.is_empty() {
+                builder = self.main_pipeline.add_transform_with_specified_len(
+                    |transform_input_port, transform_output_port| {
+                        TransformCheckConstraints::try_create(
+                            self.ctx.clone(),
+                            transform_input_port,
+                            transform_output_port,
+                            computed_schema.clone(),
+                            &constraints.checks,
+                        )
+                    },
+                    1,
+                )?;
+                builder.add_items(vec![create_dummy_item()]);
+                self.main_pipeline.add_pipe(builder.finalize());
+            }
+
             // 3. we should avoid too much little block write, because for s3 write, there are too many
             // little blocks, it will cause high latency.
             let block_thresholds = table.get_block_thresholds();
@@ -713,6 +734,25 @@ impl PipelineBuilder {
                 .add_pipe(add_builder_pipe(builder, distributed));
         }
 
+        // check constraints
+        let constraints = TableConstraints::from_options(tbl.options())?;
+        if !constraints.checks.is_empty() {
+            builder = self.main_pipeline.add_transform_with_specified_len(
+                |transform_input_port, transform_output_port| {
+                    TransformCheckConstraints::try_create(
+                        self.ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        computed_schema.clone(),
+                        &constraints.checks,
+                    )
+                },
+                fill_default_len,
+            )?;
+            self.main_pipeline
+                .add_pipe(add_builder_pipe(builder, distributed));
+        }
+
         let max_threads = self.settings.get_max_threads()?;
         let io_request_semaphore = Arc::new(Semaphore::new(max_threads as usize));
 
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::physical_plans::RecursiveCteScan;
+
+use crate::pipelines::processors::transforms::RecursiveCteScanSource;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
+use crate::pipelines::PipelineBuilder;
+
+impl PipelineBuilder {
+    pub(crate) fn build_recursive_cte(&mut self, r_cte: &RecursiveCte) -> Result<()> {
+        // The iterations of a recursive cte are executed one by one in a single source,
+        // each iteration reads the output of the previous one from the working table.
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    self.func_ctx.clone(),
+                    self.settings.clone(),
+                    r_cte.clone(),
+                    self.r_cte_state.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    pub(crate) fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let state = self
+            .r_cte_state
+            .get(&scan.cte_idx)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the working table of recursive cte {}",
+                    scan.cte_idx
+                ))
+            })?;
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteScanSource::create(
+                    self.ctx.clone(),
+                    output,
+                    state.clone(),
+                    scan.offsets.clone(),
+                )
+            },
+            1,
+        )
+    }
+}
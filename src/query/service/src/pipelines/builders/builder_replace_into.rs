@@ -109,6 +109,7 @@ impl PipelineBuilder {
             segments,
             block_slots,
             need_insert,
+            error_on_conflict,
             ..
         } = replace;
         let max_threads = self.settings.get_max_threads()?;
@@ -161,6 +162,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             self.main_pipeline.add_pipe(Pipe::create(
                 segment_partition_num,
@@ -244,6 +246,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             assert_eq!(
                 segment_partition_num,
@@ -274,6 +277,7 @@ impl PipelineBuilder {
             target_schema,
             need_insert,
             delete_when,
+            error_on_conflict,
             ..
         } = deduplicate;
 
@@ -333,6 +337,12 @@ impl PipelineBuilder {
             tbl.clone(),
             Arc::new(target_schema.clone().into()),
         )?;
+        Self::check_table_constraints(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            tbl.as_ref(),
+            delete_when.as_ref().map(|_| modified_schema.clone()),
+        )?;
 
         let _ = table.cluster_gen_for_append(
             self.ctx.clone(),
@@ -376,6 +386,7 @@ impl PipelineBuilder {
                 *table_is_empty,
                 table_level_range_index.clone(),
                 delete_when.map(|(expr, _)| (expr, delete_column_idx)),
+                *error_on_conflict,
             )?;
             self.main_pipeline
                 .add_pipe(replace_into_processor.into_pipe());
@@ -389,6 +400,7 @@ impl PipelineBuilder {
                 *table_is_empty,
                 table_level_range_index.clone(),
                 delete_when.map(|_| delete_column_idx),
+                *error_on_conflict,
             )?;
             self.main_pipeline
                 .add_pipe(replace_into_processor.into_pipe());
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -26,6 +28,7 @@ use databend_common_sql::StreamContext;
 
 use crate::pipelines::processors::transforms::MaterializedCteSource;
 use crate::pipelines::processors::transforms::TransformAddInternalColumns;
+use crate::pipelines::processors::transforms::TransformSample;
 use crate::pipelines::processors::TransformAddStreamColumns;
 use crate::pipelines::PipelineBuilder;
 
@@ -60,6 +63,31 @@ impl PipelineBuilder {
             })?;
         }
 
+        // Sample the rows if the table can't sample the blocks or the rows by itself.
+        if let Some(sample) = scan
+            .source
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.sample.as_ref())
+        {
+            let (sampled, level) = match sample.level {
+                SampleLevel::Row => (table.support_row_sample(), "ROW"),
+                SampleLevel::Block => (table.support_block_sample(), "BLOCK"),
+            };
+            if !sampled {
+                // The rows are read in parallel, their order is not stable.
+                if sample.seed.is_some() {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "TABLESAMPLE {level} with REPEATABLE is not supported by the table engine {}",
+                        table.engine()
+                    )));
+                }
+                self.main_pipeline.add_transform(|input, output| {
+                    TransformSample::try_create(input, output, sample.clone())
+                })?;
+            }
+        }
+
         let schema = scan.source.schema();
         let mut projection = scan
             .name_mapping
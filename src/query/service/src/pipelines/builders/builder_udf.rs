@@ -28,6 +28,7 @@ impl PipelineBuilder {
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfScript::try_create(
                     self.func_ctx.clone(),
+                    self.settings.clone(),
                     udf.udf_funcs.clone(),
                     input,
                     output,
@@ -46,6 +46,7 @@ impl PipelineBuilder {
             update.query_row_id_col,
             &mut self.main_pipeline,
         )?;
+        Self::check_table_constraints(self.ctx.clone(), &mut self.main_pipeline, table, None)?;
 
         if table.change_tracking_enabled() {
             let stream_ctx = StreamContext::try_create(
@@ -19,12 +19,19 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_sql::executor::physical_plans::Window;
+use databend_common_storage::DataOperator;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionSpill;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
+use crate::sessions::TableContext;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -70,7 +77,34 @@ impl PipelineBuilder {
 
             sort_desc.extend(order_by.clone());
 
-            self.build_sort_pipeline(input_schema.clone(), sort_desc, window.limit, None)?;
+            let (max_memory_usage, bytes_limit) = self.get_window_spill_memory_settings()?;
+            if !partition_by.is_empty() && window.limit.is_none() && max_memory_usage != 0 {
+                // Hash-partition the input by the partition by columns, each partition is
+                // sorted separately and may be spilled.
+                self.main_pipeline.try_resize(1)?;
+                let num_partitions = self.settings.get_window_num_partitions()?;
+                let max_block_size = self.settings.get_max_block_size()? as usize;
+                let config =
+                    SpillerConfig::create(query_spill_prefix(self.ctx.get_tenant().as_str()));
+                self.main_pipeline.add_transform(|input, output| {
+                    let op = DataOperator::instance().operator();
+                    let spiller =
+                        Spiller::create(self.ctx.clone(), op, config.clone(), SpillerType::Window)?;
+                    Ok(ProcessorPtr::create(TransformWindowPartitionSpill::create(
+                        input,
+                        output,
+                        spiller,
+                        partition_by.clone(),
+                        sort_desc.clone(),
+                        num_partitions,
+                        max_block_size,
+                        max_memory_usage,
+                        bytes_limit,
+                    )))
+                })?;
+            } else {
+                self.build_sort_pipeline(input_schema.clone(), sort_desc, window.limit, None)?;
+            }
         }
         // `TransformWindow` is a pipeline breaker.
         self.main_pipeline.try_resize(1)?;
@@ -136,4 +170,34 @@ impl PipelineBuilder {
 
         self.main_pipeline.try_resize(old_output_len)
     }
+
+    /// Returns the max memory usage and the bytes limit of the window partitioner,
+    /// `(0, 0)` means the window spilling is disabled.
+    fn get_window_spill_memory_settings(&self) -> Result<(usize, usize)> {
+        let memory_ratio = self.settings.get_window_partition_spilling_memory_ratio()?;
+        let bytes_limit = self
+            .settings
+            .get_window_partition_spilling_bytes_threshold_per_proc()?;
+        if memory_ratio == 0 && bytes_limit == 0 {
+            // If these two settings are not set, do not enable window spill.
+            return Ok((0, 0));
+        }
+        let memory_ratio = (memory_ratio as f64 / 100_f64).min(1_f64);
+        let max_memory_usage = match self.settings.get_max_memory_usage()? {
+            0 => usize::MAX,
+            max_memory_usage => {
+                if memory_ratio == 0_f64 {
+                    usize::MAX
+                } else {
+                    (max_memory_usage as f64 * memory_ratio) as usize
+                }
+            }
+        };
+        // The window partitioner runs in a single processor.
+        let bytes_limit = match bytes_limit {
+            0 => max_memory_usage,
+            bytes => bytes,
+        };
+        Ok((max_memory_usage, bytes_limit))
+    }
 }
@@ -19,6 +19,8 @@ use std::sync::atomic::AtomicU64;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::OnceLock;
+use std::time::Duration;
 
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
@@ -58,6 +60,7 @@ use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::ProcessorPtr;
 use crate::pipelines::processors::UpdateList;
 use crate::pipelines::processors::UpdateTrigger;
+use crate::sessions::CpuQuota;
 
 enum State {
     Idle,
@@ -156,6 +159,8 @@ struct ExecutingGraph {
     /// - the high 32 bit store the number of points that can be consumed
     /// - the low 32 bit store this points belong to which epoch
     points: AtomicU64,
+    /// the cpu quota of the workload group the query belongs to
+    cpu_quota: OnceLock<Arc<CpuQuota>>,
 }
 
 type StateLockGuard = ExecutingGraph;
@@ -168,6 +173,7 @@ impl ExecutingGraph {
             graph,
             finished_nodes: AtomicUsize::new(0),
             points: AtomicU64::new((MAX_POINTS << 32) | init_epoch as u64),
+            cpu_quota: OnceLock::new(),
         })
     }
 
@@ -182,6 +188,7 @@ impl ExecutingGraph {
             finished_nodes: AtomicUsize::new(0),
             graph,
             points: AtomicU64::new((MAX_POINTS << 32) | init_epoch as u64),
+            cpu_quota: OnceLock::new(),
         })
     }
 
@@ -712,10 +719,37 @@ impl RunningGraph {
     }
 
     /// Checks if a task can be performed in the current epoch, consuming a point if possible.
+    ///
+    /// The tasks are held back while the cpu quota of the graph is overdrawn.
     pub fn can_perform_task(&self, global_epoch: u32, max_points: u64) -> bool {
+        if !self.cpu_quota_wait_time().is_zero() {
+            return false;
+        }
         self.0.can_perform_task(global_epoch, max_points)
     }
 
+    /// Bind the graph to the cpu quota of its workload group.
+    pub fn set_cpu_quota(&self, cpu_quota: Option<Arc<CpuQuota>>) {
+        if let Some(cpu_quota) = cpu_quota {
+            let _ = self.0.cpu_quota.set(cpu_quota);
+        }
+    }
+
+    /// Charge the cpu time spent by a processor of the graph to its cpu quota.
+    pub fn consume_cpu_time(&self, cpu_time: Duration) {
+        if let Some(cpu_quota) = self.0.cpu_quota.get() {
+            cpu_quota.consume(cpu_time);
+        }
+    }
+
+    /// How long the tasks of the graph should be held back for its cpu quota.
+    pub fn cpu_quota_wait_time(&self) -> Duration {
+        match self.0.cpu_quota.get() {
+            Some(cpu_quota) => cpu_quota.wait_time(),
+            None => Duration::ZERO,
+        }
+    }
+
     pub fn format_graph_nodes(&self) -> String {
         pub struct NodeDisplay {
             id: usize,
@@ -18,20 +18,27 @@ use std::time::Duration;
 use databend_common_exception::Result;
 use databend_common_settings::Settings;
 
+use crate::sessions::CpuQuota;
+use crate::sessions::WorkloadGroupManager;
+
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub enable_new_executor: bool,
     pub query_id: Arc<String>,
     pub max_execute_time_in_seconds: Duration,
+    /// The cpu quota of the workload group the query belongs to, if any.
+    pub cpu_quota: Option<Arc<CpuQuota>>,
 }
 
 impl ExecutorSettings {
     pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+        let cpu_quota = WorkloadGroupManager::instance().get_query_cpu_quota(&query_id);
         Ok(ExecutorSettings {
             enable_new_executor: settings.get_enable_experimental_queries_executor()?,
             query_id: Arc::new(query_id),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            cpu_quota,
         })
     }
 }
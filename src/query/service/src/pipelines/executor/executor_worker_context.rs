@@ -143,9 +143,11 @@ impl ExecutorWorkerContext {
 
         proc.processor.process()?;
 
-        let nanos = instant.elapsed().as_nanos();
+        let elapsed = instant.elapsed();
+        let nanos = elapsed.as_nanos();
         assume(nanos < 18446744073709551615_u128);
         Profile::record_usize_profile(ProfileStatisticsName::CpuTime, nanos as usize);
+        proc.graph.consume_cpu_time(elapsed);
         Ok(Some((proc.processor.id(), proc.graph)))
     }
 
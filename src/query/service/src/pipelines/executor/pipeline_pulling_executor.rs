@@ -44,6 +44,7 @@ use crate::pipelines::executor::QueryPipelineExecutor;
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::ProcessorPtr;
 use crate::pipelines::PipelineBuildResult;
+use crate::sessions::WorkloadGroupManager;
 
 struct State {
     is_finished: AtomicBool,
@@ -105,10 +106,12 @@ pub struct PipelinePullingExecutor {
 impl PipelinePullingExecutor {
     fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
-        tracking_payload.mem_stat = Some(MemStat::create(format!(
-            "QueryExecutionMemStat-{}",
-            query_id
-        )));
+        // The memory of the query is also accounted to its workload group, if any.
+        let parent_mem_stat = WorkloadGroupManager::instance().get_query_mem_stat(query_id);
+        tracking_payload.mem_stat = Some(MemStat::create_child(
+            format!("QueryExecutionMemStat-{}", query_id),
+            parent_mem_stat.into_iter().collect(),
+        ));
         tracking_payload
     }
 
@@ -190,6 +190,7 @@ impl QueriesPipelineExecutor {
     ) -> Result<Arc<QueriesPipelineExecutor>> {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = QueriesExecutorTasksQueue::create(threads_num);
+        graph.set_cpu_quota(settings.cpu_quota.clone());
 
         Ok(Arc::new(QueriesPipelineExecutor {
             graph,
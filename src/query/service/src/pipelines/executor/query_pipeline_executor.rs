@@ -185,6 +185,7 @@ impl QueryPipelineExecutor {
     ) -> Result<Arc<QueryPipelineExecutor>> {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = QueryExecutorTasksQueue::create(threads_num);
+        graph.set_cpu_quota(settings.cpu_quota.clone());
 
         Ok(Arc::new(QueryPipelineExecutor {
             graph,
@@ -437,6 +438,14 @@ impl QueryPipelineExecutor {
                         let schedule_queue = graph.schedule_queue(executed_pid)?;
                         schedule_queue.schedule(&self.global_tasks_queue, &mut context, self);
                     }
+
+                    // The threads are dedicated to the query, they are held back while
+                    // the cpu quota of its workload group is overdrawn.
+                    let mut wait_time = graph.cpu_quota_wait_time();
+                    while !wait_time.is_zero() && !self.global_tasks_queue.is_finished() {
+                        std::thread::sleep(wait_time);
+                        wait_time = graph.cpu_quota_wait_time();
+                    }
                 }
             }
         }
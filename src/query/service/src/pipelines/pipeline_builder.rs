@@ -32,6 +32,7 @@ use crate::api::DefaultExchangeInjector;
 use crate::api::ExchangeInjector;
 use crate::pipelines::processors::transforms::HashJoinBuildState;
 use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::processors::transforms::RecursiveCteState;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
@@ -49,6 +50,8 @@ pub struct PipelineBuilder {
 
     // Cte -> state, each cte has it's own state
     pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    // Recursive cte -> working table
+    pub r_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
 
     pub(crate) exchange_injector: Arc<dyn ExchangeInjector>,
 }
@@ -68,6 +71,7 @@ impl PipelineBuilder {
             main_pipeline: Pipeline::with_scopes(scopes),
             exchange_injector: DefaultExchangeInjector::create(),
             cte_state: HashMap::new(),
+            r_cte_state: HashMap::new(),
             merge_into_probe_data_fields: None,
             join_state: None,
         }
@@ -149,6 +153,8 @@ impl PipelineBuilder {
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 self.build_materialized_cte(materialized_cte)
             }
+            PhysicalPlan::RecursiveCte(r_cte) => self.build_recursive_cte(r_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
 
             // Copy into.
             PhysicalPlan::CopyIntoTable(copy) => self.build_copy_into_table(copy),
@@ -171,6 +171,10 @@ impl HashJoinProbeState {
             | JoinType::Right
             | JoinType::Full => self.probe_join(input, probe_state),
             JoinType::Cross => self.cross_join(input, probe_state),
+            JoinType::Asof | JoinType::LeftAsof => Err(ErrorCode::Unimplemented(format!(
+                "{} is unimplemented",
+                self.hash_join_state.hash_join_desc.join_type
+            ))),
         }
     }
 
@@ -0,0 +1,275 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::Result;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FromData;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::SortColumnDescription;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::plans::JoinType;
+
+use crate::pipelines::processors::transforms::range_join::order_match;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+// For asof join, `conditions[0]` is the match condition and the others are the equi conditions.
+// The keys block of a table contains the equi keys, the match key and the row index,
+// sorted by the equi keys, and then by the match key in the order of the match condition.
+impl RangeJoinState {
+    /// Merge the right table into one sorted keys block, each left block is a task.
+    pub(crate) fn asof_partition(&self) -> Result<()> {
+        let left_table = self.left_table.read();
+        let mut right_table = self.right_table.write();
+        if !right_table.is_empty() {
+            let merged_right_table = DataBlock::concat(&right_table)?;
+            let keys_block = self.asof_sorted_keys(&merged_right_table, false)?;
+            right_table.clear();
+            right_table.push(merged_right_table);
+            self.right_sorted_blocks.write().push(keys_block);
+        }
+
+        let mut tasks = self.tasks.write();
+        let mut row_offset = self.row_offset.write();
+        for left_idx in 0..left_table.len() {
+            tasks.push((left_idx, 0));
+            row_offset.push((0, 0));
+        }
+        Ok(())
+    }
+
+    /// Match each row of the left block with the nearest right row by merging the sorted keys.
+    pub fn asof_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let (left_idx, _) = self.tasks.read()[task_id];
+        let left_table = self.left_table.read();
+        let left_block = &left_table[left_idx];
+        let mut matched_rows = vec![None; left_block.num_rows()];
+
+        let right_sorted_blocks = self.right_sorted_blocks.read();
+        if let Some(right_keys) = right_sorted_blocks.first() {
+            let left_keys = self.asof_sorted_keys(left_block, true)?;
+            let num_equi_keys = self.conditions.len() - 1;
+            let (left_columns, left_rows) = split_keys_block(&left_keys, num_equi_keys);
+            let (right_columns, right_rows) = split_keys_block(right_keys, num_equi_keys);
+            let op = self.conditions[0].operator.as_str();
+
+            // The latest right row which satisfies the match condition, the later left rows
+            // with the same equi keys also satisfy it as they are sorted in the same order.
+            let mut candidate = None;
+            let mut j = 0;
+            for i in 0..left_rows.len() {
+                if has_null(&left_columns, i) {
+                    continue;
+                }
+                while j < right_rows.len() {
+                    if has_null(&right_columns, j) {
+                        j += 1;
+                        continue;
+                    }
+                    match compare_equi_keys(&left_columns, i, &right_columns, j, num_equi_keys) {
+                        Ordering::Greater => j += 1,
+                        Ordering::Equal
+                            if order_match(
+                                op,
+                                &unsafe { left_columns[num_equi_keys].index_unchecked(i) },
+                                &unsafe { right_columns[num_equi_keys].index_unchecked(j) },
+                            ) =>
+                        {
+                            candidate = Some(j);
+                            j += 1;
+                        }
+                        _ => break,
+                    }
+                }
+                if let Some(candidate) = candidate {
+                    if compare_equi_keys(&left_columns, i, &right_columns, candidate, num_equi_keys)
+                        == Ordering::Equal
+                    {
+                        matched_rows[left_rows[i] as usize] = Some(right_rows[candidate]);
+                    }
+                }
+            }
+        }
+
+        let result = self.asof_join_result(left_block, &matched_rows)?;
+        if result.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![result])
+    }
+
+    fn asof_join_result(
+        &self,
+        left_block: &DataBlock,
+        matched_rows: &[Option<u32>],
+    ) -> Result<DataBlock> {
+        let right_table = self.right_table.read();
+        if self.join_type == JoinType::LeftAsof {
+            let num_rows = left_block.num_rows();
+            let mut result_block = left_block.clone();
+            match right_table.first() {
+                Some(right_block) => {
+                    let indices = matched_rows
+                        .iter()
+                        .map(|row| row.unwrap_or(0))
+                        .collect::<Vec<_>>();
+                    let validity = matched_rows.iter().map(Option::is_some).collect::<Bitmap>();
+                    let right_result_block = right_block.take(&indices, &mut None)?;
+                    for entry in right_result_block.columns() {
+                        result_block.add_column(wrap_validity(entry, num_rows, &validity));
+                    }
+                }
+                None => {
+                    for field in self.right_schema.fields() {
+                        result_block.add_column(BlockEntry::new(
+                            field.data_type().wrap_nullable(),
+                            Value::Scalar(Scalar::Null),
+                        ));
+                    }
+                }
+            }
+            return Ok(result_block);
+        }
+
+        let (left_indices, right_indices): (Vec<u32>, Vec<u32>) = matched_rows
+            .iter()
+            .enumerate()
+            .filter_map(|(left, right)| right.map(|right| (left as u32, right)))
+            .unzip();
+        if left_indices.is_empty() {
+            return Ok(DataBlock::empty());
+        }
+        let mut result_block = left_block.take(&left_indices, &mut None)?;
+        let right_result_block = right_table[0].take(&right_indices, &mut None)?;
+        for entry in right_result_block.columns() {
+            result_block.add_column(entry.clone());
+        }
+        Ok(result_block)
+    }
+
+    // Evaluate the equi keys and the match key of the block, and sort them with the row index.
+    fn asof_sorted_keys(&self, block: &DataBlock, left: bool) -> Result<DataBlock> {
+        let func_ctx = FunctionContext::default();
+        let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+        let num_rows = block.num_rows();
+
+        let mut columns = Vec::with_capacity(self.conditions.len() + 1);
+        let mut sort_descriptions = Vec::with_capacity(self.conditions.len());
+        // Equi keys first, then the match key.
+        for (idx, condition) in self
+            .conditions
+            .iter()
+            .skip(1)
+            .chain(self.conditions.iter().take(1))
+            .enumerate()
+        {
+            let expr = match left {
+                true => condition.left_expr.as_expr(&BUILTIN_FUNCTIONS),
+                false => condition.right_expr.as_expr(&BUILTIN_FUNCTIONS),
+            };
+            let column = evaluator
+                .run(&expr)?
+                .convert_to_full_column(expr.data_type(), num_rows);
+            columns.push(column);
+            // The match key is ascending for `>`/`>=`, so the nearest right row is the latest
+            // one less than the left row, and descending for `<`/`<=`.
+            let asc = !matches!(condition.operator.as_str(), "lt" | "lte");
+            sort_descriptions.push(SortColumnDescription {
+                offset: idx,
+                asc,
+                nulls_first: true,
+                is_nullable: expr.data_type().is_nullable(),
+            });
+        }
+        columns.push(UInt32Type::from_data(
+            (0..num_rows as u32).collect::<Vec<_>>(),
+        ));
+
+        let keys_block = DataBlock::new_from_columns(columns);
+        DataBlock::sort(&keys_block, &sort_descriptions, None)
+    }
+}
+
+// Split the keys block into the key columns and the row indices.
+fn split_keys_block(keys_block: &DataBlock, num_equi_keys: usize) -> (Vec<Column>, Vec<u32>) {
+    let num_rows = keys_block.num_rows();
+    let mut columns = keys_block
+        .columns()
+        .iter()
+        .map(|entry| {
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        })
+        .collect::<Vec<_>>();
+    let row_index = columns.pop().unwrap();
+    debug_assert_eq!(columns.len(), num_equi_keys + 1);
+    let rows = UInt32Type::try_downcast_column(&row_index)
+        .unwrap()
+        .to_vec();
+    (columns, rows)
+}
+
+fn has_null(columns: &[Column], row: usize) -> bool {
+    columns
+        .iter()
+        .any(|column| unsafe { column.index_unchecked(row) }.is_null())
+}
+
+fn compare_equi_keys(
+    left_columns: &[Column],
+    left_row: usize,
+    right_columns: &[Column],
+    right_row: usize,
+    num_equi_keys: usize,
+) -> Ordering {
+    for (left, right) in left_columns.iter().zip(right_columns).take(num_equi_keys) {
+        let left = unsafe { left.index_unchecked(left_row) };
+        let right = unsafe { right.index_unchecked(right_row) };
+        match left.cmp(&right) {
+            Ordering::Equal => continue,
+            ordering => return ordering,
+        }
+    }
+    Ordering::Equal
+}
+
+// Set the right columns of the left rows without matched rows to null.
+fn wrap_validity(entry: &BlockEntry, num_rows: usize, validity: &Bitmap) -> BlockEntry {
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows);
+    let column = match column {
+        Column::Null { .. } => column,
+        Column::Nullable(nullable) => Column::Nullable(Box::new(NullableColumn {
+            column: nullable.column,
+            validity: &nullable.validity & validity,
+        })),
+        column => Column::Nullable(Box::new(NullableColumn {
+            column,
+            validity: validity.clone(),
+        })),
+    };
+    BlockEntry::new(entry.data_type.wrap_nullable(), Value::Column(column))
+}
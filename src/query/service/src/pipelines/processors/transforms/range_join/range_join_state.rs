@@ -23,6 +23,7 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
 use databend_common_expression::Evaluator;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
@@ -31,6 +32,7 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::executor::physical_plans::RangeJoin;
 use databend_common_sql::executor::physical_plans::RangeJoinCondition;
 use databend_common_sql::executor::physical_plans::RangeJoinType;
+use databend_common_sql::plans::JoinType;
 use parking_lot::Mutex;
 use parking_lot::RwLock;
 
@@ -47,7 +49,9 @@ pub struct RangeJoinState {
     // For iejoin, it's L1: sort by the first join key
     pub(crate) left_sorted_blocks: RwLock<Vec<DataBlock>>,
     pub(crate) conditions: Vec<RangeJoinCondition>,
-    // pub(crate) join_type: JoinType,
+    pub(crate) join_type: JoinType,
+    pub(crate) range_join_type: RangeJoinType,
+    pub(crate) right_schema: DataSchemaRef,
     pub(crate) other_conditions: Vec<RemoteExpr>,
     // Pipeline event related
     pub(crate) partition_finished: Mutex<bool>,
@@ -64,21 +68,23 @@ pub struct RangeJoinState {
 }
 
 impl RangeJoinState {
-    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Self {
+    pub fn new(ctx: Arc<QueryContext>, range_join: &RangeJoin) -> Result<Self> {
         let ie_join_state = if matches!(range_join.range_join_type, RangeJoinType::IEJoin) {
             Some(IEJoinState::new(range_join))
         } else {
             None
         };
 
-        Self {
+        Ok(Self {
             ctx,
             left_table: RwLock::new(vec![]),
             right_table: RwLock::new(vec![]),
             right_sorted_blocks: Default::default(),
             left_sorted_blocks: Default::default(),
             conditions: range_join.conditions.clone(),
-            // join_type: range_join.join_type.clone(),
+            join_type: range_join.join_type.clone(),
+            range_join_type: range_join.range_join_type.clone(),
+            right_schema: range_join.right.output_schema()?,
             other_conditions: range_join.other_conditions.clone(),
             partition_finished: Mutex::new(false),
             finished_notify: Arc::new(WatchNotify::new()),
@@ -88,7 +94,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
-        }
+        })
     }
 
     pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
@@ -172,6 +178,10 @@ impl RangeJoinState {
     }
 
     pub(crate) fn partition(&self) -> Result<()> {
+        if matches!(self.range_join_type, RangeJoinType::Asof) {
+            return self.asof_partition();
+        }
+
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let left_table = self.left_table.read();
         // Right table is bigger than left table
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_sql::parse_computed_expr;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+struct CheckConstraint {
+    name: String,
+    sql: String,
+    expr: Expr,
+}
+
+/// Validates the CHECK constraints of a table against the blocks to be written,
+/// the blocks are passed through unchanged. A row passes a constraint if the
+/// expression is evaluated as TRUE or NULL.
+pub struct TransformCheckConstraints {
+    func_ctx: FunctionContext,
+    constraints: Vec<CheckConstraint>,
+}
+
+impl TransformCheckConstraints
+where Self: Transform
+{
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        input_schema: DataSchemaRef,
+        checks: &BTreeMap<String, String>,
+    ) -> Result<ProcessorPtr> {
+        let mut constraints = Vec::with_capacity(checks.len());
+        for (name, sql) in checks.iter() {
+            let expr = parse_computed_expr(ctx.clone(), input_schema.clone(), sql)?;
+            constraints.push(CheckConstraint {
+                name: name.clone(),
+                sql: sql.clone(),
+                expr,
+            });
+        }
+
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self {
+                func_ctx: ctx.get_function_context()?,
+                constraints,
+            },
+        )))
+    }
+}
+
+impl Transform for TransformCheckConstraints {
+    const NAME: &'static str = "CheckConstraintsTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for constraint in self.constraints.iter() {
+            let column = evaluator
+                .run(&constraint.expr)?
+                .convert_to_full_column(constraint.expr.data_type(), num_rows);
+            let (values, validity) = match column {
+                Column::Null { .. } => continue,
+                Column::Boolean(values) => (values, None),
+                Column::Nullable(box nullable) => match nullable.column {
+                    Column::Boolean(values) => (values, Some(nullable.validity)),
+                    _ => unreachable!("check constraint must be a boolean expression"),
+                },
+                _ => unreachable!("check constraint must be a boolean expression"),
+            };
+            let violated = (0..num_rows).any(|row| {
+                !values.get_bit(row) && validity.as_ref().map_or(true, |v| v.get_bit(row))
+            });
+            if violated {
+                return Err(ErrorCode::CheckConstraintViolated(format!(
+                    "CHECK constraint {} `{}` is violated",
+                    constraint.name, constraint.sql
+                )));
+            }
+        }
+        Ok(block)
+    }
+}
@@ -0,0 +1,242 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_settings::Settings;
+use databend_common_sql::executor::physical_plans::RecursiveCte;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::IndexType;
+use futures::TryStreamExt;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::stream::PullingExecutorStream;
+
+/// The working table of a recursive cte, holds the rows produced by the last iteration.
+#[derive(Default)]
+pub struct RecursiveCteState {
+    pub working_table: RwLock<Vec<DataBlock>>,
+}
+
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    settings: Arc<Settings>,
+    r_cte: RecursiveCte,
+    r_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
+    max_depth: u64,
+    // The number of the executed steps, the anchor is the step 0.
+    iteration: u64,
+    // Rows that have been output, only used by `UNION`.
+    seen: HashSet<Vec<Scalar>>,
+    // The memory size of the rows in `seen`, it's limited by `max_memory_usage`.
+    seen_memory_size: usize,
+    max_memory_usage: usize,
+}
+
+impl RecursiveCteSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        func_ctx: FunctionContext,
+        settings: Arc<Settings>,
+        r_cte: RecursiveCte,
+        mut r_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
+    ) -> Result<ProcessorPtr> {
+        let max_depth = settings.get_max_cte_recursive_depth()?;
+        let max_memory_usage = match settings.get_max_memory_usage()? {
+            0 => usize::MAX,
+            max_memory_usage => max_memory_usage as usize,
+        };
+        r_cte_state.insert(r_cte.cte_idx, Arc::new(RecursiveCteState::default()));
+        AsyncSourcer::create(ctx.clone(), output_port, RecursiveCteSource {
+            ctx,
+            func_ctx,
+            settings,
+            r_cte,
+            r_cte_state,
+            max_depth,
+            iteration: 0,
+            seen: HashSet::new(),
+            seen_memory_size: 0,
+            max_memory_usage,
+        })
+    }
+
+    async fn execute_plan(&self, plan: &PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let mut builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            QueryContext::create_from(self.ctx.clone()),
+            vec![],
+        );
+        builder.r_cte_state = self.r_cte_state.clone();
+
+        let mut build_res = builder.finalize(plan)?;
+        build_res.set_max_threads(self.settings.get_max_threads()? as usize);
+
+        let settings = ExecutorSettings::try_create(&self.settings, self.ctx.get_id())?;
+        let executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        PullingExecutorStream::create(executor)?
+            .try_collect::<Vec<DataBlock>>()
+            .await
+    }
+
+    // Reorder the columns of the output of `plan` by the columns of the cte.
+    fn project(&self, plan: &PhysicalPlan, left_side: bool, block: DataBlock) -> Result<DataBlock> {
+        let schema = plan.output_schema()?;
+        let num_rows = block.num_rows();
+        let mut columns = Vec::with_capacity(self.r_cte.pairs.len());
+        for (left, right) in self.r_cte.pairs.iter() {
+            let name = if left_side { left } else { right };
+            let offset = schema.index_of(name)?;
+            columns.push(block.get_by_offset(offset).clone());
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    // Remove the rows that have been output, only used by `UNION`.
+    fn distinct(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let values = block
+                .columns()
+                .iter()
+                .map(|entry| entry.value.index(row).unwrap())
+                .collect::<Vec<_>>();
+            let memory_size = values
+                .iter()
+                .map(|value| value.memory_size())
+                .sum::<usize>();
+            let inserted = self
+                .seen
+                .insert(values.into_iter().map(|value| value.to_owned()).collect());
+            if inserted {
+                self.seen_memory_size += memory_size;
+                if self.seen_memory_size > self.max_memory_usage {
+                    return Err(ErrorCode::RecursiveCteMemoryExceeded(format!(
+                        "Recursive query '{}' exceeded the memory limit {} bytes when removing the duplicated rows, consider increasing the setting `max_memory_usage` or using `UNION ALL`",
+                        self.r_cte.cte_name, self.max_memory_usage
+                    )));
+                }
+            }
+            bitmap.push(inserted);
+        }
+        block.filter_with_bitmap(&bitmap.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let (plan, left_side) = if self.iteration == 0 {
+            (self.r_cte.left.clone(), true)
+        } else {
+            (self.r_cte.right.clone(), false)
+        };
+        self.iteration += 1;
+
+        let blocks = self.execute_plan(&plan).await?;
+        let blocks = blocks
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .map(|block| self.project(&plan, left_side, block))
+            .collect::<Result<Vec<_>>>()?;
+        let mut block = if blocks.is_empty() {
+            DataBlock::empty()
+        } else {
+            DataBlock::concat(&blocks)?
+        };
+        if self.r_cte.distinct {
+            block = self.distinct(block)?;
+        }
+        if block.is_empty() {
+            return Ok(None);
+        }
+        // Only the recursive steps are counted, and the recursion terminating right
+        // after `max_depth` steps doesn't exceed the limit.
+        if self.iteration - 1 > self.max_depth {
+            return Err(ErrorCode::RecursiveCteDepthExceeded(format!(
+                "Recursive query '{}' exceeded the max recursive depth {}, consider increasing the setting `max_cte_recursive_depth`",
+                self.r_cte.cte_name, self.max_depth
+            )));
+        }
+
+        let state = self.r_cte_state.get(&self.r_cte.cte_idx).unwrap();
+        *state.working_table.write() = vec![block.clone()];
+        Ok(Some(block))
+    }
+}
+
+pub struct RecursiveCteScanSource {
+    state: Arc<RecursiveCteState>,
+    offsets: Vec<usize>,
+}
+
+impl RecursiveCteScanSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        state: Arc<RecursiveCteState>,
+        offsets: Vec<usize>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output_port, RecursiveCteScanSource { state, offsets })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteScanSource {
+    const NAME: &'static str = "RecursiveCteScanSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let block = self.state.working_table.write().pop();
+        match block {
+            Some(b) => {
+                let num_rows = b.num_rows();
+                let columns = self
+                    .offsets
+                    .iter()
+                    .map(|offset| b.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                Ok(Some(DataBlock::new(columns, num_rows)))
+            }
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::SampleConfig;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+
+/// Filter the rows of the scan randomly by `TABLESAMPLE`, for the tables which can't
+/// sample the data by themselves.
+pub struct TransformSample {
+    sample: SampleConfig,
+}
+
+impl TransformSample
+where Self: Transform
+{
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        sample: SampleConfig,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self { sample },
+        )))
+    }
+}
+
+impl Transform for TransformSample {
+    const NAME: &'static str = "SampleTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let bitmap = self.sample.sample_random_rows(block.num_rows());
+        block.filter_with_bitmap(&bitmap)
+    }
+}
@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
+use arrow_array::cast::AsArray;
+use arrow_array::RecordBatch;
+use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Schema;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -26,16 +32,169 @@ use databend_common_expression::DataSchema;
 use databend_common_expression::FunctionContext;
 use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_settings::Settings;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
 
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
 use crate::pipelines::processors::Processor;
+
+/// The embedded interpreter that runs the script UDFs of one language.
+pub enum ScriptRuntime {
+    JavaScript(arrow_udf_js::Runtime),
+    #[cfg(feature = "python-udf")]
+    Python(arrow_udf_python::Runtime),
+}
+
+impl ScriptRuntime {
+    pub fn try_create(language: &str) -> Result<Self> {
+        match language.to_lowercase().as_str() {
+            "javascript" => arrow_udf_js::Runtime::new()
+                .map(ScriptRuntime::JavaScript)
+                .map_err(|err| ErrorCode::UDFDataError(format!("Cannot create js runtime: {err}"))),
+            // The sandboxed runtime only imports a limited set of modules and disables
+            // the builtins like `open` and `eval`.
+            #[cfg(feature = "python-udf")]
+            "python" => arrow_udf_python::Runtime::builder()
+                .sandboxed(true)
+                .build()
+                .map(ScriptRuntime::Python)
+                .map_err(|err| {
+                    ErrorCode::UDFDataError(format!("Cannot create python runtime: {err}"))
+                }),
+            #[cfg(not(feature = "python-udf"))]
+            "python" => Err(ErrorCode::Unimplemented(
+                "Python UDF is not supported, databend-query must be built with the `python-udf` feature",
+            )),
+            _ => Err(ErrorCode::UDFDataError(format!(
+                "Unsupported script UDF language '{language}'"
+            ))),
+        }
+    }
+
+    fn add_function(
+        &mut self,
+        name: &str,
+        return_type: ArrowDataType,
+        func: &ScriptFunction,
+    ) -> Result<()> {
+        match self {
+            ScriptRuntime::JavaScript(runtime) => {
+                if !func.runtime_version.is_empty() {
+                    return Err(ErrorCode::UDFDataError(
+                        "RUNTIME_VERSION is not supported by javascript UDFs",
+                    ));
+                }
+                runtime
+                    .add_function_with_handler(
+                        name,
+                        return_type,
+                        arrow_udf_js::CallMode::ReturnNullOnNullInput,
+                        func.code,
+                        func.handler,
+                    )
+                    .map_err(|err| {
+                        ErrorCode::UDFDataError(format!("Cannot add js function: {err}"))
+                    })
+            }
+            #[cfg(feature = "python-udf")]
+            ScriptRuntime::Python(runtime) => {
+                let code = python_function_code(func)?;
+                runtime
+                    .add_function_with_handler(
+                        name,
+                        return_type,
+                        arrow_udf_python::CallMode::ReturnNullOnNullInput,
+                        &code,
+                        func.handler,
+                    )
+                    .map_err(|err| {
+                        ErrorCode::UDFDataError(format!("Cannot add python function: {err}"))
+                    })
+            }
+        }
+    }
+
+    fn call(&self, name: &str, input: &RecordBatch) -> Result<RecordBatch> {
+        let result = match self {
+            ScriptRuntime::JavaScript(runtime) => runtime.call(name, input),
+            #[cfg(feature = "python-udf")]
+            ScriptRuntime::Python(runtime) => runtime.call(name, input),
+        };
+        result.map_err(|err| ErrorCode::UDFDataError(format!("{err:#}")))
+    }
+}
+
+/// The definition of a script UDF.
+struct ScriptFunction<'a> {
+    code: &'a str,
+    handler: &'a str,
+    runtime_version: &'a str,
+}
+
+/// Appends the check of the runtime version to the code of a python UDF,
+/// the UDF fails to be added if the embedded interpreter is of another version.
+#[cfg(feature = "python-udf")]
+fn python_function_code(func: &ScriptFunction) -> Result<String> {
+    if func.runtime_version.is_empty() {
+        return Ok(func.code.to_string());
+    }
+    let version = func
+        .runtime_version
+        .split('.')
+        .take(2)
+        .map(|v| v.parse::<u32>())
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .ok()
+        .filter(|v| v.len() == 2)
+        .ok_or_else(|| {
+            ErrorCode::UDFDataError(format!(
+                "Invalid python runtime version '{}'",
+                func.runtime_version
+            ))
+        })?;
+    Ok(format!(
+        r#"{code}
+
+import sys as __databend_sys__
+if __databend_sys__.version_info[:2] != ({major}, {minor}):
+    raise RuntimeError(
+        "Python runtime version {runtime_version} is not available, the embedded interpreter is "
+        f"{{__databend_sys__.version_info[0]}}.{{__databend_sys__.version_info[1]}}"
+    )
+"#,
+        code = func.code,
+        major = version[0],
+        minor = version[1],
+        runtime_version = func.runtime_version,
+    ))
+}
+
+/// The runtimes return the errors of the rows in the `error` column.
+fn check_row_errors(func_name: &str, batch: &RecordBatch) -> Result<()> {
+    if batch.num_columns() < 2 {
+        return Ok(());
+    }
+    let errors = batch
+        .column_by_name("error")
+        .and_then(|column| column.as_string_opt::<i32>());
+    if let Some(error) = errors.and_then(|errors| errors.iter().flatten().next()) {
+        return Err(ErrorCode::UDFDataError(format!(
+            "Script UDF '{func_name}' failed: {error}"
+        )));
+    }
+    Ok(())
+}
+
 pub struct TransformUdfScript {
     funcs: Vec<UdfFunctionDesc>,
-    js_runtime: Arc<arrow_udf_js::Runtime>,
-    // TODO:
-    // py_runtime: Arc<arrow_udf_python::Runtime>,
+    // Runtimes by the lowercase language name.
+    runtimes: HashMap<String, ScriptRuntime>,
+    // The time budget of the script UDFs, `None` means no limit.
+    timeout: Option<Duration>,
+    elapsed: Duration,
+    // The max memory of a batch returned by a script UDF, `None` means no limit.
+    memory_limit: Option<usize>,
 }
 
 unsafe impl Send for TransformUdfScript {}
@@ -43,33 +202,50 @@ unsafe impl Send for TransformUdfScript {}
 impl TransformUdfScript {
     pub fn try_create(
         _func_ctx: FunctionContext,
+        settings: Arc<Settings>,
         funcs: Vec<UdfFunctionDesc>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<Box<dyn Processor>> {
-        let mut js_runtime = arrow_udf_js::Runtime::new()
-            .map_err(|err| ErrorCode::UDFDataError(format!("Cannot create js runtime: {err}")))?;
+        let timeout = match settings.get_script_udf_timeout_ms()? {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        };
+        let memory_limit = match settings.get_script_udf_memory_limit()? {
+            0 => None,
+            bytes => Some(bytes as usize),
+        };
 
+        let mut runtimes = HashMap::new();
         for func in funcs.iter() {
             let tmp_schema =
                 DataSchema::new(vec![DataField::new("tmp", func.data_type.as_ref().clone())]);
             let arrow_schema = Schema::from(&tmp_schema);
 
-            let (_, _, code) = func.udf_type.as_script().unwrap();
-            js_runtime
-                .add_function_with_handler(
-                    &func.name,
-                    arrow_schema.field(0).data_type().clone(),
-                    arrow_udf_js::CallMode::ReturnNullOnNullInput,
-                    code,
-                    &func.func_name,
-                )
-                .map_err(|err| ErrorCode::UDFDataError(format!("Cannot add js function: {err}")))?;
+            let (language, runtime_version, code) = func.udf_type.as_script().unwrap();
+            let language = language.to_lowercase();
+            if !runtimes.contains_key(&language) {
+                let runtime = ScriptRuntime::try_create(&language)?;
+                runtimes.insert(language.clone(), runtime);
+            }
+            let script = ScriptFunction {
+                code,
+                handler: &func.func_name,
+                runtime_version,
+            };
+            runtimes.get_mut(&language).unwrap().add_function(
+                &func.name,
+                arrow_schema.field(0).data_type().clone(),
+                &script,
+            )?;
         }
 
         Ok(Transformer::create(input, output, Self {
             funcs,
-            js_runtime: Arc::new(js_runtime),
+            runtimes,
+            timeout,
+            elapsed: Duration::ZERO,
+            memory_limit,
         }))
     }
 }
@@ -109,10 +285,37 @@ impl Transform for TransformUdfScript {
                 .to_record_batch_with_dataschema(&data_schema)
                 .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
 
-            let result_batch = self
-                .js_runtime
-                .call(&func.name, &input_batch)
-                .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
+            let (language, _, _) = func.udf_type.as_script().unwrap();
+            let runtime = self.runtimes.get(&language.to_lowercase()).unwrap();
+            let start = Instant::now();
+            let result = runtime.call(&func.name, &input_batch);
+            self.elapsed += start.elapsed();
+            if let Some(timeout) = self.timeout {
+                if self.elapsed >= timeout {
+                    return Err(ErrorCode::UDFDataError(format!(
+                        "Script UDF '{}' exceeded the time limit {}ms, consider increasing the setting `script_udf_timeout_ms`",
+                        func.func_name,
+                        timeout.as_millis()
+                    )));
+                }
+            }
+            let result_batch = result.map_err(|err| {
+                ErrorCode::UDFDataError(format!(
+                    "Script UDF '{}' failed: {}",
+                    func.func_name,
+                    err.message()
+                ))
+            })?;
+            check_row_errors(&func.func_name, &result_batch)?;
+            if let Some(memory_limit) = self.memory_limit {
+                let memory_size = result_batch.get_array_memory_size();
+                if memory_size > memory_limit {
+                    return Err(ErrorCode::UDFDataError(format!(
+                        "Script UDF '{}' returned {memory_size} bytes, exceeded the memory limit {memory_limit} bytes, consider increasing the setting `script_udf_memory_limit`",
+                        func.func_name
+                    )));
+                }
+            }
 
             let schema = DataSchema::try_from(&(*result_batch.schema()))?;
             let (result_block, _result_schema) =
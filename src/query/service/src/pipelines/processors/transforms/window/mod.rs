@@ -14,8 +14,10 @@
 
 mod frame_bound;
 mod transform_window;
+mod transform_window_partition_spill;
 mod window_function;
 
 pub use frame_bound::FrameBound;
 pub use transform_window::TransformWindow;
+pub use transform_window_partition_spill::TransformWindowPartitionSpill;
 pub use window_function::WindowFunctionInfo;
@@ -0,0 +1,244 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::DataBlock;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use log::info;
+
+use crate::spillers::Spiller;
+
+enum State {
+    /// Collecting the input blocks into the window partitions.
+    Collect,
+    /// Spilling all the window partitions in memory.
+    Spill,
+    /// Restoring the window partitions one by one, and output them sorted.
+    Restore,
+}
+
+/// Hash-partition the input of the window functions by the `PARTITION BY` columns,
+/// so only one window partition needs to be in memory at a time.
+///
+/// All the rows of a `PARTITION BY` key are in the same window partition, the window
+/// partitions are output one by one, sorted by the partition and order by columns.
+/// The window partitions are spilled when the memory usage exceeds the limits.
+pub struct TransformWindowPartitionSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    state: State,
+
+    partition_by: Vec<usize>,
+    sort_desc: Vec<SortColumnDescription>,
+    max_block_size: usize,
+    max_memory_usage: usize,
+    spilling_bytes_threshold: usize,
+    spiller: Spiller,
+
+    input_data: Option<DataBlock>,
+    /// The blocks of each window partition in memory.
+    partitions: Vec<Vec<DataBlock>>,
+    buffered_bytes: usize,
+    next_restore_partition: usize,
+    output_data: VecDeque<DataBlock>,
+}
+
+impl TransformWindowPartitionSpill {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        spiller: Spiller,
+        partition_by: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        num_partitions: usize,
+        max_block_size: usize,
+        max_memory_usage: usize,
+        spilling_bytes_threshold: usize,
+    ) -> Box<dyn Processor> {
+        debug_assert!((1..=256).contains(&num_partitions));
+        Box::new(TransformWindowPartitionSpill {
+            input,
+            output,
+            state: State::Collect,
+            partition_by,
+            sort_desc,
+            max_block_size,
+            max_memory_usage,
+            spilling_bytes_threshold,
+            spiller,
+            input_data: None,
+            partitions: vec![vec![]; num_partitions],
+            buffered_bytes: 0,
+            next_restore_partition: 0,
+            output_data: VecDeque::new(),
+        })
+    }
+
+    fn need_spill(&self) -> bool {
+        (self.spilling_bytes_threshold != 0 && self.buffered_bytes >= self.spilling_bytes_threshold)
+            || (self.max_memory_usage != 0
+                && GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.max_memory_usage)
+    }
+
+    fn collect(&mut self, block: DataBlock) -> Result<()> {
+        let block = block.convert_to_full();
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        let columns = self
+            .partition_by
+            .iter()
+            .map(|i| block.get_by_offset(*i).value.as_column().unwrap().clone())
+            .collect::<Vec<_>>();
+        let mut hashes = vec![0; num_rows];
+        group_hash_columns(&columns, &mut hashes);
+
+        let num_partitions = self.partitions.len() as u64;
+        let indices = hashes
+            .iter()
+            .map(|hash| (*hash % num_partitions) as u16)
+            .collect::<Vec<_>>();
+        let blocks = block.scatter(&indices, self.partitions.len())?;
+        for (partition, block) in self.partitions.iter_mut().zip(blocks) {
+            if !block.is_empty() {
+                self.buffered_bytes += block.memory_size();
+                partition.push(block);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionSpill {
+    fn name(&self) -> String {
+        "TransformWindowPartitionSpill".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            State::Collect => {
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+                if self.input.has_data() {
+                    self.input_data = Some(self.input.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+                if self.input.is_finished() {
+                    if !self.spiller.partition_location.is_empty() {
+                        info!(
+                            "Window partition spill finished: \n{}",
+                            self.spiller.format_spill_info()
+                        );
+                    }
+                    self.state = State::Restore;
+                    return Ok(Event::Async);
+                }
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::Spill => Ok(Event::Async),
+            State::Restore => {
+                if self.next_restore_partition < self.partitions.len() {
+                    return Ok(Event::Async);
+                }
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            self.collect(block)?;
+            if self.need_spill() {
+                self.state = State::Spill;
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.state {
+            State::Spill => {
+                for (partition_id, partition) in self.partitions.iter_mut().enumerate() {
+                    if partition.is_empty() {
+                        continue;
+                    }
+                    let block = DataBlock::concat(&std::mem::take(partition))?;
+                    self.spiller
+                        .spill_with_partition(partition_id as u8, block)
+                        .await?;
+                }
+                self.buffered_bytes = 0;
+                self.state = State::Collect;
+            }
+            State::Restore => {
+                let partition_id = self.next_restore_partition;
+                self.next_restore_partition += 1;
+
+                let mut blocks = std::mem::take(&mut self.partitions[partition_id]);
+                let partition_id = partition_id as u8;
+                if self.spiller.partition_location.contains_key(&partition_id) {
+                    blocks.extend(self.spiller.read_spilled_partition(&partition_id).await?);
+                }
+                if blocks.is_empty() {
+                    return Ok(());
+                }
+
+                let block = DataBlock::concat(&blocks)?;
+                drop(blocks);
+                let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+                self.output_data
+                    .extend(block.split_by_rows_no_tail(self.max_block_size));
+            }
+            State::Collect => unreachable!(),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio::time::interval;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::ListPipesReq;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_types::NonEmptyString;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_txn::TxnManager;
+use futures_util::StreamExt;
+use log::info;
+use log::warn;
+
+use crate::interpreters::InterpreterFactory;
+use crate::locks::LockManager;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// Loads the newly arrived files of the pipes with `AUTO_INGEST = TRUE`.
+///
+/// Every `pipe_auto_ingest_interval_secs` the pipes are refreshed with
+/// `ALTER PIPE <name> REFRESH` as the user who created the pipe, with the role
+/// owning the pipe as the current role. The refresh holds the lock of the target
+/// table, so the nodes of the cluster refresh a pipe one after another and the
+/// later ones skip the files loaded by the earlier ones.
+pub struct PipeAutoIngestService;
+
+impl PipeAutoIngestService {
+    pub fn start(conf: &InnerConfig) {
+        let interval_secs = conf.query.pipe_auto_ingest_interval_secs;
+        if interval_secs == 0 {
+            return;
+        }
+
+        let tenant = conf.query.tenant_id.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            let mut ticker = interval(Duration::from_secs(interval_secs));
+            loop {
+                ticker.tick().await;
+                if let Err(cause) = Self::ingest_pipes(&tenant).await {
+                    warn!("Failed to list the pipes to auto ingest: {:?}", cause);
+                }
+            }
+        });
+        info!("Pipe auto ingestion started, interval: {}s", interval_secs);
+    }
+
+    #[async_backtrace::framed]
+    async fn ingest_pipes(tenant: &NonEmptyString) -> Result<()> {
+        let catalog = CatalogManager::instance().get_default_catalog(TxnManager::init())?;
+        let pipes = catalog
+            .list_pipes(ListPipesReq::new(tenant.as_str()))
+            .await?;
+        for pipe in pipes
+            .iter()
+            .filter(|pipe| pipe.auto_ingest && !pipe.execution_paused)
+        {
+            if let Err(cause) = Self::refresh_pipe(tenant, pipe).await {
+                warn!("Failed to auto ingest pipe '{}': {:?}", pipe.name, cause);
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn refresh_pipe(tenant: &NonEmptyString, pipe: &PipeMeta) -> Result<()> {
+        let (Some(owner), Some(creator)) = (&pipe.owner, &pipe.creator) else {
+            return Err(ErrorCode::PermissionDenied(format!(
+                "Pipe '{}' has no owner, recreate it to auto ingest",
+                pipe.name
+            )));
+        };
+        let user = UserApiProvider::instance()
+            .get_user(tenant, creator.clone())
+            .await?;
+
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_authed_user(user, None).await?;
+        // Fails if the role owning the pipe is no longer granted to the creator.
+        session.set_current_role_checked(owner).await?;
+        let ctx = session.create_query_context().await?;
+
+        // Serialize the refreshes of the nodes by the lock of the target table.
+        ctx.get_settings()
+            .set_setting("enable_table_lock".to_string(), "1".to_string())?;
+        let table_lock = {
+            let (catalog, database, table) = Self::target_table(&ctx, pipe)?;
+            let table = ctx.get_table(&catalog, &database, &table).await?;
+            LockManager::create_table_lock(table.get_table_info().clone())?
+        };
+        let _guard = table_lock.try_lock(ctx.clone()).await?;
+
+        let sql = format!("ALTER PIPE {} REFRESH", pipe.name);
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let mut stream = interpreter.execute(ctx).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    /// The table loaded by the pipe, its name is fully qualified when the pipe is created.
+    fn target_table(ctx: &QueryContext, pipe: &PipeMeta) -> Result<(String, String, String)> {
+        let tokens = tokenize_sql(&pipe.copy_stmt)?;
+        let (stmt, _) = parse_sql(&tokens, ctx.get_settings().get_sql_dialect()?)?;
+        let Statement::CopyIntoTable(copy_stmt) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Pipe '{}' has an invalid copy statement: {}",
+                pipe.name, pipe.copy_stmt
+            )));
+        };
+        let dst = copy_stmt.dst;
+        let catalog = dst
+            .catalog
+            .map_or_else(|| ctx.get_current_catalog(), |catalog| catalog.name);
+        let database = dst
+            .database
+            .map_or_else(|| ctx.get_current_database(), |database| database.name);
+        Ok((catalog, database, dst.table.name))
+    }
+}
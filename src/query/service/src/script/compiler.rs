@@ -0,0 +1,442 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ScriptStatement;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::Span;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// A placeholder for the target of a jump that is not known yet.
+const UNRESOLVED: usize = usize::MAX;
+
+/// The instructions of a compiled script, control flow is lowered to jumps
+/// between the indexes of the instructions.
+#[derive(Debug, Clone)]
+pub enum ScriptIR {
+    /// Evaluates `value` and binds it to the variable `name`,
+    /// the variable must have been declared if `declare` is false.
+    Assign {
+        span: Span,
+        name: String,
+        value: Expr,
+        declare: bool,
+    },
+    /// Runs the query and binds its result to the result set `name`.
+    LetQuery {
+        span: Span,
+        name: String,
+        query: Statement,
+    },
+    /// Runs a SQL statement and discards its result.
+    Execute {
+        span: Span,
+        stmt: Statement,
+    },
+    /// Starts a new iteration over the rows of the result set.
+    IterStart {
+        span: Span,
+        iter: usize,
+        resultset: String,
+    },
+    /// Binds the next row of the iterator to `variable`,
+    /// jumps to `end` if all rows have been consumed.
+    IterNext {
+        iter: usize,
+        variable: String,
+        end: usize,
+    },
+    /// Jumps to `to` if `condition` does not evaluate to true.
+    JumpIfFalse {
+        span: Span,
+        condition: Expr,
+        to: usize,
+    },
+    Jump {
+        to: usize,
+    },
+    Return {
+        span: Span,
+        value: Option<Expr>,
+    },
+}
+
+struct LoopContext {
+    label: Option<String>,
+    break_jumps: Vec<usize>,
+    continue_jumps: Vec<usize>,
+}
+
+/// Compiles the statements of a script into a flat list of [`ScriptIR`].
+pub fn compile(script: &[ScriptStatement]) -> Result<Vec<ScriptIR>> {
+    let mut compiler = Compiler::default();
+    compiler.compile_block(script)?;
+    Ok(compiler.code)
+}
+
+/// Variables are case-insensitive unless they are quoted.
+pub fn normalize_identifier(ident: &Identifier) -> String {
+    if ident.is_quoted() {
+        ident.name.clone()
+    } else {
+        ident.name.to_lowercase()
+    }
+}
+
+#[derive(Default)]
+struct Compiler {
+    code: Vec<ScriptIR>,
+    loops: Vec<LoopContext>,
+    next_iter: usize,
+    next_hidden_var: usize,
+}
+
+impl Compiler {
+    fn compile_block(&mut self, block: &[ScriptStatement]) -> Result<()> {
+        for stmt in block {
+            self.compile_stmt(stmt)?;
+        }
+        Ok(())
+    }
+
+    fn compile_stmt(&mut self, stmt: &ScriptStatement) -> Result<()> {
+        match stmt {
+            ScriptStatement::LetVar { span, declare } => {
+                let value = match &declare.data_type {
+                    Some(data_type) => Expr::Cast {
+                        span: *span,
+                        expr: Box::new(declare.default.clone()),
+                        target_type: data_type.clone(),
+                        pg_style: false,
+                    },
+                    None => declare.default.clone(),
+                };
+                self.emit(ScriptIR::Assign {
+                    span: *span,
+                    name: normalize_identifier(&declare.name),
+                    value,
+                    declare: true,
+                });
+            }
+            ScriptStatement::LetQuery { span, declare } => {
+                self.emit(ScriptIR::LetQuery {
+                    span: *span,
+                    name: normalize_identifier(&declare.name),
+                    query: Statement::Query(Box::new(declare.query.clone())),
+                });
+            }
+            ScriptStatement::Assign { span, name, value } => {
+                self.emit(ScriptIR::Assign {
+                    span: *span,
+                    name: normalize_identifier(name),
+                    value: value.clone(),
+                    declare: false,
+                });
+            }
+            ScriptStatement::Return { span, value } => {
+                self.emit(ScriptIR::Return {
+                    span: *span,
+                    value: value.clone(),
+                });
+            }
+            ScriptStatement::ForLoop {
+                span,
+                variable,
+                is_reverse,
+                lower_bound,
+                upper_bound,
+                body,
+                label,
+            } => {
+                let variable = normalize_identifier(variable);
+                let end_variable = self.hidden_var("for_end");
+                let (start, end, cmp_op, step_op) = if *is_reverse {
+                    (
+                        upper_bound,
+                        lower_bound,
+                        BinaryOperator::Gte,
+                        BinaryOperator::Minus,
+                    )
+                } else {
+                    (
+                        lower_bound,
+                        upper_bound,
+                        BinaryOperator::Lte,
+                        BinaryOperator::Plus,
+                    )
+                };
+                self.emit(ScriptIR::Assign {
+                    span: *span,
+                    name: variable.clone(),
+                    value: start.clone(),
+                    declare: true,
+                });
+                self.emit(ScriptIR::Assign {
+                    span: *span,
+                    name: end_variable.clone(),
+                    value: end.clone(),
+                    declare: true,
+                });
+
+                let check = self.emit(ScriptIR::JumpIfFalse {
+                    span: *span,
+                    condition: binary_op(
+                        cmp_op,
+                        var_ref(*span, &variable),
+                        var_ref(*span, &end_variable),
+                    ),
+                    to: UNRESOLVED,
+                });
+                self.enter_loop(label);
+                self.compile_block(body)?;
+                let step = self.emit(ScriptIR::Assign {
+                    span: *span,
+                    name: variable.clone(),
+                    value: binary_op(step_op, var_ref(*span, &variable), Expr::Literal {
+                        span: *span,
+                        lit: Literal::UInt64(1),
+                    }),
+                    declare: false,
+                });
+                self.emit(ScriptIR::Jump { to: check });
+                let end = self.code.len();
+                self.patch(check, end);
+                self.exit_loop(step, end);
+            }
+            ScriptStatement::ForIn {
+                span,
+                variable,
+                resultset,
+                body,
+                label,
+            } => {
+                let iter = self.next_iter;
+                self.next_iter += 1;
+                self.emit(ScriptIR::IterStart {
+                    span: *span,
+                    iter,
+                    resultset: normalize_identifier(resultset),
+                });
+                let next = self.emit(ScriptIR::IterNext {
+                    iter,
+                    variable: normalize_identifier(variable),
+                    end: UNRESOLVED,
+                });
+                self.enter_loop(label);
+                self.compile_block(body)?;
+                self.emit(ScriptIR::Jump { to: next });
+                let end = self.code.len();
+                self.patch(next, end);
+                self.exit_loop(next, end);
+            }
+            ScriptStatement::WhileLoop {
+                span,
+                condition,
+                body,
+                label,
+            } => {
+                let check = self.emit(ScriptIR::JumpIfFalse {
+                    span: *span,
+                    condition: condition.clone(),
+                    to: UNRESOLVED,
+                });
+                self.enter_loop(label);
+                self.compile_block(body)?;
+                self.emit(ScriptIR::Jump { to: check });
+                let end = self.code.len();
+                self.patch(check, end);
+                self.exit_loop(check, end);
+            }
+            ScriptStatement::RepeatLoop {
+                span,
+                body,
+                until_condition,
+                label,
+            } => {
+                let start = self.code.len();
+                self.enter_loop(label);
+                self.compile_block(body)?;
+                // Loop again until the condition becomes true.
+                let check = self.emit(ScriptIR::JumpIfFalse {
+                    span: *span,
+                    condition: until_condition.clone(),
+                    to: start,
+                });
+                let end = self.code.len();
+                self.exit_loop(check, end);
+            }
+            ScriptStatement::Loop { body, label, .. } => {
+                let start = self.code.len();
+                self.enter_loop(label);
+                self.compile_block(body)?;
+                self.emit(ScriptIR::Jump { to: start });
+                let end = self.code.len();
+                self.exit_loop(start, end);
+            }
+            ScriptStatement::Break { span, label } => {
+                let jump = self.emit(ScriptIR::Jump { to: UNRESOLVED });
+                self.find_loop(*span, label)?.break_jumps.push(jump);
+            }
+            ScriptStatement::Continue { span, label } => {
+                let jump = self.emit(ScriptIR::Jump { to: UNRESOLVED });
+                self.find_loop(*span, label)?.continue_jumps.push(jump);
+            }
+            ScriptStatement::Case {
+                span,
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => {
+                let conditions = conditions
+                    .iter()
+                    .map(|condition| match operand {
+                        Some(operand) => {
+                            binary_op(BinaryOperator::Eq, operand.clone(), condition.clone())
+                        }
+                        None => condition.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                self.compile_if(*span, &conditions, results, else_result)?;
+            }
+            ScriptStatement::If {
+                span,
+                conditions,
+                results,
+                else_result,
+            } => {
+                self.compile_if(*span, conditions, results, else_result)?;
+            }
+            ScriptStatement::SQLStatement { span, stmt } => {
+                self.emit(ScriptIR::Execute {
+                    span: *span,
+                    stmt: stmt.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_if(
+        &mut self,
+        span: Span,
+        conditions: &[Expr],
+        results: &[Vec<ScriptStatement>],
+        else_result: &Option<Vec<ScriptStatement>>,
+    ) -> Result<()> {
+        let mut end_jumps = Vec::with_capacity(conditions.len());
+        for (condition, result) in conditions.iter().zip(results.iter()) {
+            let check = self.emit(ScriptIR::JumpIfFalse {
+                span,
+                condition: condition.clone(),
+                to: UNRESOLVED,
+            });
+            self.compile_block(result)?;
+            end_jumps.push(self.emit(ScriptIR::Jump { to: UNRESOLVED }));
+            let next = self.code.len();
+            self.patch(check, next);
+        }
+        if let Some(else_result) = else_result {
+            self.compile_block(else_result)?;
+        }
+        let end = self.code.len();
+        for jump in end_jumps {
+            self.patch(jump, end);
+        }
+        Ok(())
+    }
+
+    fn emit(&mut self, ir: ScriptIR) -> usize {
+        self.code.push(ir);
+        self.code.len() - 1
+    }
+
+    fn patch(&mut self, pos: usize, target: usize) {
+        match &mut self.code[pos] {
+            ScriptIR::Jump { to } | ScriptIR::JumpIfFalse { to, .. } => *to = target,
+            ScriptIR::IterNext { end, .. } => *end = target,
+            _ => unreachable!("instruction {pos} is not a jump"),
+        }
+    }
+
+    fn hidden_var(&mut self, prefix: &str) -> String {
+        let name = format!("__{prefix}_{}", self.next_hidden_var);
+        self.next_hidden_var += 1;
+        name
+    }
+
+    fn enter_loop(&mut self, label: &Option<Identifier>) {
+        self.loops.push(LoopContext {
+            label: label.as_ref().map(normalize_identifier),
+            break_jumps: vec![],
+            continue_jumps: vec![],
+        });
+    }
+
+    fn exit_loop(&mut self, continue_target: usize, break_target: usize) {
+        let ctx = self.loops.pop().unwrap();
+        for jump in ctx.continue_jumps {
+            self.patch(jump, continue_target);
+        }
+        for jump in ctx.break_jumps {
+            self.patch(jump, break_target);
+        }
+    }
+
+    fn find_loop(&mut self, span: Span, label: &Option<Identifier>) -> Result<&mut LoopContext> {
+        match label {
+            Some(label) => {
+                let name = normalize_identifier(label);
+                self.loops
+                    .iter_mut()
+                    .rev()
+                    .find(|ctx| ctx.label.as_ref() == Some(&name))
+                    .ok_or_else(|| {
+                        ErrorCode::ScriptSemanticError(format!("`{name}` is not defined"))
+                            .set_span(span)
+                    })
+            }
+            None => self.loops.last_mut().ok_or_else(|| {
+                ErrorCode::ScriptSemanticError("not in a loop".to_string()).set_span(span)
+            }),
+        }
+    }
+}
+
+fn var_ref(span: Span, name: &str) -> Expr {
+    Expr::ColumnRef {
+        span,
+        column: ColumnRef {
+            database: None,
+            table: None,
+            // Quoted to keep the normalized name unchanged.
+            column: ColumnID::Name(Identifier::from_name_with_quoted(span, name, Some('"'))),
+        },
+    }
+}
+
+fn binary_op(op: BinaryOperator, left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOp {
+        span: None,
+        op,
+        left: Box::new(left),
+        right: Box::new(right),
+    }
+}
@@ -0,0 +1,541 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TypeName;
+use databend_common_ast::ast::UnaryOperator;
+use databend_common_ast::Span;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::box_render;
+use databend_common_expression::types::date::date_to_string;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+use ethnum::i256;
+use futures::TryStreamExt;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::script::compiler::normalize_identifier;
+use crate::script::compiler::ScriptIR;
+use crate::sessions::QueryContext;
+
+/// The rows returned by a query in the script.
+pub struct ResultSet {
+    pub schema: DataSchemaRef,
+    pub block: DataBlock,
+}
+
+pub enum ReturnValue {
+    Var(Scalar),
+    Set(Arc<ResultSet>),
+}
+
+impl ReturnValue {
+    /// Renders the value as the text returned by the script.
+    pub fn to_text(&self, tz: Tz) -> Result<Option<String>> {
+        match self {
+            ReturnValue::Var(scalar) => Ok(scalar_to_text(scalar, tz)),
+            ReturnValue::Set(set) => Ok(Some(box_render(
+                &set.schema,
+                &[set.block.clone()],
+                usize::MAX,
+                usize::MAX,
+                usize::MAX,
+                false,
+            )?)),
+        }
+    }
+}
+
+struct RowCursor {
+    resultset: Arc<ResultSet>,
+    row: usize,
+}
+
+/// Runs the instructions of a compiled script, each SQL statement is planned
+/// and executed in the context of the script.
+pub struct ScriptExecutor {
+    ctx: Arc<QueryContext>,
+    code: Vec<ScriptIR>,
+    max_steps: u64,
+    tz: Tz,
+
+    vars: HashMap<String, Scalar>,
+    resultsets: HashMap<String, Arc<ResultSet>>,
+    // The next row of each running `FOR ... IN <resultset>` loop.
+    iters: HashMap<usize, RowCursor>,
+    // The rows bound to the loop variables of `FOR ... IN <resultset>`.
+    rows: HashMap<String, RowCursor>,
+}
+
+impl ScriptExecutor {
+    pub fn try_create(ctx: Arc<QueryContext>, code: Vec<ScriptIR>) -> Result<Self> {
+        let max_steps = ctx.get_settings().get_script_max_steps()?;
+        let tz = ctx.get_function_context()?.tz.tz;
+        Ok(ScriptExecutor {
+            ctx,
+            code,
+            max_steps,
+            tz,
+            vars: HashMap::new(),
+            resultsets: HashMap::new(),
+            iters: HashMap::new(),
+            rows: HashMap::new(),
+        })
+    }
+
+    pub fn tz(&self) -> Tz {
+        self.tz
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(&mut self) -> Result<Option<ReturnValue>> {
+        let mut pc = 0;
+        let mut steps = 0;
+        while pc < self.code.len() {
+            self.ctx.check_aborting()?;
+            steps += 1;
+            if steps > self.max_steps {
+                return Err(ErrorCode::ScriptExecutionError(format!(
+                    "Script exceeded the max steps {}, consider increasing the setting `script_max_steps`",
+                    self.max_steps
+                )));
+            }
+
+            let ir = self.code[pc].clone();
+            pc += 1;
+            match ir {
+                ScriptIR::Assign {
+                    span,
+                    name,
+                    value,
+                    declare,
+                } => {
+                    if !declare && !self.vars.contains_key(&name) {
+                        return Err(ErrorCode::ScriptSemanticError(format!(
+                            "variable `{name}` is not defined"
+                        ))
+                        .set_span(span));
+                    }
+                    let value = self.eval(span, value).await?;
+                    self.vars.insert(name, value);
+                }
+                ScriptIR::LetQuery { span, name, query } => {
+                    let (schema, block) = self.run_sql(span, query, false).await?;
+                    self.resultsets
+                        .insert(name, Arc::new(ResultSet { schema, block }));
+                }
+                ScriptIR::Execute { span, stmt } => {
+                    self.run_sql(span, stmt, false).await?;
+                }
+                ScriptIR::IterStart {
+                    span,
+                    iter,
+                    resultset,
+                } => {
+                    let resultset = self.resultsets.get(&resultset).cloned().ok_or_else(|| {
+                        ErrorCode::ScriptSemanticError(format!(
+                            "resultset `{resultset}` is not defined"
+                        ))
+                        .set_span(span)
+                    })?;
+                    self.iters.insert(iter, RowCursor { resultset, row: 0 });
+                }
+                ScriptIR::IterNext {
+                    iter,
+                    variable,
+                    end,
+                } => {
+                    let cursor = self.iters.get_mut(&iter).unwrap();
+                    if cursor.row >= cursor.resultset.block.num_rows() {
+                        self.iters.remove(&iter);
+                        pc = end;
+                    } else {
+                        self.rows.insert(variable, RowCursor {
+                            resultset: cursor.resultset.clone(),
+                            row: cursor.row,
+                        });
+                        cursor.row += 1;
+                    }
+                }
+                ScriptIR::JumpIfFalse {
+                    span,
+                    condition,
+                    to,
+                } => {
+                    let value = self.eval(span, condition).await?;
+                    if !matches!(value, Scalar::Boolean(true)) {
+                        pc = to;
+                    }
+                }
+                ScriptIR::Jump { to } => {
+                    pc = to;
+                }
+                ScriptIR::Return { span, value } => {
+                    return match value {
+                        Some(value) => self.eval_return(span, value).await.map(Some),
+                        None => Ok(None),
+                    };
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn eval_return(&mut self, span: Span, value: Expr) -> Result<ReturnValue> {
+        // `RETURN <resultset>` or `RETURN TABLE(<resultset>)`.
+        let name = match &value {
+            Expr::FunctionCall {
+                func: FunctionCall { name, args, .. },
+                ..
+            } if name.name.eq_ignore_ascii_case("table") && args.len() == 1 => {
+                column_name(&args[0])
+            }
+            expr => column_name(expr),
+        };
+        if let Some(resultset) = name.and_then(|name| self.resultsets.get(&name)) {
+            return Ok(ReturnValue::Set(resultset.clone()));
+        }
+
+        Ok(ReturnValue::Var(self.eval(span, value).await?))
+    }
+
+    /// Evaluates the expression by running `SELECT <expr>`.
+    async fn eval(&mut self, span: Span, expr: Expr) -> Result<Scalar> {
+        let stmt = Statement::Query(Box::new(Query {
+            span,
+            with: None,
+            body: SetExpr::Select(Box::new(SelectStmt {
+                span,
+                hints: None,
+                distinct: false,
+                select_list: vec![SelectTarget::AliasedExpr {
+                    expr: Box::new(expr),
+                    alias: None,
+                }],
+                from: vec![],
+                selection: None,
+                group_by: None,
+                having: None,
+                window_list: None,
+                qualify: None,
+            })),
+            order_by: vec![],
+            limit: vec![],
+            offset: None,
+            ignore_result: false,
+        }));
+        let (_, block) = self.run_sql(span, stmt, true).await?;
+        if block.num_rows() != 1 || block.num_columns() != 1 {
+            return Err(ErrorCode::ScriptExecutionError(
+                "expression must return exactly one value",
+            )
+            .set_span(span));
+        }
+        Ok(block.get_by_offset(0).value.index(0).unwrap().to_owned())
+    }
+
+    async fn run_sql(
+        &mut self,
+        span: Span,
+        mut stmt: Statement,
+        is_expr: bool,
+    ) -> Result<(DataSchemaRef, DataBlock)> {
+        let mut replacer = VariableReplacer {
+            executor: self,
+            is_expr,
+            error: None,
+        };
+        stmt.drive_mut(&mut replacer);
+        if let Some(e) = replacer.error {
+            return Err(e);
+        }
+
+        // The statement may have changed the tables used by the next ones.
+        self.ctx.clear_tables_cache();
+        let sql = stmt.to_string();
+        let (plan, _) = interpreter_plan_sql(self.ctx.clone(), &sql)
+            .await
+            .map_err(|e| e.set_span(span))?;
+        let schema = plan.schema();
+        let interpreter = InterpreterFactory::get(self.ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(self.ctx.clone()).await?;
+        let blocks = stream
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>();
+        let block = if blocks.is_empty() {
+            DataBlock::empty_with_schema(schema.clone())
+        } else {
+            DataBlock::concat(&blocks)?
+        };
+        Ok((schema, block))
+    }
+
+    fn var_to_expr(&self, span: Span, name: &str) -> Result<Expr> {
+        match self.vars.get(name) {
+            Some(value) => scalar_to_expr(span, value, self.tz),
+            None => Err(ErrorCode::ScriptSemanticError(format!(
+                "variable `{name}` is not defined"
+            ))
+            .set_span(span)),
+        }
+    }
+
+    fn row_field_to_expr(&self, span: Span, cursor: &RowCursor, field: &str) -> Result<Expr> {
+        let schema = &cursor.resultset.schema;
+        let offset = schema
+            .fields()
+            .iter()
+            .position(|f| f.name().eq_ignore_ascii_case(field))
+            .ok_or_else(|| {
+                ErrorCode::ScriptSemanticError(format!("column `{field}` is not found"))
+                    .set_span(span)
+            })?;
+        let value = cursor
+            .resultset
+            .block
+            .get_by_offset(offset)
+            .value
+            .index(cursor.row)
+            .unwrap()
+            .to_owned();
+        scalar_to_expr(span, &value, self.tz)
+    }
+}
+
+/// Replaces the references to the script variables with their values.
+///
+/// `:var` and `IDENTIFIER(:var)` are replaced in all statements, plain
+/// `var` and `row.column` are only replaced in script expressions.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter), Identifier(enter))]
+struct VariableReplacer<'a> {
+    executor: &'a ScriptExecutor,
+    is_expr: bool,
+    error: Option<ErrorCode>,
+}
+
+impl VariableReplacer<'_> {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = match expr {
+            Expr::Hole { span, name } => {
+                Some(self.executor.var_to_expr(*span, &name.to_lowercase()))
+            }
+            Expr::ColumnRef {
+                span,
+                column:
+                    ColumnRef {
+                        database: None,
+                        table,
+                        column: ColumnID::Name(column),
+                    },
+            } if self.is_expr => match table {
+                None => {
+                    let name = normalize_identifier(column);
+                    if self.executor.vars.contains_key(&name) {
+                        Some(self.executor.var_to_expr(*span, &name))
+                    } else {
+                        None
+                    }
+                }
+                Some(table) => self
+                    .executor
+                    .rows
+                    .get(&normalize_identifier(table))
+                    .map(|cursor| self.executor.row_field_to_expr(*span, cursor, &column.name)),
+            },
+            _ => None,
+        };
+        match result {
+            Some(Ok(new_expr)) => *expr = new_expr,
+            Some(Err(e)) => self.error = Some(e),
+            None => {}
+        }
+    }
+
+    fn enter_identifier(&mut self, ident: &mut Identifier) {
+        if self.error.is_some() || !ident.is_hole {
+            return;
+        }
+        let name = ident.name.to_lowercase();
+        match self.executor.vars.get(&name) {
+            Some(Scalar::String(value)) => {
+                // Keep simple names unquoted so they follow the case rules of identifiers.
+                let is_simple = value.chars().enumerate().all(|(i, c)| {
+                    c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit())
+                });
+                let quote = if is_simple && !value.is_empty() {
+                    None
+                } else {
+                    Some('`')
+                };
+                *ident = Identifier::from_name_with_quoted(ident.span, value.clone(), quote);
+            }
+            Some(_) => {
+                self.error = Some(
+                    ErrorCode::ScriptSemanticError(format!(
+                        "variable `{name}` used in IDENTIFIER must be a string"
+                    ))
+                    .set_span(ident.span),
+                );
+            }
+            None => {
+                self.error = Some(
+                    ErrorCode::ScriptSemanticError(format!("variable `{name}` is not defined"))
+                        .set_span(ident.span),
+                );
+            }
+        }
+    }
+}
+
+fn column_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::ColumnRef {
+            column:
+                ColumnRef {
+                    database: None,
+                    table: None,
+                    column: ColumnID::Name(column),
+                },
+            ..
+        } => Some(normalize_identifier(column)),
+        _ => None,
+    }
+}
+
+fn scalar_to_text(scalar: &Scalar, tz: Tz) -> Option<String> {
+    match scalar {
+        Scalar::Null => None,
+        Scalar::String(s) => Some(s.clone()),
+        Scalar::Date(d) => Some(date_to_string(*d, tz).to_string()),
+        Scalar::Timestamp(ts) => Some(timestamp_to_string(*ts, tz).to_string()),
+        other => Some(other.as_ref().to_string()),
+    }
+}
+
+/// Converts the value of a variable to a literal expression.
+fn scalar_to_expr(span: Span, scalar: &Scalar, tz: Tz) -> Result<Expr> {
+    let literal = |lit| Expr::Literal { span, lit };
+    let negative = |lit| Expr::UnaryOp {
+        span,
+        op: UnaryOperator::Minus,
+        expr: Box::new(Expr::Literal { span, lit }),
+    };
+    let cast = |value: String, target_type| Expr::Cast {
+        span,
+        expr: Box::new(Expr::Literal {
+            span,
+            lit: Literal::String(value),
+        }),
+        target_type,
+        pg_style: false,
+    };
+
+    let expr = match scalar {
+        Scalar::Null => literal(Literal::Null),
+        Scalar::Boolean(b) => literal(Literal::Boolean(*b)),
+        Scalar::String(s) => literal(Literal::String(s.clone())),
+        Scalar::Number(n) => {
+            let signed = match n {
+                NumberScalar::UInt8(v) => Ok(*v as u64),
+                NumberScalar::UInt16(v) => Ok(*v as u64),
+                NumberScalar::UInt32(v) => Ok(*v as u64),
+                NumberScalar::UInt64(v) => Ok(*v),
+                NumberScalar::Int8(v) => Err(*v as i64),
+                NumberScalar::Int16(v) => Err(*v as i64),
+                NumberScalar::Int32(v) => Err(*v as i64),
+                NumberScalar::Int64(v) => Err(*v),
+                NumberScalar::Float32(v) => return Ok(float_to_expr(span, v.0 as f64)),
+                NumberScalar::Float64(v) => return Ok(float_to_expr(span, v.0)),
+            };
+            match signed {
+                Ok(v) => literal(Literal::UInt64(v)),
+                Err(v) if v >= 0 => literal(Literal::UInt64(v as u64)),
+                Err(v) => negative(Literal::UInt64(v.unsigned_abs())),
+            }
+        }
+        Scalar::Decimal(d) => {
+            let (value, size) = match d {
+                DecimalScalar::Decimal128(v, size) => (i256::from(*v), size),
+                DecimalScalar::Decimal256(v, size) => (*v, size),
+            };
+            let lit = |value| Literal::Decimal256 {
+                value,
+                precision: size.precision,
+                scale: size.scale,
+            };
+            if value < i256::ZERO {
+                negative(lit(-value))
+            } else {
+                literal(lit(value))
+            }
+        }
+        Scalar::Date(d) => cast(date_to_string(*d, tz).to_string(), TypeName::Date),
+        Scalar::Timestamp(ts) => cast(
+            timestamp_to_string(*ts, tz).to_string(),
+            TypeName::Timestamp,
+        ),
+        other => {
+            return Err(ErrorCode::ScriptExecutionError(format!(
+                "unsupported variable value {}",
+                other.as_ref()
+            ))
+            .set_span(span));
+        }
+    };
+    Ok(expr)
+}
+
+fn float_to_expr(span: Span, v: f64) -> Expr {
+    let lit = Literal::Float64(v.abs());
+    if v.is_sign_negative() {
+        Expr::UnaryOp {
+            span,
+            op: UnaryOperator::Minus,
+            expr: Box::new(Expr::Literal { span, lit }),
+        }
+    } else {
+        Expr::Literal { span, lit }
+    }
+}
@@ -0,0 +1,381 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bulk ingestion of Arrow record batches, i.e. `CommandStatementIngest` of Flight SQL,
+//! which is used by ADBC drivers for `adbc_ingest`.
+
+use std::sync::Arc;
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::error::FlightError;
+use arrow_flight::sql::server::PeekableFlightDataStream;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::ProstMessageExt;
+use databend_common_ast::parser::quote::quote_ident;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_expression::TableSchema;
+use databend_common_pipeline_sources::StreamSource;
+use databend_common_sql::plans::insert::InsertValue;
+use databend_common_sql::plans::Insert;
+use databend_common_sql::plans::InsertInputSource;
+use databend_common_sql::plans::Plan;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use log::info;
+use parking_lot::Mutex;
+
+use self::command_statement_ingest::table_definition_options::TableExistsOption;
+use self::command_statement_ingest::table_definition_options::TableNotExistOption;
+use super::FlightSqlServiceImpl;
+use crate::interpreters::Accessor;
+use crate::interpreters::Interpreter;
+use crate::pipelines::processors::transforms::TransformRuntimeCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+
+/// Represents a bulk ingestion request, the data is sent in the `FlightData` stream of `DoPut`.
+///
+/// It is defined in Flight SQL 15, the arrow-flight we depend on does not ship it yet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementIngest {
+    /// The behavior for handling the table definition.
+    #[prost(message, optional, tag = "1")]
+    pub table_definition_options:
+        ::core::option::Option<command_statement_ingest::TableDefinitionOptions>,
+    /// The table to load data into.
+    #[prost(string, tag = "2")]
+    pub table: ::prost::alloc::string::String,
+    /// The db_schema of the destination table to load data into.
+    #[prost(string, optional, tag = "3")]
+    pub schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// The catalog of the destination table to load data into.
+    #[prost(string, optional, tag = "4")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    /// Store ingested data in a temporary table.
+    #[prost(bool, tag = "5")]
+    pub temporary: bool,
+    /// Perform the ingestion as part of this transaction.
+    #[prost(bytes = "bytes", optional, tag = "6")]
+    pub transaction_id: ::core::option::Option<::prost::bytes::Bytes>,
+    /// Backend-specific options.
+    #[prost(map = "string, string", tag = "1000")]
+    pub options:
+        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+}
+
+pub mod command_statement_ingest {
+    /// Options for table definition behavior.
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct TableDefinitionOptions {
+        #[prost(
+            enumeration = "table_definition_options::TableNotExistOption",
+            tag = "1"
+        )]
+        pub if_not_exist: i32,
+        #[prost(enumeration = "table_definition_options::TableExistsOption", tag = "2")]
+        pub if_exists: i32,
+    }
+
+    pub mod table_definition_options {
+        /// The action to take if the target table does not exist.
+        #[derive(
+            Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+        )]
+        #[repr(i32)]
+        pub enum TableNotExistOption {
+            Unspecified = 0,
+            Create = 1,
+            Fail = 2,
+        }
+
+        /// The action to take if the target table already exists.
+        #[derive(
+            Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+        )]
+        #[repr(i32)]
+        pub enum TableExistsOption {
+            Unspecified = 0,
+            Fail = 1,
+            Append = 2,
+            Replace = 3,
+        }
+    }
+}
+
+impl ProstMessageExt for CommandStatementIngest {
+    fn type_url() -> &'static str {
+        "type.googleapis.com/arrow.flight.protocol.sql.CommandStatementIngest"
+    }
+
+    fn as_any(&self) -> Any {
+        Any {
+            type_url: CommandStatementIngest::type_url().to_string(),
+            value: ::prost::Message::encode_to_vec(self).into(),
+        }
+    }
+}
+
+impl FlightSqlServiceImpl {
+    /// Stream the record batches of a `DoPut` into the target table, returns the number of
+    /// rows written.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_ingest(
+        &self,
+        session: Arc<Session>,
+        cmd: CommandStatementIngest,
+        flight_data: PeekableFlightDataStream,
+    ) -> Result<i64> {
+        if cmd.temporary {
+            return Err(ErrorCode::Unimplemented(
+                "Ingesting into temporary table is not supported",
+            ));
+        }
+        // The transaction is bound to the session, the ingestion is performed in it only if
+        // it's the active transaction of the session.
+        if let Some(transaction_id) = &cmd.transaction_id {
+            let txn_mgr = session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if !txn_mgr.is_active() || txn_mgr.txn_id().as_bytes() != transaction_id.as_ref() {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Transaction {} is not active in current session",
+                    String::from_utf8_lossy(transaction_id)
+                )));
+            }
+        }
+
+        let mut batches =
+            FlightRecordBatchStream::new_from_flight_data(flight_data.map_err(FlightError::Tonic));
+        // The schema is only known after the first message is decoded.
+        let first = batches
+            .next()
+            .await
+            .transpose()
+            .map_err(|e| ErrorCode::BadBytes(format!("fail to decode flight data: {e}")))?;
+        let arrow_schema = batches
+            .schema()
+            .cloned()
+            .ok_or_else(|| ErrorCode::BadBytes("missing schema in flight data"))?;
+
+        let context = session.create_query_context().await?;
+        let catalog = match &cmd.catalog {
+            Some(catalog) => catalog.clone(),
+            None => context.get_current_catalog(),
+        };
+        let database = match &cmd.schema {
+            Some(database) => database.clone(),
+            None => context.get_current_database(),
+        };
+        let table_name = cmd.table.clone();
+        info!(
+            "execute_ingest into {catalog}.{database}.{table_name}, schema={:?}",
+            arrow_schema
+        );
+
+        let options = cmd.table_definition_options.unwrap_or_default();
+        let overwrite = match context.get_table(&catalog, &database, &table_name).await {
+            Ok(_) => match options.if_exists() {
+                TableExistsOption::Append => false,
+                TableExistsOption::Replace => true,
+                TableExistsOption::Fail | TableExistsOption::Unspecified => {
+                    return Err(ErrorCode::TableAlreadyExists(format!(
+                        "Table '{database}'.'{table_name}' already exists"
+                    )));
+                }
+            },
+            Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => match options.if_not_exist() {
+                TableNotExistOption::Create => {
+                    let schema = TableSchema::try_from(arrow_schema.as_ref())?;
+                    let sql = create_table_sql(&catalog, &database, &table_name, &schema)?;
+                    let (plan, plan_extras) = self.plan_sql(&session, &sql).await?;
+                    self.execute_update(session.clone(), &plan, &plan_extras)
+                        .await?;
+                    false
+                }
+                TableNotExistOption::Fail | TableNotExistOption::Unspecified => return Err(e),
+            },
+            Err(e) => return Err(e),
+        };
+
+        // Create a new context, so that the table created above is visible.
+        let context = session.create_query_context().await?;
+        context.attach_query_str(
+            QueryKind::Insert,
+            format!(
+                "INSERT INTO {}.{} /* flight sql ingest */",
+                quote_name(&database)?,
+                quote_name(&table_name)?
+            ),
+        );
+        let table = context.get_table(&catalog, &database, &table_name).await?;
+
+        // The columns of the record batches are matched with the table by name,
+        // the access check is the same as `INSERT [OVERWRITE] INTO`.
+        let table_schema = table.schema();
+        let insert_schema = Arc::new(DataSchema::new(
+            arrow_schema
+                .fields()
+                .iter()
+                .map(|f| Ok(DataField::from(table_schema.field_with_name(f.name())?)))
+                .collect::<Result<Vec<_>>>()?,
+        ));
+        let plan = Plan::Insert(Box::new(Insert {
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: table_name.clone(),
+            table_id: table.get_id(),
+            schema: table_schema.clone(),
+            overwrite,
+            source: InsertInputSource::Values(InsertValue::Values { rows: vec![] }),
+        }));
+        Accessor::create(context.clone()).check(&plan).await?;
+
+        let source_schema = DataSchema::try_from(arrow_schema.as_ref())?;
+        let blocks = futures::stream::iter(first.map(Ok))
+            .chain(batches)
+            .map(move |batch| {
+                let batch = batch
+                    .map_err(|e| ErrorCode::BadBytes(format!("fail to decode flight data: {e}")))?;
+                let (block, _) = DataBlock::from_record_batch(&source_schema, &batch)?;
+                Ok(block)
+            });
+
+        let interpreter = IngestInterpreter {
+            ctx: context.clone(),
+            table,
+            insert_schema,
+            overwrite,
+            blocks: Mutex::new(Some(Box::pin(blocks))),
+        };
+        let mut stream = interpreter.execute(context.clone()).await?;
+        while let Some(block) = stream.next().await {
+            block?;
+        }
+
+        let affected_rows = context.get_write_progress_value().rows;
+        Ok(affected_rows as i64)
+    }
+}
+
+fn create_table_sql(
+    catalog: &str,
+    database: &str,
+    table: &str,
+    schema: &TableSchema,
+) -> Result<String> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            let nullable = if field.is_nullable() {
+                " NULL"
+            } else {
+                " NOT NULL"
+            };
+            Ok(format!(
+                "{} {}{}",
+                quote_name(field.name())?,
+                field.data_type().remove_recursive_nullable().sql_name(),
+                nullable
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(format!(
+        "CREATE TABLE {}.{}.{} ({})",
+        quote_name(catalog)?,
+        quote_name(database)?,
+        quote_name(table)?,
+        columns.join(", ")
+    ))
+}
+
+/// Quote the name as an identifier of all the dialects, a backtick can't be escaped
+/// in a quoted identifier, so the names with backticks are rejected.
+fn quote_name(name: &str) -> Result<String> {
+    if name.contains('`') {
+        return Err(ErrorCode::InvalidArgument(format!(
+            "Invalid name '{name}', it must not contain backticks"
+        )));
+    }
+    Ok(quote_ident(name, '`', true))
+}
+
+/// Appends the decoded blocks to the table, cast to the types of the table columns.
+struct IngestInterpreter {
+    ctx: Arc<QueryContext>,
+    table: Arc<dyn Table>,
+    insert_schema: DataSchemaRef,
+    overwrite: bool,
+    blocks: Mutex<Option<SendableDataBlockStream>>,
+}
+
+#[async_trait::async_trait]
+impl Interpreter for IngestInterpreter {
+    fn name(&self) -> &str {
+        "FlightSqlIngestInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.table.check_mutable()?;
+
+        let mut build_res = PipelineBuildResult::create();
+        build_res.main_pipeline.add_source(
+            |output| StreamSource::create(self.ctx.clone(), self.blocks.lock().take(), output),
+            1,
+        )?;
+
+        let func_ctx = self.ctx.get_function_context()?;
+        build_res
+            .main_pipeline
+            .add_transform(|transform_input_port, transform_output_port| {
+                TransformRuntimeCastSchema::try_create(
+                    transform_input_port,
+                    transform_output_port,
+                    self.insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+
+        PipelineBuilder::build_append2table_with_commit_pipeline(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            self.table.clone(),
+            self.insert_schema.clone(),
+            None,
+            vec![],
+            self.overwrite,
+            AppendMode::Normal,
+            unsafe { self.ctx.get_settings().get_deduplicate_label()? },
+        )?;
+
+        Ok(build_res)
+    }
+}
@@ -15,6 +15,7 @@
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
 mod catalog;
+mod ingest;
 mod query;
 mod service;
 mod session;
@@ -29,7 +30,11 @@ use dashmap::DashMap;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use futures::Stream;
+pub use ingest::command_statement_ingest;
+pub use ingest::CommandStatementIngest;
 use parking_lot::Mutex;
+pub use service::CancelResult;
+pub use service::EndTransaction;
 use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
@@ -52,6 +57,8 @@ type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
     statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    /// The id of the query whose results are being fetched for each statement handle.
+    queries: Arc<DashMap<Uuid, String>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -60,6 +67,7 @@ impl FlightSqlServiceImpl {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
             statements: Arc::new(Default::default()),
+            queries: Arc::new(Default::default()),
         }
     }
 }
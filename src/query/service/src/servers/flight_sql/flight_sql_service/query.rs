@@ -111,12 +111,26 @@ impl FlightSqlServiceImpl {
         Ok(affected_rows as i64)
     }
 
+    /// Execute `BEGIN`, `COMMIT` or `ROLLBACK` in the session.
+    #[async_backtrace::framed]
+    pub(super) async fn execute_txn_command(
+        &self,
+        session: &Arc<Session>,
+        sql: &str,
+    ) -> Result<()> {
+        let (plan, plan_extras) = self.plan_sql(session, sql).await?;
+        self.execute_update(session.clone(), &plan, &plan_extras)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the id of the query and the stream of its results.
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
         plan: &Plan,
         plan_extras: &PlanExtras,
-    ) -> Result<DoGetStream> {
+    ) -> Result<(String, DoGetStream)> {
         let is_native_client = session.get_status().read().is_native_client;
 
         let context = session
@@ -128,6 +142,7 @@ impl FlightSqlServiceImpl {
             get_query_kind(&plan_extras.statement),
             plan_extras.statement.to_mask_sql(),
         );
+        let query_id = context.get_id();
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
@@ -241,7 +256,7 @@ impl FlightSqlServiceImpl {
         }
 
         let st = receiver_to_stream(receiver);
-        Ok(Box::pin(st))
+        Ok((query_id, Box::pin(st)))
     }
 }
 
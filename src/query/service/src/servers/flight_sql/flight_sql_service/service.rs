@@ -64,10 +64,13 @@ use arrow_flight::PutResult;
 use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
+use dashmap::DashMap;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use futures::Stream;
+use futures::StreamExt;
 use log::info;
 use prost::Message;
 use tonic::metadata::MetadataValue;
@@ -77,8 +80,11 @@ use tonic::Response;
 use tonic::Status;
 use tonic::Streaming;
 
+use super::ingest::CommandStatementIngest;
 use super::status;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::WorkloadGroupManager;
 
 fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, Status> {
     message
@@ -193,11 +199,28 @@ impl FlightSqlService for FlightSqlServiceImpl {
         info!("do_get_fallback with handle={handle}");
 
         let handle_plan = self.statements.get(&handle).unwrap();
-        let stream = self
+        let (query_id, stream) = match self
             .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
             .await
-            .map_err(|e| status!("fail to execute", e))?;
-        let resp = Response::new(stream);
+        {
+            Ok(res) => res,
+            Err(e) => {
+                self.queries.remove(&handle);
+                return Err(status!("fail to execute", e));
+            }
+        };
+        self.queries.insert(handle, query_id.clone());
+        // The query is forgotten once its results are consumed or dropped.
+        let guard = QueryEntryGuard {
+            queries: self.queries.clone(),
+            handle,
+            query_id,
+        };
+        let stream = stream.map(move |data| {
+            let _guard = &guard;
+            data
+        });
+        let resp = Response::new(Box::pin(stream) as _);
         Ok(resp)
     }
 
@@ -619,6 +642,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
                 Ok(handle) => {
                     if self.get_session(&request).is_ok() {
                         self.statements.remove(&handle);
+                        self.queries.remove(&handle);
                     }
                 }
                 Err(e) => {
@@ -678,20 +702,92 @@ impl FlightSqlService for FlightSqlServiceImpl {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn do_put_fallback(
+        &self,
+        request: Request<PeekableFlightDataStream>,
+        message: Any,
+    ) -> Result<Response<<Self as FlightService>::DoPutStream>, Status> {
+        let session = self.get_session(&request)?;
+        let cmd: CommandStatementIngest = try_unpack_any(message)?;
+        info!(
+            "do_put_fallback with ingest into table={:?}, schema={:?}, catalog={:?}",
+            cmd.table, cmd.schema, cmd.catalog
+        );
+
+        let record_count = self
+            .execute_ingest(session, cmd, request.into_inner())
+            .await
+            .map_err(|e| status!("fail to ingest", e))?;
+        let result = DoPutUpdateResult { record_count };
+        let result = PutResult {
+            app_metadata: result.as_any().encode_to_vec().into(),
+        };
+        let result = futures::stream::iter(vec![Ok(result)]);
+        Ok(Response::new(Box::pin(result)))
+    }
+
+    /// Transactions are bound to the session, so there is at most one active transaction
+    /// for each token, its id is the one of the `TxnManager` of the session.
+    #[async_backtrace::framed]
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        if session.txn_mgr().lock().is_active() {
+            return Err(Status::failed_precondition(
+                "a transaction is already active in current session",
+            ));
+        }
+
+        self.execute_txn_command(&session, "BEGIN")
+            .await
+            .map_err(|e| status!("fail to begin transaction", e))?;
+        let transaction_id = session.txn_mgr().lock().txn_id().to_string();
+        info!("do_action_begin_transaction with transaction_id={transaction_id}");
+        Ok(ActionBeginTransactionResult {
+            transaction_id: transaction_id.into_bytes().into(),
+        })
     }
 
+    #[async_backtrace::framed]
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let transaction_id = std::str::from_utf8(&query.transaction_id)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding transaction id: {e}")))?;
+        info!(
+            "do_action_end_transaction with transaction_id={transaction_id}, action={:?}",
+            EndTransaction::try_from(query.action)
+        );
+
+        {
+            let txn_mgr = session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if (!txn_mgr.is_active() && !txn_mgr.is_fail()) || txn_mgr.txn_id() != transaction_id {
+                return Err(Status::not_found(format!(
+                    "transaction {transaction_id} not found in current session"
+                )));
+            }
+        }
+
+        let sql = match EndTransaction::try_from(query.action) {
+            Ok(EndTransaction::Commit) => "COMMIT",
+            Ok(EndTransaction::Rollback) => "ROLLBACK",
+            _ => {
+                return Err(Status::invalid_argument(
+                    "end transaction action is not specified",
+                ));
+            }
+        };
+        self.execute_txn_command(&session, sql)
+            .await
+            .map_err(|e| status!("fail to end transaction", e))
     }
 
     async fn do_action_begin_savepoint(
@@ -699,7 +795,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
     async fn do_action_end_savepoint(
@@ -707,15 +803,56 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented("savepoint is not supported"))
     }
 
+    /// The query to cancel is the one whose results are being fetched for the statement handle in
+    /// the ticket of the `FlightInfo`, it is killed the same way as `KILL QUERY`.
+    #[async_backtrace::framed]
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let info = FlightInfo::decode(query.info)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding flight info: {e}")))?;
+
+        let query_id = info
+            .endpoint
+            .iter()
+            .filter_map(|endpoint| endpoint.ticket.as_ref())
+            .filter_map(|ticket| Any::decode(ticket.ticket.clone()).ok())
+            .filter_map(|message| message.unpack::<FetchResults>().ok().flatten())
+            .filter_map(|fetch_results| Uuid::try_parse(&fetch_results.handle).ok())
+            .find_map(|handle| self.queries.remove(&handle));
+        let Some((_, query_id)) = query_id else {
+            info!("do_action_cancel_query({info:?}): unknown query");
+            return Ok(ActionCancelQueryResult {
+                result: CancelResult::Unspecified.into(),
+            });
+        };
+
+        // Queries of a session run one at a time, the query is running only if it's
+        // the current one of the session.
+        if session.get_current_query_id().as_ref() != Some(&query_id) {
+            info!("do_action_cancel_query with query_id={query_id}: not running");
+            return Ok(ActionCancelQueryResult {
+                result: CancelResult::NotCancellable.into(),
+            });
+        }
+        info!("do_action_cancel_query with query_id={query_id}");
+
+        if !QueriesQueueManager::instance().remove(query_id.clone())
+            && !WorkloadGroupManager::instance().remove(&query_id)
+        {
+            session.force_kill_query(ErrorCode::AbortedQuery(
+                "Aborted query, because the query was cancelled by the client",
+            ));
+        }
+        Ok(ActionCancelQueryResult {
+            result: CancelResult::Cancelled.into(),
+        })
     }
 }
 
@@ -737,3 +874,39 @@ impl ProstMessageExt for FetchResults {
         }
     }
 }
+
+/// Removes the query of a statement handle from `FlightSqlServiceImpl::queries` when
+/// the results of the query are dropped, unless the handle has run another query.
+struct QueryEntryGuard {
+    queries: Arc<DashMap<Uuid, String>>,
+    handle: Uuid,
+    query_id: String,
+}
+
+impl Drop for QueryEntryGuard {
+    fn drop(&mut self) {
+        self.queries
+            .remove_if(&self.handle, |_, query_id| query_id == &self.query_id);
+    }
+}
+
+/// The action to end a transaction with, i.e. `ActionEndTransactionRequest.EndTransaction`.
+///
+/// The arrow-flight we depend on does not export the nested enums of Flight SQL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum EndTransaction {
+    Unspecified = 0,
+    Commit = 1,
+    Rollback = 2,
+}
+
+/// The result of cancelling a query, i.e. `ActionCancelQueryResult.CancelResult`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CancelResult {
+    Unspecified = 0,
+    Cancelled = 1,
+    Cancelling = 2,
+    NotCancellable = 3,
+}
@@ -69,6 +69,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupManager;
 
 // accept all clickhouse params, so they do not go to settings.
 #[derive(Serialize, Deserialize, Debug)]
@@ -260,6 +261,10 @@ pub async fn clickhouse_handler_get(
             ));
         }
 
+        let _workload_group_guard = WorkloadGroupManager::instance()
+            .acquire(&context)
+            .await
+            .map_err(BadRequest)?;
         let query_entry = QueryEntry::create(&context).map_err(BadRequest)?;
         let _guard = QueriesQueueManager::instance()
             .acquire(query_entry)
@@ -342,6 +347,10 @@ pub async fn clickhouse_handler_post(
         };
         info!("receive clickhouse http post, (query + body) = {}", &msg);
 
+        let _workload_group_guard = WorkloadGroupManager::instance()
+            .acquire(&ctx)
+            .await
+            .map_err(BadRequest)?;
         let entry = QueryEntry::create(&ctx).map_err(BadRequest)?;
         let _guard = QueriesQueueManager::instance()
             .acquire(entry)
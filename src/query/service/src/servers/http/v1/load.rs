@@ -52,6 +52,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::SessionType;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupManager;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct LoadResponse {
@@ -128,6 +129,10 @@ pub async fn streaming_load(
         }
     }
 
+    let _workload_group_guard = WorkloadGroupManager::instance()
+        .acquire(&context)
+        .await
+        .map_err(InternalServerError)?;
     let entry = QueryEntry::create(&context).map_err(InternalServerError)?;
     let _guard = QueriesQueueManager::instance()
         .acquire(entry)
@@ -49,6 +49,8 @@ use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::Session;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupGuard;
+use crate::sessions::WorkloadGroupManager;
 
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ExecuteStateKind {
@@ -114,6 +116,8 @@ pub struct ExecuteRunning {
     schema: Vec<QueryResponseField>,
     #[allow(dead_code)]
     queue_guard: AcquireQueueGuard,
+    #[allow(dead_code)]
+    workload_group_guard: Option<WorkloadGroupGuard>,
 }
 
 pub struct ExecuteStopped {
@@ -299,6 +303,7 @@ impl ExecuteState {
         block_sender: SizedChannelSender<DataBlock>,
         format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
     ) -> Result<()> {
+        let workload_group_guard = WorkloadGroupManager::instance().acquire(&ctx).await?;
         let entry = QueryEntry::create(&ctx)?;
         let queue_guard = QueriesQueueManager::instance().acquire(entry).await?;
 
@@ -317,6 +322,7 @@ impl ExecuteState {
             session,
             ctx: ctx.clone(),
             queue_guard,
+            workload_group_guard,
             schema: QueryResponseField::from_schema(plan.schema()),
         };
         info!("{}: http query change state to Running", &ctx.get_id());
@@ -59,6 +59,7 @@ use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
 use crate::sessions::Session;
 use crate::sessions::TableContext;
+use crate::sessions::WorkloadGroupManager;
 use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
@@ -356,6 +357,8 @@ impl InteractiveWorkerBase {
                 info!("Normal query: {}", query);
                 let context = self.session.create_query_context().await?;
 
+                let _workload_group_guard =
+                    WorkloadGroupManager::instance().acquire(&context).await?;
                 let entry = QueryEntry::create(&context)?;
                 let _guard = QueriesQueueManager::instance().acquire(entry).await?;
 
@@ -182,6 +182,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 },
                 DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -232,7 +233,10 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     };
 
                     let num_rows = block.num_rows();
-                    let encoder = FieldEncoderValues::create_for_mysql_handler(format.timezone);
+                    let encoder = FieldEncoderValues::create_for_mysql_handler(
+                        format.timezone,
+                        format.geometry_format,
+                    );
                     let mut buf = Vec::<u8>::new();
 
                     let columns = block
@@ -24,6 +24,7 @@ mod session_mgr_status;
 mod session_privilege_mgr;
 mod session_status;
 mod session_type;
+mod workload_group_mgr;
 
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -43,3 +44,6 @@ pub use session_mgr::SessionManager;
 pub use session_mgr_status::SessionManagerStatus;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use workload_group_mgr::CpuQuota;
+pub use workload_group_mgr::WorkloadGroupGuard;
+pub use workload_group_mgr::WorkloadGroupManager;
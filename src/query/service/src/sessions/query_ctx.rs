@@ -62,6 +62,7 @@ use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_formats::parse_geometry_format;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
@@ -89,6 +90,7 @@ use databend_common_storage::StorageMetrics;
 use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_iceberg::IcebergTable;
+use databend_common_storages_orc::OrcTable;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultScan;
 use databend_common_storages_stage::StageTable;
@@ -112,6 +114,7 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::sessions::WorkloadGroupManager;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -340,6 +343,7 @@ impl TableContext for QueryContext {
                 plan.tbl_args.clone(),
             ),
             DataSourceInfo::ParquetSource(table_info) => ParquetRSTable::from_info(table_info),
+            DataSourceInfo::OrcSource(table_info) => OrcTable::from_info(table_info),
             DataSourceInfo::ResultScanSource(table_info) => ResultScan::from_info(table_info),
         }
     }
@@ -594,7 +598,11 @@ impl TableContext for QueryContext {
         let timezone = tz.parse::<Tz>().map_err(|_| {
             ErrorCode::InvalidTimezone("Timezone has been checked and should be valid")
         })?;
-        let format = FormatSettings { timezone };
+        let geometry_format = parse_geometry_format(&self.get_settings())?;
+        let format = FormatSettings {
+            timezone,
+            geometry_format,
+        };
         Ok(format)
     }
 
@@ -668,11 +676,12 @@ impl TableContext for QueryContext {
     }
 
     fn get_queued_queries(&self) -> Vec<ProcessInfo> {
-        let queries = QueriesQueueManager::instance()
+        let mut queries = QueriesQueueManager::instance()
             .list()
             .iter()
             .map(|x| x.query_id.clone())
             .collect::<HashSet<_>>();
+        queries.extend(WorkloadGroupManager::instance().queued_queries());
 
         SessionManager::instance()
             .processes_info()
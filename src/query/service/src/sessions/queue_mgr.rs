@@ -18,6 +18,7 @@ use std::future::Future;
 use std::hash::Hash;
 use std::pin::Pin;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::task::Context;
@@ -63,7 +64,11 @@ pub(crate) struct Inner<Data: QueueData> {
 }
 
 pub struct QueueManager<Data: QueueData> {
+    permits: AtomicUsize,
     semaphore: Arc<Semaphore>,
+    /// The permits to be forgotten once they are released by the running queries,
+    /// after the number of permits is reduced below the number of acquired ones.
+    forget_permits: Arc<AtomicUsize>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
 }
 
@@ -78,17 +83,49 @@ impl<Data: QueueData> QueueManager<Data> {
         GlobalInstance::get::<Arc<Self>>()
     }
 
-    pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
-        if permits == 0 {
-            permits = usize::MAX >> 4;
-        }
-
+    pub fn create(permits: usize) -> Arc<QueueManager<Data>> {
+        let permits = Self::normalize_permits(permits);
         Arc::new(QueueManager {
+            permits: AtomicUsize::new(permits),
             queue: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
+            forget_permits: Arc::new(AtomicUsize::new(0)),
         })
     }
 
+    fn normalize_permits(permits: usize) -> usize {
+        match permits {
+            0 => usize::MAX >> 4,
+            permits => permits,
+        }
+    }
+
+    /// Change the number of permits, the permits held by the running queries are kept.
+    ///
+    /// If there are fewer permits than the running queries, the surplus is retired
+    /// as the running queries finish.
+    pub fn set_permits(&self, permits: usize) {
+        let permits = Self::normalize_permits(permits);
+        let old_permits = self.permits.swap(permits, Ordering::SeqCst);
+
+        if permits > old_permits {
+            let added = permits - old_permits;
+            // Cancel the permits which are still to be forgotten first.
+            let forget = self
+                .forget_permits
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |forget| {
+                    Some(forget - forget.min(added))
+                })
+                .unwrap_or_default();
+            self.semaphore.add_permits(added - forget.min(added));
+        } else if permits < old_permits {
+            let removed = old_permits - permits;
+            let forgotten = self.semaphore.forget_permits(removed);
+            self.forget_permits
+                .fetch_add(removed - forgotten, Ordering::SeqCst);
+        }
+    }
+
     pub fn list(&self) -> Vec<Arc<Data>> {
         let queue = self.queue.lock();
         queue.values().map(|x| x.data.clone()).collect::<Vec<_>>()
@@ -155,13 +192,31 @@ impl<Data: QueueData> QueueManager<Data> {
 }
 
 pub struct AcquireQueueGuard {
-    #[allow(dead_code)]
-    permit: OwnedSemaphorePermit,
+    permit: Option<OwnedSemaphorePermit>,
+    forget_permits: Arc<AtomicUsize>,
 }
 
 impl AcquireQueueGuard {
-    pub fn create(permit: OwnedSemaphorePermit) -> Self {
-        AcquireQueueGuard { permit }
+    pub fn create(permit: OwnedSemaphorePermit, forget_permits: Arc<AtomicUsize>) -> Self {
+        AcquireQueueGuard {
+            permit: Some(permit),
+            forget_permits,
+        }
+    }
+}
+
+impl Drop for AcquireQueueGuard {
+    fn drop(&mut self) {
+        let retired = self
+            .forget_permits
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |forget| {
+                forget.checked_sub(1)
+            })
+            .is_ok();
+
+        if let (true, Some(permit)) = (retired, self.permit.take()) {
+            permit.forget();
+        }
     }
 }
 
@@ -217,7 +272,10 @@ where T: Future<Output = Result<Result<OwnedSemaphorePermit, AcquireError>, Elap
                 }
 
                 Poll::Ready(match res {
-                    Ok(Ok(v)) => Ok(AcquireQueueGuard::create(v)),
+                    Ok(Ok(v)) => Ok(AcquireQueueGuard::create(
+                        v,
+                        this.manager.forget_permits.clone(),
+                    )),
                     Ok(Err(_)) => Err(ErrorCode::TokioError("acquire queue failure.")),
                     Err(_elapsed) => Err(ErrorCode::Timeout("query queuing timeout")),
                 })
@@ -0,0 +1,317 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::MemStat;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_users::UserApiProvider;
+use log::info;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::sessions::AcquireQueueGuard;
+use crate::sessions::QueryContext;
+use crate::sessions::QueryEntry;
+use crate::sessions::QueueManager;
+
+/// The period the cpu quota is accounted in, a group may burst up to its quota of
+/// one period and its tasks are held back for at most one period at a time.
+const CPU_QUOTA_PERIOD: Duration = Duration::from_millis(100);
+
+/// The cpu time the queries of a workload group may use on this node.
+///
+/// It's a token bucket refilled with `cpu_quota` percent of the cpu time of all the cores,
+/// the executors charge it with the time spent in the processors of the queries and hold
+/// their tasks back while it's overdrawn.
+pub struct CpuQuota {
+    cores: usize,
+    state: Mutex<CpuQuotaState>,
+}
+
+struct CpuQuotaState {
+    // Nanoseconds of cpu time refilled per nanosecond, 0 means unlimited.
+    rate: f64,
+    // Nanoseconds of cpu time left, negative if the quota is overdrawn.
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl CpuQuota {
+    pub fn create(cpu_quota: u64, cores: usize) -> Arc<CpuQuota> {
+        let quota = Arc::new(CpuQuota {
+            cores,
+            state: Mutex::new(CpuQuotaState {
+                rate: 0.0,
+                tokens: 0.0,
+                refilled_at: Instant::now(),
+            }),
+        });
+        quota.set_quota(cpu_quota);
+        quota
+    }
+
+    /// Change the quota (in percent) in place, 0 means unlimited.
+    pub fn set_quota(&self, cpu_quota: u64) {
+        let mut state = self.state.lock();
+        state.refill();
+        let unlimited = state.rate <= 0.0;
+        state.rate = match cpu_quota {
+            0 | 100.. => 0.0,
+            quota => quota as f64 / 100.0 * self.cores as f64,
+        };
+        // A limited quota keeps its debt, an unlimited one starts with a full bucket.
+        state.tokens = match unlimited {
+            true => state.burst(),
+            false => state.tokens.min(state.burst()),
+        };
+    }
+
+    /// Charge the cpu time spent by a task of the group.
+    pub fn consume(&self, cpu_time: Duration) {
+        let mut state = self.state.lock();
+        if state.rate > 0.0 {
+            state.refill();
+            state.tokens -= cpu_time.as_nanos() as f64;
+        }
+    }
+
+    /// How long the tasks of the group should be held back, zero if the quota isn't overdrawn.
+    ///
+    /// It's at most one period, the caller is expected to check again after waiting.
+    pub fn wait_time(&self) -> Duration {
+        let mut state = self.state.lock();
+        if state.rate <= 0.0 {
+            return Duration::ZERO;
+        }
+
+        state.refill();
+        if state.tokens >= 0.0 {
+            return Duration::ZERO;
+        }
+        let nanos = -state.tokens / state.rate;
+        Duration::from_nanos(nanos as u64).min(CPU_QUOTA_PERIOD)
+    }
+}
+
+impl CpuQuotaState {
+    fn burst(&self) -> f64 {
+        self.rate * CPU_QUOTA_PERIOD.as_nanos() as f64
+    }
+
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled_at).as_nanos() as f64;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst());
+        self.refilled_at = now;
+    }
+}
+
+/// Runtime state of a workload group on this node.
+///
+/// It is rebuilt when the group meta changes, the queue, the cpu quota and the memory
+/// tracker are carried over and resized, so the running queries keep their slots and
+/// their usage is not lost.
+pub struct WorkloadGroupState {
+    pub meta: WorkloadGroup,
+    pub queue: Arc<QueueManager<QueryEntry>>,
+    pub cpu_quota: Arc<CpuQuota>,
+    pub mem_stat: Arc<MemStat>,
+}
+
+impl WorkloadGroupState {
+    fn create(meta: WorkloadGroup, old: Option<&WorkloadGroupState>) -> Arc<WorkloadGroupState> {
+        let (queue, cpu_quota, mem_stat) = match old {
+            Some(old) => {
+                old.queue.set_permits(meta.max_concurrency as usize);
+                old.cpu_quota.set_quota(meta.cpu_quota);
+                (
+                    old.queue.clone(),
+                    old.cpu_quota.clone(),
+                    old.mem_stat.clone(),
+                )
+            }
+            None => (
+                QueueManager::create(meta.max_concurrency as usize),
+                CpuQuota::create(meta.cpu_quota, node_cpus()),
+                MemStat::create(format!("WorkloadGroupMemStat-{}", meta.name)),
+            ),
+        };
+        mem_stat.set_limit(meta.memory_quota as i64);
+
+        Arc::new(WorkloadGroupState {
+            meta,
+            queue,
+            cpu_quota,
+            mem_stat,
+        })
+    }
+}
+
+fn node_cpus() -> usize {
+    match GlobalConfig::instance().query.num_cpus {
+        0 => std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        num_cpus => num_cpus as usize,
+    }
+}
+
+pub struct WorkloadGroupManager {
+    /// (tenant, group name) -> state
+    groups: RwLock<HashMap<(String, String), Arc<WorkloadGroupState>>>,
+    /// query id -> state of the group the query is running in
+    queries: RwLock<HashMap<String, Arc<WorkloadGroupState>>>,
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadGroupManager {
+            groups: RwLock::new(HashMap::new()),
+            queries: RwLock::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Wait until the query is admitted by the workload group of the current user,
+    /// or of the current role if the user is not bound to any.
+    ///
+    /// Returns `None` if neither of them is bound to a workload group.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        self: &Arc<Self>,
+        ctx: &Arc<QueryContext>,
+    ) -> Result<Option<WorkloadGroupGuard>> {
+        let Some(name) = Self::workload_group_of(ctx).await? else {
+            return Ok(None);
+        };
+
+        let tenant = ctx.get_tenant();
+        let meta = UserApiProvider::instance()
+            .get_workload_group(&tenant, &name)
+            .await?;
+        let state = self.refresh_state(tenant.as_str(), meta);
+
+        let mut entry = QueryEntry::create(ctx)?;
+        if state.meta.queue_timeout != 0 {
+            entry.timeout = Duration::from_secs(state.meta.queue_timeout);
+        }
+        let queue_guard = state.queue.acquire(entry).await?;
+
+        let query_id = ctx.get_id();
+        self.queries.write().insert(query_id.clone(), state);
+        Ok(Some(WorkloadGroupGuard {
+            query_id,
+            manager: self.clone(),
+            _queue_guard: queue_guard,
+        }))
+    }
+
+    async fn workload_group_of(ctx: &Arc<QueryContext>) -> Result<Option<String>> {
+        let user_info = ctx.get_current_user()?;
+        if let Some(name) = user_info.option.workload_group() {
+            return Ok(Some(name.clone()));
+        }
+        let Some(role) = ctx.get_current_role() else {
+            return Ok(None);
+        };
+
+        // The role of the session may be stale, the binding is read from the meta.
+        let tenant = ctx.get_tenant();
+        match UserApiProvider::instance()
+            .get_role(&tenant, role.name)
+            .await
+        {
+            Ok(role_info) => Ok(role_info.workload_group),
+            Err(e) if e.code() == ErrorCode::UNKNOWN_ROLE => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn refresh_state(&self, tenant: &str, meta: WorkloadGroup) -> Arc<WorkloadGroupState> {
+        let key = (tenant.to_string(), meta.name.clone());
+        if let Some(state) = self.groups.read().get(&key) {
+            if state.meta == meta {
+                return state.clone();
+            }
+        }
+
+        let mut groups = self.groups.write();
+        match groups.get(&key) {
+            Some(state) if state.meta == meta => state.clone(),
+            old => {
+                info!("refresh workload group {:?}: {:?}", key, meta);
+                let state = WorkloadGroupState::create(meta, old.map(|state| state.as_ref()));
+                groups.insert(key, state.clone());
+                state
+            }
+        }
+    }
+
+    /// Remove the query from the queue of its workload group, returns true if it was queued.
+    pub fn remove(&self, query_id: &str) -> bool {
+        let groups = self.groups.read();
+        let mut removed = false;
+        for state in groups.values() {
+            removed |= state.queue.remove(query_id.to_string());
+        }
+        removed
+    }
+
+    /// Ids of the queries waiting in the queue of any workload group.
+    pub fn queued_queries(&self) -> Vec<String> {
+        let groups = self.groups.read();
+        groups
+            .values()
+            .flat_map(|state| state.queue.list())
+            .map(|entry| entry.query_id.clone())
+            .collect()
+    }
+
+    /// The memory tracker of the workload group the query is running in.
+    pub fn get_query_mem_stat(&self, query_id: &str) -> Option<Arc<MemStat>> {
+        let queries = self.queries.read();
+        queries.get(query_id).map(|state| state.mem_stat.clone())
+    }
+
+    /// The cpu quota of the workload group the query is running in.
+    pub fn get_query_cpu_quota(&self, query_id: &str) -> Option<Arc<CpuQuota>> {
+        let queries = self.queries.read();
+        queries.get(query_id).map(|state| state.cpu_quota.clone())
+    }
+}
+
+pub struct WorkloadGroupGuard {
+    query_id: String,
+    manager: Arc<WorkloadGroupManager>,
+    _queue_guard: AcquireQueueGuard,
+}
+
+impl Drop for WorkloadGroupGuard {
+    fn drop(&mut self) {
+        self.manager.queries.write().remove(&self.query_id);
+    }
+}
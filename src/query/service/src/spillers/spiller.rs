@@ -41,6 +41,7 @@ pub enum SpillerType {
     HashJoinBuild,
     HashJoinProbe,
     OrderBy,
+    Window,
     // Todo: Add more spillers type
     // Aggregation
 }
@@ -51,6 +52,7 @@ impl Display for SpillerType {
             SpillerType::HashJoinBuild => write!(f, "HashJoinBuild"),
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
             SpillerType::OrderBy => write!(f, "OrderBy"),
+            SpillerType::Window => write!(f, "Window"),
         }
     }
 }
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_ast::ast::FileLocation;
 use databend_common_ast::ast::UriLocation;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_compress::DecompressDecoder;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::BooleanType;
@@ -27,8 +28,12 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::principal::StageFileFormatType;
+use databend_common_meta_app::principal::XmlFileFormatParams;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sources::input_formats::InputContext;
+use databend_common_pipeline_sources::input_formats::InputFormatAvro;
+use databend_common_pipeline_sources::input_formats::InputFormatXML;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
@@ -36,11 +41,15 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::infer_orc_schema;
 use opendal::Scheme;
 
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
 use crate::table_functions::infer_schema::table_args::InferSchemaArgsParsed;
 
+const AVRO_HEADER_PREFIX_LEN: u64 = 1024 * 1024;
+const XML_INFER_PREFIX_LEN: u64 = 8 * 1024 * 1024;
+
 pub(crate) struct ParquetInferSchemaSource {
     is_finished: bool,
     ctx: Arc<dyn TableContext>,
@@ -145,9 +154,51 @@ impl AsyncSource for ParquetInferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                // the schema is in the header, the whole file is only read
+                // when the header does not fit in the prefix.
+                let prefix_len = first_file.size.min(AVRO_HEADER_PREFIX_LEN);
+                let prefix = operator
+                    .read_with(&first_file.path)
+                    .range(0..prefix_len)
+                    .await?;
+                match InputFormatAvro::infer_schema(&prefix) {
+                    Ok(schema) => schema,
+                    Err(_) if prefix_len < first_file.size => {
+                        let data = operator.read(&first_file.path).await?;
+                        InputFormatAvro::infer_schema(&data)?
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            StageFileFormatType::Orc => {
+                infer_orc_schema(&operator, &first_file.path, Some(first_file.size)).await?
+            }
+            StageFileFormatType::Xml => {
+                let compression = InputContext::get_compression_alg_copy(
+                    file_format_params.compression(),
+                    &first_file.path,
+                )?;
+                // the schema is inferred from the rows in a prefix of the file.
+                let prefix_len = first_file.size.min(XML_INFER_PREFIX_LEN);
+                let is_prefix = prefix_len < first_file.size;
+                let data = operator
+                    .read_with(&first_file.path)
+                    .range(0..prefix_len)
+                    .await?;
+                let data = match compression {
+                    Some(algo) if is_prefix => {
+                        DecompressDecoder::new(algo).decompress_batch(&data)?
+                    }
+                    Some(algo) => DecompressDecoder::new(algo).decompress_all(&data)?,
+                    None => data,
+                };
+                let xml_params = XmlFileFormatParams::downcast_unchecked(&file_format_params);
+                InputFormatXML::infer_schema(&data, &xml_params.row_tag, is_prefix)?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro, ORC and XML",
                 ));
             }
         };
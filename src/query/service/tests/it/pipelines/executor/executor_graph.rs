@@ -498,6 +498,7 @@ async fn create_executor_with_simple_pipeline(
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
         enable_new_executor: false,
+        cpu_quota: None,
     };
     QueryPipelineExecutor::create(pipeline, settings)
 }
@@ -15,6 +15,8 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::mpsc::channel;
@@ -29,10 +31,14 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::EmptySink;
 use databend_common_pipeline_sinks::SyncSenderSink;
 use databend_common_pipeline_sources::SyncReceiverSource;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
 use databend_query::pipelines::executor::ExecutorSettings;
 use databend_query::pipelines::executor::QueryPipelineExecutor;
+use databend_query::sessions::CpuQuota;
 use databend_query::sessions::QueryContext;
 use databend_query::test_kits::TestFixture;
 
@@ -44,6 +50,7 @@ async fn test_always_call_on_finished() -> Result<()> {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
         enable_new_executor: false,
+        cpu_quota: None,
     };
 
     {
@@ -91,6 +98,67 @@ async fn test_always_call_on_finished() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_cpu_quota() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    // 10 percent of one core, the source spends 100ms of cpu time.
+    let settings = ExecutorSettings {
+        query_id: Arc::new("".to_string()),
+        max_execute_time_in_seconds: Default::default(),
+        enable_new_executor: false,
+        cpu_quota: Some(CpuQuota::create(10, 1)),
+    };
+
+    let mut pipeline = Pipeline::create();
+    let output = OutputPort::create();
+    let source = SyncSourcer::create(ctx, output.clone(), BusySource { blocks: 50 })?;
+    pipeline.add_pipe(Pipe::create(0, 1, vec![PipeItem::create(
+        source,
+        vec![],
+        vec![output],
+    )]));
+    let input = InputPort::create();
+    let sink = ProcessorPtr::create(EmptySink::create(input.clone()));
+    pipeline.add_pipe(Pipe::create(1, 0, vec![PipeItem::create(
+        sink,
+        vec![input],
+        vec![],
+    )]));
+    pipeline.set_max_threads(1);
+
+    let executor = QueryPipelineExecutor::create(pipeline, settings)?;
+    let instant = Instant::now();
+    executor.execute()?;
+
+    // The quota is refilled with 10ms per 100ms, bursting up to 10ms.
+    assert!(instant.elapsed() >= Duration::from_millis(800));
+
+    Ok(())
+}
+
+struct BusySource {
+    blocks: usize,
+}
+
+impl SyncSource for BusySource {
+    const NAME: &'static str = "BusySource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks == 0 {
+            return Ok(None);
+        }
+
+        self.blocks -= 1;
+        let instant = Instant::now();
+        while instant.elapsed() < Duration::from_millis(2) {
+            std::hint::spin_loop();
+        }
+        Ok(Some(DataBlock::empty()))
+    }
+}
+
 fn create_pipeline() -> (Arc<AtomicBool>, Pipeline) {
     let called_finished = Arc::new(AtomicBool::new(false));
     let mut pipeline = Pipeline::create();
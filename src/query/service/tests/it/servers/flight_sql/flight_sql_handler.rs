@@ -17,22 +17,51 @@
 use std::fs;
 use std::io::Write;
 
+use std::sync::Arc;
+use std::time::Duration;
+
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
 use arrow_cast::pretty::pretty_format_batches;
+use arrow_flight::encode::FlightDataEncoderBuilder;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::ActionBeginTransactionRequest;
+use arrow_flight::sql::ActionBeginTransactionResult;
+use arrow_flight::sql::ActionCancelQueryRequest;
+use arrow_flight::sql::ActionCancelQueryResult;
+use arrow_flight::sql::ActionEndTransactionRequest;
+use arrow_flight::sql::Any;
+use arrow_flight::sql::DoPutUpdateResult;
+use arrow_flight::sql::ProstMessageExt;
+use arrow_flight::Action;
+use arrow_flight::FlightDescriptor;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType;
+use arrow_schema::Field;
+use arrow_schema::Schema;
+use prost::bytes::Bytes;
 use databend_common_base::base::tokio;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::PasswordHashMethod;
+use databend_query::servers::flight_sql::flight_sql_service::command_statement_ingest::table_definition_options::TableExistsOption;
+use databend_query::servers::flight_sql::flight_sql_service::command_statement_ingest::table_definition_options::TableNotExistOption;
+use databend_query::servers::flight_sql::flight_sql_service::command_statement_ingest::TableDefinitionOptions;
+use databend_query::servers::flight_sql::flight_sql_service::CancelResult;
+use databend_query::servers::flight_sql::flight_sql_service::CommandStatementIngest;
+use databend_query::servers::flight_sql::flight_sql_service::EndTransaction;
 use databend_query::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use futures::StreamExt;
 use futures::TryStreamExt;
 use goldenfile::Mint;
 use log::debug;
+use prost::Message;
 use tempfile::NamedTempFile;
 use tokio::net::UnixListener;
 use tokio::net::UnixStream;
@@ -73,6 +102,82 @@ async fn run_query(
     Ok(res)
 }
 
+async fn begin_transaction(client: &mut FlightSqlServiceClient<Channel>) -> Bytes {
+    let action = Action {
+        r#type: "BeginTransaction".to_string(),
+        body: ActionBeginTransactionRequest {}
+            .as_any()
+            .encode_to_vec()
+            .into(),
+    };
+    let mut results = client.do_action(action).await.unwrap();
+    let result = results.message().await.unwrap().unwrap();
+    let result: ActionBeginTransactionResult =
+        Any::decode(result.body).unwrap().unpack().unwrap().unwrap();
+    result.transaction_id
+}
+
+async fn end_transaction(
+    client: &mut FlightSqlServiceClient<Channel>,
+    transaction_id: Bytes,
+    action: EndTransaction,
+) {
+    let action = Action {
+        r#type: "EndTransaction".to_string(),
+        body: ActionEndTransactionRequest {
+            transaction_id,
+            action: action as i32,
+        }
+        .as_any()
+        .encode_to_vec()
+        .into(),
+    };
+    let mut results = client.do_action(action).await.unwrap();
+    while results.message().await.unwrap().is_some() {}
+}
+
+async fn cancel_query(
+    client: &mut FlightSqlServiceClient<Channel>,
+    info: &FlightInfo,
+) -> CancelResult {
+    let action = Action {
+        r#type: "CancelQuery".to_string(),
+        body: ActionCancelQueryRequest {
+            info: info.encode_to_vec().into(),
+        }
+        .as_any()
+        .encode_to_vec()
+        .into(),
+    };
+    let mut results = client.do_action(action).await.unwrap();
+    let result = results.message().await.unwrap().unwrap();
+    let result: ActionCancelQueryResult =
+        Any::decode(result.body).unwrap().unpack().unwrap().unwrap();
+    CancelResult::try_from(result.result).unwrap()
+}
+
+async fn ingest(
+    client: &mut FlightSqlServiceClient<Channel>,
+    cmd: CommandStatementIngest,
+    batch: RecordBatch,
+) -> std::result::Result<i64, ArrowError> {
+    let descriptor = FlightDescriptor::new_cmd(cmd.as_any().encode_to_vec());
+    let flight_data = FlightDataEncoderBuilder::new()
+        .with_flight_descriptor(Some(descriptor))
+        .build(futures::stream::iter(vec![Ok(batch)]))
+        .try_collect::<Vec<_>>()
+        .await
+        .map_err(|e| ArrowError::IpcError(e.to_string()))?;
+    let mut results = client.do_put(futures::stream::iter(flight_data)).await?;
+    let result = results
+        .message()
+        .await
+        .map_err(|e| ArrowError::IpcError(e.to_string()))?
+        .unwrap();
+    let result: DoPutUpdateResult = Any::decode(result.app_metadata).unwrap().unpack()?.unwrap();
+    Ok(result.record_count)
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -131,6 +236,157 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let txn_cases = [
+            (
+                "insert into table test1(a, b) values (3, 'z')",
+                EndTransaction::Rollback,
+            ),
+            (
+                "insert into table test1(a, b) values (4, 'w')",
+                EndTransaction::Commit,
+            ),
+        ];
+        for (case, action) in txn_cases {
+            let transaction_id = begin_transaction(&mut client).await;
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{} /* {:?} */", case, action).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = run_query(&mut client, case).await.unwrap();
+            writeln!(file, "{}", res).unwrap();
+            end_transaction(&mut client, transaction_id, action).await;
+
+            let case = "select * from test1 order by a";
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = run_query(&mut client, case).await.unwrap();
+            writeln!(file, "{}", res).unwrap();
+        }
+
+        // The names are quoted in the statements built by the ingestion, the names with
+        // backticks are rejected.
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b c", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(schema, vec![
+            Arc::new(Int32Array::from(vec![1, 2])),
+            Arc::new(StringArray::from(vec![Some("x"), None])),
+        ])
+        .unwrap();
+        let ingest_cases = [
+            (
+                "test`2",
+                TableNotExistOption::Create,
+                TableExistsOption::Fail,
+                None,
+            ),
+            (
+                "test 2",
+                TableNotExistOption::Create,
+                TableExistsOption::Fail,
+                None,
+            ),
+            (
+                "test 2",
+                TableNotExistOption::Fail,
+                TableExistsOption::Fail,
+                None,
+            ),
+            (
+                "test 2",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                None,
+            ),
+            (
+                "test 2",
+                TableNotExistOption::Fail,
+                TableExistsOption::Append,
+                Some(Bytes::from("not_a_transaction")),
+            ),
+            (
+                "test 2",
+                TableNotExistOption::Fail,
+                TableExistsOption::Replace,
+                None,
+            ),
+        ];
+        for (table, if_not_exist, if_exists, transaction_id) in ingest_cases {
+            let cmd = CommandStatementIngest {
+                table_definition_options: Some(TableDefinitionOptions {
+                    if_not_exist: if_not_exist as i32,
+                    if_exists: if_exists as i32,
+                }),
+                table: table.to_string(),
+                transaction_id,
+                ..Default::default()
+            };
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(
+                file,
+                "ingest into {} /* {:?}, {:?}, {:?} */",
+                table, if_not_exist, if_exists, cmd.transaction_id
+            )
+            .unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match ingest(&mut client, cmd, batch.clone()).await {
+                Ok(affected_rows) => affected_rows.to_string(),
+                Err(_) => "Error".to_string(),
+            };
+            writeln!(file, "{}", res).unwrap();
+
+            let case = "select * from `test 2` order by a";
+            writeln!(file, "---------- Input ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match run_query(&mut client, case).await {
+                Ok(s) => s,
+                Err(_) => "Error".to_string(),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
+
+        // The query is cancelled while its results are being fetched.
+        let sql = "select sum(number) from numbers(100000000000)";
+        let mut stmt = client.prepare(sql.to_string(), None).await.unwrap();
+        let info = stmt.execute().await.unwrap();
+        assert_eq!(
+            cancel_query(&mut client, &info).await,
+            CancelResult::Unspecified
+        );
+        let ticket = info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let flight_data = client.do_get(ticket).await.unwrap();
+        assert_eq!(
+            cancel_query(&mut client, &info).await,
+            CancelResult::Cancelled
+        );
+        let res = flight_data.collect::<Vec<_>>().await;
+        assert!(res.iter().any(|data| data.is_err()));
+        // The cancelled query is forgotten.
+        assert_eq!(
+            cancel_query(&mut client, &info).await,
+            CancelResult::Unspecified
+        );
+
+        // The query is forgotten once its results are consumed.
+        let mut stmt = client.prepare("select 1".to_string(), None).await.unwrap();
+        let info = stmt.execute().await.unwrap();
+        let ticket = info.endpoint[0].ticket.as_ref().unwrap().clone();
+        let flight_data = client.do_get(ticket).await.unwrap();
+        let res = flight_data.collect::<Vec<_>>().await;
+        assert!(res.iter().all(|data| data.is_ok()));
+        // The results are dropped by the server asynchronously after they are sent.
+        let mut result = CancelResult::Cancelled;
+        for _ in 0..100 {
+            result = cancel_query(&mut client, &info).await;
+            if result == CancelResult::Unspecified {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(result, CancelResult::Unspecified);
     };
     tokio::pin!(serve_future);
 
@@ -154,3 +154,38 @@ async fn test_list_acquire() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_set_permits() -> Result<()> {
+    let queue = QueueManager::<TestData>::create(2);
+    let guard1 = queue.acquire(TestData("TestData1".to_string())).await?;
+    let guard2 = queue.acquire(TestData("TestData2".to_string())).await?;
+
+    // The running queries keep their permits, the surplus is retired when they finish.
+    queue.set_permits(1);
+    drop(guard1);
+    let acquire = queue.acquire(TestData("TestData3".to_string()));
+    assert!(
+        tokio::time::timeout(Duration::from_millis(500), acquire)
+            .await
+            .is_err()
+    );
+
+    drop(guard2);
+    let acquire = queue.acquire(TestData("TestData4".to_string()));
+    let _guard4 = tokio::time::timeout(Duration::from_millis(500), acquire)
+        .await
+        .unwrap()?;
+
+    queue.set_permits(3);
+    let _guard5 = queue.acquire(TestData("TestData5".to_string())).await?;
+    let _guard6 = queue.acquire(TestData("TestData6".to_string())).await?;
+    let acquire = queue.acquire(TestData("TestData7".to_string()));
+    assert!(
+        tokio::time::timeout(Duration::from_millis(500), acquire)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
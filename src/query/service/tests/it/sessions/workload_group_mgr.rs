@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_query::sessions::CpuQuota;
+
+#[test]
+fn test_cpu_quota() {
+    let quota = CpuQuota::create(0, 2);
+    quota.consume(Duration::from_secs(10));
+    assert_eq!(quota.wait_time(), Duration::ZERO);
+
+    // 50 percent of 2 cores, it bursts up to 100ms of cpu time.
+    let quota = CpuQuota::create(50, 2);
+    quota.consume(Duration::from_millis(50));
+    assert_eq!(quota.wait_time(), Duration::ZERO);
+
+    quota.consume(Duration::from_secs(1));
+    let wait_time = quota.wait_time();
+    assert!(!wait_time.is_zero());
+    assert!(wait_time <= Duration::from_millis(100));
+
+    // The debt is kept when the quota is changed.
+    quota.set_quota(10);
+    assert!(!quota.wait_time().is_zero());
+
+    quota.set_quota(0);
+    assert_eq!(quota.wait_time(), Duration::ZERO);
+}
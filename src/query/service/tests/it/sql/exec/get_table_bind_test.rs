@@ -97,6 +97,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -267,6 +269,13 @@ impl Catalog for FakedCatalog {
         todo!()
     }
 
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        todo!()
+    }
+
     #[async_backtrace::framed]
     async fn create_table_index(&self, _req: CreateTableIndexReq) -> Result<CreateTableIndexReply> {
         unimplemented!()
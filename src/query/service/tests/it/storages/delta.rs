@@ -0,0 +1,75 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_query::test_kits::*;
+use futures_util::future::try_join_all;
+
+// An empty delta table with a single INT column `a`.
+const INITIAL_COMMIT: &str = r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"metaData":{"id":"4f4b7c0e-4a43-4bb0-8f0e-7e7c8b5bd5c1","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"a\",\"type\":\"integer\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":[],"configuration":{},"createdTime":1700000000000}}
+"#;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delta_concurrent_append() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.storage.allow_insecure = true;
+    let fixture = TestFixture::setup_with_config(&conf).await?;
+    fixture.create_default_database().await?;
+    let db_name = fixture.default_db_name();
+
+    let dir = tempfile::tempdir()?;
+    let log_dir = dir.path().join("_delta_log");
+    std::fs::create_dir_all(&log_dir)?;
+    std::fs::write(log_dir.join(format!("{:020}.json", 0)), INITIAL_COMMIT)?;
+
+    let qry = format!(
+        "create table {db_name}.t engine = delta location = 'fs://{}/'",
+        dir.path().display()
+    );
+    fixture.execute_command(&qry).await?;
+
+    // The appends are serialized by the table lock, each one is committed as a new version.
+    let appends = (0..8).map(|i| {
+        let qry = format!("insert into {db_name}.t values({i})");
+        let fixture = &fixture;
+        async move { fixture.execute_command(&qry).await }
+    });
+    try_join_all(appends).await?;
+
+    let mut commits = std::fs::read_dir(&log_dir)?
+        .map(|entry| entry.map(|e| e.file_name().to_string_lossy().to_string()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    commits.sort();
+    let expected_commits = (0..=8).map(|v| format!("{v:020}.json")).collect::<Vec<_>>();
+    assert_eq!(commits, expected_commits);
+
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 8        | 28       |",
+        "+----------+----------+",
+    ];
+    let qry = format!("select count(*), sum(a) from {db_name}.t");
+    expects_ok(
+        "check appended rows",
+        fixture.execute_query(qry.as_str()).await,
+        expected,
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::test_kits::*;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_clone_failed() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.storage.allow_insecure = true;
+    let fixture = TestFixture::setup_with_config(&conf).await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+
+    // The tables stored in an external location can't be cloned.
+    let dir = tempfile::tempdir()?;
+    let qry = format!(
+        "create table {db}.t(a int) 'fs://{}/'",
+        dir.path().display()
+    );
+    fixture.execute_command(&qry).await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values(1)"))
+        .await?;
+
+    let qry = format!("create table {db}.t1 clone {db}.t");
+    let res = fixture.execute_command(&qry).await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::STORAGE_UNSUPPORTED);
+
+    // The table created for the clone is dropped.
+    let ctx = fixture.new_query_ctx().await?;
+    let res = ctx.get_table(&ctx.get_current_catalog(), &db, "t1").await;
+    assert_eq!(res.err().map(|e| e.code()), Some(ErrorCode::UNKNOWN_TABLE));
+
+    Ok(())
+}
@@ -0,0 +1,250 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::catalog::CatalogCreator;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogId;
+use databend_common_meta_app::schema::CatalogInfo;
+use databend_common_meta_app::schema::CatalogMeta;
+use databend_common_meta_app::schema::CatalogNameIdent;
+use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_storages_iceberg::resolve_table_location;
+use databend_common_storages_iceberg::IcebergCreator;
+use databend_query::test_kits::TestFixture;
+use serde_json::json;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+fn new_rest_catalog(uri: String, token: &str) -> Result<Arc<dyn Catalog>> {
+    let info = CatalogInfo {
+        id: CatalogId::default().into(),
+        name_ident: CatalogNameIdent {
+            tenant: "test".to_string(),
+            catalog_name: "iceberg_ctl".to_string(),
+        }
+        .into(),
+        meta: CatalogMeta {
+            catalog_option: CatalogOption::Iceberg(IcebergCatalogOption {
+                storage_params: Box::new(StorageParams::None),
+                rest: Some(IcebergRestCatalogOption {
+                    uri,
+                    warehouse: "wh".to_string(),
+                    token: token.to_string(),
+                }),
+            }),
+            created_on: chrono::Utc::now(),
+        },
+    };
+    IcebergCreator.try_create(&info)
+}
+
+async fn mount_json(server: &MockServer, p: &str, status: u16, body: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(p))
+        .and(header("Authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(server)
+        .await;
+}
+
+fn copy_dir(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        match entry.file_type()?.is_dir() {
+            true => copy_dir(&entry.path(), &target)?,
+            false => std::fs::copy(entry.path(), target).map(|_| ())?,
+        }
+    }
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_rest_catalog() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // The table is read from the metadata file returned by the catalog,
+    // neither version-hint.text nor the latest metadata file is used.
+    let dir = tempfile::tempdir()?;
+    let table_location = dir.path().join("iceberg_tbl");
+    copy_dir(
+        &Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../../tests/data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl"),
+        &table_location,
+    )?;
+    assert!(!table_location.join("metadata/version-hint.text").exists());
+    let table_location = table_location.display().to_string();
+
+    let server = MockServer::start().await;
+    mount_json(
+        &server,
+        "/v1/config",
+        200,
+        json!({"defaults": {}, "overrides": {"prefix": "wh"}}),
+    )
+    .await;
+    mount_json(
+        &server,
+        "/v1/wh/namespaces",
+        200,
+        json!({"namespaces": [["iceberg_db"]]}),
+    )
+    .await;
+    mount_json(
+        &server,
+        "/v1/wh/namespaces/iceberg_db",
+        200,
+        json!({"namespace": ["iceberg_db"], "properties": {}}),
+    )
+    .await;
+    mount_json(
+        &server,
+        "/v1/wh/namespaces/iceberg_db/tables",
+        200,
+        json!({"identifiers": [{"namespace": ["iceberg_db"], "name": "iceberg_tbl"}]}),
+    )
+    .await;
+    mount_json(
+        &server,
+        "/v1/wh/namespaces/iceberg_db/tables/iceberg_tbl",
+        200,
+        json!({
+            "metadata-location": format!("{table_location}/metadata/00001-7d548a72-6363-484f-9117-35f1f5b73453.metadata.json"),
+            "metadata": {"format-version": 1, "location": table_location},
+        }),
+    )
+    .await;
+    let not_found =
+        json!({"error": {"message": "not found", "type": "NoSuchNamespaceException", "code": 404}});
+    mount_json(
+        &server,
+        "/v1/wh/namespaces/unknown_db",
+        404,
+        not_found.clone(),
+    )
+    .await;
+    mount_json(
+        &server,
+        "/v1/wh/namespaces/iceberg_db/tables/unknown_tbl",
+        404,
+        not_found,
+    )
+    .await;
+
+    let catalog = new_rest_catalog(server.uri(), "secret")?;
+
+    let dbs = catalog.list_databases("test").await?;
+    let db_names = dbs.iter().map(|db| db.name()).collect::<Vec<_>>();
+    assert_eq!(db_names, vec!["iceberg_db"]);
+
+    let db = catalog.get_database("test", "iceberg_db").await?;
+    let tables = db.list_tables().await?;
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].name(), "iceberg_tbl");
+    assert_eq!(tables[0].engine(), "iceberg");
+
+    let table = db.get_table("iceberg_tbl").await?;
+    let schema = table.schema();
+    let field_names = schema
+        .fields()
+        .iter()
+        .map(|f| f.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(field_names, vec!["id", "data"]);
+
+    // The returned metadata file has 3 data files, the latest one has 6.
+    let ctx = fixture.new_query_ctx().await?;
+    let (_, parts) = table.read_partitions(ctx, None, true).await?;
+    assert_eq!(parts.len(), 3);
+
+    let err = catalog
+        .get_database("test", "unknown_db")
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_DATABASE);
+
+    let err = db.get_table("unknown_tbl").await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UNKNOWN_TABLE);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_iceberg_rest_catalog_auth() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/config"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": {"message": "invalid token", "type": "NotAuthorizedException", "code": 401}
+        })))
+        .mount(&server)
+        .await;
+
+    let catalog = new_rest_catalog(server.uri(), "wrong")?;
+    let err = catalog.list_databases("test").await.unwrap_err();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+    assert!(err.message().contains("invalid token"));
+
+    Ok(())
+}
+
+#[test]
+fn test_resolve_iceberg_table_location() -> Result<()> {
+    let warehouse = StorageParams::S3(StorageS3Config {
+        bucket: "bkt".to_string(),
+        root: "/warehouse/".to_string(),
+        ..Default::default()
+    });
+
+    let sp = resolve_table_location(&warehouse, "s3://bkt/warehouse/db/tbl/")?;
+    assert_eq!(
+        sp,
+        StorageParams::S3(StorageS3Config {
+            bucket: "bkt".to_string(),
+            root: "/warehouse/db/tbl/".to_string(),
+            ..Default::default()
+        })
+    );
+
+    let sp = resolve_table_location(&StorageParams::None, "file:///tmp/db/tbl")?;
+    assert_eq!(
+        sp,
+        StorageParams::Fs(StorageFsConfig {
+            root: "/tmp/db/tbl/".to_string()
+        })
+    );
+
+    let err = resolve_table_location(&StorageParams::None, "s3://bkt/db/tbl").unwrap_err();
+    assert_eq!(err.code(), ErrorCode::BAD_ARGUMENTS);
+
+    Ok(())
+}
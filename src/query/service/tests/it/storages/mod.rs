@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod delta;
 mod fuse;
+mod iceberg;
 mod null;
 mod statistics;
 mod system;
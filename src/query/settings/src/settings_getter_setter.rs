@@ -374,6 +374,18 @@ impl Settings {
         Ok(self.try_get_u64("sort_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_window_partition_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_partition_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
+    pub fn get_window_partition_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_partition_spilling_memory_ratio")? as usize)
+    }
+
+    pub fn get_window_num_partitions(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_num_partitions")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -568,6 +580,14 @@ impl Settings {
         self.try_get_u64("external_server_request_timeout_secs")
     }
 
+    pub fn get_script_udf_timeout_ms(&self) -> Result<u64> {
+        self.try_get_u64("script_udf_timeout_ms")
+    }
+
+    pub fn get_script_udf_memory_limit(&self) -> Result<u64> {
+        self.try_get_u64("script_udf_memory_limit")
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
@@ -632,6 +652,10 @@ impl Settings {
         self.try_set_u64("enable_geo_create_table", u64::from(val))
     }
 
+    pub fn get_geometry_output_format(&self) -> Result<String> {
+        self.try_get_string("geometry_output_format")
+    }
+
     pub fn get_idle_transaction_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("idle_transaction_timeout_secs")
     }
@@ -643,4 +667,12 @@ impl Settings {
     pub fn get_statement_queued_timeout(&self) -> Result<u64> {
         self.try_get_u64("statement_queued_timeout_in_seconds")
     }
+
+    pub fn get_max_cte_recursive_depth(&self) -> Result<u64> {
+        self.try_get_u64("max_cte_recursive_depth")
+    }
+
+    pub fn get_script_max_steps(&self) -> Result<u64> {
+        self.try_get_u64("script_max_steps")
+    }
 }
@@ -48,6 +48,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::RangeJoinType;
 use crate::executor::physical_plans::ReclusterSink;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::RowFetch;
 use crate::executor::physical_plans::Sort;
 use crate::executor::physical_plans::TableScan;
@@ -251,6 +253,8 @@ fn to_format_tree(
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::RecursiveCteScan(plan) => recursive_cte_scan_to_format_tree(plan, metadata),
     }
 }
 
@@ -362,6 +366,33 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Sample
+    if let Some(sample) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.sample.as_ref())
+    {
+        let seed = sample
+            .seed
+            .map_or("NONE".to_string(), |seed| seed.to_string());
+        children.push(FormatTreeNode::new(format!(
+            "sample: [level: {:?}, probability: {}, seed: {seed}]",
+            sample.level, sample.probability
+        )));
+    }
+    // Vector index
+    if let Some(vector_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {}, column: {}, limit: {}]",
+            vector_index.index_name, vector_index.column_name, vector_index.limit
+        )));
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -895,6 +926,7 @@ fn range_join_to_format_tree(
         match plan.range_join_type {
             RangeJoinType::IEJoin => "IEJoin".to_string(),
             RangeJoinType::Merge => "MergeJoin".to_string(),
+            RangeJoinType::Asof => "AsofJoin".to_string(),
         },
         children,
     ))
@@ -1012,6 +1044,53 @@ fn union_all_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("cte name: {}", plan.cte_name)),
+        FormatTreeNode::new(format!("distinct: {}", plan.distinct)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.extend(vec![
+        to_format_tree(&plan.left, metadata, profs)?,
+        to_format_tree(&plan.right, metadata, profs)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTE".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+) -> Result<FormatTreeNode<String>> {
+    let children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTEScan".to_string(),
+        children,
+    ))
+}
+
 fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
@@ -51,6 +51,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -85,6 +87,8 @@ pub enum PhysicalPlan {
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -225,6 +229,16 @@ impl PhysicalPlan {
                 plan.plan_id = *next_id;
                 *next_id += 1;
             }
+            PhysicalPlan::RecursiveCte(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+                plan.left.adjust_plan_id(next_id);
+                plan.right.adjust_plan_id(next_id);
+            }
+            PhysicalPlan::RecursiveCteScan(plan) => {
+                plan.plan_id = *next_id;
+                *next_id += 1;
+            }
             PhysicalPlan::Udf(plan) => {
                 plan.plan_id = *next_id;
                 *next_id += 1;
@@ -344,6 +358,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(v) => v.plan_id,
             PhysicalPlan::MaterializedCte(v) => v.plan_id,
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
+            PhysicalPlan::RecursiveCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
             PhysicalPlan::Udf(v) => v.plan_id,
             PhysicalPlan::DeleteSource(v) => v.plan_id,
             PhysicalPlan::MergeInto(v) => v.plan_id,
@@ -388,6 +404,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::Udf(plan) => plan.output_schema(),
             PhysicalPlan::MergeIntoSource(plan) => plan.input.output_schema(),
             PhysicalPlan::MergeInto(plan) => Ok(plan.output_schema.clone()),
@@ -412,6 +430,7 @@ impl PhysicalPlan {
                 DataSourceInfo::TableSource(_) => "TableScan".to_string(),
                 DataSourceInfo::StageSource(_) => "StageScan".to_string(),
                 DataSourceInfo::ParquetSource(_) => "ParquetScan".to_string(),
+                DataSourceInfo::OrcSource(_) => "OrcScan".to_string(),
                 DataSourceInfo::ResultScanSource(_) => "ResultScan".to_string(),
             },
             PhysicalPlan::Filter(_) => "Filter".to_string(),
@@ -446,6 +465,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(_) => "PhysicalCteScan".to_string(),
             PhysicalPlan::MaterializedCte(_) => "PhysicalMaterializedCte".to_string(),
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "RecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
             PhysicalPlan::MergeIntoAddRowNumber(_) => "AddRowNumber".to_string(),
             PhysicalPlan::ReclusterSource(_) => "ReclusterSource".to_string(),
             PhysicalPlan::ReclusterSink(_) => "ReclusterSink".to_string(),
@@ -459,6 +480,7 @@ impl PhysicalPlan {
             PhysicalPlan::TableScan(_)
             | PhysicalPlan::CteScan(_)
             | PhysicalPlan::ConstantTableScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::CompactSource(_)
             | PhysicalPlan::DeleteSource(_)
@@ -507,6 +529,9 @@ impl PhysicalPlan {
             PhysicalPlan::MaterializedCte(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
             PhysicalPlan::ReclusterSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Udf(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::CopyIntoLocation(plan) => Box::new(std::iter::once(plan.input.as_ref())),
@@ -551,6 +576,8 @@ impl PhysicalPlan {
             | PhysicalPlan::MergeIntoSource(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCte(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ReclusterSource(_)
             | PhysicalPlan::ReclusterSink(_)
             | PhysicalPlan::UpdateSource(_) => None,
@@ -716,6 +743,11 @@ impl PhysicalPlan {
                 .iter()
                 .map(|(l, r)| format!("#{} <- #{}", l, r))
                 .join(", "),
+            PhysicalPlan::RecursiveCte(v) => v
+                .pairs
+                .iter()
+                .map(|(l, r)| format!("#{} <- #{}", l, r))
+                .join(", "),
             _ => String::new(),
         })
     }
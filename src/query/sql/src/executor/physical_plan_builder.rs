@@ -114,6 +114,12 @@ impl PhysicalPlanBuilder {
             }
             RelOperator::AddRowNumber(_) => self.build_add_row_number(s_expr, required).await,
             RelOperator::Udf(udf) => self.build_udf(s_expr, udf, required, stat_info).await,
+            RelOperator::RecursiveCte(r_cte) => {
+                self.build_recursive_cte(s_expr, r_cte, stat_info).await
+            }
+            RelOperator::RecursiveCteScan(scan) => {
+                self.build_recursive_cte_scan(scan, required).await
+            }
         }
     }
 }
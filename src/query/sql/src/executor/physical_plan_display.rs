@@ -47,6 +47,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -117,6 +119,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::ReclusterSink(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::UpdateSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::Udf(udf) => write!(f, "{}", udf)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(scan) => write!(f, "{}", scan)?,
         }
 
         for node in self.node.children() {
@@ -146,6 +150,18 @@ impl Display for MaterializedCte {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCte: [{}]", self.cte_name)
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan: [{}]", self.cte_idx)
+    }
+}
+
 impl Display for ConstantTableScan {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let columns = self
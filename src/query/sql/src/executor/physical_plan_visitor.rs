@@ -44,6 +44,8 @@ use crate::executor::physical_plans::ProjectSet;
 use crate::executor::physical_plans::RangeJoin;
 use crate::executor::physical_plans::ReclusterSink;
 use crate::executor::physical_plans::ReclusterSource;
+use crate::executor::physical_plans::RecursiveCte;
+use crate::executor::physical_plans::RecursiveCteScan;
 use crate::executor::physical_plans::ReplaceAsyncSourcer;
 use crate::executor::physical_plans::ReplaceDeduplicate;
 use crate::executor::physical_plans::ReplaceInto;
@@ -98,6 +100,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::ReclusterSink(plan) => self.replace_recluster_sink(plan),
             PhysicalPlan::UpdateSource(plan) => self.replace_update_source(plan),
             PhysicalPlan::Udf(plan) => self.replace_udf(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
         }
     }
 
@@ -259,6 +263,21 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let left = self.replace(&plan.left)?;
+        let right = self.replace(&plan.right)?;
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            left: Box::new(left),
+            right: Box::new(right),
+            ..plan.clone()
+        }))
+    }
+
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_range_join(&mut self, plan: &RangeJoin) -> Result<PhysicalPlan> {
         let left = self.replace(&plan.left)?;
         let right = self.replace(&plan.right)?;
@@ -524,6 +543,7 @@ impl PhysicalPlan {
                 PhysicalPlan::TableScan(_)
                 | PhysicalPlan::ReplaceAsyncSourcer(_)
                 | PhysicalPlan::CteScan(_)
+                | PhysicalPlan::RecursiveCteScan(_)
                 | PhysicalPlan::ConstantTableScan(_)
                 | PhysicalPlan::ReclusterSource(_)
                 | PhysicalPlan::ExchangeSource(_)
@@ -623,6 +643,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.left, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.right, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Udf(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
@@ -395,7 +395,9 @@ impl PhysicalPlanBuilder {
             | JoinType::LeftSingle
             | JoinType::Right
             | JoinType::RightSingle
-            | JoinType::Full => {
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
@@ -29,6 +30,8 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -41,10 +44,11 @@ pub struct RangeJoin {
     pub right: Box<PhysicalPlan>,
     // The first two conditions: (>, >=, <, <=)
     // Condition's left/right side only contains one table's column
+    // For asof join, the first condition is the match condition and the rest are equi conditions (=)
     pub conditions: Vec<RangeJoinCondition>,
     // The other conditions
     pub other_conditions: Vec<RemoteExpr>,
-    // Now only support inner join, will support left/right join later
+    // Now only support inner join and asof join, will support left/right join later
     pub join_type: JoinType,
     pub range_join_type: RangeJoinType,
 
@@ -55,7 +59,18 @@ pub struct RangeJoin {
 impl RangeJoin {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let mut fields = self.left.output_schema()?.fields().clone();
-        fields.extend(self.right.output_schema()?.fields().clone());
+        let right_schema = self.right.output_schema()?;
+        if self.join_type == JoinType::LeftAsof {
+            // Wrap nullable type for columns in right side.
+            fields.extend(
+                right_schema
+                    .fields()
+                    .iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_schema.fields().clone());
+        }
         Ok(DataSchemaRefExt::create(fields))
     }
 }
@@ -64,13 +79,14 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    Asof,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or "eq" for the equi conditions of asof join
     pub operator: String,
 }
 
@@ -142,6 +158,67 @@ impl PhysicalPlanBuilder {
     }
 }
 
+impl PhysicalPlanBuilder {
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        // Asof join outputs all the matched rows of left side, so the left side is not swapped.
+        let left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let right_side = self.build(s_expr.child(1)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        // The match condition is the only non-equi condition.
+        debug_assert_eq!(join.non_equi_conditions.len(), 1);
+        let mut conditions = Vec::with_capacity(join.left_conditions.len() + 1);
+        conditions.push(resolve_range_condition(
+            &join.non_equi_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?);
+        for (left, right) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            let equi_condition = ScalarExpr::FunctionCall(FunctionCall {
+                span: None,
+                func_name: "eq".to_string(),
+                params: vec![],
+                arguments: vec![left.clone(), right.clone()],
+            });
+            conditions.push(resolve_range_condition(
+                &equi_condition,
+                &left_schema,
+                &right_schema,
+                &left_prop,
+                &right_prop,
+            )?);
+        }
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions,
+            other_conditions: vec![],
+            join_type: join.join_type.clone(),
+            range_join_type: RangeJoinType::Asof,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
 fn resolve_range_condition(
     expr: &ScalarExpr,
     left_schema: &DataSchemaRef,
@@ -200,6 +277,7 @@ fn resolve_range_condition(
                     "lt" => "gt",
                     "gte" => "lte",
                     "lte" => "gte",
+                    "eq" => "eq",
                     _ => unreachable!(),
                 }
             } else {
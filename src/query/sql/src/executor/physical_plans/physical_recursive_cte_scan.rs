@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCteScan {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub cte_idx: IndexType,
+    pub output_schema: DataSchemaRef,
+    // Offsets of the output columns in the working table
+    pub offsets: Vec<usize>,
+}
+
+impl RecursiveCteScan {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte_scan(
+        &mut self,
+        scan: &crate::plans::RecursiveCteScan,
+        required: ColumnSet,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        // The columns of the working table are in the same order as the fields of the scan.
+        let mut pruned_fields = vec![];
+        let mut offsets = vec![];
+        for (offset, field) in scan.fields.iter().enumerate() {
+            if required.contains(&field.name().parse()?) {
+                pruned_fields.push(field.clone());
+                offsets.push(offset);
+            }
+        }
+
+        // 2. Build physical plan.
+        Ok(PhysicalPlan::RecursiveCteScan(RecursiveCteScan {
+            plan_id: 0,
+            cte_idx: scan.cte_idx,
+            output_schema: DataSchemaRefExt::create(pruned_fields),
+            offsets,
+        }))
+    }
+}
@@ -41,6 +41,7 @@ pub struct ReplaceDeduplicate {
     pub table_level_range_index: HashMap<ColumnId, ColumnStatistics>,
     pub need_insert: bool,
     pub delete_when: Option<(RemoteExpr, String)>,
+    pub error_on_conflict: bool,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
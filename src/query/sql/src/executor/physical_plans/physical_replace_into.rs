@@ -36,4 +36,5 @@ pub struct ReplaceInto {
     pub segments: Vec<(usize, Location)>,
     pub block_slots: Option<BlockSlotDescription>,
     pub need_insert: bool,
+    pub error_on_conflict: bool,
 }
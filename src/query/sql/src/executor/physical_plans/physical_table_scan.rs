@@ -481,13 +481,17 @@ impl PhysicalPlanBuilder {
             filters: push_down_filter,
             is_deterministic,
             prewhere: prewhere_info,
-            limit: scan.limit,
+            // The limit can't be pushed down to the sampled scan, as the rows will be
+            // filtered by the sampling after reading.
+            limit: scan.limit.filter(|_| scan.sample.is_none()),
             order_by: order_by.unwrap_or_default(),
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
             change_type: scan.change_type.clone(),
             inverted_index: scan.inverted_index.clone(),
+            vector_index: scan.vector_index.clone(),
+            sample: scan.sample.clone(),
         })
     }
 
@@ -145,6 +145,9 @@ pub struct BindContext {
     /// If true, the query is planning for aggregate index.
     /// It's used to avoid infinite loop.
     pub planning_agg_index: bool,
+    /// If true, the row access policies of the bound tables are not applied,
+    /// e.g. when binding the target table of DELETE/UPDATE/MERGE.
+    pub skip_row_access_policy: bool,
 
     pub window_definitions: DashMap<String, WindowSpec>,
 }
@@ -154,6 +157,8 @@ pub struct CteInfo {
     pub columns_alias: Vec<String>,
     pub query: Query,
     pub materialized: bool,
+    // If the cte references itself in `WITH RECURSIVE`
+    pub recursive: bool,
     pub cte_idx: IndexType,
     // Record how many times this cte is used
     pub used_count: usize,
@@ -180,6 +185,7 @@ impl BindContext {
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            skip_row_access_policy: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -200,6 +206,7 @@ impl BindContext {
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            skip_row_access_policy: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -19,6 +19,7 @@ use std::time::Instant;
 
 use chrono_tz::Tz;
 use databend_common_ast::ast::format_statement;
+use databend_common_ast::ast::AlterRoleAction;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Statement;
@@ -43,8 +44,10 @@ use crate::binder::util::illegal_ident_name;
 use crate::binder::wrap_cast;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
+use crate::binder::RecursiveCteScanInfo;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
+use crate::plans::AlterRolePlan;
 use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::DescConnectionPlan;
@@ -96,6 +99,8 @@ pub struct Binder {
     /// Use `IndexMap` because need to keep the insertion order
     /// Then wrap materialized ctes to main plan.
     pub ctes_map: Box<IndexMap<String, CteInfo>>,
+    /// The working tables of recursive ctes whose recursive term is being bound.
+    pub r_cte_scans: HashMap<String, RecursiveCteScanInfo>,
 }
 
 impl<'a> Binder {
@@ -116,6 +121,7 @@ impl<'a> Binder {
             eq_scalars: vec![],
             m_cte_bound_s_expr: Default::default(),
             ctes_map: Box::default(),
+            r_cte_scans: HashMap::new(),
         }
     }
 
@@ -325,6 +331,9 @@ impl<'a> Binder {
             Statement::CreateInvertedIndex(stmt) => self.bind_create_inverted_index(bind_context, stmt).await?,
             Statement::DropInvertedIndex(stmt) => self.bind_drop_inverted_index(bind_context, stmt).await?,
             Statement::RefreshInvertedIndex(stmt) => self.bind_refresh_inverted_index(bind_context, stmt).await?,
+            Statement::CreateVectorIndex(stmt) => self.bind_create_vector_index(bind_context, stmt).await?,
+            Statement::DropVectorIndex(stmt) => self.bind_drop_vector_index(bind_context, stmt).await?,
+            Statement::RefreshVectorIndex(stmt) => self.bind_refresh_vector_index(bind_context, stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -357,6 +366,14 @@ impl<'a> Binder {
                 if_not_exists: *if_not_exists,
                 role_name: role_name.to_string(),
             }))},
+            Statement::AlterRole(stmt) => Plan::AlterRole(Box::new(AlterRolePlan {
+                if_exists: stmt.if_exists,
+                role_name: stmt.role_name.clone(),
+                workload_group: match &stmt.action {
+                    AlterRoleAction::SetWorkloadGroup(name) => Some(name.clone()),
+                    AlterRoleAction::UnsetWorkloadGroup => None,
+                },
+            })),
             Statement::DropRole {
                 if_exists,
                 role_name,
@@ -558,6 +575,15 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -586,6 +612,15 @@ impl<'a> Binder {
                 self.bind_desc_password_policy(stmt).await?
             }
             Statement::ShowPasswordPolicies{ show_options } => self.bind_show_password_policies(bind_context, show_options).await?,
+            Statement::CreateWorkloadGroup(stmt) => {
+                self.bind_create_workload_group(stmt).await?
+            }
+            Statement::AlterWorkloadGroup(stmt) => {
+                self.bind_alter_workload_group(stmt).await?
+            }
+            Statement::DropWorkloadGroup(stmt) => {
+                self.bind_drop_workload_group(stmt).await?
+            }
             Statement::CreateTask(stmt) => {
                 self.bind_create_task(stmt).await?
             }
@@ -611,18 +646,10 @@ impl<'a> Binder {
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
             Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
-            }
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(bind_context, stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
             Statement::CreateNotification(stmt) => {
                 self.bind_create_notification(stmt).await?
             }
@@ -635,6 +662,7 @@ impl<'a> Binder {
             Statement::DescribeNotification(stmt) => {
                 self.bind_desc_notification(stmt).await?
             }
+            Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
             Statement::Begin => Plan::Begin,
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
@@ -56,6 +56,7 @@ use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_storage::StageFilesInfo;
 use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::TableConstraints;
 use derive_visitor::Drive;
 use indexmap::IndexMap;
 use log::debug;
@@ -127,6 +128,13 @@ impl<'a> Binder {
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+        let constraints = TableConstraints::from_options(table.options())?;
+        if let Some((name, _)) = constraints.enforced_unique_key() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "COPY INTO is not supported on table {} with the enforced constraint {}",
+                table_name, name
+            )));
+        }
 
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
@@ -186,9 +194,13 @@ impl<'a> Binder {
         bind_ctx: &BindContext,
         plan: CopyIntoTablePlan,
     ) -> Result<Plan> {
-        if let FileFormatParams::Parquet(fmt) = &plan.stage_table_info.stage_info.file_format_params
-            && fmt.missing_field_as == NullAs::Error
-        {
+        let copy_from_query = match &plan.stage_table_info.stage_info.file_format_params {
+            FileFormatParams::Parquet(fmt) => fmt.missing_field_as == NullAs::Error,
+            // ORC files can only be read by columns, through the stage table.
+            FileFormatParams::Orc(_) => true,
+            _ => false,
+        };
+        if copy_from_query {
             let mut select_list = Vec::with_capacity(plan.required_source_schema.num_fields());
             for dest_field in plan.required_source_schema.fields().iter() {
                 let column = Expr::ColumnRef {
@@ -554,6 +566,7 @@ fn check_transform_query(
                     location,
                     options,
                     alias,
+                    sample: None,
                 } = &select.from[0]
                 {
                     if options.is_empty() {
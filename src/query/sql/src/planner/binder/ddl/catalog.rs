@@ -34,6 +34,7 @@ use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
 
 use crate::binder::parse_storage_params_from_uri;
@@ -160,14 +161,47 @@ impl Binder {
                 })
             }
             CatalogType::Iceberg => {
-                let sp = parse_catalog_url(ctx,options.clone()).await?.ok_or_else(|| {
-                    ErrorCode::InvalidArgument(
-                        "expect storage connection but failed to find, seems the url is missing",
-                    )
-                })?;
+                let mut options = options.clone();
 
-                let opt = IcebergCatalogOption {
-                    storage_params: Box::new(sp),
+                let opt = match options.remove("type").map(|v| v.to_lowercase()).as_deref() {
+                    None | Some("storage") => {
+                        let sp = parse_catalog_url(ctx, options).await?.ok_or_else(|| {
+                            ErrorCode::InvalidArgument(
+                                "expect storage connection but failed to find, seems the url is missing",
+                            )
+                        })?;
+
+                        IcebergCatalogOption {
+                            storage_params: Box::new(sp),
+                            rest: None,
+                        }
+                    }
+                    Some("rest") => {
+                        // Remove rest options to avoid unexpected field error in uri location.
+                        let uri = options
+                            .remove("uri")
+                            .ok_or_else(|| ErrorCode::InvalidArgument("expected field: URI"))?;
+                        let warehouse = options.remove("warehouse").unwrap_or_default();
+                        let token = options.remove("token").unwrap_or_default();
+
+                        // Storage connection of the warehouse is optional for rest catalogs,
+                        // tables in local paths could still be read without it.
+                        let sp = parse_catalog_url(ctx, options).await?;
+
+                        IcebergCatalogOption {
+                            storage_params: Box::new(sp.unwrap_or(StorageParams::None)),
+                            rest: Some(IcebergRestCatalogOption {
+                                uri: uri.trim_end_matches('/').to_string(),
+                                warehouse,
+                                token,
+                            }),
+                        }
+                    }
+                    Some(other) => {
+                        return Err(ErrorCode::InvalidArgument(format!(
+                            "unsupported iceberg catalog type '{other}', expected 'storage' or 'rest'"
+                        )));
+                    }
                 };
                 CatalogOption::Iceberg(opt)
             }
@@ -16,6 +16,7 @@ use std::collections::BTreeMap;
 
 use databend_common_ast::ast::AlterDatabaseAction;
 use databend_common_ast::ast::AlterDatabaseStmt;
+use databend_common_ast::ast::CloneDatabaseSource;
 use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DatabaseRef;
@@ -24,7 +25,9 @@ use databend_common_ast::ast::SQLProperty;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
 use databend_common_ast::ast::ShowLimit;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::UndropDatabaseStmt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
@@ -35,6 +38,7 @@ use log::debug;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::CloneDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
@@ -213,6 +217,7 @@ impl Binder {
             engine,
             options,
             from_share,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -230,13 +235,51 @@ impl Binder {
         };
         let meta = self.database_meta(engine, options, from_share)?;
 
-        Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
+        let plan = CreateDatabasePlan {
             create_option: *create_option,
             tenant,
             catalog,
             database,
             meta,
-        })))
+        };
+
+        match clone_from {
+            Some(CloneDatabaseSource {
+                database: source,
+                travel_point,
+            }) => {
+                let source_catalog = source
+                    .catalog
+                    .as_ref()
+                    .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                    .unwrap_or_else(|| self.ctx.get_current_catalog());
+                let source_database =
+                    normalize_identifier(&source.database, &self.name_resolution_ctx).name;
+                let navigation = match travel_point {
+                    // A snapshot id or a tag only identifies the data of one table.
+                    Some(TimeTravelPoint::Snapshot(_)) | Some(TimeTravelPoint::Tag(_)) => {
+                        return Err(ErrorCode::BadArguments(
+                            "Incorrect CREATE query: CREATE DATABASE ... CLONE only supports AT (TIMESTAMP => ..)",
+                        ));
+                    }
+                    Some(point) => {
+                        let mut bind_context = BindContext::new();
+                        Some(
+                            self.resolve_data_travel_point(&mut bind_context, point)
+                                .await?,
+                        )
+                    }
+                    None => None,
+                };
+                Ok(Plan::CloneDatabase(Box::new(CloneDatabasePlan {
+                    create: plan,
+                    source_catalog,
+                    source_database,
+                    navigation,
+                })))
+            }
+            None => Ok(Plan::CreateDatabase(Box::new(plan))),
+        }
     }
 
     fn database_meta(
@@ -12,32 +12,41 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use databend_common_ast::ast::CreateIndexStmt;
 use databend_common_ast::ast::CreateInvertedIndexStmt;
+use databend_common_ast::ast::CreateVectorIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
 use databend_common_ast::ast::DropInvertedIndexStmt;
+use databend_common_ast::ast::DropVectorIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
 use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshVectorIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::TableDataType;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_types::NonEmptyString;
+use databend_storages_common_index::VectorIndexOptions;
 use databend_storages_common_table_meta::meta::Location;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
@@ -424,7 +433,9 @@ impl Binder {
             create_option: *create_option,
             catalog,
             index_name,
+            index_type: TableIndexType::Inverted,
             column_ids,
+            index_options: BTreeMap::new(),
             table_id,
             sync_creation: *sync_creation,
         };
@@ -457,11 +468,13 @@ impl Binder {
         }
         let table_id = table.get_id();
         let index_name = self.normalize_object_identifier(index_name);
+        check_table_index_type(&table, &index_name, TableIndexType::Inverted)?;
 
         let plan = DropTableIndexPlan {
             if_exists: *if_exists,
             catalog,
             index_name,
+            index_type: TableIndexType::Inverted,
             table_id,
         };
         Ok(Plan::DropTableIndex(Box::new(plan)))
@@ -490,7 +503,149 @@ impl Binder {
             database,
             table,
             index_name,
+            index_type: TableIndexType::Inverted,
         };
         Ok(Plan::RefreshTableIndex(Box::new(plan)))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            create_option,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            index_options,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create vector index",
+                table.engine()
+            )));
+        }
+        let table_schema = table.schema();
+        let column_name = self.normalize_object_identifier(column);
+        let Ok(field) = table_schema.field_with_name(&column_name) else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table does not have column {}",
+                column
+            )));
+        };
+        if field.data_type.remove_nullable()
+            != TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Float32)))
+        {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index currently only support Array(Float32) type, but the type of column {} is {}",
+                column, field.data_type
+            )));
+        }
+        // Check the options before creating the index.
+        VectorIndexOptions::try_create(index_options)?;
+
+        let table_id = table.get_id();
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = CreateTableIndexPlan {
+            create_option: *create_option,
+            catalog,
+            index_name,
+            index_type: TableIndexType::Vector,
+            column_ids: vec![field.column_id],
+            index_options: index_options.clone(),
+            table_id,
+            sync_creation: true,
+        };
+        Ok(Plan::CreateTableIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_vector_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &DropVectorIndexStmt,
+    ) -> Result<Plan> {
+        let DropVectorIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support drop vector index",
+                table.engine()
+            )));
+        }
+        let table_id = table.get_id();
+        let index_name = self.normalize_object_identifier(index_name);
+        check_table_index_type(&table, &index_name, TableIndexType::Vector)?;
+
+        let plan = DropTableIndexPlan {
+            if_exists: *if_exists,
+            catalog,
+            index_name,
+            index_type: TableIndexType::Vector,
+            table_id,
+        };
+        Ok(Plan::DropTableIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_vector_index(
+        &mut self,
+        _bind_context: &mut BindContext,
+        stmt: &RefreshVectorIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshVectorIndexStmt {
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+
+        let plan = RefreshTableIndexPlan {
+            catalog,
+            database,
+            table,
+            index_name,
+            index_type: TableIndexType::Vector,
+        };
+        Ok(Plan::RefreshTableIndex(Box::new(plan)))
+    }
+}
+
+// Checks the type of the index to be dropped, it's ok if the index does not exist.
+fn check_table_index_type(
+    table: &Arc<dyn Table>,
+    index_name: &str,
+    index_type: TableIndexType,
+) -> Result<()> {
+    match table.get_table_info().meta.indexes.get(index_name) {
+        Some(index) if index.index_type != index_type => Err(ErrorCode::UnsupportedIndex(format!(
+            "Index {} is not a {} index",
+            index_name, index_type
+        ))),
+        _ => Ok(()),
+    }
 }
@@ -22,7 +22,9 @@ mod index;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod pipe;
 mod role;
+mod row_access_policy;
 mod share;
 mod stage;
 mod stream;
@@ -30,3 +32,4 @@ mod table;
 mod task;
 mod view;
 mod virtual_column;
+mod workload_group;
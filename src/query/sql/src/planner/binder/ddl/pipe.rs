@@ -0,0 +1,307 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::timestamp::string_to_timestamp;
+use databend_common_meta_app::schema::GetPipeReq;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+
+use crate::binder::resolve_file_location;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        // Resolve the target table with the current catalog and database,
+        // so that the pipe always loads into the same table.
+        let (catalog, database, table) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        self.ctx.get_table(&catalog, &database, &table).await?;
+
+        let mut copy_stmt = copy_stmt.clone();
+        copy_stmt.dst.catalog = Some(Identifier::from_name_with_quoted(
+            None,
+            catalog.clone(),
+            Some('`'),
+        ));
+        copy_stmt.dst.database = Some(Identifier::from_name_with_quoted(
+            None,
+            database.clone(),
+            Some('`'),
+        ));
+        copy_stmt.dst.table = Identifier::from_name_with_quoted(None, table.clone(), Some('`'));
+
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant().to_string(),
+            name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+            catalog,
+            database,
+            table,
+            copy_stmt: copy_stmt.to_string(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    // alter_pipe
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+        let tenant = self.ctx.get_tenant();
+        match options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => {
+                if execution_paused.is_none() && comments.is_none() {
+                    return Err(ErrorCode::SyntaxException(
+                        "No options to alter".to_string(),
+                    ));
+                }
+                let plan = AlterPipePlan {
+                    if_exists: *if_exists,
+                    tenant: tenant.to_string(),
+                    name: name.to_string(),
+                    execution_paused: *execution_paused,
+                    comment: comments.clone(),
+                };
+                Ok(Plan::AlterPipe(Box::new(plan)))
+            }
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => {
+                self.bind_refresh_pipe(bind_context, name, prefix, modified_after)
+                    .await
+            }
+        }
+    }
+
+    /// Refreshing a pipe runs its `COPY INTO <table>` statement once,
+    /// files that have been loaded are skipped as in a normal copy.
+    #[async_backtrace::framed]
+    async fn bind_refresh_pipe(
+        &mut self,
+        bind_context: &mut BindContext,
+        name: &str,
+        prefix: &Option<String>,
+        modified_after: &Option<String>,
+    ) -> Result<Plan> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_default_catalog()?;
+        let reply = catalog
+            .get_pipe(GetPipeReq::new(tenant.to_string(), name))
+            .await?;
+        if reply.meta.execution_paused {
+            return Err(ErrorCode::BadArguments(format!(
+                "Pipe '{}' is paused, resume it with ALTER PIPE {} SET PIPE_EXECUTION_PAUSED = FALSE",
+                name, name
+            )));
+        }
+
+        let tokens = tokenize_sql(&reply.meta.copy_stmt)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::CopyIntoTable(mut copy_stmt) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid copy statement of pipe '{}': {}",
+                name, reply.meta.copy_stmt
+            )));
+        };
+
+        if let Some(prefix) = prefix {
+            match copy_source_location_mut(&mut copy_stmt.src)? {
+                FileLocation::Stage(location) => {
+                    *location = join_prefix(location, prefix);
+                }
+                FileLocation::Uri(uri) => {
+                    uri.path = join_prefix(&uri.path, prefix);
+                }
+            }
+        }
+
+        if let Some(modified_after) = modified_after {
+            let tz = self.ctx.get_function_context()?.tz.tz;
+            let modified_after = string_to_timestamp(modified_after, tz).ok_or_else(|| {
+                ErrorCode::BadArguments(format!(
+                    "Invalid MODIFIED_AFTER timestamp: {}",
+                    modified_after
+                ))
+            })?;
+            copy_stmt.files = Some(
+                self.list_files_modified_after(&copy_stmt, modified_after)
+                    .await?,
+            );
+        }
+
+        self.bind_copy_into_table(bind_context, &copy_stmt).await
+    }
+
+    /// List the files of the copy source modified after `modified_after`,
+    /// the returned paths are relative to the copy location so they can be
+    /// used as `FILES` of the copy.
+    ///
+    /// The files that have been loaded into the target table are excluded, a
+    /// timestamp in the past must not load them again.
+    async fn list_files_modified_after(
+        &self,
+        copy_stmt: &CopyIntoTableStmt,
+        modified_after: chrono::DateTime<chrono_tz::Tz>,
+    ) -> Result<Vec<String>> {
+        let mut src = copy_stmt.src.clone();
+        let location = copy_source_location_mut(&mut src)?;
+        let (stage_info, path) = resolve_file_location(self.ctx.as_ref(), location).await?;
+        let files_info = StageFilesInfo {
+            path,
+            files: copy_stmt.files.clone(),
+            pattern: copy_stmt.pattern.clone(),
+        };
+        let operator = init_stage_operator(&stage_info)?;
+        let thread_num = self.ctx.get_settings().get_max_threads()? as usize;
+        let files = files_info
+            .list(&operator, thread_num, false, None)
+            .await?
+            .into_iter()
+            .filter(|file| file.last_modified >= modified_after)
+            .collect::<Vec<_>>();
+
+        let dst = &copy_stmt.dst;
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(&dst.catalog, &dst.database, &dst.table);
+        let files = self
+            .ctx
+            .filter_out_copied_files(&catalog, &database, &table, &files, None)
+            .await?
+            .files_to_copy;
+        Ok(files
+            .into_iter()
+            .map(|file| relative_file_path(&files_info.path, &file.path))
+            .collect())
+    }
+
+    // drop_pipe
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+        let tenant = self.ctx.get_tenant();
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    // desc_pipe
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+        let tenant = self.ctx.get_tenant();
+        let plan = DescPipePlan {
+            tenant: tenant.to_string(),
+            name: name.to_string(),
+        };
+        Ok(Plan::DescPipe(Box::new(plan)))
+    }
+}
+
+fn copy_source_location_mut(src: &mut CopyIntoTableSource) -> Result<&mut FileLocation> {
+    match src {
+        CopyIntoTableSource::Location(location) => Ok(location),
+        CopyIntoTableSource::Query(query) => {
+            if let SetExpr::Select(select) = &mut query.body {
+                if let Some(TableReference::Location { location, .. }) = select.from.first_mut() {
+                    return Ok(location);
+                }
+            }
+            Err(ErrorCode::SyntaxException(
+                "query as source of copy only allow projection on one stage table",
+            ))
+        }
+    }
+}
+
+fn join_prefix(path: &str, prefix: &str) -> String {
+    format!(
+        "{}/{}",
+        path.trim_end_matches('/'),
+        prefix.trim_start_matches('/')
+    )
+}
+
+/// Listed files are relative to the root of the stage, while `FILES` of a copy
+/// are joined to the location path. If the location is not a directory that
+/// contains the file (e.g. a prefix like `@s/data_`), the path is made absolute,
+/// an absolute file replaces the location path when they are joined.
+fn relative_file_path(location_path: &str, file_path: &str) -> String {
+    let dir = location_path.trim_start_matches('/');
+    match file_path.strip_prefix(dir) {
+        Some(relative) if !relative.is_empty() && (dir.is_empty() || dir.ends_with('/')) => {
+            relative.to_string()
+        }
+        _ => format!("/{}", file_path),
+    }
+}
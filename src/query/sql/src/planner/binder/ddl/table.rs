@@ -42,6 +42,8 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableConstraint;
+use databend_common_ast::ast::TableConstraintKind;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
@@ -74,12 +76,15 @@ use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
+use databend_storages_common_table_meta::table::TableConstraints;
+use databend_storages_common_table_meta::table::UniqueKeyConstraint;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_CONSTRAINTS;
 use derive_visitor::DriveMut;
 use log::debug;
 use log::error;
@@ -92,6 +97,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::Visibility;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
+use crate::parse_check_constraint_expr_to_string;
 use crate::parse_computed_expr_to_string;
 use crate::parse_default_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
@@ -100,7 +106,12 @@ use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTableRowAccessPolicyAction;
+use crate::plans::AlterTableRowAccessPolicyPlan;
+use crate::plans::AlterTableTagAction;
+use crate::plans::AlterTableTagPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
@@ -419,6 +430,51 @@ impl Binder {
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = source
+        {
+            // The cloned table shares the definition and the data of the source table.
+            if !matches!(engine, None | Some(Engine::Fuse))
+                || uri_location.is_some()
+                || !cluster_by.is_empty()
+                || !table_options.is_empty()
+                || as_query.is_some()
+                || *transient
+            {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: CREATE TABLE ... CLONE can not be used with ENGINE, external location, CLUSTER BY, table options, TRANSIENT or AS SELECT",
+                ));
+            }
+
+            let (source_catalog, source_database, source_table) = self
+                .normalize_object_identifier_triple(source_catalog, source_database, source_table);
+            let navigation = match travel_point {
+                Some(point) => {
+                    let mut bind_context = BindContext::new();
+                    Some(
+                        self.resolve_data_travel_point(&mut bind_context, point)
+                            .await?,
+                    )
+                }
+                None => None,
+            };
+            return Ok(Plan::CloneTable(Box::new(CloneTablePlan {
+                create_option: *create_option,
+                tenant: self.ctx.get_tenant().to_string(),
+                catalog,
+                database,
+                table,
+                source_catalog,
+                source_database,
+                source_table,
+                navigation,
+            })));
+        }
+
         // Take FUSE engine AS default engine
         let engine = engine.unwrap_or(Engine::Fuse);
         let mut options: BTreeMap<String, String> = BTreeMap::new();
@@ -472,7 +528,7 @@ impl Binder {
         }
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -539,7 +595,8 @@ impl Binder {
                             get_storage_params_from_options(self.ctx.as_ref(), &options).await?;
                         let dop = DataOperator::try_new(&sp)?;
                         let table = IcebergTable::load_iceberg_table(dop).await?;
-                        let table_schema = IcebergTable::get_schema(&table).await?;
+                        let table_schema =
+                            IcebergTable::get_schema(table.current_table_metadata()).await?;
                         // the first version of current iceberg table do not need to persist the storage_params,
                         // since we get it from table options location and connection when load table each time.
                         // we do this in case we change this idea.
@@ -565,6 +622,29 @@ impl Binder {
             }
         };
 
+        // Bind the table constraints and keep them in the table options.
+        let schema = match &source {
+            Some(CreateTableSource::Columns(_, constraints)) if !constraints.is_empty() => {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Incorrect CREATE query: table constraints are only supported for FUSE engine, but got {}",
+                        engine
+                    )));
+                }
+                let (schema, constraints) = self.analyze_table_constraints(constraints, schema)?;
+                if as_query.is_some() && constraints.enforced_unique_key().is_some() {
+                    return Err(ErrorCode::BadArguments(
+                        "Incorrect CREATE query: CREATE TABLE ... AS SELECT can not be used with an enforced PRIMARY KEY or UNIQUE constraint",
+                    ));
+                }
+                if let Some(value) = constraints.to_option_value()? {
+                    options.insert(OPT_KEY_TABLE_CONSTRAINTS.to_owned(), value);
+                }
+                schema
+            }
+            _ => schema,
+        };
+
         // for fuse engine, we will insert database_id, so if we check it in execute phase,
         // we can't distinct user key and our internal key.
         if options.contains_key(&OPT_KEY_DATABASE_ID.to_lowercase()) {
@@ -1009,6 +1089,68 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|column| normalize_identifier(column, &self.name_resolution_ctx).name)
+                    .collect();
+                Ok(Plan::AlterTableRowAccessPolicy(Box::new(
+                    AlterTableRowAccessPolicyPlan {
+                        tenant: tenant.to_string(),
+                        catalog,
+                        database,
+                        table,
+                        action: AlterTableRowAccessPolicyAction::Add {
+                            policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                            columns,
+                        },
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(
+                Plan::AlterTableRowAccessPolicy(Box::new(AlterTableRowAccessPolicyPlan {
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    action: AlterTableRowAccessPolicyAction::Drop {
+                        policy: normalize_identifier(policy, &self.name_resolution_ctx).name,
+                    },
+                })),
+            ),
+            AlterTableAction::CreateTag {
+                if_not_exists,
+                tag,
+                travel_point,
+            } => {
+                let navigation = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point).await?),
+                    None => None,
+                };
+                Ok(Plan::AlterTableTag(Box::new(AlterTableTagPlan {
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    action: AlterTableTagAction::Create {
+                        tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                        if_not_exists: *if_not_exists,
+                        navigation,
+                    },
+                })))
+            }
+            AlterTableAction::DropTag { if_exists, tag } => {
+                Ok(Plan::AlterTableTag(Box::new(AlterTableTagPlan {
+                    tenant: tenant.to_string(),
+                    catalog,
+                    database,
+                    table,
+                    action: AlterTableTagAction::Drop {
+                        tag: normalize_identifier(tag, &self.name_resolution_ctx).name,
+                        if_exists: *if_exists,
+                    },
+                })))
+            }
         }
     }
 
@@ -1394,7 +1536,7 @@ impl Binder {
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1420,7 +1562,112 @@ impl Binder {
                     Ok((table.schema(), table.field_comments().clone()))
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::Internal(
+                "Logical error, CREATE TABLE ... CLONE should be bound as CloneTablePlan",
+            )),
+        }
+    }
+
+    /// Bind the CHECK, PRIMARY KEY and UNIQUE constraints of the table to be created.
+    /// The columns of the primary key are made NOT NULL.
+    fn analyze_table_constraints(
+        &self,
+        constraints: &[TableConstraint],
+        schema: TableSchemaRef,
+    ) -> Result<(TableSchemaRef, TableConstraints)> {
+        let mut table_constraints = TableConstraints::default();
+        let mut primary_columns = vec![];
+        // CHECK constraints are validated against the written blocks, which have no virtual computed columns.
+        let check_schema = Arc::new(schema.remove_virtual_computed_fields());
+        for (index, constraint) in constraints.iter().enumerate() {
+            let name = match &constraint.name {
+                Some(name) => normalize_identifier(name, &self.name_resolution_ctx).name,
+                None => match &constraint.kind {
+                    TableConstraintKind::Check(_) => format!("check_{}", index + 1),
+                    TableConstraintKind::PrimaryKey { .. } => "primary_key".to_string(),
+                    TableConstraintKind::Unique { .. } => format!("unique_{}", index + 1),
+                },
+            };
+            if table_constraints.checks.contains_key(&name)
+                || table_constraints.unique_keys.contains_key(&name)
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated constraint name: {}",
+                    name
+                )));
+            }
+
+            let (columns, enforced, primary) = match &constraint.kind {
+                TableConstraintKind::Check(expr) => {
+                    let expr = parse_check_constraint_expr_to_string(
+                        self.ctx.clone(),
+                        check_schema.clone(),
+                        expr,
+                    )?;
+                    table_constraints.checks.insert(name, expr);
+                    continue;
+                }
+                TableConstraintKind::PrimaryKey { columns, enforced } => {
+                    if !primary_columns.is_empty() {
+                        return Err(ErrorCode::BadArguments(
+                            "Multiple primary keys are not allowed",
+                        ));
+                    }
+                    (columns, *enforced, true)
+                }
+                TableConstraintKind::Unique { columns, enforced } => (columns, *enforced, false),
+            };
+
+            let mut key_columns = Vec::with_capacity(columns.len());
+            for column in columns {
+                let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                let field = schema.field_with_name(&column)?;
+                if field.computed_expr().is_some() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Computed column {} can not be used in constraint {}",
+                        column, name
+                    )));
+                }
+                if key_columns.contains(&column) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Duplicated column {} in constraint {}",
+                        column, name
+                    )));
+                }
+                key_columns.push(column);
+            }
+            if enforced && table_constraints.enforced_unique_key().is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "A table can have at most one enforced PRIMARY KEY or UNIQUE constraint",
+                ));
+            }
+            if primary {
+                primary_columns = key_columns.clone();
+            }
+            table_constraints
+                .unique_keys
+                .insert(name, UniqueKeyConstraint {
+                    columns: key_columns,
+                    primary,
+                    enforced,
+                });
+        }
+
+        if primary_columns.is_empty() {
+            return Ok((schema, table_constraints));
         }
+        let fields = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                if primary_columns.contains(&field.name) {
+                    field.data_type = field.data_type.remove_nullable();
+                }
+                field
+            })
+            .collect();
+        Ok((TableSchemaRefExt::create(fields), table_constraints))
     }
 
     /// Validate the schema of the table to be created.
@@ -17,11 +17,13 @@ use std::sync::Arc;
 use databend_common_ast::ast::DeleteStmt;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::TableReference;
+use databend_common_catalog::table::Table;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ROW_ID_COL_NAME;
 
+use crate::binder::scalar_common::contain_subquery;
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
@@ -29,6 +31,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::Operator;
 use crate::plans::Plan;
 use crate::plans::RelOp;
@@ -57,6 +60,54 @@ impl<'a> Binder {
         })
     }
 
+    /// The predicate of the row access policy of the target table of a DML statement,
+    /// the rows hidden from the current role must never be touched by the statement.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn dml_row_access_policy_predicate(
+        &mut self,
+        context: &BindContext,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<Option<ScalarExpr>> {
+        let predicate = self
+            .row_access_policy_predicate(context, catalog, table)
+            .await?;
+        if let Some(predicate) = &predicate {
+            if contain_subquery(predicate) {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Row access policy with subquery is not supported in DML on the table '{}'",
+                    table.name()
+                )));
+            }
+        }
+        Ok(predicate)
+    }
+
+    /// Restrict the selection of a DELETE or UPDATE to the rows visible under the row
+    /// access policy.
+    pub(in crate::planner::binder) fn apply_row_access_policy_predicate(
+        selection: Option<ScalarExpr>,
+        subquery_desc: &mut [SubqueryDesc],
+        predicate: Option<ScalarExpr>,
+    ) -> Option<ScalarExpr> {
+        let Some(predicate) = predicate else {
+            return selection;
+        };
+        // The policy columns are read along with the columns of the subqueries.
+        for desc in subquery_desc.iter_mut() {
+            desc.outer_columns.extend(predicate.used_columns());
+        }
+        Some(match selection {
+            Some(selection) => ScalarExpr::FunctionCall(FunctionCall {
+                span: selection.span(),
+                func_name: "and".to_string(),
+                params: vec![],
+                arguments: vec![predicate, selection],
+            }),
+            None => predicate,
+        })
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_delete(
         &mut self,
@@ -82,7 +133,18 @@ impl<'a> Binder {
             ));
         };
 
+        // The scan is bound without the row access policy, which is applied to the selection.
+        bind_context.skip_row_access_policy = true;
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        bind_context.skip_row_access_policy = false;
+
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let row_access_predicate = self
+            .dml_row_access_policy_predicate(&context, &catalog_name, &table)
+            .await?;
 
         context.allow_internal_columns(false);
         let mut scalar_binder = ScalarBinder::new(
@@ -95,7 +157,7 @@ impl<'a> Binder {
             self.ctes_map.clone(),
         );
 
-        let (selection, subquery_desc) = self
+        let (selection, mut subquery_desc) = self
             .process_selection(selection, table_expr, &mut scalar_binder)
             .await?;
 
@@ -108,6 +170,11 @@ impl<'a> Binder {
                 .set_span(selection.span()));
             }
         }
+        let selection = Self::apply_row_access_policy_predicate(
+            selection,
+            &mut subquery_desc,
+            row_access_predicate,
+        );
 
         let plan = DeletePlan {
             catalog_name,
@@ -24,6 +24,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::OnErrorMode;
+use databend_storages_common_table_meta::table::TableConstraints;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
@@ -34,6 +35,7 @@ use crate::plans::CopyIntoTableMode;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
+use crate::plans::Replace;
 use crate::BindContext;
 impl Binder {
     pub fn schema_project(
@@ -93,6 +95,21 @@ impl Binder {
         let table_id = table.get_id();
         let schema = self.schema_project(&table.schema(), columns)?;
 
+        // INSERT into a table with an enforced PRIMARY KEY or UNIQUE constraint
+        // is planned as a REPLACE that rejects the duplicated keys.
+        let constraints = TableConstraints::from_options(table.options())?;
+        let enforced_key = constraints
+            .enforced_unique_key()
+            .map(|(name, key)| (name.clone(), key.clone()));
+        if let Some((name, _)) = &enforced_key {
+            if *overwrite {
+                return Err(ErrorCode::BadArguments(format!(
+                    "INSERT OVERWRITE is not supported on table {} with the enforced constraint {}",
+                    table_name, name
+                )));
+            }
+        }
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
                 format,
@@ -145,6 +162,9 @@ impl Binder {
             InsertSource::RawValues { rest_str, start } => {
                 let values_str = rest_str.trim_end_matches(';').trim_start().to_owned();
                 match self.ctx.get_stage_attachment() {
+                    Some(_) if enforced_key.is_some() => Err(ErrorCode::Unimplemented(
+                        "INSERT with stage attachment is not supported on table with an enforced constraint",
+                    )),
                     Some(attachment) => {
                         return self
                             .bind_copy_from_attachment(
@@ -171,7 +191,9 @@ impl Binder {
                 let statement = Statement::Query(query);
                 let select_plan = self.bind_statement(bind_context, &statement).await?;
                 let opt_ctx = OptimizerContext::new(self.ctx.clone(), self.metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty());
+                    .with_enable_distributed_optimization(
+                        !self.ctx.get_cluster().is_empty() && enforced_key.is_none(),
+                    );
 
                 if let Plan::Query { s_expr, .. } = &select_plan {
                     if !self.check_sexpr_top(s_expr)? {
@@ -186,6 +208,32 @@ impl Binder {
             }
         };
 
+        if let Some((name, key)) = enforced_key {
+            let on_conflict_fields = key
+                .columns
+                .iter()
+                .map(|column| {
+                    schema.field_with_name(column).cloned().map_err(|_| {
+                        ErrorCode::BadArguments(format!(
+                            "column {} of the enforced constraint {} must be specified",
+                            column, name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(Plan::Replace(Box::new(Replace {
+                catalog: catalog_name.to_string(),
+                database: database_name.to_string(),
+                table: table_name,
+                table_id,
+                on_conflict_fields,
+                schema,
+                source: input_source?,
+                delete_when: None,
+                error_on_conflict: true,
+            })));
+        }
+
         let plan = Insert {
             catalog: catalog_name.to_string(),
             database: database_name.to_string(),
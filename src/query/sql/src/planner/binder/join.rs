@@ -86,6 +86,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain MATCH_CONDITION".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -114,6 +119,20 @@ impl Binder {
                 &join.op,
             )
             .await?;
+        if let Some(match_condition) = &join.match_condition {
+            if !non_equi_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "asof join only supports equi conditions and the conditions of one side in ON clause"
+                        .to_string(),
+                )
+                .set_span(non_equi_conditions[0].span()));
+            }
+            non_equi_conditions.push(
+                join_condition_resolver
+                    .resolve_match_condition(match_condition)
+                    .await?,
+            );
+        }
 
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
@@ -174,6 +193,15 @@ impl Binder {
                     right_child,
                 )
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
         }?;
         Ok((s_expr, bind_context))
     }
@@ -278,12 +306,16 @@ impl Binder {
                     | JoinType::LeftSemi
                     | JoinType::LeftAnti
                     | JoinType::RightSemi
-                    | JoinType::RightAnti => {
+                    | JoinType::RightAnti
+                    | JoinType::Asof => {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Left | JoinType::LeftSingle | JoinType::RightMark => {
+                    JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::RightMark
+                    | JoinType::LeftAsof => {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
@@ -347,7 +379,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -539,7 +571,7 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(())
     }
 
-    async fn check_join_allowed_scalar_expr(&mut self, scalars: &Vec<ScalarExpr>) -> Result<()> {
+    async fn check_join_allowed_scalar_expr(&mut self, scalars: &[ScalarExpr]) -> Result<()> {
         let f = |scalar: &ScalarExpr| {
             matches!(
                 scalar,
@@ -772,7 +804,7 @@ impl<'a> JoinConditionResolver<'a> {
         let predicate_used_columns = predicate.used_columns();
         let (left_columns, right_columns) = self.left_right_columns()?;
         match self.join_op {
-            JoinOperator::LeftOuter => {
+            JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
                 if predicate_used_columns.is_subset(&right_columns) {
                     other_join_conditions.push(predicate);
                     return Ok(true);
@@ -784,7 +816,7 @@ impl<'a> JoinConditionResolver<'a> {
                     return Ok(true);
                 }
             }
-            JoinOperator::Inner => {
+            JoinOperator::Inner | JoinOperator::Asof => {
                 if predicate_used_columns.is_subset(&left_columns)
                     || predicate_used_columns.is_subset(&right_columns)
                 {
@@ -799,6 +831,53 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    /// Resolve the `MATCH_CONDITION` of asof join, which must compare an expression of
+    /// the left table with an expression of the right table by `>`, `>=`, `<` or `<=`.
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(&mut self, match_condition: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition).await?;
+        self.check_join_allowed_scalar_expr(std::slice::from_ref(&predicate))
+            .await?;
+
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        let is_side_of = |arg: &ScalarExpr, columns: &ColumnSet| {
+            let used_columns = arg.used_columns();
+            !used_columns.is_empty() && used_columns.is_subset(columns)
+        };
+        if let ScalarExpr::FunctionCall(func) = &predicate {
+            if matches!(func.func_name.as_str(), "gt" | "gte" | "lt" | "lte")
+                && func.arguments.len() == 2
+                && ((is_side_of(&func.arguments[0], &left_columns)
+                    && is_side_of(&func.arguments[1], &right_columns))
+                    || (is_side_of(&func.arguments[0], &right_columns)
+                        && is_side_of(&func.arguments[1], &left_columns)))
+            {
+                return Ok(predicate);
+            }
+        }
+        Err(ErrorCode::SemanticError(
+            "MATCH_CONDITION should compare the left table with the right table by >, >=, < or <="
+                .to_string(),
+        )
+        .set_span(match_condition.span()))
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_context
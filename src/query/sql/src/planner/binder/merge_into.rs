@@ -36,14 +36,17 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableSchemaRef;
 use databend_common_expression::ROW_ID_COL_NAME;
+use databend_storages_common_table_meta::table::TableConstraints;
 use indexmap::IndexMap;
 
+use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::wrap_cast;
 use crate::binder::Binder;
 use crate::binder::InternalColumnBinding;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
 use crate::plans::MatchedEvaluator;
 use crate::plans::MaterializedCte;
 use crate::plans::MergeInto;
@@ -184,6 +187,13 @@ impl Binder {
             .await?;
         let table_id = table.get_id();
         let table_schema = table.schema();
+        let constraints = TableConstraints::from_options(table.options())?;
+        if let Some((name, _)) = constraints.enforced_unique_key() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "MERGE INTO is not supported on table {} with the enforced constraint {}",
+                table_name, name
+            )));
+        }
 
         // get target_table_reference
         let target_table = TableReference::Table {
@@ -194,6 +204,7 @@ impl Binder {
             alias: target_alias.clone(),
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         };
@@ -291,10 +302,12 @@ impl Binder {
 
         // Todo: (JackTan25) Maybe we can remove bind target_table
         // when the target table has been binded in bind_merge_into_source
-        // bind table for target table
+        // bind table for target table, the row access policy is applied below.
+        source_context.skip_row_access_policy = true;
         let (mut target_expr, mut target_context) = self
             .bind_single_table(&mut source_context, &target_table)
             .await?;
+        source_context.skip_row_access_policy = false;
 
         if table.change_tracking_enabled() && merge_type != MergeIntoType::InsertOnly {
             if let RelOperator::Scan(scan) = target_expr.plan() {
@@ -336,6 +349,17 @@ impl Binder {
             .write()
             .set_table_row_id_index(table_index, column_binding.index);
 
+        // The target rows hidden by the row access policy are invisible to the merge.
+        if let Some(predicate) = self
+            .dml_row_access_policy_predicate(&target_context, &catalog_name, &table)
+            .await?
+        {
+            let filter = Filter {
+                predicates: split_conjunctions(&predicate),
+            };
+            target_expr = SExpr::create_unary(Arc::new(filter.into()), Arc::new(target_expr));
+        }
+
         // add row_id_idx
         if merge_type != MergeIntoType::InsertOnly {
             columns_set.insert(column_binding.index);
@@ -345,6 +369,7 @@ impl Binder {
         let join = Join {
             op: join_type,
             condition: JoinCondition::On(Box::new(join_expr.clone())),
+            match_condition: None,
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
@@ -25,6 +25,7 @@ mod copy_into_table;
 mod ddl;
 mod delete;
 mod distinct;
+mod execute_immediate;
 mod explain;
 mod having;
 mod insert;
@@ -38,6 +39,7 @@ mod presign;
 mod project;
 mod project_set;
 mod qualify;
+mod recursive_cte;
 mod replace;
 mod scalar;
 mod scalar_common;
@@ -69,6 +71,7 @@ pub use location::get_storage_params_from_options;
 pub use location::parse_storage_params_from_uri;
 pub use location::parse_uri_location;
 pub use merge_into::MergeIntoType;
+pub use recursive_cte::RecursiveCteScanInfo;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
 pub use stream_column_factory::STREAM_COLUMN_FACTORY;
@@ -0,0 +1,356 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::SetOperator;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use derive_visitor::Drive;
+use derive_visitor::Visitor;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::CteInfo;
+use crate::normalize_identifier;
+use crate::optimizer::SExpr;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::BindContext;
+use crate::Binder;
+use crate::ColumnBinding;
+use crate::IndexType;
+use crate::NameResolutionContext;
+use crate::Visibility;
+
+/// The max times to re-bind the recursive term to find the column types of a recursive cte.
+const MAX_TYPE_RESOLUTION_ROUNDS: usize = 8;
+
+/// The working table of a recursive cte that is visible while binding its recursive term.
+#[derive(Clone, Debug)]
+pub struct RecursiveCteScanInfo {
+    pub cte_idx: IndexType,
+    pub columns: Vec<ColumnBinding>,
+    // A recursive cte can only be referenced once in its recursive term.
+    pub used: bool,
+}
+
+#[derive(Visitor)]
+#[visitor(TableReference(enter))]
+struct CteReferenceFinder {
+    name: String,
+    name_resolution_ctx: NameResolutionContext,
+    found: bool,
+}
+
+impl CteReferenceFinder {
+    fn enter_table_reference(&mut self, table_ref: &TableReference) {
+        if let TableReference::Table {
+            database: None,
+            table,
+            ..
+        } = table_ref
+        {
+            if normalize_identifier(table, &self.name_resolution_ctx).name == self.name {
+                self.found = true;
+            }
+        }
+    }
+}
+
+/// Check if the query of cte `name` references itself.
+pub fn is_recursive_cte(
+    name: &str,
+    query: &Query,
+    name_resolution_ctx: &NameResolutionContext,
+) -> bool {
+    let mut finder = CteReferenceFinder {
+        name: name.to_string(),
+        name_resolution_ctx: name_resolution_ctx.clone(),
+        found: false,
+    };
+    query.drive(&mut finder);
+    finder.found
+}
+
+impl Binder {
+    /// Bind a recursive cte of the form `anchor UNION [ALL] recursive_term`.
+    ///
+    /// The column types are decided by the anchor, and are widened until the recursive
+    /// term produces values of the same types, so `SELECT 1 UNION ALL SELECT n + 1 FROM t`
+    /// will not overflow the `UInt8` column of the anchor.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_r_cte(
+        &mut self,
+        span: Span,
+        bind_context: &mut BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut parent = Some(&*bind_context);
+        while let Some(ctx) = parent {
+            if ctx.cte_name.as_deref() == Some(table_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive reference to query '{table_name}' must not appear within a subquery"
+                ))
+                .set_span(span));
+            }
+            parent = ctx.parent.as_deref();
+        }
+
+        let query = &cte_info.query;
+        let (left, right, distinct) = match &query.body {
+            SetExpr::SetOperation(set_operation) if set_operation.op == SetOperator::Union => (
+                &set_operation.left,
+                &set_operation.right,
+                !set_operation.all,
+            ),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive query '{table_name}' must be of the form `non_recursive_term UNION [ALL] recursive_term`"
+                ))
+                .set_span(span));
+            }
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET in recursive query '{table_name}' are not supported"
+            ))
+            .set_span(span));
+        }
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_bind_context.cte_name = Some(table_name.to_string());
+        new_bind_context.cte_map_ref = Box::default();
+
+        let (left_expr, left_bind_context) = self
+            .bind_set_expr(&mut new_bind_context, left, &[], 0)
+            .await?;
+
+        let mut cols_alias = cte_info.columns_alias.clone();
+        if let Some(alias) = alias {
+            for (idx, col_alias) in alias.columns.iter().enumerate() {
+                let col_alias = normalize_identifier(col_alias, &self.name_resolution_ctx).name;
+                if idx < cols_alias.len() {
+                    cols_alias[idx] = col_alias;
+                } else {
+                    cols_alias.push(col_alias);
+                }
+            }
+        }
+        if cols_alias.len() > left_bind_context.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "The CTE '{}' has {} columns, but {} aliases were provided. Ensure the number of aliases matches the number of columns in the CTE.",
+                table_name,
+                left_bind_context.columns.len(),
+                cols_alias.len()
+            ))
+            .set_span(span));
+        }
+        let column_names = left_bind_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                cols_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+
+        // Column indexes are unique in a query, so the first column of the anchor
+        // identifies this binding of the recursive cte.
+        let cte_idx = left_bind_context.columns[0].index;
+
+        let mut column_types = left_bind_context
+            .columns
+            .iter()
+            .map(|column| *column.data_type.clone())
+            .collect::<Vec<_>>();
+        let mut rounds = 0;
+        let (right_expr, right_bind_context) = loop {
+            rounds += 1;
+            let mut scan_columns = Vec::with_capacity(column_types.len());
+            for (name, data_type) in column_names.iter().zip(column_types.iter()) {
+                let index = self
+                    .metadata
+                    .write()
+                    .add_derived_column(name.clone(), data_type.clone());
+                scan_columns.push(
+                    ColumnBindingBuilder::new(
+                        name.clone(),
+                        index,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .table_name(Some(table_name.to_string()))
+                    .build(),
+                );
+            }
+            self.r_cte_scans
+                .insert(table_name.to_string(), RecursiveCteScanInfo {
+                    cte_idx,
+                    columns: scan_columns,
+                    used: false,
+                });
+            let res = self
+                .bind_set_expr(&mut new_bind_context.clone(), right, &[], 0)
+                .await;
+            let scan_info = self.r_cte_scans.remove(table_name).unwrap();
+            let (right_expr, right_bind_context) = res?;
+
+            if !scan_info.used {
+                return Err(ErrorCode::SemanticError(format!(
+                    "recursive query '{table_name}' must reference itself in its recursive term"
+                ))
+                .set_span(right.span()));
+            }
+            if right_bind_context.columns.len() != column_types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                )
+                .set_span(right.span()));
+            }
+
+            let mut super_types = Vec::with_capacity(column_types.len());
+            for (data_type, right_col) in column_types.iter().zip(right_bind_context.columns.iter())
+            {
+                let super_type = common_super_type(
+                    data_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "recursive query '{}' column {:?} has type {} in non-recursive term but type {} overall",
+                        table_name, right_col.column_name, data_type, right_col.data_type
+                    ))
+                    .set_span(right.span())
+                })?;
+                super_types.push(super_type);
+            }
+            if super_types == column_types {
+                break (right_expr, right_bind_context);
+            }
+            if rounds >= MAX_TYPE_RESOLUTION_ROUNDS {
+                return Err(ErrorCode::SemanticError(format!(
+                    "cannot resolve the column types of recursive query '{table_name}', the types of the recursive term keep widening, consider casting the columns of the non-recursive term explicitly"
+                ))
+                .set_span(right.span()));
+            }
+            column_types = super_types;
+        };
+
+        let (mut new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            left.span(),
+            right.span(),
+            left_bind_context,
+            right_bind_context,
+            left_expr,
+            right_expr,
+            column_types,
+        )?;
+
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        for (column, column_name) in new_bind_context
+            .columns
+            .iter_mut()
+            .zip(column_names.into_iter())
+        {
+            column.database_name = None;
+            column.table_name = Some(alias_table_name.clone());
+            column.column_name = column_name;
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+
+        let r_cte = RecursiveCte {
+            cte_idx,
+            cte_name: table_name.to_string(),
+            distinct,
+            pairs,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(r_cte.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Bind the reference to the working table of a recursive cte in its recursive term.
+    pub(crate) fn bind_r_cte_scan(
+        &mut self,
+        span: Span,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let scan_info = self.r_cte_scans.get_mut(table_name).unwrap();
+        if scan_info.used {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference to query '{table_name}' must not appear more than once"
+            ))
+            .set_span(span));
+        }
+        scan_info.used = true;
+
+        let alias_table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name);
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let mut fields = Vec::with_capacity(scan_info.columns.len());
+        for (idx, column) in scan_info.columns.iter().enumerate() {
+            let mut column = column.clone();
+            if let Some(alias_table_name) = &alias_table_name {
+                column.table_name = Some(alias_table_name.clone());
+            }
+            if let Some(alias) = alias {
+                if let Some(col_alias) = alias.columns.get(idx) {
+                    column.column_name =
+                        normalize_identifier(col_alias, &self.name_resolution_ctx).name;
+                }
+            }
+            fields.push(DataField::new(
+                &column.index.to_string(),
+                *column.data_type.clone(),
+            ));
+            new_bind_context.add_column_binding(column);
+        }
+
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_idx: scan_info.cte_idx,
+                fields,
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+}
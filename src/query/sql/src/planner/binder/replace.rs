@@ -21,6 +21,7 @@ use databend_common_ast::ast::Statement;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::OnErrorMode;
+use databend_storages_common_table_meta::table::TableConstraints;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
@@ -81,6 +82,27 @@ impl Binder {
             })
             .collect::<Result<Vec<_>>>()?;
 
+        // REPLACE keeps an enforced key unique only if the rows are replaced on the same key.
+        let constraints = TableConstraints::from_options(table.options())?;
+        if let Some((name, key)) = constraints.enforced_unique_key() {
+            let mut key_columns = key.columns.clone();
+            let mut on_conflict_columns = on_conflict_fields
+                .iter()
+                .map(|f| f.name().clone())
+                .collect::<Vec<_>>();
+            key_columns.sort();
+            on_conflict_columns.sort();
+            on_conflict_columns.dedup();
+            if key_columns != on_conflict_columns {
+                return Err(ErrorCode::BadArguments(format!(
+                    "REPLACE INTO table {} must be ON the columns ({}) of the enforced constraint {}",
+                    table_name,
+                    key.columns.join(", "),
+                    name
+                )));
+            }
+        }
+
         let input_source: Result<InsertInputSource> = match source.clone() {
             InsertSource::Streaming {
                 format,
@@ -180,6 +202,7 @@ impl Binder {
             schema,
             source: input_source?,
             delete_when: delete_when.clone(),
+            error_on_conflict: false,
         };
 
         Ok(Plan::Replace(Box::new(plan)))
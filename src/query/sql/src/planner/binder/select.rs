@@ -42,6 +42,7 @@ use databend_common_exception::Result;
 use databend_common_exception::Span;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -55,6 +56,7 @@ use super::sort::OrderItem;
 use super::Finder;
 use crate::binder::join::JoinConditions;
 use crate::binder::project_set::SrfCollector;
+use crate::binder::recursive_cte::is_recursive_cte;
 use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::ColumnBindingBuilder;
 use crate::binder::CteInfo;
@@ -74,6 +76,11 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
+use crate::plans::Window;
+use crate::plans::WindowFuncFrame;
+use crate::plans::WindowFuncFrameBound;
+use crate::plans::WindowFuncFrameUnits;
+use crate::plans::WindowFuncType;
 use crate::ColumnBinding;
 use crate::ColumnEntry;
 use crate::IndexType;
@@ -130,6 +137,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -381,6 +389,8 @@ impl Binder {
                         .collect(),
                     query: *cte.query.clone(),
                     materialized: cte.materialized,
+                    recursive: with.recursive
+                        && is_recursive_cte(&table_name, &cte.query, &self.name_resolution_ctx),
                     cte_idx: idx,
                     used_count: 0,
                     stat_info: None,
@@ -499,7 +509,7 @@ impl Binder {
         }
 
         match (op, all) {
-            (SetOperator::Intersect, false) => {
+            (SetOperator::Intersect, all) => {
                 // Transfer Intersect to Semi join
                 self.bind_intersect(
                     left.span(),
@@ -508,9 +518,10 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !*all,
                 )
             }
-            (SetOperator::Except, false) => {
+            (SetOperator::Except, all) => {
                 // Transfer Except to Anti join
                 self.bind_except(
                     left.span(),
@@ -519,6 +530,7 @@ impl Binder {
                     right_bind_context,
                     left_expr,
                     right_expr,
+                    !*all,
                 )
             }
             (SetOperator::Union, true) => self.bind_union(
@@ -539,9 +551,6 @@ impl Binder {
                 right_expr,
                 true,
             ),
-            _ => Err(ErrorCode::Unimplemented(
-                "Unsupported query type, currently, databend only support intersect distinct and except distinct",
-            )),
         }
     }
 
@@ -612,6 +621,7 @@ impl Binder {
         Ok((new_expr, new_bind_context))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_intersect(
         &mut self,
         left_span: Span,
@@ -620,6 +630,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -629,9 +640,11 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftSemi,
+            distinct,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn bind_except(
         &mut self,
         left_span: Span,
@@ -640,6 +653,7 @@ impl Binder {
         right_context: BindContext,
         left_expr: SExpr,
         right_expr: SExpr,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
         self.bind_intersect_or_except(
             left_span,
@@ -649,6 +663,7 @@ impl Binder {
             left_expr,
             right_expr,
             JoinType::LeftAnti,
+            distinct,
         )
     }
 
@@ -662,14 +677,19 @@ impl Binder {
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
+        distinct: bool,
     ) -> Result<(SExpr, BindContext)> {
-        let left_expr = self.bind_distinct(
-            left_span,
-            &left_context,
-            left_context.all_column_bindings(),
-            &mut HashMap::new(),
-            left_expr,
-        )?;
+        let left_expr = if distinct {
+            self.bind_distinct(
+                left_span,
+                &left_context,
+                left_context.all_column_bindings(),
+                &mut HashMap::new(),
+                left_expr,
+            )?
+        } else {
+            left_expr
+        };
         let mut left_conditions = Vec::with_capacity(left_context.columns.len());
         let mut right_conditions = Vec::with_capacity(right_context.columns.len());
         assert_eq!(left_context.columns.len(), right_context.columns.len());
@@ -693,6 +713,31 @@ impl Binder {
                 .into(),
             );
         }
+        let (left_expr, right_expr) = if distinct {
+            (left_expr, right_expr)
+        } else {
+            // Bag semantics: number the duplicates of each row on both sides, so the
+            // n-th occurrence of a row on the left can only match the n-th one on the right.
+            let (left_expr, left_row_number) =
+                self.bind_duplicate_row_number(left_span, &left_context, left_expr);
+            let (right_expr, right_row_number) =
+                self.bind_duplicate_row_number(right_span, &right_context, right_expr);
+            left_conditions.push(
+                BoundColumnRef {
+                    span: left_span,
+                    column: left_row_number,
+                }
+                .into(),
+            );
+            right_conditions.push(
+                BoundColumnRef {
+                    span: right_span,
+                    column: right_row_number,
+                }
+                .into(),
+            );
+            (left_expr, right_expr)
+        };
         let join_conditions = JoinConditions {
             left_conditions,
             right_conditions,
@@ -703,9 +748,58 @@ impl Binder {
         Ok((s_expr, left_context))
     }
 
+    // Add `row_number() OVER (PARTITION BY <all columns>)` to the output of `s_expr`.
+    fn bind_duplicate_row_number(
+        &self,
+        span: Span,
+        bind_context: &BindContext,
+        s_expr: SExpr,
+    ) -> (SExpr, ColumnBinding) {
+        let data_type = DataType::Number(NumberDataType::UInt64);
+        let index = self
+            .metadata
+            .write()
+            .add_derived_column("row_number".to_string(), data_type.clone());
+        let column = ColumnBindingBuilder::new(
+            "row_number".to_string(),
+            index,
+            Box::new(data_type),
+            Visibility::InVisible,
+        )
+        .build();
+        let partition_by = bind_context
+            .columns
+            .iter()
+            .map(|column| ScalarItem {
+                scalar: BoundColumnRef {
+                    span,
+                    column: column.clone(),
+                }
+                .into(),
+                index: column.index,
+            })
+            .collect();
+        let window = Window {
+            span,
+            index,
+            function: WindowFuncType::RowNumber,
+            arguments: vec![],
+            partition_by,
+            order_by: vec![],
+            frame: WindowFuncFrame {
+                units: WindowFuncFrameUnits::Rows,
+                start_bound: WindowFuncFrameBound::Preceding(None),
+                end_bound: WindowFuncFrameBound::CurrentRow,
+            },
+            limit: None,
+        };
+        let s_expr = SExpr::create_unary(Arc::new(window.into()), Arc::new(s_expr));
+        (s_expr, column)
+    }
+
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
-    fn coercion_union_type(
+    pub(crate) fn coercion_union_type(
         &self,
         left_span: Span,
         right_span: Span,
@@ -31,6 +31,8 @@ use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Join;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SampleConfig as AstSampleConfig;
+use databend_common_ast::ast::SampleLevel as AstSampleLevel;
 use databend_common_ast::ast::SelectStageOptions;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
@@ -39,10 +41,13 @@ use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::UriLocation;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::plan::SampleConfig;
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -64,6 +69,8 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
@@ -73,6 +80,7 @@ use databend_common_meta_types::MetaId;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_orc::OrcTable;
 use databend_common_storages_parquet::ParquetRSTable;
 use databend_common_storages_result_cache::ResultCacheMetaManager;
 use databend_common_storages_result_cache::ResultCacheReader;
@@ -80,15 +88,19 @@ use databend_common_storages_result_cache::ResultScan;
 use databend_common_storages_stage::StageTable;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
 use databend_storages_common_table_meta::table::get_change_type;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
 use log::info;
 use parking_lot::RwLock;
 
 use crate::binder::copy_into_table::resolve_file_location;
 use crate::binder::scalar::ScalarBinder;
+use crate::binder::split_conjunctions;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
 use crate::binder::ColumnBindingBuilder;
@@ -99,9 +111,11 @@ use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
+use crate::plans::BoundColumnRef;
 use crate::plans::CteScan;
 use crate::plans::DummyTableScan;
 use crate::plans::EvalScalar;
+use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
@@ -184,6 +198,10 @@ impl Binder {
         } else {
             None
         };
+        // Check and bind the working table of a recursive cte
+        if self.r_cte_scans.contains_key(&table_name) {
+            return self.bind_r_cte_scan(*span, bind_context, &table_name, alias);
+        }
         let mut bind_cte = true;
         if let Some(cte_name) = &bind_context.cte_name {
             // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -316,10 +334,12 @@ impl Binder {
             "STREAM" => {
                 let change_type = get_change_type(&table_alias_name);
                 if change_type.is_some() {
+                    let apply_row_access_policy =
+                        !bind_context.skip_row_access_policy && !bind_context.planning_agg_index;
                     let table_index = self.metadata.write().add_table(
-                        catalog,
+                        catalog.clone(),
                         database.clone(),
-                        table_meta,
+                        table_meta.clone(),
                         table_alias_name,
                         bind_context.view_info.is_some(),
                         bind_context.planning_agg_index,
@@ -328,6 +348,12 @@ impl Binder {
                     let (s_expr, mut bind_context) = self
                         .bind_base_table(bind_context, database.as_str(), table_index, change_type)
                         .await?;
+                    let s_expr = if apply_row_access_policy {
+                        self.bind_row_access_policy(&bind_context, &catalog, &table_meta, s_expr)
+                            .await?
+                    } else {
+                        s_expr
+                    };
 
                     if let Some(alias) = alias {
                         bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -447,10 +473,12 @@ impl Binder {
                 }
             }
             _ => {
+                let apply_row_access_policy =
+                    !bind_context.skip_row_access_policy && !bind_context.planning_agg_index;
                 let table_index = self.metadata.write().add_table(
-                    catalog,
+                    catalog.clone(),
                     database.clone(),
-                    table_meta,
+                    table_meta.clone(),
                     table_alias_name,
                     bind_context.view_info.is_some(),
                     bind_context.planning_agg_index,
@@ -460,6 +488,12 @@ impl Binder {
                 let (s_expr, mut bind_context) = self
                     .bind_base_table(bind_context, database.as_str(), table_index, None)
                     .await?;
+                let s_expr = if apply_row_access_policy {
+                    self.bind_row_access_policy(&bind_context, &catalog, &table_meta, s_expr)
+                        .await?
+                } else {
+                    s_expr
+                };
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -468,6 +502,130 @@ impl Binder {
         }
     }
 
+    /// Wrap the scan of a table with the filter of its row access policy.
+    #[async_backtrace::framed]
+    async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let Some(predicate) = self
+            .row_access_policy_predicate(bind_context, catalog, table)
+            .await?
+        else {
+            return Ok(s_expr);
+        };
+        let filter = Filter {
+            predicates: split_conjunctions(&predicate),
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// The predicate of the row access policy attached to a table, the policy body is
+    /// bound with its arguments mapped to the attached columns.
+    /// `bind_context` is the context returned by `bind_base_table`.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn row_access_policy_predicate(
+        &mut self,
+        bind_context: &BindContext,
+        catalog: &str,
+        table: &Arc<dyn Table>,
+    ) -> Result<Option<ScalarExpr>> {
+        // The policy of a stream is the one attached to its base table.
+        let row_access_policy = if table.engine() == "STREAM" {
+            let options = table.options();
+            match (
+                options.get(OPT_KEY_DATABASE_NAME),
+                options.get(OPT_KEY_TABLE_NAME),
+            ) {
+                (Some(database), Some(table_name)) => self
+                    .ctx
+                    .get_table(catalog, database, table_name)
+                    .await?
+                    .get_table_info()
+                    .meta
+                    .row_access_policy
+                    .clone(),
+                _ => None,
+            }
+        } else {
+            table.get_table_info().meta.row_access_policy.clone()
+        };
+        let Some(row_access_policy) = row_access_policy else {
+            return Ok(None);
+        };
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_row_access_policy_handler();
+        let policy = handler
+            .get_row_access_policy(
+                meta_api,
+                self.ctx.get_tenant().to_string(),
+                row_access_policy.policy.clone(),
+            )
+            .await?;
+        if policy.args.len() != row_access_policy.columns.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "Row access policy '{}' expects {} columns, but got {}",
+                row_access_policy.policy,
+                policy.args.len(),
+                row_access_policy.columns.len()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column) in policy.args.iter().zip(row_access_policy.columns.iter()) {
+            let column_binding = bind_context
+                .columns
+                .iter()
+                .find(|c| c.visibility == Visibility::Visible && &c.column_name == column)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Cannot find column {} of row access policy '{}'",
+                        column, row_access_policy.policy
+                    ))
+                })?;
+            aliases.push((
+                arg_name.clone(),
+                ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column_binding.clone(),
+                }),
+            ));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        // Only the policy arguments are visible to the body.
+        let mut policy_context = BindContext::new();
+        let mut type_checker = TypeChecker::try_create(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            false,
+        )?;
+        let (scalar, data_type) = *type_checker.resolve(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy '{}' must return BOOLEAN, but got {}",
+                row_access_policy.policy, data_type
+            )));
+        }
+        Ok(Some(scalar))
+    }
+
     /// Extract the srf inner tuple fields as columns.
     #[async_backtrace::framed]
     async fn extract_srf_table_function_columns(
@@ -913,20 +1071,26 @@ impl Binder {
                 alias,
                 travel_point,
                 since_point,
+                sample,
                 pivot: _,
                 unpivot: _,
             } => {
-                self.bind_table(
-                    bind_context,
-                    span,
-                    catalog,
-                    database,
-                    table,
-                    alias,
-                    travel_point,
-                    since_point,
-                )
-                .await
+                let (s_expr, bind_context) = self
+                    .bind_table(
+                        bind_context,
+                        span,
+                        catalog,
+                        database,
+                        table,
+                        alias,
+                        travel_point,
+                        since_point,
+                    )
+                    .await?;
+                match sample {
+                    Some(sample) => Ok((bind_table_sample(*span, s_expr, sample)?, bind_context)),
+                    None => Ok((s_expr, bind_context)),
+                }
             }
             TableReference::TableFunction {
                 span,
@@ -949,13 +1113,19 @@ impl Binder {
                     .await
             }
             TableReference::Location {
-                span: _,
+                span,
                 location,
                 options,
                 alias,
+                sample,
             } => {
-                self.bind_location(bind_context, location, options, alias)
-                    .await
+                let (s_expr, bind_context) = self
+                    .bind_location(bind_context, location, options, alias)
+                    .await?;
+                match sample {
+                    Some(sample) => Ok((bind_table_sample(*span, s_expr, sample)?, bind_context)),
+                    None => Ok((s_expr, bind_context)),
+                }
             }
             TableReference::Join { join, .. } => {
                 let (left_expr, left_bind_ctx) =
@@ -1015,7 +1185,10 @@ impl Binder {
                 )
                 .await?
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::Orc(..) => {
+                OrcTable::create(stage_info.clone(), files_info, files_to_copy).await?
+            }
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -1031,12 +1204,13 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
-            FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
+            FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) | FileFormatParams::Xml(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
                     let file_type = match stage_info.file_format_params {
                         FileFormatParams::Csv(..) => "CSV",
                         FileFormatParams::Tsv(..) => "TSV",
+                        FileFormatParams::Xml(..) => "XML",
                         _ => unreachable!(), // This branch should never be reached
                     };
 
@@ -1068,7 +1242,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, ORC, NDJson, Avro, CSV, TSV, and XML. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -1223,6 +1397,11 @@ impl Binder {
         alias: &Option<TableAlias>,
         cte_info: &CteInfo,
     ) -> Result<(SExpr, BindContext)> {
+        if cte_info.recursive {
+            return self
+                .bind_r_cte(span, bind_context, table_name, alias, cte_info)
+                .await;
+        }
         let mut new_bind_context = BindContext {
             parent: Some(Box::new(bind_context.clone())),
             bound_internal_columns: BTreeMap::new(),
@@ -1237,6 +1416,7 @@ impl Binder {
             inverted_index_map: Box::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            skip_row_access_policy: false,
             allow_internal_columns: true,
             window_definitions: DashMap::new(),
         };
@@ -1453,6 +1633,9 @@ impl Binder {
     ) -> Result<NavigationPoint> {
         match travel_point {
             TimeTravelPoint::Snapshot(s) => Ok(NavigationPoint::SnapshotID(s.to_owned())),
+            TimeTravelPoint::Tag(tag) => Ok(NavigationPoint::Tag(
+                normalize_identifier(tag, &self.name_resolution_ctx).name,
+            )),
             TimeTravelPoint::Timestamp(expr) => {
                 let mut type_checker = TypeChecker::try_create(
                     bind_context,
@@ -1586,3 +1769,28 @@ fn parse_table_function_args(
         Ok(params.to_vec())
     }
 }
+
+// Attach the `TABLESAMPLE` to the scan of the base table.
+fn bind_table_sample(span: Span, s_expr: SExpr, sample: &AstSampleConfig) -> Result<SExpr> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            let level = match sample.level {
+                AstSampleLevel::Row => SampleLevel::Row,
+                AstSampleLevel::Block => SampleLevel::Block,
+            };
+            let scan = Scan {
+                sample: Some(SampleConfig {
+                    level,
+                    probability: sample.probability / 100.0,
+                    seed: sample.seed,
+                }),
+                ..scan.clone()
+            };
+            Ok(SExpr::create_leaf(Arc::new(scan.into())))
+        }
+        _ => Err(ErrorCode::SemanticError(
+            "TABLESAMPLE is only supported for base tables and stages".to_string(),
+        )
+        .set_span(span)),
+    }
+}
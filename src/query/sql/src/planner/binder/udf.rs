@@ -29,6 +29,7 @@ use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
 
 use crate::planner::resolve_type_name;
+use crate::planner::udf_validator::verify_python_imports;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CreateUDFPlan;
@@ -139,16 +140,21 @@ impl Binder {
                     )));
                 }
 
-                // TODO add python remove these line
-                if language.to_ascii_lowercase() == "python" {
-                    return Err(ErrorCode::InvalidArgument(format!(
-                        "Unallowed UDF language '{language}' is not supported now, must be javascript"
-                    )));
-                }
-
-                let mut runtime_version = runtime_version.to_string();
-                if runtime_version.is_empty() && language.to_lowercase() == "python" {
-                    runtime_version = "3.12.0".to_string();
+                // An empty runtime version runs the UDF by the embedded interpreter.
+                if language.to_lowercase() == "python" {
+                    if !runtime_version.is_empty() && !is_python3_version(runtime_version) {
+                        return Err(ErrorCode::InvalidArgument(format!(
+                            "Invalid python runtime version '{runtime_version}', must be like '3.12' or '3.12.0'"
+                        )));
+                    }
+                    verify_python_imports(
+                        code,
+                        &GlobalConfig::instance().query.python_udf_allow_imports,
+                    )?;
+                } else if !runtime_version.is_empty() {
+                    return Err(ErrorCode::InvalidArgument(
+                        "RUNTIME_VERSION is only supported by python UDFs",
+                    ));
                 }
 
                 Ok(UserDefinedFunction {
@@ -160,7 +166,7 @@ impl Binder {
                         return_type,
                         handler: handler.clone(),
                         language: language.clone(),
-                        runtime_version,
+                        runtime_version: runtime_version.clone(),
                     }),
                     created_on: Utc::now(),
                 })
@@ -191,3 +197,12 @@ impl Binder {
         Ok(Plan::AlterUDF(Box::new(AlterUDFPlan { udf })))
     }
 }
+
+fn is_python3_version(version: &str) -> bool {
+    let parts = version.split('.').collect::<Vec<_>>();
+    (2..=3).contains(&parts.len())
+        && parts[0] == "3"
+        && parts[1..]
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
@@ -24,6 +24,7 @@ use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchema;
 use databend_common_expression::ROW_VERSION_COL_NAME;
+use databend_storages_common_table_meta::table::TableConstraints;
 
 use crate::binder::Binder;
 use crate::binder::ScalarBinder;
@@ -74,12 +75,18 @@ impl Binder {
             ));
         };
 
+        // The scan is bound without the row access policy, which is applied to the selection.
+        bind_context.skip_row_access_policy = true;
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
+        bind_context.skip_row_access_policy = false;
 
         let table = self
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+        let row_access_predicate = self
+            .dml_row_access_policy_predicate(&context, &catalog_name, &table)
+            .await?;
 
         context.allow_internal_columns(false);
         let mut scalar_binder = ScalarBinder::new(
@@ -92,6 +99,8 @@ impl Binder {
             self.ctes_map.clone(),
         );
         let schema = table.schema();
+        let constraints = TableConstraints::from_options(table.options())?;
+        let enforced_key = constraints.enforced_unique_key();
         let mut update_columns = HashMap::with_capacity(update_list.len());
         for update_expr in update_list {
             let col_name = normalize_identifier(&update_expr.name, &self.name_resolution_ctx).name;
@@ -109,6 +118,14 @@ impl Binder {
                     field.name()
                 )));
             }
+            if let Some((name, key)) = enforced_key {
+                if key.columns.contains(&col_name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Column `{}` of the enforced constraint {} can not be updated",
+                        col_name, name
+                    )));
+                }
+            }
 
             // TODO(zhyass): update_list support subquery.
             let (scalar, _) = scalar_binder.bind(&update_expr.expr).await?;
@@ -122,7 +139,7 @@ impl Binder {
             update_columns.insert(index, scalar);
         }
 
-        let (selection, subquery_desc) = self
+        let (selection, mut subquery_desc) = self
             .process_selection(selection, table_expr, &mut scalar_binder)
             .await?;
 
@@ -135,6 +152,11 @@ impl Binder {
                 .set_span(selection.span()));
             }
         }
+        let selection = Self::apply_row_access_policy_predicate(
+            selection,
+            &mut subquery_desc,
+            row_access_predicate,
+        );
 
         if table.change_tracking_enabled() {
             let (index, row_version) = Self::update_row_version(
@@ -66,6 +66,7 @@ impl Dataframe {
             alias: None,
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         };
@@ -447,6 +448,22 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
+        )?;
+        self.s_expr = s_expr;
+        self.bind_context = bind_context;
+        Ok(self)
+    }
+
+    pub async fn except_all(mut self, dataframe: Dataframe) -> Result<Self> {
+        let (s_expr, bind_context) = self.binder.bind_except(
+            None,
+            None,
+            self.bind_context,
+            dataframe.bind_context,
+            self.s_expr,
+            dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -461,6 +478,22 @@ impl Dataframe {
             dataframe.bind_context,
             self.s_expr,
             dataframe.s_expr,
+            true,
+        )?;
+        self.s_expr = s_expr;
+        self.bind_context = bind_context;
+        Ok(self)
+    }
+
+    pub async fn intersect_all(mut self, dataframe: Dataframe) -> Result<Self> {
+        let (s_expr, bind_context) = self.binder.bind_intersect(
+            None,
+            None,
+            self.bind_context,
+            dataframe.bind_context,
+            self.s_expr,
+            dataframe.s_expr,
+            false,
         )?;
         self.s_expr = s_expr;
         self.bind_context = bind_context;
@@ -483,6 +516,7 @@ impl Dataframe {
                 alias: None,
                 travel_point: None,
                 since_point: None,
+                sample: None,
                 pivot: None,
                 unpivot: None,
             };
@@ -496,6 +530,7 @@ impl Dataframe {
                 join: Join {
                     op: op.clone(),
                     condition: condition.clone(),
+                    match_condition: None,
                     left: Box::new(left),
                     right: Box::new(right),
                 },
@@ -345,6 +345,69 @@ pub fn parse_computed_expr_to_string(
     Ok(format!("{:#}", ast))
 }
 
+/// Validates the expression of a CHECK constraint against the table schema,
+/// returns the normalized SQL text to be stored in the table meta.
+pub fn parse_check_constraint_expr_to_string(
+    ctx: Arc<dyn TableContext>,
+    table_schema: TableSchemaRef,
+    ast: &AExpr,
+) -> Result<String> {
+    let settings = Settings::create(NonEmptyString::new("dummy").unwrap());
+    let mut bind_context = BindContext::new();
+    let mut metadata = Metadata::default();
+    for (index, field) in table_schema.fields().iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            field.name().clone(),
+            field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let mut type_checker = TypeChecker::try_create(
+        &mut bind_context,
+        ctx,
+        &name_resolution_ctx,
+        Arc::new(RwLock::new(metadata)),
+        &[],
+        false,
+    )?;
+
+    let (scalar, data_type) = *databend_common_base::runtime::block_on(type_checker.resolve(ast))?;
+    if data_type.remove_nullable() != DataType::Boolean {
+        return Err(ErrorCode::SemanticError(format!(
+            "expected check constraint expression have type BOOLEAN, but `{}` has type {}.",
+            ast, data_type,
+        )));
+    }
+    let check_expr = scalar.as_expr()?;
+    if !check_expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        return Err(ErrorCode::SemanticError(format!(
+            "check constraint expression `{}` is not deterministic.",
+            check_expr.sql_display(),
+        )));
+    }
+    let mut ast = ast.clone();
+    let mut normalizer = IdentifierNormalizer {
+        ctx: &name_resolution_ctx,
+    };
+    ast.drive_mut(&mut normalizer);
+    Ok(format!("{:#}", ast))
+}
+
 pub fn parse_lambda_expr(
     ctx: Arc<dyn TableContext>,
     columns: &[(String, DataType)],
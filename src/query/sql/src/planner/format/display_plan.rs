@@ -67,12 +67,14 @@ impl Plan {
             // Databases
             Plan::ShowCreateDatabase(_) => Ok("ShowCreateDatabase".to_string()),
             Plan::CreateDatabase(_) => Ok("CreateDatabase".to_string()),
+            Plan::CloneDatabase(_) => Ok("CloneDatabase".to_string()),
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
+            Plan::CloneTable(_) => Ok("CloneTable".to_string()),
             Plan::ShowCreateTable(_) => Ok("ShowCreateTable".to_string()),
             Plan::DropTable(_) => Ok("DropTable".to_string()),
             Plan::UndropTable(_) => Ok("UndropTable".to_string()),
@@ -148,6 +150,7 @@ impl Plan {
             Plan::DropUDF(_) => Ok("DropUDF".to_string()),
             Plan::AlterUser(_) => Ok("AlterUser".to_string()),
             Plan::CreateRole(_) => Ok("CreateRole".to_string()),
+            Plan::AlterRole(_) => Ok("AlterRole".to_string()),
             Plan::DropRole(_) => Ok("DropRole".to_string()),
             Plan::Presign(_) => Ok("Presign".to_string()),
 
@@ -172,12 +175,19 @@ impl Plan {
             Plan::ShowObjectGrantPrivileges(_) => Ok("ShowObjectGrantPrivileges".to_string()),
             Plan::ShowGrantTenantsOfShare(_) => Ok("ShowGrantTenantsOfShare".to_string()),
             Plan::RevertTable(_) => Ok("RevertTable".to_string()),
+            Plan::AlterTableTag(_) => Ok("AlterTableTag".to_string()),
 
             // data mask
             Plan::CreateDatamaskPolicy(_) => Ok("CreateDatamaskPolicy".to_string()),
             Plan::DropDatamaskPolicy(_) => Ok("DropDatamaskPolicy".to_string()),
             Plan::DescDatamaskPolicy(_) => Ok("DescDatamaskPolicy".to_string()),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
+            Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
+            Plan::DescRowAccessPolicy(_) => Ok("DescRowAccessPolicy".to_string()),
+            Plan::AlterTableRowAccessPolicy(_) => Ok("AlterTableRowAccessPolicy".to_string()),
+
             // network policy
             Plan::CreateNetworkPolicy(_) => Ok("CreateNetworkPolicy".to_string()),
             Plan::AlterNetworkPolicy(_) => Ok("AlterNetworkPolicy".to_string()),
@@ -191,6 +201,11 @@ impl Plan {
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
 
+            // workload group
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
+
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
             Plan::DropTask(_) => Ok("DropTask".to_string()),
@@ -213,6 +228,15 @@ impl Plan {
             Plan::DropNotification(_) => Ok("DropNotification".to_string()),
             Plan::DescNotification(_) => Ok("DescNotification".to_string()),
             Plan::AlterNotification(_) => Ok("AlterNotification".to_string()),
+
+            // Pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
+
+            // Script
+            Plan::ExecuteImmediate(_) => Ok("ExecuteImmediate".to_string()),
         }
     }
 }
@@ -260,6 +284,8 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             agg_index: None,
             change_type: None,
             inverted_index: None,
+            vector_index: None,
+            sample: None,
             statistics: Default::default(),
             update_stream_columns: false,
         });
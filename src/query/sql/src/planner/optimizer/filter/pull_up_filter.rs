@@ -70,7 +70,7 @@ impl PullUpFilterOptimizer {
             RelOperator::Filter(filter) => self.pull_up_filter(s_expr, filter),
             RelOperator::Join(join) if !join.is_lateral => self.pull_up_join(s_expr, join),
             RelOperator::EvalScalar(eval_scalar) => self.pull_up_eval_scalar(s_expr, eval_scalar),
-            RelOperator::MaterializedCte(_) => Ok(s_expr.clone()),
+            RelOperator::MaterializedCte(_) | RelOperator::RecursiveCte(_) => Ok(s_expr.clone()),
             _ => self.pull_up_others(s_expr),
         }
     }
@@ -68,6 +68,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
         RelOperator::AddRowNumber(_) => "AddRowNumber".to_string(),
         RelOperator::Udf(_) => "Udf".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
     }
 }
 
@@ -261,7 +261,9 @@ impl DPhpy {
             RelOperator::DummyTableScan(_)
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
-            | RelOperator::MaterializedCte(_) => Ok((Arc::new(s_expr.clone()), true)),
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_)
+            | RelOperator::RecursiveCteScan(_) => Ok((Arc::new(s_expr.clone()), true)),
         }
     }
 
@@ -101,6 +101,13 @@ pub fn outer_to_inner(after_join_reorder: bool, s_expr: &SExpr) -> Result<(SExpr
                     JoinType::Full
                 }
             }
+            JoinType::LeftAsof => {
+                if eliminate_right_null {
+                    JoinType::Asof
+                } else {
+                    join.join_type
+                }
+            }
             _ => unreachable!(),
         };
 
@@ -202,6 +209,11 @@ fn outer_to_inner_impl(after_join_reorder: bool, s_expr: &SExpr) -> Result<(SExp
                     join.join_type = JoinType::Left
                 }
             }
+            JoinType::LeftAsof => {
+                if left_join {
+                    join.join_type = JoinType::Asof
+                }
+            }
             _ => unreachable!(),
         }
     }
@@ -129,6 +129,8 @@ fn find_group_by_keys(child: &SExpr, group_by_keys: &mut HashSet<IndexType>) ->
         | RelOperator::Udf(_)
         | RelOperator::Scan(_)
         | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::Join(_) => {}
     }
     Ok(())
@@ -0,0 +1,227 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::F32;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::schema::TableIndexType;
+use databend_storages_common_index::VectorIndexOptions;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::ConstantExpr;
+use crate::plans::EvalScalar;
+use crate::plans::FunctionCall;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Input:  Sort(limit)
+///           \
+///          EvalScalar(distance(col, query))
+///             \
+///            LogicalGet
+///
+/// Output: Sort(limit)
+///           \
+///          EvalScalar(distance(col, query))
+///             \
+///            LogicalGet(padding vector index)
+///
+/// The vector index is only used to prune the blocks, the rows are still
+/// sorted by the distance computed by the scalar function.
+pub struct RuleTryApplyVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleTryApplyVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyVectorIndex,
+            metadata,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Sort,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::EvalScalar,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                }],
+            }],
+        }
+    }
+
+    fn try_build_vector_index(
+        &self,
+        scan: &Scan,
+        func: &FunctionCall,
+        limit: usize,
+    ) -> Result<Option<VectorIndexInfo>> {
+        let (column_ref, query) = match func.arguments.as_slice() {
+            [
+                ScalarExpr::BoundColumnRef(column_ref),
+                ScalarExpr::ConstantExpr(query),
+            ]
+            | [
+                ScalarExpr::ConstantExpr(query),
+                ScalarExpr::BoundColumnRef(column_ref),
+            ] => (column_ref, query),
+            _ => return Ok(None),
+        };
+        let Some(column_name) = self.base_column_name(scan, column_ref) else {
+            return Ok(None);
+        };
+        let Some(query) = vector_of_constant(query) else {
+            return Ok(None);
+        };
+
+        let table = self.metadata.read().table(scan.table_index).table();
+        let table_info = table.get_table_info();
+        let Ok(column_id) = table_info.schema().column_id_of(&column_name) else {
+            return Ok(None);
+        };
+        for index in table_info.meta.indexes.values() {
+            if index.index_type != TableIndexType::Vector || index.column_ids != [column_id] {
+                continue;
+            }
+            let options = VectorIndexOptions::try_create(&index.options)?;
+            // The index can't be searched by a query vector of another dimension.
+            if options.distance_type.function_name() == func.func_name
+                && options.dimension == query.len()
+            {
+                return Ok(Some(VectorIndexInfo {
+                    index_name: index.name.clone(),
+                    index_version: index.version.clone(),
+                    column_name,
+                    query,
+                    limit,
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    // Returns the name of the column if it is a column of the scanned table.
+    fn base_column_name(&self, scan: &Scan, column_ref: &BoundColumnRef) -> Option<String> {
+        if column_ref.column.table_index != Some(scan.table_index) {
+            return None;
+        }
+        match self.metadata.read().column(column_ref.column.index) {
+            ColumnEntry::BaseTableColumn(column)
+                if column.path_indices.is_none() && column.virtual_computed_expr.is_none() =>
+            {
+                Some(column.column_name.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Rule for RuleTryApplyVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let Some(limit) = sort.limit else {
+            return Ok(());
+        };
+        // The rows with null distance are placed last, so they are never in the result.
+        let [sort_item] = sort.items.as_slice() else {
+            return Ok(());
+        };
+        if !sort_item.asc || sort_item.nulls_first {
+            return Ok(());
+        }
+
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        if scan.vector_index.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+            || scan.sample.is_some()
+            || scan.change_type.is_some()
+        {
+            return Ok(());
+        }
+
+        let Some(item) = eval_scalar
+            .items
+            .iter()
+            .find(|item| item.index == sort_item.index)
+        else {
+            return Ok(());
+        };
+        let ScalarExpr::FunctionCall(func) = &item.scalar else {
+            return Ok(());
+        };
+        let Some(vector_index) = self.try_build_vector_index(&scan, func, limit)? else {
+            return Ok(());
+        };
+
+        scan.vector_index = Some(vector_index);
+        let scan_expr = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(scan_expr)]);
+
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+// Converts the constant array to the query vector, returns `None` if it is empty,
+// contains null values or contains values that can't be exactly represented as f32.
+fn vector_of_constant(constant: &ConstantExpr) -> Option<Vec<F32>> {
+    let Scalar::Array(column) = &constant.value else {
+        return None;
+    };
+    column
+        .iter()
+        .map(|value| match value {
+            ScalarRef::Number(num) => match num.integer_to_i128() {
+                Some(v) => ((v as f32) as i128 == v).then_some(F32::from(v as f32)),
+                None => num.float_to_f64().and_then(f64_to_exact_f32),
+            },
+            ScalarRef::Decimal(decimal) => f64_to_exact_f32(decimal.to_float64()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|vector| !vector.is_empty())
+}
+
+fn f64_to_exact_f32(value: f64) -> Option<F32> {
+    ((value as f32) as f64 == value).then_some(F32::from(value as f32))
+}
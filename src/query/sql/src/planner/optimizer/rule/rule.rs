@@ -37,6 +37,7 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitUnion,
         RuleID::PushDownLimitEvalScalar,
         RuleID::PushDownLimitSort,
+        RuleID::TryApplyVectorIndex,
         RuleID::PushDownLimitWindow,
         RuleID::PushDownLimitAggregate,
         RuleID::PushDownLimitOuterJoin,
@@ -101,6 +102,7 @@ pub enum RuleID {
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyVectorIndex,
     CommuteJoin,
 
     // Exploration rules
@@ -143,6 +145,7 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyVectorIndex => write!(f, "TryApplyVectorIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
         }
     }
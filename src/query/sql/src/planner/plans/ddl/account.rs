@@ -16,6 +16,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use databend_common_ast::ast::AlterPasswordAction;
 use databend_common_ast::ast::PasswordSetOptions;
+use databend_common_ast::ast::WorkloadGroupOptions;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -58,6 +59,14 @@ pub struct CreateRolePlan {
     pub role_name: String,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterRolePlan {
+    pub if_exists: bool,
+    pub role_name: String,
+    // None means the role is unbound from its workload group
+    pub workload_group: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropRolePlan {
     pub if_exists: bool,
@@ -258,3 +267,44 @@ impl DescPasswordPolicyPlan {
         ])
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateWorkloadGroupPlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl CreateWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub options: WorkloadGroupOptions,
+}
+
+impl AlterWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropWorkloadGroupPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropWorkloadGroupPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
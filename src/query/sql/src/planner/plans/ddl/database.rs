@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::DataSchemaRef;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
@@ -57,6 +58,15 @@ impl From<&CreateDatabasePlan> for CreateDatabaseReq {
     }
 }
 
+/// Clone, i.e. `CREATE DATABASE .. CLONE`.
+#[derive(Clone, Debug)]
+pub struct CloneDatabasePlan {
+    pub create: CreateDatabasePlan,
+    pub source_catalog: String,
+    pub source_database: String,
+    pub navigation: Option<NavigationPoint>,
+}
+
 /// Drop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DropDatabasePlan {
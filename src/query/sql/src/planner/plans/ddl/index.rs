@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_ast::ast::TableIndexType;
 use databend_common_expression::ColumnId;
 use databend_common_meta_app::schema::CreateOption;
@@ -57,7 +59,9 @@ pub struct CreateTableIndexPlan {
     pub create_option: CreateOption,
     pub catalog: String,
     pub index_name: String,
+    pub index_type: databend_common_meta_app::schema::TableIndexType,
     pub column_ids: Vec<ColumnId>,
+    pub index_options: BTreeMap<String, String>,
     pub table_id: MetaId,
     pub sync_creation: bool,
 }
@@ -68,6 +72,7 @@ pub struct DropTableIndexPlan {
     pub if_exists: bool,
     pub catalog: String,
     pub index_name: String,
+    pub index_type: databend_common_meta_app::schema::TableIndexType,
     pub table_id: MetaId,
 }
 
@@ -77,4 +82,5 @@ pub struct RefreshTableIndexPlan {
     pub database: String,
     pub table: String,
     pub index_name: String,
+    pub index_type: databend_common_meta_app::schema::TableIndexType,
 }
@@ -19,6 +19,7 @@ mod database;
 mod file_format;
 mod index;
 mod notification;
+mod pipe;
 mod stage;
 mod stream;
 mod table;
@@ -34,6 +35,7 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use notification::*;
+pub use pipe::*;
 pub use stage::*;
 pub use stream::*;
 pub use table::*;
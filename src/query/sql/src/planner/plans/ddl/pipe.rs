@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+pub fn pipe_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("created_on", DataType::Timestamp),
+        DataField::new("name", DataType::String),
+        DataField::new("owner", DataType::String.wrap_nullable()),
+        DataField::new("definition", DataType::String),
+        DataField::new("auto_ingest", DataType::Boolean),
+        DataField::new("execution_paused", DataType::Boolean),
+        DataField::new("comment", DataType::String),
+        DataField::new("updated_on", DataType::Timestamp.wrap_nullable()),
+    ]))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub auto_ingest: bool,
+    pub comment: String,
+    /// The target table of the copy, used to check the privileges.
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The `COPY INTO <table>` statement with a fully qualified table name.
+    pub copy_stmt: String,
+}
+
+impl CreatePipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+// alter
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+    pub execution_paused: Option<bool>,
+    pub comment: Option<String>,
+}
+
+impl AlterPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+// drop
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DropPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescPipePlan {
+    pub tenant: String,
+    pub name: String,
+}
+
+impl DescPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        pipe_schema()
+    }
+}
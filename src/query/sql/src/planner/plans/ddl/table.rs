@@ -63,6 +63,27 @@ impl CreateTablePlan {
     }
 }
 
+/// Clone, i.e. `CREATE TABLE .. CLONE`.
+#[derive(Clone, Debug)]
+pub struct CloneTablePlan {
+    pub create_option: CreateOption,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+
+    pub source_catalog: String,
+    pub source_database: String,
+    pub source_table: String,
+    pub navigation: Option<NavigationPoint>,
+}
+
+impl CloneTablePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
 /// Desc.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DescribeTablePlan {
@@ -266,6 +287,35 @@ impl SetOptionsPlan {
     }
 }
 
+/// Create or drop a named snapshot of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterTableTagAction {
+    Create {
+        tag: String,
+        if_not_exists: bool,
+        navigation: Option<NavigationPoint>,
+    },
+    Drop {
+        tag: String,
+        if_exists: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterTableTagPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub action: AlterTableTagAction,
+}
+
+impl AlterTableTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 // Table add column
 #[derive(Clone, Debug, PartialEq)]
 pub struct AddTableColumnPlan {
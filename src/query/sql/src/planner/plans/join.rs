@@ -61,6 +61,12 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each row of left side with the nearest row of right side that
+    /// has the same equi keys and satisfies the match condition. The match condition is
+    /// the only one in `non_equi_conditions`.
+    Asof,
+    /// Left Asof Join also outputs the left rows without matched rows.
+    LeftAsof,
 }
 
 impl JoinType {
@@ -88,12 +94,17 @@ impl JoinType {
                 | JoinType::Full
                 | JoinType::LeftSingle
                 | JoinType::RightSingle
+                | JoinType::LeftAsof
         )
     }
 
     pub fn is_mark_join(&self) -> bool {
         matches!(self, JoinType::LeftMark | JoinType::RightMark)
     }
+
+    pub fn is_asof_join(&self) -> bool {
+        matches!(self, JoinType::Asof | JoinType::LeftAsof)
+    }
 }
 
 impl Display for JoinType {
@@ -138,6 +149,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -482,9 +499,14 @@ impl Operator for Join {
                     + f64::max(right_cardinality, inner_join_cardinality)
                     - inner_join_cardinality
             }
-            JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftSemi | JoinType::Asof => {
+                f64::min(left_cardinality, inner_join_cardinality)
+            }
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::LeftAsof => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -518,8 +540,10 @@ impl Operator for Join {
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
         // if join/probe side is Serial or this is a non-equi join, we use Serial distribution
+        // TODO: asof join can be executed in parallel by redistributing on the equi keys
         if probe_physical_prop.distribution == Distribution::Serial
             || build_physical_prop.distribution == Distribution::Serial
+            || self.join_type.is_asof_join()
             || (self.left_conditions.is_empty()
                 && self.right_conditions.is_empty()
                 && !self.non_equi_conditions.is_empty())
@@ -578,7 +602,10 @@ impl Operator for Join {
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
-        if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
+        if self.join_type != JoinType::Cross
+            && !self.join_type.is_asof_join()
+            && !ctx.get_settings().get_enforce_broadcast_join()?
+        {
             // (Hash, Hash)
             children_required.extend(
                 self.left_conditions
@@ -605,6 +632,8 @@ impl Operator for Join {
                 | JoinType::RightSemi
                 | JoinType::LeftMark
                 | JoinType::RightSingle
+                | JoinType::Asof
+                | JoinType::LeftAsof
         ) {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
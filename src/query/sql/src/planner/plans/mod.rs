@@ -24,6 +24,7 @@ mod delete;
 mod dummy_table_scan;
 mod eval_scalar;
 mod exchange;
+mod execute_immediate;
 mod filter;
 pub mod insert;
 mod join;
@@ -39,8 +40,11 @@ mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
+pub mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod setting;
@@ -64,6 +68,7 @@ pub use delete::SubqueryDesc;
 pub use dummy_table_scan::DummyTableScan;
 pub use eval_scalar::*;
 pub use exchange::*;
+pub use execute_immediate::ExecuteImmediatePlan;
 pub use filter::*;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
@@ -82,8 +87,11 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use setting::*;
@@ -38,6 +38,8 @@ use crate::plans::ConstantTableScan;
 use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Udf;
 use crate::plans::Window;
 
@@ -97,6 +99,8 @@ pub enum RelOp {
     ConstantTableScan,
     AddRowNumber,
     Udf,
+    RecursiveCte,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -122,6 +126,8 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
     Udf(Udf),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 }
 
 impl Operator for RelOperator {
@@ -144,6 +150,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
             RelOperator::AddRowNumber(rel_op) => rel_op.rel_op(),
             RelOperator::Udf(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -166,6 +174,8 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.arity(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.arity(),
             RelOperator::Udf(rel_op) => rel_op.arity(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.arity(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.arity(),
         }
     }
 
@@ -188,6 +198,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -210,6 +222,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -232,6 +246,8 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::AddRowNumber(rel_op) => rel_op.derive_stats(rel_expr),
             RelOperator::Udf(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_stats(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_stats(rel_expr),
         }
     }
 
@@ -294,6 +310,12 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 
@@ -355,6 +377,12 @@ impl Operator for RelOperator {
             RelOperator::Udf(rel_op) => {
                 rel_op.compute_required_prop_children(ctx, rel_expr, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_children(ctx, rel_expr, required)
+            }
         }
     }
 }
@@ -427,6 +455,7 @@ impl From<Join> for RelOperator {
 
 impl TryFrom<RelOperator> for Join {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Join(value) = value {
             Ok(value)
@@ -446,6 +475,7 @@ impl From<EvalScalar> for RelOperator {
 
 impl TryFrom<RelOperator> for EvalScalar {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::EvalScalar(value) = value {
             Ok(value)
@@ -465,6 +495,7 @@ impl From<Filter> for RelOperator {
 
 impl TryFrom<RelOperator> for Filter {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Filter(value) = value {
             Ok(value)
@@ -482,6 +513,7 @@ impl From<Aggregate> for RelOperator {
 
 impl TryFrom<RelOperator> for Aggregate {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Aggregate(value) = value {
             Ok(value)
@@ -501,6 +533,7 @@ impl From<Window> for RelOperator {
 
 impl TryFrom<RelOperator> for Window {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Window(value) = value {
             Ok(value)
@@ -518,6 +551,7 @@ impl From<Sort> for RelOperator {
 
 impl TryFrom<RelOperator> for Sort {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Sort(value) = value {
             Ok(value)
@@ -535,6 +569,7 @@ impl From<Limit> for RelOperator {
 
 impl TryFrom<RelOperator> for Limit {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Limit(value) = value {
             Ok(value)
@@ -552,6 +587,7 @@ impl From<Exchange> for RelOperator {
 
 impl TryFrom<RelOperator> for Exchange {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::Exchange(value) = value {
             Ok(value)
@@ -571,6 +607,7 @@ impl From<UnionAll> for RelOperator {
 
 impl TryFrom<RelOperator> for UnionAll {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::UnionAll(value) = value {
             Ok(value)
@@ -590,6 +627,7 @@ impl From<DummyTableScan> for RelOperator {
 
 impl TryFrom<RelOperator> for DummyTableScan {
     type Error = ErrorCode;
+
     fn try_from(value: RelOperator) -> Result<Self> {
         if let RelOperator::DummyTableScan(value) = value {
             Ok(value)
@@ -658,3 +696,43 @@ impl TryFrom<RelOperator> for Udf {
         }
     }
 }
+
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
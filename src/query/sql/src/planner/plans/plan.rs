@@ -32,14 +32,21 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
+use crate::plans::AlterRolePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTableRowAccessPolicyPlan;
+use crate::plans::AlterTableTagPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
+use crate::plans::AlterWorkloadGroupPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneDatabasePlan;
+use crate::plans::CloneTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -51,7 +58,9 @@ use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
@@ -63,12 +72,15 @@ use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
+use crate::plans::CreateWorkloadGroupPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescConnectionPlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -82,7 +94,9 @@ use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
@@ -96,6 +110,8 @@ use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::DropVirtualColumnPlan;
+use crate::plans::DropWorkloadGroupPlan;
+use crate::plans::ExecuteImmediatePlan;
 use crate::plans::ExecuteTaskPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::GrantPrivilegePlan;
@@ -190,6 +206,7 @@ pub enum Plan {
     // Databases
     ShowCreateDatabase(Box<ShowCreateDatabasePlan>),
     CreateDatabase(Box<CreateDatabasePlan>),
+    CloneDatabase(Box<CloneDatabasePlan>),
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
@@ -199,6 +216,7 @@ pub enum Plan {
     ShowCreateTable(Box<ShowCreateTablePlan>),
     DescribeTable(Box<DescribeTablePlan>),
     CreateTable(Box<CreateTablePlan>),
+    CloneTable(Box<CloneTablePlan>),
     DropTable(Box<DropTablePlan>),
     UndropTable(Box<UndropTablePlan>),
     RenameTable(Box<RenameTablePlan>),
@@ -218,6 +236,7 @@ pub enum Plan {
     AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
+    AlterTableTag(Box<AlterTableTagPlan>),
 
     // Insert
     Insert(Box<Insert>),
@@ -262,6 +281,7 @@ pub enum Plan {
     // Role
     ShowRoles(Box<ShowRolesPlan>),
     CreateRole(Box<CreateRolePlan>),
+    AlterRole(Box<AlterRolePlan>),
     DropRole(Box<DropRolePlan>),
     GrantRole(Box<GrantRolePlan>),
     GrantPriv(Box<GrantPrivilegePlan>),
@@ -314,6 +334,12 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+    AlterTableRowAccessPolicy(Box<AlterTableRowAccessPolicyPlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -327,6 +353,11 @@ pub enum Plan {
     DropPasswordPolicy(Box<DropPasswordPolicyPlan>),
     DescPasswordPolicy(Box<DescPasswordPolicyPlan>),
 
+    // Workload Group
+    CreateWorkloadGroup(Box<CreateWorkloadGroupPlan>),
+    AlterWorkloadGroup(Box<AlterWorkloadGroupPlan>),
+    DropWorkloadGroup(Box<DropWorkloadGroupPlan>),
+
     // Task
     CreateTask(Box<CreateTaskPlan>),
     AlterTask(Box<AlterTaskPlan>),
@@ -345,6 +376,15 @@ pub enum Plan {
     AlterNotification(Box<AlterNotificationPlan>),
     DropNotification(Box<DropNotificationPlan>),
     DescNotification(Box<DescNotificationPlan>),
+
+    // Pipes
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
+
+    // Scripts
+    ExecuteImmediate(Box<ExecuteImmediatePlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -448,6 +488,11 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
+            Plan::AlterTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::AlterTableTag(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
@@ -459,6 +504,8 @@ impl Plan {
             Plan::ShowTasks(plan) => plan.schema(),
             Plan::ExecuteTask(plan) => plan.schema(),
             Plan::DescNotification(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
+            Plan::ExecuteImmediate(plan) => plan.schema(),
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
 
@@ -493,6 +540,7 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
                 | Plan::DescPasswordPolicy(_)
@@ -502,6 +550,8 @@ impl Plan {
                 | Plan::DescribeTask(_)
                 | Plan::DescConnection(_)
                 | Plan::ShowConnections(_)
+                | Plan::DescPipe(_)
+                | Plan::ExecuteImmediate(_)
                 | Plan::MergeInto(_)
         )
     }
@@ -0,0 +1,148 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// `RecursiveCte` evaluates a `WITH RECURSIVE` common table expression.
+///
+/// The left child is the non-recursive (anchor) term, the right child is the
+/// recursive term, which reads the rows produced by the previous iteration
+/// through a `RecursiveCteScan` with the same `cte_idx`. The right child is
+/// evaluated repeatedly until it produces no new rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    // The id of the working table, shared with the `RecursiveCteScan` in the recursive term.
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    // `UNION` (true) or `UNION ALL` (false)
+    pub distinct: bool,
+    // Pairs of unioned columns, the left ones are the output columns
+    pub pairs: Vec<(IndexType, IndexType)>,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for (left, right) in &self.pairs {
+            used_columns.insert(*left);
+            used_columns.insert(*right);
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn arity(&self) -> usize {
+        2
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive output columns
+        let output_columns = left_prop
+            .output_columns
+            .union(&right_prop.output_columns)
+            .cloned()
+            .collect();
+
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns.clone());
+        used_columns.extend(right_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+            orderings: vec![],
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_stats(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // The number of iterations is unknown before execution, so estimate one
+        // round of the recursive term on top of the anchor.
+        let left_stat_info = rel_expr.derive_cardinality_child(0)?;
+        let right_stat_info = rel_expr.derive_cardinality_child(1)?;
+        Ok(Arc::new(StatInfo {
+            cardinality: left_stat_info.cardinality + right_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        // Both terms are executed locally by the recursive cte source.
+        Ok(RequiredProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn compute_required_prop_children(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _required: &RequiredProperty,
+    ) -> Result<Vec<Vec<RequiredProperty>>> {
+        Ok(vec![vec![
+            RequiredProperty {
+                distribution: Distribution::Serial,
+            },
+            RequiredProperty {
+                distribution: Distribution::Serial,
+            },
+        ]])
+    }
+}
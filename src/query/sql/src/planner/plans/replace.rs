@@ -32,6 +32,9 @@ pub struct Replace {
     pub schema: TableSchemaRef,
     pub source: InsertInputSource,
     pub delete_when: Option<Expr>,
+    /// Reject the rows with duplicated keys instead of replacing the existing rows,
+    /// used to insert into a table with an enforced PRIMARY KEY or UNIQUE constraint.
+    pub error_on_conflict: bool,
 }
 
 impl PartialEq for Replace {
@@ -41,6 +44,7 @@ impl PartialEq for Replace {
             && self.table == other.table
             && self.schema == other.schema
             && self.on_conflict_fields == other.on_conflict_fields
+            && self.error_on_conflict == other.error_on_conflict
     }
 }
 
@@ -17,6 +17,8 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_catalog::plan::SampleConfig;
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_context::TableContext;
@@ -101,6 +103,8 @@ pub struct Scan {
     // Whether to update stream columns.
     pub update_stream_columns: bool,
     pub inverted_index: Option<InvertedIndexInfo>,
+    pub vector_index: Option<VectorIndexInfo>,
+    pub sample: Option<SampleConfig>,
 
     pub statistics: Statistics,
 }
@@ -130,6 +134,8 @@ impl Scan {
             change_type: self.change_type.clone(),
             update_stream_columns: self.update_stream_columns,
             inverted_index: self.inverted_index.clone(),
+            vector_index: self.vector_index.clone(),
+            sample: self.sample.clone(),
         }
     }
 
@@ -266,9 +272,13 @@ impl Operator for Scan {
             (Some(precise_cardinality), None) => precise_cardinality as f64,
             (_, _) => 0.0,
         };
+        let cardinality = match &self.sample {
+            Some(sample) => cardinality * sample.probability,
+            None => cardinality,
+        };
 
-        // If prewhere is not none, we can't get precise cardinality
-        let precise_cardinality = if self.prewhere.is_none() {
+        // If prewhere or sample is not none, we can't get precise cardinality
+        let precise_cardinality = if self.prewhere.is_none() && self.sample.is_none() {
             precise_cardinality
         } else {
             None
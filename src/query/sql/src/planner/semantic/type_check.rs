@@ -84,6 +84,7 @@ use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::schema::TableIndexType;
 use databend_common_users::UserApiProvider;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
@@ -107,6 +108,7 @@ use crate::optimizer::SExpr;
 use crate::parse_lambda_expr;
 use crate::planner::metadata::optimize_remove_count_args;
 use crate::planner::semantic::lowering::TypeCheck;
+use crate::planner::udf_validator::verify_python_imports;
 use crate::plans::Aggregate;
 use crate::plans::AggregateFunction;
 use crate::plans::AggregateMode;
@@ -1032,12 +1034,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1744,6 +1741,30 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the k and counters of approx_top_k to params
+        let params = if func_name.eq_ignore_ascii_case("approx_top_k")
+            && (arguments.len() == 2 || arguments.len() == 3)
+            && params.is_empty()
+        {
+            let mut new_params = Vec::with_capacity(arguments.len() - 1);
+            for (argument, arg_type) in arguments.iter().zip(arg_types.iter()).skip(1) {
+                let value = ConstantExpr::try_from(argument.clone());
+                let is_positive_integer = match &value {
+                    Ok(v) => v.value.is_positive(),
+                    Err(_) => false,
+                } && arg_type.is_integer();
+                if !is_positive_integer {
+                    return Err(ErrorCode::SemanticError(
+                        "The k and counters of `approx_top_k` must be constant positive int",
+                    ));
+                }
+                new_params.push(value.unwrap().value);
+            }
+            new_params
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2098,7 +2119,9 @@ impl<'a> TypeChecker<'a> {
         let mut index_name = "".to_string();
         let mut index_schema = None;
         for table_index in table_indexes.values() {
-            if table_index.column_ids.contains(&column_id) {
+            if table_index.index_type == TableIndexType::Inverted
+                && table_index.column_ids.contains(&column_id)
+            {
                 index_name = table_index.name.clone();
 
                 let mut index_fields = Vec::with_capacity(table_index.column_ids.len());
@@ -2462,6 +2485,34 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, &func_name, vec![], args)
     }
 
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let func_name = match interval_kind {
+            ASTIntervalKind::Year => "to_years",
+            ASTIntervalKind::Quarter => "to_quarters",
+            ASTIntervalKind::Month => "to_months",
+            ASTIntervalKind::Week => "to_weeks",
+            ASTIntervalKind::Day => "to_days",
+            ASTIntervalKind::Hour => "to_hours",
+            ASTIntervalKind::Minute => "to_minutes",
+            ASTIntervalKind::Second => "to_seconds",
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported interval unit {interval_kind}, only these interval units are currently supported: [year, quarter, month, week, day, hour, minute, second]"
+                ))
+                .set_span(span));
+            }
+        };
+        self.resolve_function(span, func_name, vec![], &[expr])
+            .await
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     pub async fn resolve_date_trunc(
@@ -3312,6 +3363,13 @@ impl<'a> TypeChecker<'a> {
         arguments: &[Expr],
         udf_definition: UDFScript,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if udf_definition.language.eq_ignore_ascii_case("python") {
+            verify_python_imports(
+                &udf_definition.code,
+                &GlobalConfig::instance().query.python_udf_allow_imports,
+            )?;
+        }
+
         let mut args = Vec::with_capacity(arguments.len());
         for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
             let box (arg, ty) = self.resolve(argument).await?;
@@ -4155,6 +4213,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Binary => TableDataType::Binary,
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
+        TypeName::Interval => TableDataType::Interval,
         TypeName::Date => TableDataType::Date,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name(item_type, not_null)?))
@@ -32,6 +32,7 @@ impl ViewRewriter {
             alias,
             travel_point,
             since_point,
+            sample,
             pivot,
             unpivot,
         } = table_ref
@@ -51,6 +52,7 @@ impl ViewRewriter {
                     alias: alias.clone(),
                     travel_point: travel_point.clone(),
                     since_point: since_point.clone(),
+                    sample: sample.clone(),
                     pivot: pivot.clone(),
                     unpivot: unpivot.clone(),
                 }
@@ -90,3 +90,51 @@ impl UDFValidator {
         )))
     }
 }
+
+/// Checks that a python script UDF only imports the modules in `allow_imports`.
+///
+/// `import a.b` and `from a.b import c` are checked by the top level module `a`,
+/// relative imports and `__import__` are always rejected.
+///
+/// This only rejects the obvious imports early, when the UDF is created. The
+/// sandboxed python runtime rejects the imports of modules outside its own
+/// limited set when the UDF runs.
+pub fn verify_python_imports(code: &str, allow_imports: &[String]) -> Result<()> {
+    if code.contains("__import__") {
+        return Err(ErrorCode::InvalidArgument(
+            "Python UDF is not allowed to use `__import__`",
+        ));
+    }
+
+    let statements = code
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split(';'))
+        .map(|stmt| stmt.trim());
+    for stmt in statements {
+        let modules = if let Some(rest) = stmt.strip_prefix("import ") {
+            rest.split(',')
+                .map(|module| module.split_whitespace().next().unwrap_or_default())
+                .collect::<Vec<_>>()
+        } else if let Some(rest) = stmt.strip_prefix("from ") {
+            vec![rest.split_whitespace().next().unwrap_or_default()]
+        } else {
+            continue;
+        };
+
+        for module in modules {
+            let top_level = module.split('.').next().unwrap_or_default();
+            if top_level.is_empty() || module.starts_with('.') {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Python UDF is not allowed to use relative import '{stmt}'"
+                )));
+            }
+            if !allow_imports.iter().any(|allowed| allowed == top_level) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "Unallowed python module '{top_level}', it is not in python_udf_allow_imports"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
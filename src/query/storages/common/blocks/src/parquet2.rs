@@ -25,6 +25,8 @@ use databend_common_arrow::write_parquet_file;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::converts::arrow2::storage_data_block;
+use databend_common_expression::converts::arrow2::storage_table_schema;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::TableCompression;
@@ -36,7 +38,7 @@ pub fn blocks_to_parquet(
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
 ) -> Result<(u64, ThriftFileMetaData)> {
-    let arrow_schema = ArrowSchema::from(schema.as_ref());
+    let arrow_schema = ArrowSchema::from(&storage_table_schema(schema.as_ref()));
 
     let row_group_write_options = WriteOptions {
         write_statistics: false,
@@ -46,7 +48,7 @@ pub fn blocks_to_parquet(
     };
     let batches = blocks
         .into_iter()
-        .map(Chunk::try_from)
+        .map(|block| Chunk::try_from(storage_data_block(&block)))
         .collect::<Result<Vec<_>>>()?;
 
     let encoding_map = |data_type: &ArrowDataType| match data_type {
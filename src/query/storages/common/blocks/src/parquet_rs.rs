@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use databend_common_exception::Result;
 use databend_common_expression::converts::arrow::table_schema_to_arrow_schema_ignore_inside_nullable;
+use databend_common_expression::converts::arrow2::storage_data_block;
+use databend_common_expression::converts::arrow2::storage_table_schema;
 use databend_common_expression::DataBlock;
 use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::TableCompression;
@@ -42,12 +44,13 @@ pub fn blocks_to_parquet(
         .set_statistics_enabled(EnabledStatistics::None)
         .set_bloom_filter_enabled(false)
         .build();
+    let table_schema = storage_table_schema(table_schema);
     let batches = blocks
         .into_iter()
-        .map(|block| block.to_record_batch(table_schema))
+        .map(|block| storage_data_block(&block).to_record_batch(&table_schema))
         .collect::<Result<Vec<_>>>()?;
     let arrow_schema = Arc::new(table_schema_to_arrow_schema_ignore_inside_nullable(
-        table_schema,
+        &table_schema,
     ));
     let mut writer = ArrowWriter::try_new(write_buffer, arrow_schema, Some(props))?;
     for batch in batches {
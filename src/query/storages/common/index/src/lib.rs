@@ -21,6 +21,7 @@ mod index;
 mod inverted_index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
@@ -31,3 +32,9 @@ pub use inverted_index::InvertedIndexDirectory;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
+pub use vector_index::VectorIndex;
+pub use vector_index::VectorIndexOptions;
+pub use vector_index::VECTOR_INDEX_OPTION_DIMENSION;
+pub use vector_index::VECTOR_INDEX_OPTION_DISTANCE;
+pub use vector_index::VECTOR_INDEX_OPTION_EF_CONSTRUCT;
+pub use vector_index::VECTOR_INDEX_OPTION_M;
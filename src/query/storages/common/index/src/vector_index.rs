@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_vector::DistanceType;
+use databend_common_vector::HnswIndex;
+use databend_common_vector::DEFAULT_HNSW_EF_CONSTRUCTION;
+use databend_common_vector::DEFAULT_HNSW_EF_SEARCH;
+use databend_common_vector::DEFAULT_HNSW_M;
+
+pub const VECTOR_INDEX_OPTION_DISTANCE: &str = "distance";
+pub const VECTOR_INDEX_OPTION_M: &str = "m";
+pub const VECTOR_INDEX_OPTION_EF_CONSTRUCT: &str = "ef_construct";
+pub const VECTOR_INDEX_OPTION_DIMENSION: &str = "dimension";
+
+/// Options of the HNSW vector index, parsed from the options of the table index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VectorIndexOptions {
+    pub distance_type: DistanceType,
+    pub m: usize,
+    pub ef_construct: usize,
+    /// The length of the indexed vectors, the vectors of other lengths can't be indexed or searched.
+    pub dimension: usize,
+}
+
+impl VectorIndexOptions {
+    pub fn try_create(options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut index_options = VectorIndexOptions {
+            distance_type: DistanceType::default(),
+            m: DEFAULT_HNSW_M,
+            ef_construct: DEFAULT_HNSW_EF_CONSTRUCTION,
+            dimension: 0,
+        };
+        for (key, value) in options {
+            match key.to_lowercase().as_str() {
+                VECTOR_INDEX_OPTION_DISTANCE => {
+                    index_options.distance_type = value.parse()?;
+                }
+                VECTOR_INDEX_OPTION_M => {
+                    index_options.m = parse_positive_option(key, value)?;
+                }
+                VECTOR_INDEX_OPTION_EF_CONSTRUCT => {
+                    index_options.ef_construct = parse_positive_option(key, value)?;
+                }
+                VECTOR_INDEX_OPTION_DIMENSION => {
+                    index_options.dimension = parse_positive_option(key, value)?;
+                }
+                _ => {
+                    return Err(ErrorCode::IndexOptionInvalid(format!(
+                        "Unsupported vector index option: {}, expect distance, m, ef_construct or dimension",
+                        key
+                    )));
+                }
+            }
+        }
+        if index_options.dimension == 0 {
+            return Err(ErrorCode::IndexOptionInvalid(
+                "Vector index option dimension is required",
+            ));
+        }
+        Ok(index_options)
+    }
+}
+
+fn parse_positive_option(key: &str, value: &str) -> Result<usize> {
+    match value.parse::<usize>() {
+        Ok(v) if v > 0 => Ok(v),
+        _ => Err(ErrorCode::IndexOptionInvalid(format!(
+            "Vector index option {} must be a positive integer, but got {}",
+            key, value
+        ))),
+    }
+}
+
+/// HNSW index of a vector column in a block.
+///
+/// The index is built from the non-null vectors of the column, the row ids
+/// of the vectors in the block are kept in the graph.
+pub struct VectorIndex {
+    pub hnsw: HnswIndex,
+}
+
+impl VectorIndex {
+    pub const VERSION: u8 = 1;
+
+    /// Build the index of an `Array(Float32)` column, returns `None` if all the values are null.
+    pub fn try_create(column: &Column, options: &VectorIndexOptions) -> Result<Option<Self>> {
+        let mut hnsw = HnswIndex::new(options.distance_type, options.m, options.ef_construct);
+
+        let (column, validity) = match column {
+            Column::Nullable(box nullable_column) => {
+                (&nullable_column.column, Some(&nullable_column.validity))
+            }
+            _ => (column, None),
+        };
+        let Some(column) = ArrayType::<Float32Type>::try_downcast_column(column) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Vector index only support Array(Float32) column, but got {}",
+                column.data_type()
+            )));
+        };
+
+        let mut vector = Vec::new();
+        for (row, value) in column.iter().enumerate() {
+            if validity.is_some_and(|v| !v.get_bit(row)) {
+                continue;
+            }
+            if value.len() != options.dimension {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Vector index expect vectors of dimension {}, but got a vector of length {}",
+                    options.dimension,
+                    value.len()
+                )));
+            }
+            vector.clear();
+            vector.extend(value.iter().map(|v| v.0));
+            hnsw.insert(row as u32, &vector)?;
+        }
+
+        if hnsw.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(VectorIndex { hnsw }))
+    }
+
+    /// Returns the row offsets and distances of the approximate `k`
+    /// nearest vectors in the block, ordered by the distance.
+    pub fn search(&self, query: &[f32], k: usize) -> Result<Vec<(usize, F32)>> {
+        let result = self.hnsw.search(query, k, DEFAULT_HNSW_EF_SEARCH)?;
+        Ok(result
+            .into_iter()
+            .map(|(row, distance)| (row as usize, F32::from(distance)))
+            .collect())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = vec![Self::VERSION];
+        bytes.extend(self.hnsw.to_bytes()?);
+        Ok(bytes)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match bytes.split_first() {
+            Some((version, data)) if *version == Self::VERSION => Ok(VectorIndex {
+                hnsw: HnswIndex::from_bytes(data)?,
+            }),
+            Some((version, _)) => Err(ErrorCode::StorageOther(format!(
+                "Unsupported vector index version: {}",
+                version
+            ))),
+            None => Err(ErrorCode::StorageOther("Vector index file is empty")),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::ArrayType;
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_vector::DistanceType;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_index::VectorIndexOptions;
+
+fn vector_column(vectors: Vec<Option<Vec<F32>>>) -> Column {
+    type T = NullableType<ArrayType<Float32Type>>;
+    T::upcast_column(T::column_from_iter(
+        vectors.into_iter().map(|v| v.map(|v| v.into())),
+        &[],
+    ))
+}
+
+#[test]
+fn test_vector_index_options() {
+    let options = VectorIndexOptions::try_create(&BTreeMap::from([(
+        "dimension".to_string(),
+        "2".to_string(),
+    )]))
+    .unwrap();
+    assert_eq!(options.distance_type, DistanceType::Cosine);
+    assert_eq!(options.dimension, 2);
+
+    let options = VectorIndexOptions::try_create(&BTreeMap::from([
+        ("distance".to_string(), "l2".to_string()),
+        ("m".to_string(), "8".to_string()),
+        ("ef_construct".to_string(), "40".to_string()),
+        ("dimension".to_string(), "128".to_string()),
+    ]))
+    .unwrap();
+    assert_eq!(options.distance_type, DistanceType::L2);
+    assert_eq!(options.m, 8);
+    assert_eq!(options.ef_construct, 40);
+    assert_eq!(options.dimension, 128);
+
+    // The dimension is required.
+    assert!(VectorIndexOptions::try_create(&BTreeMap::new()).is_err());
+
+    for (key, value) in [
+        ("distance", "dot"),
+        ("m", "0"),
+        ("ef", "10"),
+        ("dimension", "0"),
+    ] {
+        let options = BTreeMap::from([
+            (key.to_string(), value.to_string()),
+            ("dimension".to_string(), "2".to_string()),
+        ]);
+        assert!(VectorIndexOptions::try_create(&options).is_err());
+    }
+}
+
+#[test]
+fn test_vector_index() {
+    let vectors = (0..100)
+        .map(|i| {
+            if i % 10 == 0 {
+                None
+            } else {
+                Some(vec![F32::from(i as f32), F32::from(1.0)])
+            }
+        })
+        .collect::<Vec<_>>();
+    let column = vector_column(vectors);
+    let options = VectorIndexOptions::try_create(&BTreeMap::from([
+        ("distance".to_string(), "l2".to_string()),
+        ("dimension".to_string(), "2".to_string()),
+    ]))
+    .unwrap();
+
+    let index = VectorIndex::try_create(&column, &options).unwrap().unwrap();
+    assert_eq!(index.hnsw.len(), 90);
+
+    let index = VectorIndex::from_bytes(&index.to_bytes().unwrap()).unwrap();
+    let result = index.search(&[20.2, 1.0], 3).unwrap();
+    // Row 20 is null, so it is not in the index.
+    let rows = result.iter().map(|(row, _)| *row).collect::<Vec<_>>();
+    assert_eq!(rows, vec![21, 19, 22]);
+
+    let nulls = vector_column(vec![None, None]);
+    assert!(VectorIndex::try_create(&nulls, &options).unwrap().is_none());
+
+    let mismatched = vector_column(vec![Some(vec![F32::from(1.0); 3])]);
+    assert!(VectorIndex::try_create(&mismatched, &options).is_err());
+}
@@ -14,10 +14,13 @@
 
 mod stream_keys;
 mod table_compression;
+mod table_constraints;
 mod table_keys;
 mod table_prefix;
 
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
+pub use table_constraints::TableConstraints;
+pub use table_constraints::UniqueKeyConstraint;
 pub use table_keys::*;
 pub use table_prefix::*;
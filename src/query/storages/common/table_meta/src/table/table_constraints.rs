@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_TABLE_CONSTRAINTS;
+
+/// The constraints of a table, stored as JSON in the table option [`OPT_KEY_TABLE_CONSTRAINTS`].
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableConstraints {
+    /// CHECK constraints, from the constraint name to the SQL text of the expression.
+    pub checks: BTreeMap<String, String>,
+    /// PRIMARY KEY and UNIQUE constraints, by constraint name.
+    pub unique_keys: BTreeMap<String, UniqueKeyConstraint>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UniqueKeyConstraint {
+    pub columns: Vec<String>,
+    pub primary: bool,
+    /// Whether the key is checked when writing the table, otherwise it is informational only.
+    pub enforced: bool,
+}
+
+impl TableConstraints {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_TABLE_CONSTRAINTS) {
+            Some(value) => serde_json::from_str(value).map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "Invalid table option {}: {}",
+                    OPT_KEY_TABLE_CONSTRAINTS, e
+                ))
+            }),
+            None => Ok(TableConstraints::default()),
+        }
+    }
+
+    /// Encodes the constraints as the value of the table option [`OPT_KEY_TABLE_CONSTRAINTS`],
+    /// `None` means there is no constraint.
+    pub fn to_option_value(&self) -> Result<Option<String>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        serde_json::to_string(self)
+            .map(Some)
+            .map_err(|e| ErrorCode::Internal(format!("Fail to encode table constraints: {}", e)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checks.is_empty() && self.unique_keys.is_empty()
+    }
+
+    /// The enforced PRIMARY KEY or UNIQUE constraint, a table has at most one.
+    pub fn enforced_unique_key(&self) -> Option<(&String, &UniqueKeyConstraint)> {
+        self.unique_keys.iter().find(|(_, key)| key.enforced)
+    }
+
+    /// The name of the PRIMARY KEY or UNIQUE constraint that contains the column, if any.
+    pub fn unique_key_of_column(&self, column: &str) -> Option<&String> {
+        self.unique_keys
+            .iter()
+            .find(|(_, key)| key.columns.iter().any(|c| c == column))
+            .map(|(name, _)| name)
+    }
+}
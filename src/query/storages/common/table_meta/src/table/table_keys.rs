@@ -25,6 +25,18 @@ pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
 
+// Zero-copy cloned table options, the values are comma separated table ids.
+// The source tables (and their sources) whose data are referenced by a cloned table.
+pub const OPT_KEY_CLONE_SOURCE_TABLE_IDS: &str = "clone_source_table_ids";
+// The tables that are cloned from this table, directly or indirectly.
+pub const OPT_KEY_CLONED_TABLE_IDS: &str = "cloned_table_ids";
+
+// Named snapshots of a fuse table, stored as a JSON map from the tag name to the tagged snapshot.
+pub const OPT_KEY_SNAPSHOT_TAGS: &str = "snapshot_tags";
+
+// CHECK, PRIMARY KEY and UNIQUE constraints of a table, stored as a JSON.
+pub const OPT_KEY_TABLE_CONSTRAINTS: &str = "constraints";
+
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
@@ -54,6 +66,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_IDS);
+    r.insert(OPT_KEY_CLONED_TABLE_IDS);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
+    r.insert(OPT_KEY_TABLE_CONSTRAINTS);
     r
 });
 
@@ -64,6 +80,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
+    r.insert(OPT_KEY_CLONE_SOURCE_TABLE_IDS);
+    r.insert(OPT_KEY_CLONED_TABLE_IDS);
+    r.insert(OPT_KEY_SNAPSHOT_TAGS);
+    r.insert(OPT_KEY_TABLE_CONSTRAINTS);
     r
 });
 
@@ -0,0 +1,278 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_sinks::AsyncSink;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::table::DeltaTable;
+use crate::writer::AddActionsMeta;
+
+/// The highest writer version whose features are all supported.
+const MAX_SUPPORTED_WRITER_VERSION: i32 = 2;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAction {
+    pub path: String,
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+    pub modification_time: i64,
+    pub data_change: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAction {
+    pub path: String,
+    pub deletion_timestamp: i64,
+    pub data_change: bool,
+    pub extended_file_metadata: bool,
+    pub partition_values: BTreeMap<String, Option<String>>,
+    pub size: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitInfo {
+    pub timestamp: i64,
+    pub operation: String,
+    pub operation_parameters: BTreeMap<String, String>,
+    pub is_blind_append: bool,
+    pub engine_info: String,
+}
+
+/// The actions of a commit, one JSON object per line in the commit file.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum Action {
+    CommitInfo(CommitInfo),
+    Add(AddAction),
+    Remove(RemoveAction),
+}
+
+fn commit_path(version: i64) -> String {
+    format!("_delta_log/{version:020}.json")
+}
+
+/// Write the actions as the commit next to `read_version`, the version of the table
+/// the actions are based on.
+///
+/// If other writers committed after `read_version`, a blind append is retried on the next
+/// version unless they changed the metadata or protocol, other commits fail with a conflict.
+///
+/// There is no atomic create on object storages, the commits of databend are serialized by the
+/// table lock. A commit overwritten by another writer meanwhile is reported as a conflict.
+#[async_backtrace::framed]
+pub async fn commit_actions(
+    op: &Operator,
+    read_version: i64,
+    actions: &[Action],
+    is_blind_append: bool,
+) -> Result<i64> {
+    let mut body = String::new();
+    for action in actions {
+        body.push_str(&serde_json::to_string(action)?);
+        body.push('\n');
+    }
+
+    let mut version = read_version + 1;
+    loop {
+        let path = commit_path(version);
+        if !op.is_exist(&path).await? {
+            op.write(&path, body.clone()).await?;
+            if op.read(&path).await? != body.as_bytes() {
+                return Err(ErrorCode::TableVersionMismatched(format!(
+                    "Delta table commit {version} was overwritten by a concurrent writer, please retry"
+                )));
+            }
+            return Ok(version);
+        }
+
+        let winning = op.read(&path).await?;
+        if !is_blind_append || changes_metadata(&winning)? {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Delta table was modified by a concurrent commit {version} after version {read_version}, please retry"
+            )));
+        }
+        version += 1;
+    }
+}
+
+/// Whether the commit changes the metadata or the protocol of the table.
+fn changes_metadata(commit: &[u8]) -> Result<bool> {
+    for line in commit.split(|b| *b == b'\n') {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let action: JsonValue = serde_json::from_slice(line)?;
+        if action.get("metaData").is_some() || action.get("protocol").is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Commit the data files written by the [`DataFileWriter`](crate::writer::DataFileWriter)s
+/// as a new version of the table.
+pub struct CommitSink {
+    sp: StorageParams,
+    op: Operator,
+    partition_columns: Vec<String>,
+    overwrite: bool,
+    read_version: i64,
+    removes: Vec<RemoveAction>,
+    adds: Vec<AddAction>,
+}
+
+impl CommitSink {
+    pub fn create(
+        sp: StorageParams,
+        op: Operator,
+        partition_columns: Vec<String>,
+        overwrite: bool,
+    ) -> Self {
+        CommitSink {
+            sp,
+            op,
+            partition_columns,
+            overwrite,
+            read_version: -1,
+            removes: vec![],
+            adds: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for CommitSink {
+    const NAME: &'static str = "DeltaCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_start(&mut self) -> Result<()> {
+        // The version the insertion is based on, commits after it are checked for conflicts.
+        let table = DeltaTable::load(&self.sp).await?;
+        check_writable(&table, self.overwrite)?;
+        self.read_version = table.version();
+
+        if self.overwrite {
+            let deletion_timestamp = Utc::now().timestamp_millis();
+            let adds = table
+                .snapshot()
+                .and_then(|f| f.file_actions())
+                .map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!("Cannot read file_actions: {e:?}"))
+                })?;
+            self.removes = adds
+                .iter()
+                .map(|add| RemoveAction {
+                    path: add.path.clone(),
+                    deletion_timestamp,
+                    data_change: true,
+                    extended_file_metadata: true,
+                    partition_values: add.partition_values.clone().into_iter().collect(),
+                    size: add.size,
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        if self.adds.is_empty() && self.removes.is_empty() {
+            return Ok(());
+        }
+
+        let mode = if self.overwrite {
+            "Overwrite"
+        } else {
+            "Append"
+        };
+        let operation_parameters = BTreeMap::from([
+            ("mode".to_string(), mode.to_string()),
+            (
+                "partitionBy".to_string(),
+                serde_json::to_string(&self.partition_columns)?,
+            ),
+        ]);
+        let mut actions = vec![Action::CommitInfo(CommitInfo {
+            timestamp: Utc::now().timestamp_millis(),
+            operation: "WRITE".to_string(),
+            operation_parameters,
+            is_blind_append: !self.overwrite,
+            engine_info: format!("Databend/{}", env!("CARGO_PKG_VERSION")),
+        })];
+        actions.extend(self.removes.drain(..).map(Action::Remove));
+        actions.extend(self.adds.drain(..).map(Action::Add));
+
+        commit_actions(&self.op, self.read_version, &actions, !self.overwrite).await?;
+        Ok(())
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .take_meta()
+            .and_then(AddActionsMeta::downcast_from)
+        {
+            self.adds.extend(meta.adds);
+        }
+        Ok(false)
+    }
+}
+
+/// Only the writer features up to [`MAX_SUPPORTED_WRITER_VERSION`] are supported,
+/// the later ones (e.g. CHECK constraints, generated columns) must be enforced by writers.
+fn check_writable(table: &deltalake::table::DeltaTable, overwrite: bool) -> Result<()> {
+    let protocol = table.protocol().map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot read delta table protocol: {e:?}"))
+    })?;
+    if protocol.min_writer_version > MAX_SUPPORTED_WRITER_VERSION {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Writing delta table with writer version {} is not supported, the max supported version is {}",
+            protocol.min_writer_version, MAX_SUPPORTED_WRITER_VERSION
+        )));
+    }
+
+    let metadata = table.metadata().map_err(|e| {
+        ErrorCode::ReadTableDataError(format!("Cannot read delta table metadata: {e:?}"))
+    })?;
+    let append_only = metadata
+        .configuration
+        .get("delta.appendOnly")
+        .and_then(|v| v.as_deref())
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+    if overwrite && append_only {
+        return Err(ErrorCode::TableNotWritable(
+            "Delta table is append-only (delta.appendOnly = true), INSERT OVERWRITE is not allowed",
+        ));
+    }
+    Ok(())
+}
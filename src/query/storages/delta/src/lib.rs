@@ -16,10 +16,13 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod commit;
 mod dal;
 mod partition;
 mod partition_columns;
 mod table;
 mod table_source;
+mod writer;
 
 pub use table::DeltaTable;
+pub use table::DELTA_ENGINE;
@@ -17,3 +17,4 @@ mod values_serde;
 
 pub use pushdown_transform::get_pushdown_without_partition_columns;
 pub use values_serde::get_partition_values;
+pub use values_serde::scalar_to_str;
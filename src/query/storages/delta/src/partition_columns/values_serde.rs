@@ -82,6 +82,20 @@ pub fn str_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
     }
 }
 
+/// Serialize a partition value into the string kept in the `partitionValues` of the log,
+/// `None` for null.
+pub fn scalar_to_str(value: &Scalar) -> Result<Option<String>> {
+    match value {
+        Scalar::Null => Ok(None),
+        Scalar::String(s) => Ok(Some(s.clone())),
+        Scalar::Number(n) => Ok(Some(n.to_string())),
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "can not use value {} as delta partition",
+            value.as_ref()
+        ))),
+    }
+}
+
 pub fn get_partition_values(add: &Add, fields: &[&TableField]) -> Result<Vec<Scalar>> {
     let mut values = Vec::with_capacity(fields.len());
     for f in fields {
@@ -25,6 +25,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -35,13 +36,19 @@ use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storages_parquet::ParquetFilesPart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
 use deltalake::kernel::Add;
 use deltalake::DeltaTableBuilder;
@@ -52,11 +59,13 @@ use tokio::sync::OnceCell;
 use url::Url;
 
 // use object_store_opendal::OpendalStore;
+use crate::commit::CommitSink;
 use crate::dal::OpendalStore;
 use crate::partition::DeltaPartInfo;
 use crate::partition_columns::get_partition_values;
 use crate::partition_columns::get_pushdown_without_partition_columns;
 use crate::table_source::DeltaTableSource;
+use crate::writer::DataFileWriter;
 
 pub const DELTA_ENGINE: &str = "DELTA";
 
@@ -378,6 +387,55 @@ impl Table for DeltaTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let schema = self.schema();
+        pipeline.add_transform(|input, output| {
+            let writer = DataFileWriter::try_create(
+                ctx.clone(),
+                op.clone(),
+                schema.clone(),
+                self.meta.partition_columns.clone(),
+            )?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        let sp = self.get_storage_params()?;
+        let op = init_operator(sp)?;
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            let sink = CommitSink::create(
+                sp.clone(),
+                op.clone(),
+                self.meta.partition_columns.clone(),
+                overwrite,
+            );
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                ctx.clone(),
+                sink,
+            )))
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
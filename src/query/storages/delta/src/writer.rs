@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::aggregates::eval_aggr;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::commit::AddAction;
+use crate::partition_columns::scalar_to_str;
+
+/// The in-memory size of the rows buffered for a data file of one partition.
+const MAX_BUFFERED_BYTES: usize = 256 * 1024 * 1024;
+/// Longer strings are not collected into the stats, as the bounds must not be truncated.
+const MAX_STATS_STRING_LEN: usize = 32;
+/// The directory name of null partition values, the same as hive and spark.
+const NULL_PARTITION_VALUE: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The values of the partition columns, `None` for null.
+type PartitionValues = Vec<Option<String>>;
+
+/// The add actions of the data files written by a [`DataFileWriter`].
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct AddActionsMeta {
+    pub adds: Vec<AddAction>,
+}
+
+#[typetag::serde(name = "delta_add_actions_meta")]
+impl BlockMetaInfo for AddActionsMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        AddActionsMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Default)]
+struct PartitionBuffer {
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+/// Split the appended blocks by the partition values, and buffer them into parquet data files.
+///
+/// Partition columns are not stored in the data files, but in the directory names and
+/// the `partitionValues` of the add actions.
+pub struct DataFileWriter {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    partition_columns: Vec<String>,
+    partition_indexes: Vec<FieldIndex>,
+    data_indexes: Vec<FieldIndex>,
+    data_schema: TableSchemaRef,
+    buffers: HashMap<PartitionValues, PartitionBuffer>,
+    adds: Vec<AddAction>,
+}
+
+impl DataFileWriter {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        op: Operator,
+        schema: TableSchemaRef,
+        partition_columns: Vec<String>,
+    ) -> Result<Self> {
+        let partition_indexes = partition_columns
+            .iter()
+            .map(|name| schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        let data_indexes = (0..schema.num_fields())
+            .filter(|i| !partition_indexes.contains(i))
+            .collect::<Vec<_>>();
+        let data_schema = Arc::new(TableSchema::new(
+            data_indexes
+                .iter()
+                .map(|i| schema.fields()[*i].clone())
+                .collect(),
+        ));
+        Ok(DataFileWriter {
+            ctx,
+            op,
+            partition_columns,
+            partition_indexes,
+            data_indexes,
+            data_schema,
+            buffers: HashMap::new(),
+            adds: vec![],
+        })
+    }
+
+    /// Split the block by the partition values, the partition columns are removed.
+    fn split_by_partition(&self, block: DataBlock) -> Result<Vec<(PartitionValues, DataBlock)>> {
+        let block = block.convert_to_full();
+        let num_rows = block.num_rows();
+        let project = |block: &DataBlock| {
+            let columns = self
+                .data_indexes
+                .iter()
+                .map(|i| block.get_by_offset(*i).clone())
+                .collect();
+            DataBlock::new(columns, block.num_rows())
+        };
+        if self.partition_indexes.is_empty() {
+            return Ok(vec![(vec![], project(&block))]);
+        }
+
+        let partition_columns = self
+            .partition_indexes
+            .iter()
+            .map(|i| block.get_by_offset(*i).value.as_column().unwrap())
+            .collect::<Vec<_>>();
+        let mut partitions: Vec<PartitionValues> = vec![];
+        let mut partition_ids = HashMap::new();
+        let mut indices = Vec::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let values = partition_columns
+                .iter()
+                .map(|c| scalar_to_str(&c.index(row).unwrap().to_owned()))
+                .collect::<Result<PartitionValues>>()?;
+            let id = *partition_ids.entry(values.clone()).or_insert_with(|| {
+                partitions.push(values);
+                partitions.len() - 1
+            });
+            indices.push(id as u32);
+        }
+
+        let blocks = block.scatter(&indices, partitions.len())?;
+        Ok(partitions
+            .into_iter()
+            .zip(blocks.iter().map(project))
+            .collect())
+    }
+
+    async fn flush(&mut self, values: PartitionValues, blocks: Vec<DataBlock>) -> Result<()> {
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+        let batch = block.to_record_batch(&self.data_schema)?;
+
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let mut buf = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        let mut dir = String::new();
+        for (name, value) in self.partition_columns.iter().zip(values.iter()) {
+            let value = value.as_deref().unwrap_or(NULL_PARTITION_VALUE);
+            dir.push_str(&format!(
+                "{}={}/",
+                escape_path_name(name),
+                escape_path_name(value)
+            ));
+        }
+        let path = format!("{dir}part-00000-{}.c000.zstd.parquet", Uuid::new_v4());
+        let size = buf.len() as i64;
+        self.op.write(&path, buf).await?;
+
+        let partition_values = self
+            .partition_columns
+            .iter()
+            .cloned()
+            .zip(values)
+            .collect::<BTreeMap<_, _>>();
+        self.adds.push(AddAction {
+            path: encode_uri_path(&path),
+            partition_values,
+            size,
+            modification_time: Utc::now().timestamp_millis(),
+            data_change: true,
+            stats: Some(collect_stats(&block, &self.data_schema, num_rows)?),
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for DataFileWriter {
+    const NAME: &'static str = "DeltaDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+        let progress_values = ProgressValues {
+            rows: data.num_rows(),
+            bytes: data.memory_size(),
+        };
+        self.ctx.get_write_progress().incr(&progress_values);
+
+        for (values, block) in self.split_by_partition(data)? {
+            if block.is_empty() {
+                continue;
+            }
+            let buffer = self.buffers.entry(values.clone()).or_default();
+            buffer.bytes += block.memory_size();
+            buffer.blocks.push(block);
+            if buffer.bytes >= MAX_BUFFERED_BYTES {
+                let blocks = self.buffers.remove(&values).unwrap().blocks;
+                self.flush(values, blocks).await?;
+            }
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        for (values, buffer) in std::mem::take(&mut self.buffers) {
+            self.flush(values, buffer.blocks).await?;
+        }
+        if self.adds.is_empty() {
+            return Ok(None);
+        }
+        let meta = AddActionsMeta {
+            adds: std::mem::take(&mut self.adds),
+        };
+        Ok(Some(DataBlock::empty_with_meta(Box::new(meta))))
+    }
+}
+
+/// Collect the per-file statistics used by the readers for data skipping.
+///
+/// See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#per-file-statistics>
+fn collect_stats(block: &DataBlock, schema: &TableSchema, num_rows: usize) -> Result<String> {
+    let mut min_values = Map::new();
+    let mut max_values = Map::new();
+    let mut null_count = Map::new();
+    for (entry, field) in block.columns().iter().zip(schema.fields()) {
+        let column = entry.value.as_column().unwrap();
+        let data_type = entry.data_type.remove_nullable();
+        if !matches!(
+            data_type,
+            DataType::Number(_) | DataType::String | DataType::Date | DataType::Timestamp
+        ) {
+            continue;
+        }
+
+        let (is_all_null, validity) = column.validity();
+        let nulls = match (is_all_null, validity) {
+            (true, _) => num_rows,
+            (false, Some(bitmap)) => bitmap.unset_bits(),
+            (false, None) => 0,
+        };
+        null_count.insert(field.name().clone(), json!(nulls));
+        if nulls == num_rows {
+            continue;
+        }
+
+        if let (Some(min), Some(max)) = (
+            aggregate_to_json("min", column, num_rows)?,
+            aggregate_to_json("max", column, num_rows)?,
+        ) {
+            min_values.insert(field.name().clone(), min);
+            max_values.insert(field.name().clone(), max);
+        }
+    }
+
+    let stats = json!({
+        "numRecords": num_rows,
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    });
+    Ok(stats.to_string())
+}
+
+fn aggregate_to_json(name: &str, column: &Column, num_rows: usize) -> Result<Option<JsonValue>> {
+    let (values, _) = eval_aggr(name, vec![], &[column.clone()], num_rows)?;
+    let Some(value) = values.index(0) else {
+        return Ok(None);
+    };
+    let value = match value.to_owned() {
+        Scalar::Number(NumberScalar::Float32(v)) if !v.is_finite() => None,
+        Scalar::Number(NumberScalar::Float64(v)) if !v.is_finite() => None,
+        Scalar::Number(v) => serde_json::from_str(&v.to_string()).ok(),
+        Scalar::String(v) if v.chars().count() <= MAX_STATS_STRING_LEN => Some(json!(v)),
+        Scalar::Date(v) => NaiveDate::from_num_days_from_ce_opt(v + 719_163)
+            .map(|d| json!(d.format("%Y-%m-%d").to_string())),
+        // The bounds are in milliseconds, the max is rounded up to keep it an upper bound.
+        Scalar::Timestamp(v) => {
+            let millis = match name {
+                "max" => (v + 999).div_euclid(1000),
+                _ => v.div_euclid(1000),
+            };
+            DateTime::from_timestamp_millis(millis)
+                .map(|t| json!(t.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()))
+        }
+        _ => None,
+    };
+    Ok(value)
+}
+
+/// Escape the special characters in a partition directory name, the same as hive and spark.
+fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_control() || "\"#%'*/:=?\\{[]^".contains(c) {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// The `path` of the actions is a relative URI, so the escaped directory names are encoded again.
+fn encode_uri_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for b in path.bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~/=".contains(&b) {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{b:02X}"));
+        }
+    }
+    encoded
+}
@@ -859,6 +859,14 @@ impl Table for FuseTable {
         matches!(self.storage_format, FuseStorageFormat::Native)
     }
 
+    fn support_block_sample(&self) -> bool {
+        true
+    }
+
+    fn support_row_sample(&self) -> bool {
+        true
+    }
+
     fn support_index(&self) -> bool {
         true
     }
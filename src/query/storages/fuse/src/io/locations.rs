@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_storages_common_index::InvertedIndexDirectory;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::meta::IndexInfo;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -35,6 +36,7 @@ use crate::FUSE_TBL_AGG_INDEX_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_INFO_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -168,6 +170,18 @@ impl TableMetaLocationGenerator {
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
 
+    pub fn gen_vector_index_location_from_block_location(loc: &str, index_version: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        let block_id = block_name.split('_').next().unwrap_or(block_name);
+        format!(
+            "{prefix}/{FUSE_TBL_VECTOR_INDEX_PREFIX}/{index_version}/{block_id}_v{}.index",
+            VectorIndex::VERSION,
+        )
+    }
+
     pub fn gen_inverted_index_location(&self, id: String) -> String {
         format!(
             "{}/{}/{}_v{}.index",
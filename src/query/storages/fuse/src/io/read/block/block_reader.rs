@@ -16,12 +16,14 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow::datatypes::Field;
+use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use databend_common_arrow::parquet::metadata::SchemaDescriptor;
 use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::converts::arrow2::storage_table_schema;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataField;
@@ -126,7 +128,7 @@ impl BlockReader {
             }
         };
 
-        let arrow_schema = schema.as_ref().into();
+        let arrow_schema = ArrowSchema::from(&storage_table_schema(&schema));
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
 
         let column_nodes = ColumnNodes::new_from_schema(&arrow_schema, Some(&schema));
@@ -20,6 +20,7 @@ pub mod meta;
 mod read_settings;
 mod snapshot_history_reader;
 mod utils;
+mod vector_index;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
@@ -36,5 +37,6 @@ pub use meta::MetaReaders;
 pub use meta::TableSnapshotReader;
 pub use read_settings::ReadSettings;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector_index::load_vector_index;
 pub use virtual_column::VirtualColumnReader;
 pub use virtual_column::VirtualMergeIOReadResult;
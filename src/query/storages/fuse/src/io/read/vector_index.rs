@@ -0,0 +1,28 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_storages_common_index::VectorIndex;
+use opendal::ErrorKind;
+use opendal::Operator;
+
+/// Loads the vector index of a block, returns `None` if the block has not been indexed.
+#[minitrace::trace]
+pub async fn load_vector_index(dal: Operator, location: &str) -> Result<Option<VectorIndex>> {
+    match dal.read(location).await {
+        Ok(data) => Ok(Some(VectorIndex::from_bytes(&data)?)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -18,9 +18,12 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_arrow::arrow::chunk::Chunk as ArrowChunk;
+use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::native::write::NativeWriter;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::converts::arrow2::storage_data_block;
+use databend_common_expression::converts::arrow2::storage_table_schema;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
@@ -66,7 +69,7 @@ pub fn serialize_block(
             Ok(meta)
         }
         FuseStorageFormat::Native => {
-            let arrow_schema = schema.as_ref().into();
+            let arrow_schema = ArrowSchema::from(&storage_table_schema(&schema));
             let leaf_column_ids = schema.to_leaf_column_ids();
 
             let mut default_compress_ratio = Some(2.10f64);
@@ -85,7 +88,7 @@ pub fn serialize_block(
                 },
             );
 
-            let batch = ArrowChunk::try_from(block)?;
+            let batch = ArrowChunk::try_from(storage_data_block(&block))?;
 
             writer.start()?;
             writer.write(&batch)?;
@@ -0,0 +1,383 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaId;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONED_TABLE_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_CLONE_SOURCE_TABLE_IDS;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use log::info;
+use log::warn;
+use uuid::Uuid;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::gc::LocationTuple;
+use crate::FuseTable;
+
+const MAX_REGISTER_CLONE_RETRIES: usize = 10;
+
+/// Files that the purge of a table must keep, even if they are no longer
+/// referenced by the snapshots of the table itself.
+#[derive(Default)]
+pub struct RetainedLocations {
+    /// Set if the table is a clone, files outside of this prefix belong to the source tables.
+    pub storage_prefix: Option<String>,
+    /// Files of this table that are referenced by the tables cloned from it,
+    /// or by the tagged snapshots.
+    pub segments: HashSet<String>,
+    pub locations: LocationTuple,
+    /// The tagged snapshots and their table statistics.
+    pub snapshots: HashSet<String>,
+    pub table_statistics: HashSet<String>,
+}
+
+impl RetainedLocations {
+    fn is_foreign(&self, location: &str) -> bool {
+        match &self.storage_prefix {
+            Some(prefix) => !location.starts_with(prefix),
+            None => false,
+        }
+    }
+
+    pub fn retain_segment(&self, location: &str) -> bool {
+        self.is_foreign(location) || self.segments.contains(location)
+    }
+
+    pub fn retain_block(&self, location: &str) -> bool {
+        self.is_foreign(location) || self.locations.block_location.contains(location)
+    }
+
+    pub fn retain_bloom(&self, location: &str) -> bool {
+        self.is_foreign(location) || self.locations.bloom_location.contains(location)
+    }
+
+    pub fn retain_snapshot(&self, location: &str) -> bool {
+        self.snapshots.contains(location)
+    }
+
+    pub fn retain_table_statistics(&self, location: &str) -> bool {
+        self.table_statistics.contains(location)
+    }
+}
+
+impl FuseTable {
+    /// The tables whose data are referenced by this table, if it is created by `CREATE TABLE .. CLONE`.
+    pub fn clone_source_table_ids(&self) -> Vec<MetaId> {
+        parse_table_ids(self.table_info.options(), OPT_KEY_CLONE_SOURCE_TABLE_IDS)
+    }
+
+    /// The tables that are cloned from this table.
+    pub fn cloned_table_ids(&self) -> Vec<MetaId> {
+        parse_table_ids(self.table_info.options(), OPT_KEY_CLONED_TABLE_IDS)
+    }
+
+    /// Commits the first snapshot of a newly created (empty) table, which references
+    /// the segments of the snapshot of `source`, no data will be copied.
+    #[async_backtrace::framed]
+    pub async fn do_clone_from(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        source: &FuseTable,
+    ) -> Result<()> {
+        if source.table_info.meta.storage_params.is_some()
+            || matches!(source.table_info.db_type, DatabaseType::ShareDB(_))
+        {
+            return Err(ErrorCode::StorageUnsupported(format!(
+                "Clone table {} is not supported, only the tables stored in the default storage can be cloned",
+                source.table_info.desc
+            )));
+        }
+
+        let mut source_table_ids = vec![source.get_id()];
+        for id in source.clone_source_table_ids() {
+            if !source_table_ids.contains(&id) {
+                source_table_ids.push(id);
+            }
+        }
+
+        // Register the clone before the snapshot of the source is read, so that the files
+        // it references will not be purged by the gc of the sources.
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        for source_table_id in &source_table_ids {
+            register_cloned_table(
+                catalog.as_ref(),
+                ctx.get_tenant().as_str(),
+                *source_table_id,
+                self.get_id(),
+            )
+            .await?;
+        }
+
+        let Some(snapshot) = source.read_table_snapshot().await? else {
+            // Nothing to share.
+            return Ok(());
+        };
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &None,
+            None,
+            self.schema().as_ref().clone(),
+            snapshot.summary.clone(),
+            snapshot.segments.clone(),
+            self.cluster_key_meta(),
+            None,
+            None,
+        );
+
+        let mut table_info = self.table_info.clone();
+        table_info.meta.options.insert(
+            OPT_KEY_CLONE_SOURCE_TABLE_IDS.to_string(),
+            join_table_ids(&source_table_ids),
+        );
+
+        info!(
+            "clone table {} from {}, snapshot {}, segments {}",
+            self.table_info.desc,
+            source.table_info.desc,
+            snapshot.snapshot_id,
+            snapshot.segments.len()
+        );
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
+    /// Returns the tables cloned from this table, which may still reference its data.
+    #[async_backtrace::framed]
+    pub async fn referencing_cloned_table_ids(&self, catalog: &dyn Catalog) -> Result<Vec<MetaId>> {
+        let mut table_ids = vec![];
+        for table_id in self.cloned_table_ids() {
+            match catalog.get_table_meta_by_id(table_id).await {
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+                Ok(_) => table_ids.push(table_id),
+            }
+        }
+        Ok(table_ids)
+    }
+
+    /// Collects the files that must not be purged by the gc of this table:
+    /// - the files of the source tables, if this table is a clone.
+    /// - the tagged snapshots, and the files of this table referenced by them.
+    /// - the files of this table which are referenced by any snapshot of its clones.
+    #[async_backtrace::framed]
+    pub async fn get_retained_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<RetainedLocations> {
+        let prefix = format!("{}/", self.meta_location_generator.prefix());
+        let mut retained = RetainedLocations::default();
+        if !self.clone_source_table_ids().is_empty() {
+            retained.storage_prefix = Some(prefix.clone());
+        }
+
+        let mut segments = HashSet::new();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        for (tag, snapshot_tag) in self.snapshot_tags()? {
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(&snapshot_tag.snapshot_location),
+                location: snapshot_tag.snapshot_location,
+                len_hint: None,
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "snapshot {} of tag {} of table {} not found",
+                        params.location, tag, self.table_info.desc
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            segments.extend(
+                snapshot
+                    .segments
+                    .iter()
+                    .filter(|(location, _)| location.starts_with(&prefix))
+                    .cloned(),
+            );
+            if let Some(location) = &snapshot.table_statistics_location {
+                retained.table_statistics.insert(location.clone());
+            }
+            retained.snapshots.insert(params.location);
+        }
+
+        let cloned_table_ids = self.cloned_table_ids();
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        for table_id in cloned_table_ids {
+            let (ident, meta) = match catalog.get_table_meta_by_id(table_id).await {
+                // The clone has been vacuumed, it references nothing.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE_ID => continue,
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            let table_info = TableInfo {
+                ident,
+                desc: format!("table id {}", table_id),
+                meta: meta.as_ref().clone(),
+                ..Default::default()
+            };
+            let cloned_table = FuseTable::do_create(table_info)?;
+            let reader = MetaReaders::table_snapshot_reader(cloned_table.get_operator());
+            for location in cloned_table.list_snapshot_files().await? {
+                let params = LoadParams {
+                    ver: TableMetaLocationGenerator::snapshot_version(&location),
+                    location,
+                    len_hint: None,
+                    put_cache: false,
+                };
+                let snapshot = match reader.read(&params).await {
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                        warn!(
+                            "snapshot {} of cloned table {} already collected",
+                            params.location, table_id
+                        );
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                    Ok(v) => v,
+                };
+                segments.extend(
+                    snapshot
+                        .segments
+                        .iter()
+                        .filter(|(location, _)| location.starts_with(&prefix))
+                        .cloned(),
+                );
+            }
+        }
+
+        if segments.is_empty() {
+            return Ok(retained);
+        }
+
+        let segments = segments.into_iter().collect::<Vec<Location>>();
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, true)
+            .await?;
+        retained.segments = segments.into_iter().map(|(location, _)| location).collect();
+        retained.locations = LocationTuple {
+            block_location: locations
+                .block_location
+                .into_iter()
+                .filter(|location| location.starts_with(&prefix))
+                .collect(),
+            bloom_location: locations
+                .bloom_location
+                .into_iter()
+                .filter(|location| location.starts_with(&prefix))
+                .collect(),
+        };
+        Ok(retained)
+    }
+}
+
+/// Adds `table_id` to the cloned tables of `source_table_id`.
+async fn register_cloned_table(
+    catalog: &dyn Catalog,
+    tenant: &str,
+    source_table_id: MetaId,
+    table_id: MetaId,
+) -> Result<()> {
+    let mut retries = 0;
+    loop {
+        let (ident, meta) = catalog.get_table_meta_by_id(source_table_id).await?;
+        let mut cloned_table_ids = parse_table_ids(&meta.options, OPT_KEY_CLONED_TABLE_IDS);
+        if cloned_table_ids.contains(&table_id) {
+            return Ok(());
+        }
+        cloned_table_ids.push(table_id);
+
+        let db_id = meta
+            .options
+            .get(OPT_KEY_DATABASE_ID)
+            .and_then(|id| id.parse::<MetaId>().ok())
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Invalid fuse table, table option {} of table id {} not found",
+                    OPT_KEY_DATABASE_ID, source_table_id
+                ))
+            })?;
+        let db_name = catalog.get_db_name_by_id(db_id).await?;
+        let req = UpsertTableOptionReq {
+            table_id: source_table_id,
+            seq: MatchSeq::Exact(ident.seq),
+            options: HashMap::from([(
+                OPT_KEY_CLONED_TABLE_IDS.to_string(),
+                Some(join_table_ids(&cloned_table_ids)),
+            )]),
+        };
+        match catalog.upsert_table_option(tenant, &db_name, req).await {
+            Err(e)
+                if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED
+                    && retries < MAX_REGISTER_CLONE_RETRIES =>
+            {
+                retries += 1;
+                warn!(
+                    "register cloned table {} of table id {} got TableVersionMismatched, retry {}",
+                    table_id, source_table_id, retries
+                );
+            }
+            Err(e) => return Err(e),
+            Ok(_) => return Ok(()),
+        }
+    }
+}
+
+fn parse_table_ids(options: &BTreeMap<String, String>, key: &str) -> Vec<MetaId> {
+    options
+        .get(key)
+        .map(|ids| {
+            ids.split(',')
+                .filter_map(|id| id.trim().parse::<MetaId>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn join_table_ids(table_ids: &[MetaId]) -> String {
+    table_ids
+        .iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
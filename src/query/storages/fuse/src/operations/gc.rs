@@ -40,6 +40,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::RetainedLocations;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -88,6 +89,9 @@ impl FuseTable {
             })
             .await?;
 
+        // Files shared with the source tables or the cloned tables must be kept.
+        let retained = self.get_retained_locations(ctx).await?;
+
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
@@ -166,6 +170,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &retained,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -181,6 +186,7 @@ impl FuseTable {
                         ctx,
                         &mut counter,
                         &root_snapshot_info.referenced_locations,
+                        &retained,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -221,6 +227,7 @@ impl FuseTable {
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &retained,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -232,6 +239,7 @@ impl FuseTable {
                     ctx,
                     &mut counter,
                     &root_snapshot_info.referenced_locations,
+                    &retained,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -253,6 +261,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &retained,
                 &table_agg_index_ids,
             )
             .await?;
@@ -316,6 +325,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        retained: &RetainedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -331,7 +341,9 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || retained.retain_block(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
@@ -345,16 +357,33 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || retained.retain_bloom(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
             }
 
-            purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
+            purge_files.extend(
+                chunk
+                    .iter()
+                    .filter(|loc| !retained.retain_segment(&loc.0))
+                    .map(|loc| loc.0.clone()),
+            );
         }
-        purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
-        purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.extend(
+            ts_to_be_purged
+                .iter()
+                .filter(|loc| !retained.retain_table_statistics(loc))
+                .map(|loc| loc.to_string()),
+        );
+        purge_files.extend(
+            snapshots_to_be_purged
+                .iter()
+                .filter(|loc| !retained.retain_snapshot(loc))
+                .map(|loc| loc.to_string()),
+        );
 
         Ok(())
     }
@@ -365,6 +394,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        retained: &RetainedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -383,7 +413,9 @@ impl FuseTable {
             let mut blocks_to_be_purged = HashSet::new();
             let mut agg_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || retained.retain_block(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
@@ -398,7 +430,9 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || retained.retain_bloom(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
@@ -407,6 +441,7 @@ impl FuseTable {
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
+                    .filter(|loc| !retained.retain_segment(&loc.0))
                     .map(|loc| loc.0.clone())
                     .collect::<Vec<String>>(),
             );
@@ -434,26 +469,44 @@ impl FuseTable {
             .await?;
         }
 
+        // The tagged snapshots are kept.
+        let ts_to_be_purged = ts_to_be_purged
+            .into_iter()
+            .filter(|loc| !retained.retain_table_statistics(loc))
+            .collect();
+        let snapshots_to_be_purged = snapshots_to_be_purged
+            .into_iter()
+            .filter(|loc| !retained.retain_snapshot(loc))
+            .collect();
         self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
             .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn purge_root_snapshot(
         &self,
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        retained: &RetainedLocations,
         table_agg_index_ids: &[u64],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
+                .filter(|loc| !retained.retain_segment(&loc.0))
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        root_location_tuple
+            .block_location
+            .retain(|loc| !retained.retain_block(loc));
+        root_location_tuple
+            .bloom_location
+            .retain(|loc| !retained.retain_bloom(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         for index_id in table_agg_index_ids {
@@ -476,15 +529,16 @@ impl FuseTable {
 
         let mut ts_to_be_purged = HashSet::new();
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
-            ts_to_be_purged.insert(ts);
+            if !retained.retain_table_statistics(&ts) {
+                ts_to_be_purged.insert(ts);
+            }
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        let mut snapshots_to_be_purged = HashSet::new();
+        if !retained.retain_snapshot(&root_snapshot_location) {
+            snapshots_to_be_purged.insert(root_snapshot_location);
+        }
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -15,6 +15,7 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -31,11 +32,14 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tag;
 mod truncate;
 mod update;
 mod util;
+mod vector_index;
 
 pub use agg_index_sink::AggIndexSink;
+pub use clone::RetainedLocations;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use delete::MutationBlockPruningContext;
@@ -45,6 +49,8 @@ pub use read::build_row_fetcher_pipeline;
 pub use read::can_merge_into_target_build_bloom_filter;
 pub use read::need_reserve_block_info;
 pub use replace_into::*;
+pub use tag::encode_snapshot_tags;
+pub use tag::SnapshotTag;
 pub use util::acquire_task_permit;
 pub use util::collect_incremental_blocks;
 pub use util::column_parquet_metas;
@@ -20,6 +20,7 @@ use std::ops::BitAnd;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow::array::Array;
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_arrow::native::read::ArrayIter;
 use databend_common_arrow::parquet::metadata::ColumnDescriptor;
@@ -30,6 +31,7 @@ use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SampleConfig;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::plan::VirtualColumnInfo;
 use databend_common_catalog::table_context::TableContext;
@@ -67,6 +69,7 @@ use xorf::BinaryFuse16;
 use super::native_data_source::NativeDataSource;
 use super::util::add_data_block_meta;
 use super::util::need_reserve_block_info;
+use super::util::row_sample_of_plan;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -240,6 +243,8 @@ pub struct NativeDeserializeDataTransform {
 
     // for merge_into target build.
     need_reserve_block_info: bool,
+    // `TABLESAMPLE ROW`, the rows are kept by their offsets in the block.
+    row_sample: Option<SampleConfig>,
 }
 
 impl NativeDeserializeDataTransform {
@@ -387,6 +392,7 @@ impl NativeDeserializeDataTransform {
                 bloom_runtime_filter: None,
                 read_state: ReadPartState::new(),
                 need_reserve_block_info,
+                row_sample: row_sample_of_plan(plan),
             },
         )))
     }
@@ -570,6 +576,9 @@ impl NativeDeserializeDataTransform {
         let mut data_block = self
             .block_reader
             .build_default_values_block(fuse_part.nums_rows)?;
+        if let Some(bitmap) = self.sample_rows(fuse_part, 0..fuse_part.nums_rows) {
+            data_block = data_block.filter_with_bitmap(&bitmap)?;
+        }
         if let Some(virtual_columns) = &self.virtual_columns {
             for virtual_column in virtual_columns {
                 // if the source column is default value, the virtual column is always Null.
@@ -594,6 +603,20 @@ impl NativeDeserializeDataTransform {
         data_block.resort(&self.src_schema, &self.output_schema)
     }
 
+    /// Sample the rows of the part by their offsets in the block if there is `TABLESAMPLE ROW`,
+    /// returns the bitmap of the selected rows.
+    fn sample_rows(
+        &self,
+        fuse_part: &FuseBlockPartInfo,
+        offsets: impl Iterator<Item = usize>,
+    ) -> Option<Bitmap> {
+        self.row_sample.as_ref().map(|sample| {
+            offsets
+                .map(|offset| sample.sample_row(&fuse_part.location, offset))
+                .collect()
+        })
+    }
+
     /// Initialize the read state for a new partition.
     fn new_read_state(&mut self) -> Result<()> {
         debug_assert!(self.read_state.is_finished());
@@ -982,7 +1005,9 @@ impl NativeDeserializeDataTransform {
         // `TransformAddInternalColumns` will generate internal columns using `InternalColumnMeta` in next pipeline.
         let mut block = block.resort(&self.src_schema, &self.output_schema)?;
         let fuse_part = FuseBlockPartInfo::from_part(&self.parts[0])?;
-        let offsets = if self.block_reader.query_internal_columns() {
+        let need_offsets =
+            self.block_reader.query_internal_columns() || self.block_reader.update_stream_columns();
+        let mut offsets = if need_offsets || self.row_sample.is_some() {
             let offset = self.read_state.offset;
             let offsets = if let Some(count) = self.read_state.filtered_count {
                 let filter_executor = self.filter_executor.as_mut().unwrap();
@@ -997,6 +1022,22 @@ impl NativeDeserializeDataTransform {
         } else {
             None
         };
+        if let Some(bitmap) = offsets
+            .as_ref()
+            .and_then(|offsets| self.sample_rows(fuse_part, offsets.iter().copied()))
+        {
+            block = block.filter_with_bitmap(&bitmap)?;
+            offsets = offsets.map(|offsets| {
+                offsets
+                    .into_iter()
+                    .zip(bitmap.iter())
+                    .filter_map(|(offset, keep)| keep.then_some(offset))
+                    .collect()
+            });
+        }
+        if !need_offsets {
+            offsets = None;
+        }
         block = add_data_block_meta(
             block,
             fuse_part,
@@ -1090,7 +1131,11 @@ impl Processor for NativeDeserializeDataTransform {
                     // This means it's an empty projection
                     let part = self.parts.front().unwrap();
                     let fuse_part = FuseBlockPartInfo::from_part(part)?;
-                    let mut data_block = DataBlock::new(vec![], fuse_part.nums_rows);
+                    let num_rows = match self.sample_rows(fuse_part, 0..fuse_part.nums_rows) {
+                        Some(bitmap) => bitmap.len() - bitmap.unset_bits(),
+                        None => fuse_part.nums_rows,
+                    };
+                    let mut data_block = DataBlock::new(vec![], num_rows);
                     data_block = add_data_block_meta(
                         data_block,
                         fuse_part,
@@ -25,6 +25,7 @@ use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::SampleConfig;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -46,6 +47,7 @@ use xorf::BinaryFuse16;
 use super::parquet_data_source::ParquetDataSource;
 use super::util::add_data_block_meta;
 use super::util::need_reserve_block_info;
+use super::util::row_sample_of_plan;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
@@ -76,6 +78,7 @@ pub struct DeserializeDataTransform {
     cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
     // for merge_into target build.
     need_reserve_block_info: bool,
+    row_sample: Option<SampleConfig>,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
@@ -128,6 +131,7 @@ impl DeserializeDataTransform {
             base_block_ids: plan.base_block_ids.clone(),
             cached_runtime_filter: None,
             need_reserve_block_info,
+            row_sample: row_sample_of_plan(plan),
         })))
     }
 
@@ -276,10 +280,20 @@ impl Processor for DeserializeDataTransform {
 
                     let mut filter = None;
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
-                        if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
-                        }
+                        filter = self.runtime_filter(data_block.clone())?;
+                    }
+                    // `TABLESAMPLE ROW` keeps the rows by their offsets in the block.
+                    if let Some(sample) = &self.row_sample {
+                        let bitmap: Bitmap = (0..origin_num_rows)
+                            .map(|offset| sample.sample_row(&part.location, offset))
+                            .collect();
+                        filter = Some(match filter {
+                            Some(filter) => (&filter) & (&bitmap),
+                            None => bitmap,
+                        });
+                    }
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
                     }
 
                     // Add optional virtual columns
@@ -313,7 +327,9 @@ impl Processor for DeserializeDataTransform {
 
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `TransformAddInternalColumns` will generate internal columns using `BlockMetaIndex` in next pipeline.
-                    let offsets = if self.block_reader.query_internal_columns() {
+                    let offsets = if self.block_reader.query_internal_columns()
+                        || self.block_reader.update_stream_columns()
+                    {
                         filter.as_ref().map(|bitmap| {
                             (0..origin_num_rows)
                                 .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
@@ -18,7 +18,10 @@ use std::sync::Arc;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::merge_into_join::MergeIntoJoinType;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
+use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::InternalColumnMeta;
+use databend_common_catalog::plan::SampleConfig;
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -43,6 +46,15 @@ pub struct MergeIntoSourceBuildBloomInfo {
     pub target_table_index: usize,
 }
 
+/// The `TABLESAMPLE ROW` of the scan, the rows are sampled by their offsets in the blocks
+/// when the blocks are deserialized.
+pub(crate) fn row_sample_of_plan(plan: &DataSourcePlan) -> Option<SampleConfig> {
+    plan.push_downs
+        .as_ref()
+        .and_then(|push_downs| push_downs.sample.clone())
+        .filter(|sample| sample.level == SampleLevel::Row)
+}
+
 pub fn need_reserve_block_info(ctx: Arc<dyn TableContext>, table_idx: usize) -> (bool, bool) {
     let merge_into_join = ctx.get_merge_into_join();
     (
@@ -97,7 +109,9 @@ pub(crate) fn add_data_block_meta(
 
     if update_stream_columns {
         // Fill `BlockMetaInfoPtr` if update stream columns
-        let stream_meta = gen_mutation_stream_meta(meta, &fuse_part.location)?;
+        let mut stream_meta = gen_mutation_stream_meta(meta, &fuse_part.location)?;
+        // The block may be read in several pages or filtered, keep the origin row numbers.
+        stream_meta.offsets = offsets.clone();
         meta = Some(Box::new(stream_meta));
     }
 
@@ -418,9 +418,9 @@ impl FuseTable {
     }
 
     fn is_exact(push_downs: &Option<PushDownInfo>) -> bool {
-        push_downs
-            .as_ref()
-            .map_or(true, |extra| extra.filters.is_none())
+        push_downs.as_ref().map_or(true, |extra| {
+            extra.filters.is_none() && extra.sample.is_none()
+        })
     }
 
     fn all_columns_partitions(
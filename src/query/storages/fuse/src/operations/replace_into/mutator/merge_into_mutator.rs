@@ -102,6 +102,8 @@ struct AggregationContext {
     query_id: String,
     // generate stream columns if necessary
     stream_ctx: Option<StreamContext>,
+    // reject the rows that conflict with the existing rows, instead of replacing them
+    error_on_conflict: bool,
 }
 
 // Apply MergeIntoOperations to segments
@@ -123,6 +125,7 @@ impl MergeIntoOperationAggregator {
         read_settings: ReadSettings,
         block_builder: BlockBuilder,
         io_request_semaphore: Arc<Semaphore>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let data_accessor = table.get_operator();
         let table_schema = table.schema_with_stream();
@@ -214,6 +217,7 @@ impl MergeIntoOperationAggregator {
                 io_request_semaphore,
                 query_id,
                 stream_ctx,
+                error_on_conflict,
             }),
         })
     }
@@ -459,6 +463,18 @@ impl AggregationContext {
         let delete_nums = bitmap.unset_bits();
         info!("number of row deleted: {}", delete_nums);
 
+        if self.error_on_conflict && delete_nums > 0 {
+            let key_columns = on_conflict_fields
+                .iter()
+                .map(|field| field.table_field.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ErrorCode::UniqueKeyViolated(format!(
+                "duplicated key detected, {} row(s) conflict with the existing rows on ({})",
+                delete_nums, key_columns
+            )));
+        }
+
         // shortcut: nothing to be deleted
         if delete_nums == 0 {
             info!("nothing deleted");
@@ -59,6 +59,9 @@ pub struct ReplaceIntoMutator {
     table_range_index: HashMap<ColumnId, ColumnStatistics>,
     key_saw: HashSet<UniqueKeyDigest>,
     partitioner: Option<Partitioner>,
+    // the keys of all the input rows, only tracked if duplicated keys should be rejected,
+    // i.e. writing into a table with an enforced PRIMARY KEY or UNIQUE constraint.
+    input_key_saw: Option<HashSet<UniqueKeyDigest>>,
 }
 
 impl ReplaceIntoMutator {
@@ -69,6 +72,7 @@ impl ReplaceIntoMutator {
         bloom_filter_column_indexes: Vec<FieldIndex>,
         table_schema: &TableSchema,
         table_range_idx: HashMap<ColumnId, ColumnStatistics>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let partitioner = if !cluster_keys.is_empty()
             && ctx.get_settings().get_enable_replace_into_partitioning()?
@@ -88,6 +92,7 @@ impl ReplaceIntoMutator {
             table_range_index: table_range_idx,
             key_saw: Default::default(),
             partitioner,
+            input_key_saw: error_on_conflict.then(HashSet::new),
         })
     }
 }
@@ -101,6 +106,23 @@ enum ColumnHash {
 
 impl ReplaceIntoMutator {
     pub fn process_input_block(&mut self, data_block: &DataBlock) -> Result<MergeIntoOperation> {
+        // duplicated keys of the input rows are rejected before any pruning
+        if let Some(input_key_saw) = &mut self.input_key_saw {
+            let column_values = on_conflict_key_column_values(&self.on_conflict_fields, data_block);
+            if let ColumnHash::Conflict(conflict_row_idx) =
+                Self::build_column_hash(&column_values, input_key_saw, data_block.num_rows())?
+            {
+                return Err(ErrorCode::UniqueKeyViolated(format!(
+                    "duplicated key detected in the values being inserted (only the first one will be described): {}",
+                    Self::describe_conflict(
+                        &self.on_conflict_fields,
+                        &column_values,
+                        conflict_row_idx
+                    )
+                )));
+            }
+        }
+
         // pruning rows by using table level range index
         // rows that definitely have no conflict will be removed
         metrics_inc_replace_original_row_number(data_block.num_rows() as u64);
@@ -186,23 +208,11 @@ impl ReplaceIntoMutator {
                 Ok(MergeIntoOperation::Delete(vec![delete_action]))
             }
             ColumnHash::Conflict(conflict_row_idx) => {
-                let conflict_description = {
-                    let conflicts = column_values
-                        .iter()
-                        .zip(self.on_conflict_fields.iter())
-                        .map(|(col, field)| {
-                            let col_name = &field.table_field.name;
-                            // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
-                            let row_value = col.index(conflict_row_idx).unwrap();
-                            let row_value_message =
-                                Self::extract_col_value_for_err_message(row_value);
-                            format!("\"{}\":{}", col_name, row_value_message)
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-
-                    format!("at row {}, [{}]", conflict_row_idx, conflicts)
-                };
+                let conflict_description = Self::describe_conflict(
+                    &self.on_conflict_fields,
+                    &column_values,
+                    conflict_row_idx,
+                );
                 Err(ErrorCode::StorageOther(format!(
                     "duplicated data detected in the values being replaced into (only the first one will be described): {}",
                     conflict_description
@@ -211,6 +221,27 @@ impl ReplaceIntoMutator {
         }
     }
 
+    fn describe_conflict(
+        on_conflict_fields: &[OnConflictField],
+        column_values: &[&Value<AnyType>],
+        conflict_row_idx: usize,
+    ) -> String {
+        let conflicts = column_values
+            .iter()
+            .zip(on_conflict_fields.iter())
+            .map(|(col, field)| {
+                let col_name = &field.table_field.name;
+                // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
+                let row_value = col.index(conflict_row_idx).unwrap();
+                let row_value_message = Self::extract_col_value_for_err_message(row_value);
+                format!("\"{}\":{}", col_name, row_value_message)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!("at row {}, [{}]", conflict_row_idx, conflicts)
+    }
+
     fn build_column_hash(
         column_values: &[&Value<AnyType>],
         saw: &mut HashSet<UniqueKeyDigest>,
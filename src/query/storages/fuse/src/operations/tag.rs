@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_TAGS;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+/// A named snapshot of a fuse table, created by `ALTER TABLE .. CREATE TAG`.
+///
+/// The tagged snapshot, and the files it references, are kept by purge and vacuum
+/// until the tag is dropped.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotTag {
+    pub snapshot_location: String,
+    pub created_on: DateTime<Utc>,
+}
+
+impl FuseTable {
+    /// The tags of this table, ordered by name.
+    pub fn snapshot_tags(&self) -> Result<BTreeMap<String, SnapshotTag>> {
+        match self.table_info.options().get(OPT_KEY_SNAPSHOT_TAGS) {
+            Some(tags) => serde_json::from_str(tags).map_err(|e| {
+                ErrorCode::Internal(format!(
+                    "Invalid table option {} of table {}: {}",
+                    OPT_KEY_SNAPSHOT_TAGS, self.table_info.desc, e
+                ))
+            }),
+            None => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// Creates a tag of the snapshot at `point`, or of the current snapshot if no point is given.
+    #[async_backtrace::framed]
+    pub async fn new_snapshot_tag(&self, point: Option<&NavigationPoint>) -> Result<SnapshotTag> {
+        let snapshot_location = match point {
+            Some(point) => self.navigate_to(point).await?.snapshot_loc().await?,
+            None => self.snapshot_loc().await?,
+        };
+        let snapshot_location = snapshot_location.ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound("Empty table has no snapshot to tag")
+        })?;
+
+        Ok(SnapshotTag {
+            snapshot_location,
+            created_on: Utc::now(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_tag(&self, tag: &str) -> Result<Arc<FuseTable>> {
+        let tags = self.snapshot_tags()?;
+        let snapshot_tag = tags.get(tag).ok_or_else(|| {
+            ErrorCode::UnknownSnapshotTag(format!(
+                "Unknown tag '{}' of table {}",
+                tag, self.table_info.desc
+            ))
+        })?;
+
+        // The tagged snapshot may no longer be in the history of the current snapshot
+        // (e.g. after the table has been reverted), load it directly.
+        let ver = TableMetaLocationGenerator::snapshot_version(&snapshot_tag.snapshot_location);
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let params = LoadParams {
+            location: snapshot_tag.snapshot_location.clone(),
+            len_hint: None,
+            ver,
+            put_cache: true,
+        };
+        let snapshot = reader.read(&params).await?;
+        self.load_table_at_snapshot(snapshot.as_ref(), ver)
+    }
+}
+
+/// Encodes the tags as the value of the table option [`OPT_KEY_SNAPSHOT_TAGS`],
+/// `None` means the option should be removed.
+pub fn encode_snapshot_tags(tags: &BTreeMap<String, SnapshotTag>) -> Result<Option<String>> {
+    if tags.is_empty() {
+        return Ok(None);
+    }
+    serde_json::to_string(tags)
+        .map(Some)
+        .map_err(|e| ErrorCode::Internal(format!("Fail to encode snapshot tags: {}", e)))
+}
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableIndex;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_index::VectorIndexOptions;
+use log::info;
+
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the HNSW index of the vector column for the blocks that are not indexed yet.
+    ///
+    /// Each block has its own index file, the location is derived from the block
+    /// location and the version of the index, so the snapshot is not changed.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_vector_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index: &TableIndex,
+    ) -> Result<()> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            // no snapshot
+            return Ok(());
+        };
+
+        let table_schema = &self.get_table_info().meta.schema;
+        let Some(column_id) = index.column_ids.first() else {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Vector index {} is invalid",
+                index.name
+            )));
+        };
+        let field = table_schema.field_of_column_id(*column_id)?;
+        let field_index = table_schema.index_of(field.name())?;
+        let options = VectorIndexOptions::try_create(&index.options)?;
+
+        let projection = Projection::Columns(vec![field_index]);
+        let block_reader =
+            self.create_block_reader(ctx.clone(), projection, false, false, false)?;
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+        let operator = self.get_operator_ref();
+
+        let mut indexed_blocks = 0;
+        for (segment_loc, ver) in snapshot.segments.iter() {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: segment_loc.to_string(),
+                    len_hint: None,
+                    ver: *ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                let index_location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &block_meta.location.0,
+                        &index.version,
+                    );
+                // The block has been indexed.
+                if operator.is_exist(&index_location).await? {
+                    continue;
+                }
+
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let entry = block.get_by_offset(0);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+
+                // Blocks with all null vectors are not indexed and will always be read.
+                if let Some(vector_index) = VectorIndex::try_create(&column, &options)? {
+                    write_data(vector_index.to_bytes()?, operator, &index_location).await?;
+                    indexed_blocks += 1;
+                }
+            }
+        }
+
+        info!(
+            "refresh vector index {} of table {}, {} blocks indexed",
+            index.name,
+            self.get_table_info().name,
+            indexed_blocks
+        );
+        Ok(())
+    }
+}
@@ -18,6 +18,7 @@ use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -52,6 +53,7 @@ use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -272,7 +274,7 @@ impl FusePruner {
     }
 
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> sample pruner -> vector index pruner -> topn pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -397,6 +399,9 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
+                    let metas = self
+                        .vector_index_pruning(self.sample_pruning(metas))
+                        .await?;
                     self.topn_pruning(metas)
                 }
             }
@@ -460,11 +465,50 @@ impl FusePruner {
                 // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                 // will get here, we can prevent other mutations like update and so on.
                 // TopN pruner.
+                let metas = self
+                    .vector_index_pruning(self.sample_pruning(metas))
+                    .await?;
                 self.topn_pruning(metas)
             }
         }
     }
 
+    // sample pruner:
+    // if there is `TABLESAMPLE BLOCK`, keep the blocks by the probability of the sample
+    fn sample_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        match self.push_down.as_ref().and_then(|p| p.sample.as_ref()) {
+            Some(sample) if sample.level == SampleLevel::Block => metas
+                .into_iter()
+                .filter(|(_, meta)| sample.sample_part(&meta.location.0))
+                .collect(),
+            _ => metas,
+        }
+    }
+
+    // vector index pruner:
+    // if the nearest rows are searched by the vector index, keep the blocks which contain them
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        match self
+            .push_down
+            .as_ref()
+            .and_then(|p| p.vector_index.as_ref())
+        {
+            Some(vector_index) => {
+                let vector_index_pruner =
+                    VectorIndexPruner::create(self.pruning_ctx.clone(), vector_index.clone());
+                vector_index_pruner.pruning(metas).await
+            }
+            None => Ok(metas),
+        }
+    }
+
     // topn pruner:
     // if there are ordering + limit clause and no filters, use topn pruner
     fn topn_pruning(
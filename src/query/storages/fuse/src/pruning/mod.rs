@@ -19,6 +19,7 @@ mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
+mod vector_index_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
@@ -30,3 +31,4 @@ pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
 pub use segment_pruner::SegmentPruner;
+pub use vector_index_pruner::VectorIndexPruner;
@@ -0,0 +1,117 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::F32;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::future;
+
+use crate::io::read::load_vector_index;
+use crate::io::TableMetaLocationGenerator;
+use crate::pruning::PruningContext;
+
+/// Prunes the blocks by the vector index of each block.
+///
+/// The `k` nearest rows of each indexed block are searched, only the blocks
+/// which contain one of the global `k` nearest rows are kept. Blocks that
+/// have not been indexed yet are always kept, the rows are ordered and
+/// limited after reading, so the result does not depend on the pruning.
+pub struct VectorIndexPruner {
+    pruning_ctx: Arc<PruningContext>,
+    vector_index: VectorIndexInfo,
+}
+
+impl VectorIndexPruner {
+    pub fn create(pruning_ctx: Arc<PruningContext>, vector_index: VectorIndexInfo) -> Self {
+        VectorIndexPruner {
+            pruning_ctx,
+            vector_index,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let limit = self.vector_index.limit;
+        let query = Arc::new(
+            self.vector_index
+                .query
+                .iter()
+                .map(|v| v.0)
+                .collect::<Vec<_>>(),
+        );
+
+        let pruning_tasks = metas.iter().enumerate().map(|(idx, (_, block_meta))| {
+            let dal = self.pruning_ctx.dal.clone();
+            let query = query.clone();
+            let location =
+                TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                    &block_meta.location.0,
+                    &self.vector_index.index_version,
+                );
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                let distances = match load_vector_index(dal, &location).await? {
+                    Some(index) => Some(index.search(&query, limit)?),
+                    None => None,
+                };
+                Result::<_, ErrorCode>::Ok((idx, distances))
+            }
+        });
+
+        let join_handlers = self
+            .pruning_ctx
+            .pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(
+                self.pruning_ctx.pruning_semaphore.clone(),
+                pruning_tasks,
+            )
+            .await?;
+
+        let joint = future::try_join_all(join_handlers)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("vector index pruning failure, {}", e)))?;
+
+        let mut keep = vec![false; metas.len()];
+        let mut candidates: Vec<(F32, usize)> = Vec::new();
+        for item in joint {
+            let (idx, distances) = item?;
+            match distances {
+                Some(distances) => {
+                    candidates.extend(distances.into_iter().map(|(_, distance)| (distance, idx)))
+                }
+                // The block has not been indexed.
+                None => keep[idx] = true,
+            }
+        }
+        candidates.sort();
+        for (_, idx) in candidates.into_iter().take(limit) {
+            keep[idx] = true;
+        }
+
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(meta, keep)| keep.then_some(meta))
+            .collect())
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_cache::LoadParams;
+
+use crate::io::MetaReaders;
+use crate::io::TableMetaLocationGenerator;
+use crate::sessions::TableContext;
+use crate::FuseTable;
+
+pub struct FuseTag<'a> {
+    pub ctx: Arc<dyn TableContext>,
+    pub table: &'a FuseTable,
+}
+
+impl<'a> FuseTag<'a> {
+    pub fn new(ctx: Arc<dyn TableContext>, table: &'a FuseTable) -> Self {
+        Self { ctx, table }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn get_tags(self) -> Result<DataBlock> {
+        let tags = self.table.snapshot_tags()?;
+        let len = tags.len();
+        let mut names: Vec<String> = Vec::with_capacity(len);
+        let mut snapshot_ids: Vec<Option<String>> = Vec::with_capacity(len);
+        let mut snapshot_locations: Vec<String> = Vec::with_capacity(len);
+        let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut created_on: Vec<i64> = Vec::with_capacity(len);
+
+        let reader = MetaReaders::table_snapshot_reader(self.table.get_operator());
+        for (name, tag) in tags {
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(&tag.snapshot_location),
+                location: tag.snapshot_location.clone(),
+                len_hint: None,
+                put_cache: false,
+            };
+            // The tagged snapshot is kept by gc, but list the tag anyway if it is lost.
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => None,
+                Err(e) => return Err(e),
+                Ok(v) => Some(v),
+            };
+
+            names.push(name);
+            snapshot_ids.push(
+                snapshot
+                    .as_ref()
+                    .map(|s| s.snapshot_id.simple().to_string()),
+            );
+            snapshot_locations.push(tag.snapshot_location);
+            timestamps.push(
+                snapshot
+                    .as_ref()
+                    .and_then(|s| s.timestamp)
+                    .map(|dt| dt.timestamp_micros()),
+            );
+            created_on.push(tag.created_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_opt_data(snapshot_ids),
+            StringType::from_data(snapshot_locations),
+            TimestampType::from_opt_data(timestamps),
+            TimestampType::from_data(created_on),
+        ]))
+    }
+
+    pub fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("snapshot_id", TableDataType::String.wrap_nullable()),
+            TableField::new("snapshot_location", TableDataType::String),
+            TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ])
+    }
+}
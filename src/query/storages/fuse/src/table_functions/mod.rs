@@ -19,6 +19,7 @@ mod fuse_encodings;
 mod fuse_segments;
 mod fuse_snapshots;
 mod fuse_statistics;
+mod fuse_tags;
 mod table_args;
 
 pub use clustering_information::ClusteringInformation;
@@ -36,4 +37,5 @@ pub use fuse_segments::FuseSegmentTable;
 pub use fuse_snapshots::FuseSnapshot;
 pub use fuse_snapshots::FuseSnapshotTable;
 pub use fuse_statistics::FuseStatisticTable;
+pub use fuse_tags::FuseTagTable;
 pub use table_args::*;
@@ -71,6 +71,8 @@ use databend_common_meta_app::schema::RenameTableReply;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -95,6 +97,8 @@ use futures::TryStreamExt;
 use opendal::Metakey;
 
 use crate::database::IcebergDatabase;
+use crate::database::IcebergRestDatabase;
+use crate::rest::IcebergRestClient;
 use crate::IcebergTable;
 
 pub const ICEBERG_CATALOG: &str = "iceberg";
@@ -111,9 +115,20 @@ impl CatalogCreator for IcebergCreator {
             ),
         };
 
-        let data_operator = DataOperator::try_new(&opt.storage_params)?;
-        let catalog: Arc<dyn Catalog> =
-            Arc::new(IcebergCatalog::try_create(info.clone(), data_operator)?);
+        let catalog: Arc<dyn Catalog> = match &opt.rest {
+            Some(rest) => {
+                let client =
+                    IcebergRestClient::try_create(rest.clone(), *opt.storage_params.clone())?;
+                Arc::new(IcebergCatalog::try_create_with_rest(
+                    info.clone(),
+                    Arc::new(client),
+                )?)
+            }
+            None => {
+                let data_operator = DataOperator::try_new(&opt.storage_params)?;
+                Arc::new(IcebergCatalog::try_create(info.clone(), data_operator)?)
+            }
+        };
 
         Ok(catalog)
     }
@@ -125,13 +140,24 @@ impl CatalogCreator for IcebergCreator {
 /// - Instances of `Database` are created from reading subdirectories of
 ///    Iceberg table
 /// - Table metadata are saved in external Iceberg storage
+///
+/// For catalogs created with `TYPE='rest'`, databases and tables are
+/// listed and loaded from an Iceberg REST catalog service instead.
 #[derive(Clone, Debug)]
 pub struct IcebergCatalog {
     /// info of this iceberg table.
     info: CatalogInfo,
 
+    /// where databases and tables are discovered
+    backend: IcebergCatalogBackend,
+}
+
+#[derive(Clone, Debug)]
+enum IcebergCatalogBackend {
     /// underlying storage access operator
-    operator: DataOperator,
+    Storage(DataOperator),
+    /// client of the REST catalog service
+    Rest(Arc<IcebergRestClient>),
 }
 
 impl IcebergCatalog {
@@ -151,14 +177,41 @@ impl IcebergCatalog {
     /// a `default` database will be generated directly
     #[minitrace::trace]
     pub fn try_create(info: CatalogInfo, operator: DataOperator) -> Result<Self> {
-        Ok(Self { info, operator })
+        Ok(Self {
+            info,
+            backend: IcebergCatalogBackend::Storage(operator),
+        })
+    }
+
+    /// create a new iceberg catalog served by an Iceberg REST catalog
+    #[minitrace::trace]
+    pub fn try_create_with_rest(info: CatalogInfo, client: Arc<IcebergRestClient>) -> Result<Self> {
+        Ok(Self {
+            info,
+            backend: IcebergCatalogBackend::Rest(client),
+        })
     }
 
     /// list read databases
     #[minitrace::trace]
     #[async_backtrace::framed]
     pub async fn list_database_from_read(&self) -> Result<Vec<Arc<dyn Database>>> {
-        let op = self.operator.operator();
+        let operator = match &self.backend {
+            IcebergCatalogBackend::Storage(operator) => operator,
+            IcebergCatalogBackend::Rest(client) => {
+                let mut dbs: Vec<Arc<dyn Database>> = vec![];
+                for db_name in client.list_namespaces().await? {
+                    dbs.push(Arc::new(IcebergRestDatabase::create(
+                        &self.name(),
+                        &db_name,
+                        client.clone(),
+                    )));
+                }
+                return Ok(dbs);
+            }
+        };
+
+        let op = operator.operator();
         let mut dbs = vec![];
         let mut ls = op.lister_with("/").metakey(Metakey::Mode).await?;
         while let Some(dir) = ls.try_next().await? {
@@ -191,9 +244,25 @@ impl Catalog for IcebergCatalog {
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn get_database(&self, _tenant: &str, db_name: &str) -> Result<Arc<dyn Database>> {
+        let data_operator = match &self.backend {
+            IcebergCatalogBackend::Storage(operator) => operator,
+            IcebergCatalogBackend::Rest(client) => {
+                if !client.namespace_exists(db_name).await? {
+                    return Err(ErrorCode::UnknownDatabase(format!(
+                        "Database {db_name} does not exist"
+                    )));
+                }
+                return Ok(Arc::new(IcebergRestDatabase::create(
+                    &self.name(),
+                    db_name,
+                    client.clone(),
+                )));
+            }
+        };
+
         let rel_path = format!("{db_name}/");
 
-        let operator = self.operator.operator();
+        let operator = data_operator.operator();
         if !operator.is_exist(&rel_path).await? {
             return Err(ErrorCode::UnknownDatabase(format!(
                 "Database {db_name} does not exist"
@@ -201,8 +270,7 @@ impl Catalog for IcebergCatalog {
         }
 
         // storage params for database
-        let db_sp = self
-            .operator
+        let db_sp = data_operator
             .params()
             .map_root(|root| format!("{root}{rel_path}"));
         let db_root = DataOperator::try_create(&db_sp).await?;
@@ -371,6 +439,14 @@ impl Catalog for IcebergCatalog {
         unimplemented!()
     }
 
+    #[async_backtrace::framed]
+    async fn set_table_row_access_policy(
+        &self,
+        _req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply> {
+        unimplemented!()
+    }
+
     #[async_backtrace::framed]
     async fn create_table_index(&self, _req: CreateTableIndexReq) -> Result<CreateTableIndexReply> {
         unimplemented!()
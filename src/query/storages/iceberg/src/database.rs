@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Wrapping of the parent directory containing iceberg tables
+//! Wrapping of the parent directory containing iceberg tables,
+//! or of a namespace in an Iceberg REST catalog
 
 use std::sync::Arc;
 
@@ -30,8 +31,25 @@ use futures::StreamExt;
 use opendal::EntryMode;
 use opendal::Metakey;
 
+use crate::rest::IcebergRestClient;
 use crate::table::IcebergTable;
 
+fn new_database_info(db_name: &str) -> DatabaseInfo {
+    DatabaseInfo {
+        ident: DatabaseIdent { db_id: 0, seq: 0 },
+        name_ident: DatabaseNameIdent {
+            db_name: db_name.to_string(),
+            ..Default::default()
+        },
+        meta: DatabaseMeta {
+            engine: "iceberg".to_string(),
+            created_on: chrono::Utc::now(),
+            updated_on: chrono::Utc::now(),
+            ..Default::default()
+        },
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct IcebergDatabase {
     /// catalog this database belongs to
@@ -45,23 +63,10 @@ pub struct IcebergDatabase {
 impl IcebergDatabase {
     /// create a new database, but from reading
     pub fn create(ctl_name: &str, db_name: &str, db_root: DataOperator) -> Self {
-        let info = DatabaseInfo {
-            ident: DatabaseIdent { db_id: 0, seq: 0 },
-            name_ident: DatabaseNameIdent {
-                db_name: db_name.to_string(),
-                ..Default::default()
-            },
-            meta: DatabaseMeta {
-                engine: "iceberg".to_string(),
-                created_on: chrono::Utc::now(),
-                updated_on: chrono::Utc::now(),
-                ..Default::default()
-            },
-        };
         Self {
             ctl_name: ctl_name.to_string(),
             db_root,
-            info,
+            info: new_database_info(db_name),
         }
     }
 }
@@ -96,6 +101,7 @@ impl Database for IcebergDatabase {
             &self.info.name_ident.db_name,
             table_name,
             tbl_root,
+            None,
         )
         .await?;
         let tbl = Arc::new(tbl) as Arc<dyn Table>;
@@ -120,3 +126,64 @@ impl Database for IcebergDatabase {
         Ok(tables)
     }
 }
+
+/// A namespace of an Iceberg REST catalog
+#[derive(Clone, Debug)]
+pub struct IcebergRestDatabase {
+    /// catalog this database belongs to
+    ctl_name: String,
+    /// client of the REST catalog serving this namespace
+    client: Arc<IcebergRestClient>,
+    /// database information
+    info: DatabaseInfo,
+}
+
+impl IcebergRestDatabase {
+    pub fn create(ctl_name: &str, db_name: &str, client: Arc<IcebergRestClient>) -> Self {
+        Self {
+            ctl_name: ctl_name.to_string(),
+            client,
+            info: new_database_info(db_name),
+        }
+    }
+}
+
+#[async_trait]
+impl Database for IcebergRestDatabase {
+    fn name(&self) -> &str {
+        &self.info.name_ident.db_name
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_table(&self, table_name: &str) -> Result<Arc<dyn Table>> {
+        let db_name = &self.info.name_ident.db_name;
+        let (table_sp, metadata_path) = self.client.load_table(db_name, table_name).await?;
+        let tbl_root = DataOperator::try_create(&table_sp).await?;
+
+        let tbl = IcebergTable::try_create_from_iceberg_catalog(
+            &self.ctl_name,
+            db_name,
+            table_name,
+            tbl_root,
+            Some(metadata_path),
+        )
+        .await?;
+        let tbl = Arc::new(tbl) as Arc<dyn Table>;
+
+        Ok(tbl)
+    }
+
+    #[async_backtrace::framed]
+    async fn list_tables(&self) -> Result<Vec<Arc<dyn Table>>> {
+        let mut tables = vec![];
+        for tbl_name in self.client.list_tables(self.name()).await? {
+            let table = self.get_table(&tbl_name).await?;
+            tables.push(table);
+        }
+        Ok(tables)
+    }
+}
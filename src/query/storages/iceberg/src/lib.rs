@@ -86,6 +86,21 @@
 //! ```sql
 //! SELECT * FROM icb_ctl.default.icbg_tbl_0;
 //! ```
+//!
+//! ## REST Catalogs
+//!
+//! Tables exposed by an Iceberg REST catalog service are accessed with `TYPE='rest'`,
+//! namespaces of the service are listed as databases:
+//! ```sql
+//! CREATE CATALOG icb_ctl TYPE=ICEBERG CONNECTION=(
+//! TYPE='rest'
+//! URI='http://127.0.0.1:8181'
+//! WAREHOUSE='wh'  -- optional
+//! TOKEN='...'     -- optional, sent as bearer token
+//! URL='s3://bkt/' -- optional, storage connection to access table files
+//! ... -- credentials of the storage
+//! )
+//! ```
 
 #![feature(lazy_cell)]
 #![feature(impl_trait_in_assoc_type)]
@@ -93,12 +108,20 @@
 
 mod catalog;
 mod database;
+mod manifest;
+mod mutation;
 mod partition;
+mod rest;
 mod stats;
 mod table;
 mod table_source;
+mod transaction;
+mod writer;
 
 pub use catalog::IcebergCatalog;
 pub use catalog::IcebergCreator;
 pub use catalog::ICEBERG_CATALOG;
+pub use rest::resolve_table_location;
+pub use rest::IcebergRestClient;
 pub use table::IcebergTable;
+pub use table::ICEBERG_ENGINE;
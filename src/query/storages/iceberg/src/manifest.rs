@@ -0,0 +1,553 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Avro manifests and manifest lists of [Iceberg format v2](https://iceberg.apache.org/spec/#manifests).
+//!
+//! Files are read by field name, so manifests written by other engines (and v1 manifests
+//! of upgraded tables) can be carried over into the snapshots we commit.
+
+use std::sync::LazyLock;
+
+use apache_avro::types::Value;
+use apache_avro::Codec;
+use apache_avro::Reader;
+use apache_avro::Schema;
+use apache_avro::Writer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// `content` of a data file which holds rows, the others hold position or equality deletes.
+pub const DATA_FILE_CONTENT: i32 = 0;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct DataFile {
+    pub content: i32,
+    pub file_path: String,
+    pub file_format: String,
+    pub record_count: i64,
+    pub file_size_in_bytes: i64,
+    pub column_sizes: Option<Vec<(i32, i64)>>,
+    pub value_counts: Option<Vec<(i32, i64)>>,
+    pub null_value_counts: Option<Vec<(i32, i64)>>,
+    pub nan_value_counts: Option<Vec<(i32, i64)>>,
+    pub lower_bounds: Option<Vec<(i32, Vec<u8>)>>,
+    pub upper_bounds: Option<Vec<(i32, Vec<u8>)>>,
+    pub key_metadata: Option<Vec<u8>>,
+    pub split_offsets: Option<Vec<i64>>,
+    pub equality_ids: Option<Vec<i32>>,
+    pub sort_order_id: Option<i32>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManifestStatus {
+    Existing = 0,
+    Added = 1,
+    Deleted = 2,
+}
+
+/// An entry of a manifest, the inherited snapshot id and sequence numbers are resolved.
+#[derive(Clone, Debug)]
+pub struct ManifestEntry {
+    pub status: ManifestStatus,
+    pub snapshot_id: i64,
+    pub sequence_number: i64,
+    pub file_sequence_number: i64,
+    /// the data file has partition values, it can not be rewritten yet.
+    pub partitioned: bool,
+    pub data_file: DataFile,
+}
+
+/// An entry of a manifest list.
+#[derive(Clone, Debug)]
+pub struct ManifestFile {
+    pub manifest_path: String,
+    pub manifest_length: i64,
+    pub partition_spec_id: i32,
+    pub content: i32,
+    pub sequence_number: i64,
+    pub min_sequence_number: i64,
+    pub added_snapshot_id: i64,
+    pub added_files_count: i32,
+    pub existing_files_count: i32,
+    pub deleted_files_count: i32,
+    pub added_rows_count: i64,
+    pub existing_rows_count: i64,
+    pub deleted_rows_count: i64,
+}
+
+/// The key-value metadata written into the header of a manifest.
+pub struct ManifestMetadata {
+    pub schema: String,
+    pub schema_id: i64,
+    pub partition_spec_id: i32,
+}
+
+static MANIFEST_ENTRY_SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+    // maps with int keys are arrays of key-value records in avro.
+    let map_fields = [
+        ("column_sizes", 108, 117, 118, "long"),
+        ("value_counts", 109, 119, 120, "long"),
+        ("null_value_counts", 110, 121, 122, "long"),
+        ("nan_value_counts", 137, 138, 139, "long"),
+        ("lower_bounds", 125, 126, 127, "bytes"),
+        ("upper_bounds", 128, 129, 130, "bytes"),
+    ]
+    .iter()
+    .map(|(name, field_id, key_id, value_id, value_type)| {
+        format!(
+            r#"{{"name":"{name}","type":["null",{{"type":"array","items":{{"type":"record","name":"k{key_id}_v{value_id}","fields":[{{"name":"key","type":"int","field-id":{key_id}}},{{"name":"value","type":"{value_type}","field-id":{value_id}}}]}},"logicalType":"map"}}],"default":null,"field-id":{field_id}}}"#
+        )
+    })
+    .collect::<Vec<_>>()
+    .join(",");
+
+    let schema = format!(
+        r#"{{"type":"record","name":"manifest_entry","fields":[
+            {{"name":"status","type":"int","field-id":0}},
+            {{"name":"snapshot_id","type":["null","long"],"default":null,"field-id":1}},
+            {{"name":"sequence_number","type":["null","long"],"default":null,"field-id":3}},
+            {{"name":"file_sequence_number","type":["null","long"],"default":null,"field-id":4}},
+            {{"name":"data_file","type":{{"type":"record","name":"r2","fields":[
+                {{"name":"content","type":"int","field-id":134}},
+                {{"name":"file_path","type":"string","field-id":100}},
+                {{"name":"file_format","type":"string","field-id":101}},
+                {{"name":"partition","type":{{"type":"record","name":"r102","fields":[]}},"field-id":102}},
+                {{"name":"record_count","type":"long","field-id":103}},
+                {{"name":"file_size_in_bytes","type":"long","field-id":104}},
+                {map_fields},
+                {{"name":"key_metadata","type":["null","bytes"],"default":null,"field-id":131}},
+                {{"name":"split_offsets","type":["null",{{"type":"array","items":"long","element-id":133}}],"default":null,"field-id":132}},
+                {{"name":"equality_ids","type":["null",{{"type":"array","items":"int","element-id":136}}],"default":null,"field-id":135}},
+                {{"name":"sort_order_id","type":["null","int"],"default":null,"field-id":140}}
+            ]}},"field-id":2}}
+        ]}}"#
+    );
+    Schema::parse_str(&schema).expect("manifest entry schema must be valid")
+});
+
+static MANIFEST_FILE_SCHEMA: LazyLock<Schema> = LazyLock::new(|| {
+    let schema = r#"{"type":"record","name":"manifest_file","fields":[
+        {"name":"manifest_path","type":"string","field-id":500},
+        {"name":"manifest_length","type":"long","field-id":501},
+        {"name":"partition_spec_id","type":"int","field-id":502},
+        {"name":"content","type":"int","field-id":517},
+        {"name":"sequence_number","type":"long","field-id":515},
+        {"name":"min_sequence_number","type":"long","field-id":516},
+        {"name":"added_snapshot_id","type":"long","field-id":503},
+        {"name":"added_files_count","type":"int","field-id":504},
+        {"name":"existing_files_count","type":"int","field-id":505},
+        {"name":"deleted_files_count","type":"int","field-id":506},
+        {"name":"added_rows_count","type":"long","field-id":512},
+        {"name":"existing_rows_count","type":"long","field-id":513},
+        {"name":"deleted_rows_count","type":"long","field-id":514},
+        {"name":"partitions","type":["null",{"type":"array","items":{"type":"record","name":"r508","fields":[
+            {"name":"contains_null","type":"boolean","field-id":509},
+            {"name":"contains_nan","type":["null","boolean"],"default":null,"field-id":518},
+            {"name":"lower_bound","type":["null","bytes"],"default":null,"field-id":510},
+            {"name":"upper_bound","type":["null","bytes"],"default":null,"field-id":511}
+        ]},"element-id":508}],"default":null,"field-id":507},
+        {"name":"key_metadata","type":["null","bytes"],"default":null,"field-id":519}
+    ]}"#;
+    Schema::parse_str(schema).expect("manifest file schema must be valid")
+});
+
+/// Read the entries of a manifest list.
+pub fn read_manifest_list(data: &[u8]) -> Result<Vec<ManifestFile>> {
+    let reader = Reader::new(data).map_err(avro_error)?;
+    let mut manifests = vec![];
+    for value in reader {
+        let value = value.map_err(avro_error)?;
+        manifests.push(ManifestFile {
+            manifest_path: required(get_string(&value, "manifest_path"), "manifest_path")?,
+            manifest_length: required(get_long(&value, "manifest_length"), "manifest_length")?,
+            partition_spec_id: get_int(&value, "partition_spec_id").unwrap_or(0),
+            content: get_int(&value, "content").unwrap_or(0),
+            sequence_number: get_long(&value, "sequence_number").unwrap_or(0),
+            min_sequence_number: get_long(&value, "min_sequence_number").unwrap_or(0),
+            added_snapshot_id: get_long(&value, "added_snapshot_id").unwrap_or(0),
+            // the counts are named `added_data_files_count` and so on in format v1.
+            added_files_count: get_int(&value, "added_files_count")
+                .or_else(|| get_int(&value, "added_data_files_count"))
+                .unwrap_or(0),
+            existing_files_count: get_int(&value, "existing_files_count")
+                .or_else(|| get_int(&value, "existing_data_files_count"))
+                .unwrap_or(0),
+            deleted_files_count: get_int(&value, "deleted_files_count")
+                .or_else(|| get_int(&value, "deleted_data_files_count"))
+                .unwrap_or(0),
+            added_rows_count: get_long(&value, "added_rows_count").unwrap_or(0),
+            existing_rows_count: get_long(&value, "existing_rows_count").unwrap_or(0),
+            deleted_rows_count: get_long(&value, "deleted_rows_count").unwrap_or(0),
+        });
+    }
+    Ok(manifests)
+}
+
+/// Write a manifest list, the snapshot must be the one which adds the list.
+pub fn write_manifest_list(
+    manifests: &[ManifestFile],
+    snapshot_id: i64,
+    parent_snapshot_id: Option<i64>,
+    sequence_number: i64,
+) -> Result<Vec<u8>> {
+    let mut writer = Writer::with_codec(&MANIFEST_FILE_SCHEMA, vec![], Codec::Deflate);
+    let mut metadata = vec![
+        ("snapshot-id", snapshot_id.to_string()),
+        ("sequence-number", sequence_number.to_string()),
+        ("format-version", "2".to_string()),
+    ];
+    if let Some(parent) = parent_snapshot_id {
+        metadata.push(("parent-snapshot-id", parent.to_string()));
+    }
+    for (key, value) in metadata {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    for manifest in manifests {
+        let record = Value::Record(vec![
+            (
+                "manifest_path".to_string(),
+                Value::String(manifest.manifest_path.clone()),
+            ),
+            (
+                "manifest_length".to_string(),
+                Value::Long(manifest.manifest_length),
+            ),
+            (
+                "partition_spec_id".to_string(),
+                Value::Int(manifest.partition_spec_id),
+            ),
+            ("content".to_string(), Value::Int(manifest.content)),
+            (
+                "sequence_number".to_string(),
+                Value::Long(manifest.sequence_number),
+            ),
+            (
+                "min_sequence_number".to_string(),
+                Value::Long(manifest.min_sequence_number),
+            ),
+            (
+                "added_snapshot_id".to_string(),
+                Value::Long(manifest.added_snapshot_id),
+            ),
+            (
+                "added_files_count".to_string(),
+                Value::Int(manifest.added_files_count),
+            ),
+            (
+                "existing_files_count".to_string(),
+                Value::Int(manifest.existing_files_count),
+            ),
+            (
+                "deleted_files_count".to_string(),
+                Value::Int(manifest.deleted_files_count),
+            ),
+            (
+                "added_rows_count".to_string(),
+                Value::Long(manifest.added_rows_count),
+            ),
+            (
+                "existing_rows_count".to_string(),
+                Value::Long(manifest.existing_rows_count),
+            ),
+            (
+                "deleted_rows_count".to_string(),
+                Value::Long(manifest.deleted_rows_count),
+            ),
+            ("partitions".to_string(), optional(None)),
+            ("key_metadata".to_string(), optional(None)),
+        ]);
+        writer.append(record).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+/// Read the entries of a manifest, inheriting the snapshot id and the sequence numbers
+/// from the manifest list entry where they are not written.
+pub fn read_manifest(data: &[u8], manifest: &ManifestFile) -> Result<Vec<ManifestEntry>> {
+    let reader = Reader::new(data).map_err(avro_error)?;
+    let mut entries = vec![];
+    for value in reader {
+        let value = value.map_err(avro_error)?;
+        let status = match required(get_int(&value, "status"), "status")? {
+            0 => ManifestStatus::Existing,
+            1 => ManifestStatus::Added,
+            2 => ManifestStatus::Deleted,
+            other => {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "invalid status {other} of iceberg manifest entry"
+                )));
+            }
+        };
+        // sequence numbers are only inherited by the files added in the manifest.
+        let inherited_sequence_number = match status {
+            ManifestStatus::Added => manifest.sequence_number,
+            _ => 0,
+        };
+        let sequence_number =
+            get_long(&value, "sequence_number").unwrap_or(inherited_sequence_number);
+        let data_file = get_field(&value, "data_file").ok_or_else(|| {
+            ErrorCode::ReadTableDataError("iceberg manifest entry lacks data_file")
+        })?;
+        let partitioned = matches!(
+            get_field(data_file, "partition"),
+            Some(Value::Record(fields)) if !fields.is_empty()
+        );
+        entries.push(ManifestEntry {
+            status,
+            snapshot_id: get_long(&value, "snapshot_id").unwrap_or(manifest.added_snapshot_id),
+            sequence_number,
+            file_sequence_number: get_long(&value, "file_sequence_number")
+                .unwrap_or(sequence_number),
+            partitioned,
+            data_file: DataFile {
+                content: get_int(data_file, "content").unwrap_or(DATA_FILE_CONTENT),
+                file_path: required(get_string(data_file, "file_path"), "file_path")?,
+                file_format: required(get_string(data_file, "file_format"), "file_format")?,
+                record_count: required(get_long(data_file, "record_count"), "record_count")?,
+                file_size_in_bytes: required(
+                    get_long(data_file, "file_size_in_bytes"),
+                    "file_size_in_bytes",
+                )?,
+                column_sizes: get_int_map(data_file, "column_sizes", as_long),
+                value_counts: get_int_map(data_file, "value_counts", as_long),
+                null_value_counts: get_int_map(data_file, "null_value_counts", as_long),
+                nan_value_counts: get_int_map(data_file, "nan_value_counts", as_long),
+                lower_bounds: get_int_map(data_file, "lower_bounds", as_bytes),
+                upper_bounds: get_int_map(data_file, "upper_bounds", as_bytes),
+                key_metadata: get_field(data_file, "key_metadata").and_then(as_bytes),
+                split_offsets: get_array(data_file, "split_offsets", as_long),
+                equality_ids: get_array(data_file, "equality_ids", |v| match v {
+                    Value::Int(v) => Some(*v),
+                    _ => None,
+                }),
+                sort_order_id: get_int(data_file, "sort_order_id"),
+            },
+        });
+    }
+    Ok(entries)
+}
+
+/// Write a manifest of data files.
+///
+/// The sequence numbers of added files are left to be inherited from the manifest list.
+pub fn write_manifest(entries: &[ManifestEntry], metadata: &ManifestMetadata) -> Result<Vec<u8>> {
+    let mut writer = Writer::with_codec(&MANIFEST_ENTRY_SCHEMA, vec![], Codec::Deflate);
+    for (key, value) in [
+        ("schema", metadata.schema.clone()),
+        ("schema-id", metadata.schema_id.to_string()),
+        ("partition-spec", "[]".to_string()),
+        ("partition-spec-id", metadata.partition_spec_id.to_string()),
+        ("format-version", "2".to_string()),
+        ("content", "data".to_string()),
+    ] {
+        writer
+            .add_user_metadata(key.to_string(), value)
+            .map_err(avro_error)?;
+    }
+
+    for entry in entries {
+        let (sequence_number, file_sequence_number) = match entry.status {
+            ManifestStatus::Added => (None, None),
+            _ => (
+                Some(Value::Long(entry.sequence_number)),
+                Some(Value::Long(entry.file_sequence_number)),
+            ),
+        };
+        let file = &entry.data_file;
+        let data_file = Value::Record(vec![
+            ("content".to_string(), Value::Int(file.content)),
+            (
+                "file_path".to_string(),
+                Value::String(file.file_path.clone()),
+            ),
+            (
+                "file_format".to_string(),
+                Value::String(file.file_format.clone()),
+            ),
+            ("partition".to_string(), Value::Record(vec![])),
+            ("record_count".to_string(), Value::Long(file.record_count)),
+            (
+                "file_size_in_bytes".to_string(),
+                Value::Long(file.file_size_in_bytes),
+            ),
+            (
+                "column_sizes".to_string(),
+                int_map(&file.column_sizes, |v| Value::Long(*v)),
+            ),
+            (
+                "value_counts".to_string(),
+                int_map(&file.value_counts, |v| Value::Long(*v)),
+            ),
+            (
+                "null_value_counts".to_string(),
+                int_map(&file.null_value_counts, |v| Value::Long(*v)),
+            ),
+            (
+                "nan_value_counts".to_string(),
+                int_map(&file.nan_value_counts, |v| Value::Long(*v)),
+            ),
+            (
+                "lower_bounds".to_string(),
+                int_map(&file.lower_bounds, |v| Value::Bytes(v.clone())),
+            ),
+            (
+                "upper_bounds".to_string(),
+                int_map(&file.upper_bounds, |v| Value::Bytes(v.clone())),
+            ),
+            (
+                "key_metadata".to_string(),
+                optional(file.key_metadata.clone().map(Value::Bytes)),
+            ),
+            (
+                "split_offsets".to_string(),
+                optional(
+                    file.split_offsets
+                        .as_ref()
+                        .map(|v| Value::Array(v.iter().map(|v| Value::Long(*v)).collect())),
+                ),
+            ),
+            (
+                "equality_ids".to_string(),
+                optional(
+                    file.equality_ids
+                        .as_ref()
+                        .map(|v| Value::Array(v.iter().map(|v| Value::Int(*v)).collect())),
+                ),
+            ),
+            (
+                "sort_order_id".to_string(),
+                optional(file.sort_order_id.map(Value::Int)),
+            ),
+        ]);
+        let record = Value::Record(vec![
+            ("status".to_string(), Value::Int(entry.status as i32)),
+            (
+                "snapshot_id".to_string(),
+                optional(Some(Value::Long(entry.snapshot_id))),
+            ),
+            ("sequence_number".to_string(), optional(sequence_number)),
+            (
+                "file_sequence_number".to_string(),
+                optional(file_sequence_number),
+            ),
+            ("data_file".to_string(), data_file),
+        ]);
+        writer.append(record).map_err(avro_error)?;
+    }
+    writer.into_inner().map_err(avro_error)
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("fail to read or write iceberg manifest: {e}"))
+}
+
+fn required<T>(value: Option<T>, name: &str) -> Result<T> {
+    value.ok_or_else(|| {
+        ErrorCode::ReadTableDataError(format!("iceberg manifest lacks required field {name}"))
+    })
+}
+
+/// Get a field of a record, unwrapping the optional union.
+fn get_field<'a>(record: &'a Value, name: &str) -> Option<&'a Value> {
+    let Value::Record(fields) = record else {
+        return None;
+    };
+    let (_, value) = fields.iter().find(|(n, _)| n == name)?;
+    match value {
+        Value::Union(_, value) => match value.as_ref() {
+            Value::Null => None,
+            value => Some(value),
+        },
+        Value::Null => None,
+        value => Some(value),
+    }
+}
+
+fn as_long(value: &Value) -> Option<i64> {
+    match value {
+        Value::Long(v) => Some(*v),
+        Value::Int(v) => Some(*v as i64),
+        _ => None,
+    }
+}
+
+fn as_bytes(value: &Value) -> Option<Vec<u8>> {
+    match value {
+        Value::Bytes(v) | Value::Fixed(_, v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+fn get_long(record: &Value, name: &str) -> Option<i64> {
+    get_field(record, name).and_then(as_long)
+}
+
+fn get_int(record: &Value, name: &str) -> Option<i32> {
+    match get_field(record, name)? {
+        Value::Int(v) => Some(*v),
+        _ => None,
+    }
+}
+
+fn get_string(record: &Value, name: &str) -> Option<String> {
+    match get_field(record, name)? {
+        Value::String(v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+fn get_array<T>(record: &Value, name: &str, f: impl Fn(&Value) -> Option<T>) -> Option<Vec<T>> {
+    match get_field(record, name)? {
+        Value::Array(values) => values.iter().map(f).collect(),
+        _ => None,
+    }
+}
+
+/// Maps with int keys are stored as arrays of key-value records.
+fn get_int_map<T>(
+    record: &Value,
+    name: &str,
+    f: impl Fn(&Value) -> Option<T>,
+) -> Option<Vec<(i32, T)>> {
+    get_array(record, name, |kv| {
+        let key = get_int(kv, "key")?;
+        let value = f(get_field(kv, "value")?)?;
+        Some((key, value))
+    })
+}
+
+fn optional(value: Option<Value>) -> Value {
+    match value {
+        Some(value) => Value::Union(1, Box::new(value)),
+        None => Value::Union(0, Box::new(Value::Null)),
+    }
+}
+
+fn int_map<T>(map: &Option<Vec<(i32, T)>>, f: impl Fn(&T) -> Value) -> Value {
+    optional(map.as_ref().map(|map| {
+        Value::Array(
+            map.iter()
+                .map(|(k, v)| {
+                    Value::Record(vec![
+                        ("key".to_string(), Value::Int(*k)),
+                        ("value".to_string(), f(v)),
+                    ])
+                })
+                .collect(),
+        )
+    }))
+}
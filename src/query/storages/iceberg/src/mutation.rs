@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Not;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use databend_common_catalog::plan::Filters;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_storage::init_operator;
+use databend_storages_common_pruner::RangePrunerCreator;
+use opendal::Operator;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+use crate::manifest::DataFile;
+use crate::stats::get_stats_of_manifest_data_file;
+use crate::table::IcebergTable;
+use crate::transaction::IcebergTransaction;
+use crate::transaction::SnapshotOperation;
+use crate::transaction::TableMetadata;
+use crate::writer::write_data_file;
+use crate::writer::DataFileLocation;
+
+/// Data files smaller than this are merged by compaction.
+const COMPACT_FILE_SIZE: i64 = 64 * 1024 * 1024;
+/// The in-memory size of the rows merged into one data file by compaction.
+const COMPACT_BUFFERED_BYTES: usize = 256 * 1024 * 1024;
+
+impl IcebergTable {
+    /// Delete the rows matching the filter by rewriting the data files which contain them,
+    /// all the rows are deleted without filter.
+    ///
+    /// Returns the number of deleted rows.
+    #[async_backtrace::framed]
+    pub async fn delete(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        filters: Option<Filters>,
+    ) -> Result<u64> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let mut txn = IcebergTransaction::begin(op.clone()).await?;
+        txn.metadata().check_writable(self.name())?;
+        let files = txn.data_files().await?;
+
+        let mut deleted_rows = 0;
+        let Some(filters) = filters else {
+            for file in files {
+                deleted_rows += file.record_count as u64;
+                txn.remove_file(file.file_path);
+            }
+            txn.commit(SnapshotOperation::Delete).await?;
+            return Ok(deleted_rows);
+        };
+
+        let schema = self.schema();
+        let func_ctx = ctx.get_function_context()?;
+        let filter = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+        let pruner = RangePrunerCreator::try_create(func_ctx.clone(), &schema, Some(&filter))?;
+        let filter = filter.project_column_ref(|name| schema.index_of(name).unwrap());
+
+        let target = DataFileLocation::create(txn.metadata())?;
+        let mut added = vec![];
+        for file in files {
+            // The file has no matching rows according to its bounds.
+            if let Some(stats) = get_stats_of_manifest_data_file(&schema, &file) {
+                if !pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+
+            let block = read_data_file(&op, txn.metadata(), &file, &schema).await?;
+            let num_rows = block.num_rows();
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let predicates = evaluator
+                .run(&filter)
+                .map_err(|e| e.add_message("eval filter failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            let remain = match predicates {
+                Value::Scalar(false) => continue,
+                Value::Scalar(true) => None,
+                Value::Column(bitmap) => match bitmap.unset_bits() {
+                    0 => None,
+                    n if n == num_rows => continue,
+                    _ => Some(block.filter_with_bitmap(&bitmap.not())?),
+                },
+            };
+
+            let remain_rows = remain.as_ref().map_or(0, |b| b.num_rows());
+            deleted_rows += (num_rows - remain_rows) as u64;
+            txn.remove_file(file.file_path);
+            if let Some(remain) = remain {
+                added.push(write_data_file(&op, &target, &schema, &[remain]).await?);
+            }
+        }
+
+        let operation = match added.is_empty() {
+            true => SnapshotOperation::Delete,
+            false => SnapshotOperation::Overwrite,
+        };
+        txn.add_files(added);
+        txn.commit(operation).await?;
+        Ok(deleted_rows)
+    }
+
+    /// Merge the small data files of the current snapshot.
+    #[async_backtrace::framed]
+    pub async fn compact(&self) -> Result<()> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let mut txn = IcebergTransaction::begin(op.clone()).await?;
+        txn.metadata().check_writable(self.name())?;
+        let small_files = txn
+            .data_files()
+            .await?
+            .into_iter()
+            .filter(|f| f.file_size_in_bytes < COMPACT_FILE_SIZE)
+            .collect::<Vec<_>>();
+        if small_files.len() < 2 {
+            return Ok(());
+        }
+
+        let schema = self.schema();
+        let target = DataFileLocation::create(txn.metadata())?;
+        let mut added = vec![];
+        let mut blocks = vec![];
+        let mut buffered_bytes = 0;
+        for file in small_files {
+            let block = read_data_file(&op, txn.metadata(), &file, &schema).await?;
+            txn.remove_file(file.file_path);
+            buffered_bytes += block.memory_size();
+            blocks.push(block);
+            if buffered_bytes >= COMPACT_BUFFERED_BYTES {
+                added.push(write_data_file(&op, &target, &schema, &blocks).await?);
+                blocks.clear();
+                buffered_bytes = 0;
+            }
+        }
+        if !blocks.is_empty() {
+            added.push(write_data_file(&op, &target, &schema, &blocks).await?);
+        }
+
+        txn.add_files(added);
+        txn.commit(SnapshotOperation::Replace).await
+    }
+}
+
+/// Read the table columns of a parquet data file.
+async fn read_data_file(
+    op: &Operator,
+    metadata: &TableMetadata,
+    file: &DataFile,
+    schema: &TableSchemaRef,
+) -> Result<DataBlock> {
+    let data = op.read(&metadata.rel_path(&file.file_path)?).await?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(data))?.build()?;
+
+    let mut blocks = vec![];
+    for batch in reader {
+        let batch = batch?;
+        let batch_schema = batch.schema();
+        let mut columns = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            let index = batch_schema.index_of(field.name())?;
+            columns.push(Column::from_arrow_rs(
+                batch.column(index).clone(),
+                &DataType::from(field.data_type()),
+            )?);
+        }
+        blocks.push(DataBlock::new_from_columns(columns));
+    }
+    match blocks.len() {
+        0 => Ok(DataBlock::empty_with_schema(Arc::new(
+            schema.as_ref().into(),
+        ))),
+        _ => DataBlock::concat(&blocks),
+    }
+}
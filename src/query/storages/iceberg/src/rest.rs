@@ -0,0 +1,355 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Client of the Iceberg REST catalog.
+//!
+//! Only the read-only endpoints used to discover namespaces and tables are implemented,
+//! see the [REST catalog spec](https://github.com/apache/iceberg/blob/main/open-api/rest-catalog-open-api.yaml).
+//!
+//! Namespaces are mapped to databases, nested namespace levels are joined with `.`.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::IcebergRestCatalogOption;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+/// Characters other than the unreserved ones of RFC 3986 are encoded in path segments.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Levels of a nested namespace are separated by the unit separator in request paths.
+const NAMESPACE_SEPARATOR: &str = "\u{1f}";
+
+#[derive(Deserialize)]
+struct ConfigResponse {
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct ListNamespacesResponse {
+    #[serde(default)]
+    namespaces: Vec<Vec<String>>,
+    #[serde(rename = "next-page-token", default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TableIdentifier {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ListTablesResponse {
+    #[serde(default)]
+    identifiers: Vec<TableIdentifier>,
+    #[serde(rename = "next-page-token", default)]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LoadTableResponse {
+    #[serde(rename = "metadata-location", default)]
+    metadata_location: Option<String>,
+    metadata: TableMetadata,
+}
+
+#[derive(Deserialize)]
+struct TableMetadata {
+    location: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorModel,
+}
+
+#[derive(Deserialize)]
+struct ErrorModel {
+    message: String,
+}
+
+/// Client of an Iceberg REST catalog service.
+pub struct IcebergRestClient {
+    option: IcebergRestCatalogOption,
+    /// storage of the warehouse, used to access files of tables
+    storage_params: StorageParams,
+    client: reqwest::Client,
+    /// path prefix of all catalog endpoints, fetched from the config endpoint
+    prefix: OnceCell<String>,
+}
+
+impl IcebergRestClient {
+    pub fn try_create(
+        option: IcebergRestCatalogOption,
+        storage_params: StorageParams,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder().build().map_err(|err| {
+            ErrorCode::Internal(format!("failed to build iceberg rest client: {err}"))
+        })?;
+
+        Ok(Self {
+            option,
+            storage_params,
+            client,
+            prefix: OnceCell::new(),
+        })
+    }
+
+    /// List all namespaces, nested levels are joined with `.`.
+    #[async_backtrace::framed]
+    pub async fn list_namespaces(&self) -> Result<Vec<String>> {
+        let mut namespaces = vec![];
+        let mut page_token = None;
+        loop {
+            let path = self.endpoint("namespaces").await?;
+            let resp: ListNamespacesResponse = self
+                .get(&path, &page_query(page_token.as_deref()))
+                .await?
+                .ok_or_else(|| ErrorCode::Internal(format!("{path} is not found")))?;
+
+            namespaces.extend(resp.namespaces.into_iter().map(|ns| ns.join(".")));
+            match resp.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(namespaces),
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn namespace_exists(&self, namespace: &str) -> Result<bool> {
+        let path = self
+            .endpoint(&format!("namespaces/{}", encode_namespace(namespace)))
+            .await?;
+        let resp: Option<serde_json::Value> = self.get(&path, &[]).await?;
+        Ok(resp.is_some())
+    }
+
+    /// List names of tables in the namespace.
+    #[async_backtrace::framed]
+    pub async fn list_tables(&self, namespace: &str) -> Result<Vec<String>> {
+        let mut tables = vec![];
+        let mut page_token = None;
+        loop {
+            let path = self
+                .endpoint(&format!(
+                    "namespaces/{}/tables",
+                    encode_namespace(namespace)
+                ))
+                .await?;
+            let resp: ListTablesResponse = self
+                .get(&path, &page_query(page_token.as_deref()))
+                .await?
+                .ok_or_else(|| {
+                    ErrorCode::UnknownDatabase(format!("Database {namespace} does not exist"))
+                })?;
+
+            tables.extend(resp.identifiers.into_iter().map(|ident| ident.name));
+            match resp.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(tables),
+            }
+        }
+    }
+
+    /// Load the table and return the storage params of its location, along with
+    /// the path of its current metadata file relative to the location.
+    #[async_backtrace::framed]
+    pub async fn load_table(
+        &self,
+        namespace: &str,
+        table_name: &str,
+    ) -> Result<(StorageParams, String)> {
+        let path = self
+            .endpoint(&format!(
+                "namespaces/{}/tables/{}",
+                encode_namespace(namespace),
+                utf8_percent_encode(table_name, PATH_SEGMENT)
+            ))
+            .await?;
+        let resp: LoadTableResponse = self.get(&path, &[]).await?.ok_or_else(|| {
+            ErrorCode::UnknownTable(format!(
+                "table {table_name} does not exist or is not a valid table"
+            ))
+        })?;
+
+        let location = resp.metadata.location.trim_end_matches('/');
+        let metadata_location = resp.metadata_location.ok_or_else(|| {
+            ErrorCode::UnknownTable(format!(
+                "table {table_name} has no metadata file, it may not be committed yet"
+            ))
+        })?;
+        let metadata_path = metadata_location
+            .strip_prefix(location)
+            .and_then(|path| path.strip_prefix('/'))
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "metadata file {metadata_location} of table {table_name} is not in the table location {location}"
+                ))
+            })?;
+
+        let table_sp = resolve_table_location(&self.storage_params, location)?;
+        Ok((table_sp, metadata_path.to_string()))
+    }
+
+    /// Build the path of an endpoint, the prefix is fetched from the config endpoint at first call.
+    async fn endpoint(&self, path: &str) -> Result<String> {
+        let prefix = self
+            .prefix
+            .get_or_try_init(|| async {
+                let url = format!("{}/v1/config", self.option.uri);
+                let query = match self.option.warehouse.is_empty() {
+                    true => vec![],
+                    false => vec![("warehouse", self.option.warehouse.as_str())],
+                };
+                let resp: ConfigResponse = self.get(&url, &query).await?.ok_or_else(|| {
+                    ErrorCode::Internal(format!(
+                        "{} is not a valid iceberg rest catalog",
+                        self.option.uri
+                    ))
+                })?;
+
+                // Server overrides take precedence over defaults.
+                let prefix = resp
+                    .overrides
+                    .get("prefix")
+                    .or_else(|| resp.defaults.get("prefix"))
+                    .map(|v| v.trim_matches('/').to_string())
+                    .unwrap_or_default();
+                Ok::<_, ErrorCode>(prefix)
+            })
+            .await?;
+
+        match prefix.is_empty() {
+            true => Ok(format!("{}/v1/{path}", self.option.uri)),
+            false => Ok(format!("{}/v1/{prefix}/{path}", self.option.uri)),
+        }
+    }
+
+    /// Send a GET request, returns `None` if the resource is not found.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        query: &[(&str, &str)],
+    ) -> Result<Option<T>> {
+        let mut req = self.client.get(url).query(query);
+        if !self.option.token.is_empty() {
+            req = req.bearer_auth(&self.option.token);
+        }
+
+        let resp = req.send().await.map_err(|err| {
+            ErrorCode::Internal(format!("iceberg rest catalog request {url} failed: {err}"))
+        })?;
+        let status = resp.status();
+        let body = resp.bytes().await.map_err(|err| {
+            ErrorCode::Internal(format!("iceberg rest catalog request {url} failed: {err}"))
+        })?;
+
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            let message = serde_json::from_slice::<ErrorResponse>(&body)
+                .map(|v| v.error.message)
+                .unwrap_or_else(|_| String::from_utf8_lossy(&body).to_string());
+            let message = format!("iceberg rest catalog request {url} failed: {status}, {message}");
+            return match status {
+                StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                    Err(ErrorCode::AuthenticateFailure(message))
+                }
+                _ => Err(ErrorCode::Internal(message)),
+            };
+        }
+
+        serde_json::from_slice(&body).map(Some).map_err(|err| {
+            ErrorCode::Internal(format!(
+                "invalid response of iceberg rest catalog request {url}: {err}"
+            ))
+        })
+    }
+}
+
+impl Debug for IcebergRestClient {
+    // The token is not printed.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IcebergRestClient")
+            .field("uri", &self.option.uri)
+            .field("warehouse", &self.option.warehouse)
+            .field("storage_params", &self.storage_params)
+            .finish()
+    }
+}
+
+fn page_query(page_token: Option<&str>) -> Vec<(&str, &str)> {
+    match page_token {
+        Some(token) => vec![("pageToken", token)],
+        None => vec![],
+    }
+}
+
+fn encode_namespace(namespace: &str) -> String {
+    let namespace = namespace
+        .split('.')
+        .collect::<Vec<_>>()
+        .join(NAMESPACE_SEPARATOR);
+    utf8_percent_encode(&namespace, PATH_SEGMENT).to_string()
+}
+
+/// Resolve the storage params of a table location like `s3://bucket/path/to/table`.
+///
+/// Local locations are accessed directly, other locations are accessed with
+/// the connection of the warehouse storage, which must be in the same bucket.
+pub fn resolve_table_location(warehouse: &StorageParams, location: &str) -> Result<StorageParams> {
+    let location = location.trim_end_matches('/');
+    let path = match location.split_once("://") {
+        None => {
+            return Ok(StorageParams::Fs(StorageFsConfig {
+                root: format!("{location}/"),
+            }));
+        }
+        Some(("file", path)) => {
+            return Ok(StorageParams::Fs(StorageFsConfig {
+                root: format!("{path}/"),
+            }));
+        }
+        Some((_, bucket_and_path)) => bucket_and_path
+            .split_once('/')
+            .map(|(_, path)| path)
+            .unwrap_or_default(),
+    };
+
+    if matches!(warehouse, StorageParams::None) {
+        return Err(ErrorCode::BadArguments(format!(
+            "storage connection of the catalog is required to access iceberg table at {location}"
+        )));
+    }
+    Ok(warehouse.clone().map_root(|_| format!("/{path}/")))
+}
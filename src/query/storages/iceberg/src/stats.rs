@@ -27,6 +27,8 @@ use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use icelake::types::DataFile;
 
+use crate::manifest;
+
 /// Try to convert statistics in [`DataFile`] to [`StatisticsOfColumns`].
 pub fn get_stats_of_data_file(schema: &TableSchema, df: &DataFile) -> Option<StatisticsOfColumns> {
     match (&df.lower_bounds, &df.upper_bounds, &df.null_value_counts) {
@@ -46,6 +48,28 @@ pub fn get_stats_of_data_file(schema: &TableSchema, df: &DataFile) -> Option<Sta
     }
 }
 
+/// Try to convert statistics in a [`manifest::DataFile`] written by databend to [`StatisticsOfColumns`].
+pub fn get_stats_of_manifest_data_file(
+    schema: &TableSchema,
+    df: &manifest::DataFile,
+) -> Option<StatisticsOfColumns> {
+    let (Some(lower), Some(upper), Some(null_counts)) =
+        (&df.lower_bounds, &df.upper_bounds, &df.null_value_counts)
+    else {
+        return None;
+    };
+    let lower = lower.iter().cloned().collect::<HashMap<_, _>>();
+    let upper = upper.iter().cloned().collect::<HashMap<_, _>>();
+    let null_counts = null_counts.iter().cloned().collect::<HashMap<_, _>>();
+    let mut stats: HashMap<u32, ColumnStatistics> = HashMap::with_capacity(schema.num_fields());
+    for field in schema.fields.iter() {
+        if let Some(stat) = get_column_stats(field, &lower, &upper, &null_counts, &None) {
+            stats.insert(field.column_id, stat);
+        }
+    }
+    Some(stats)
+}
+
 /// Try get [`ColumnStatistics`] for one column.
 fn get_column_stats(
     field: &TableField,
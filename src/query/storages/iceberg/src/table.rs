@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use arrow_schema::Schema as ArrowSchema;
@@ -28,6 +29,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,8 +41,13 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sinks::AsyncSinker;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
 use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storages_parquet::ParquetFilesPart;
@@ -48,16 +55,29 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use databend_storages_common_table_meta::meta::TableSnapshot;
 use icelake::catalog::Catalog;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
 use crate::partition::IcebergPartInfo;
 use crate::stats::get_stats_of_data_file;
+use crate::stats::get_stats_of_manifest_data_file;
 use crate::table_source::IcebergTableSource;
+use crate::transaction::IcebergTransaction;
+use crate::transaction::TableMetadata;
+use crate::writer::CommitSink;
+use crate::writer::DataFileWriter;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+/// The metadata file of the table returned by the catalog, relative to the table directory.
+///
+/// Tables of a REST catalog are read from this file instead of the latest one in storage.
+pub const OPT_KEY_METADATA_LOCATION: &str = "metadata_location";
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
@@ -84,7 +104,7 @@ impl IcebergTable {
         }
     }
 
-    fn get_storage_params(&self) -> Result<&StorageParams> {
+    pub(crate) fn get_storage_params(&self) -> Result<&StorageParams> {
         self.info.meta.storage_params.as_ref().ok_or_else(|| {
             ErrorCode::BadArguments(format!(
                 "Iceberg table {} must have storage parameters",
@@ -105,9 +125,7 @@ impl IcebergTable {
         })
     }
 
-    pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
-        let meta = table.current_table_metadata();
-
+    pub async fn get_schema(meta: &icelake::types::TableMetadata) -> Result<TableSchema> {
         // Build arrow schema from iceberg metadata.
         let arrow_schema: ArrowSchema = meta
             .schemas
@@ -133,15 +151,35 @@ impl IcebergTable {
     }
 
     /// create a new table on the table directory
+    ///
+    /// The table is read from `metadata_location` if given, otherwise from the latest metadata file.
     #[async_backtrace::framed]
     pub async fn try_create_from_iceberg_catalog(
         catalog: &str,
         database: &str,
         table_name: &str,
         dop: DataOperator,
+        metadata_location: Option<String>,
     ) -> Result<IcebergTable> {
-        let table = Self::load_iceberg_table(dop.clone()).await?;
-        let table_schema = Self::get_schema(&table).await?;
+        let (table, table_schema) = match &metadata_location {
+            Some(path) => {
+                let data = dop.operator().read(path).await?;
+                let meta = icelake::types::parse_table_metadata(&data).map_err(|e| {
+                    ErrorCode::ReadTableDataError(format!(
+                        "Cannot parse iceberg table metadata {path}: {e:?}"
+                    ))
+                })?;
+                (None, Self::get_schema(&meta).await?)
+            }
+            None => {
+                let table = Self::load_iceberg_table(dop.clone()).await?;
+                let table_schema = Self::get_schema(table.current_table_metadata()).await?;
+                (Some(table), table_schema)
+            }
+        };
+        let options = metadata_location
+            .map(|path| BTreeMap::from([(OPT_KEY_METADATA_LOCATION.to_string(), path)]))
+            .unwrap_or_default();
 
         // construct table info
         let info = TableInfo {
@@ -154,6 +192,7 @@ impl IcebergTable {
                 engine: "iceberg".to_string(),
                 created_on: Utc::now(),
                 storage_params: Some(dop.params()),
+                options,
                 ..Default::default()
             },
             ..Default::default()
@@ -161,7 +200,7 @@ impl IcebergTable {
 
         Ok(Self {
             info,
-            table: OnceCell::new_with(Some(table)),
+            table: OnceCell::new_with(table),
         })
     }
 
@@ -254,11 +293,11 @@ impl IcebergTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
-        let table = self.table().await?;
-
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let schema = self.schema();
+        let data_files = match self.info.meta.options.get(OPT_KEY_METADATA_LOCATION) {
+            Some(path) => self.data_files_of_metadata(path, &schema).await?,
+            None => self.current_data_files(&schema).await?,
+        };
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -267,47 +306,29 @@ impl IcebergTable {
                 .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
         });
 
-        let schema = self.schema();
-
         let pruner =
             RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
 
-        // TODO: support other file formats. We only support parquet files now.
         let mut read_rows = 0;
         let mut read_bytes = 0;
         let total_files = data_files.len();
         let parts = data_files
             .into_iter()
-            .filter(|df| {
-                if let Some(stats) = get_stats_of_data_file(&schema, df) {
-                    pruner.should_keep(&stats, None)
-                } else {
-                    true
-                }
+            .filter(|df| match &df.stats {
+                Some(stats) => pruner.should_keep(stats, None),
+                None => true,
             })
-            .map(|v: icelake::types::DataFile| {
-                read_rows += v.record_count as usize;
-                read_bytes += v.file_size_in_bytes as usize;
-                match v.file_format {
-                    icelake::types::DataFileFormat::Parquet => {
-                        let location = table
-                            .rel_path(&v.file_path)
-                            .expect("file path must be rel to table");
-                        Ok(Arc::new(
-                            Box::new(IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(
-                                ParquetFilesPart {
-                                    files: vec![(location, v.file_size_in_bytes as u64)],
-                                    estimated_uncompressed_size: v.file_size_in_bytes as u64, // This field is not used here.
-                                },
-                            ))) as Box<dyn PartInfo>,
-                        ))
-                    }
-                    _ => Err(ErrorCode::Unimplemented(
-                        "Only parquet format is supported for iceberg table",
-                    )),
-                }
+            .map(|v| {
+                read_rows += v.record_count;
+                read_bytes += v.size as usize;
+                Arc::new(Box::new(IcebergPartInfo::Parquet(ParquetPart::ParquetFiles(
+                    ParquetFilesPart {
+                        files: vec![(v.location, v.size)],
+                        estimated_uncompressed_size: v.size, // This field is not used here.
+                    },
+                ))) as Box<dyn PartInfo>)
             })
-            .collect::<Result<Vec<_>>>()?;
+            .collect::<Vec<_>>();
 
         // TODO: more precise pruning.
 
@@ -323,6 +344,68 @@ impl IcebergTable {
             Partitions::create(PartitionsShuffleKind::Mod, parts),
         ))
     }
+
+    /// The data files of the current snapshot in the latest metadata file.
+    async fn current_data_files(&self, schema: &TableSchema) -> Result<Vec<ParquetDataFile>> {
+        let table = self.table().await?;
+        let data_files = table.current_data_files().await.map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
+        })?;
+
+        // TODO: support other file formats. We only support parquet files now.
+        data_files
+            .into_iter()
+            .map(|v| match v.file_format {
+                icelake::types::DataFileFormat::Parquet => Ok(ParquetDataFile {
+                    location: table
+                        .rel_path(&v.file_path)
+                        .expect("file path must be rel to table"),
+                    size: v.file_size_in_bytes as u64,
+                    record_count: v.record_count as usize,
+                    stats: get_stats_of_data_file(schema, &v),
+                }),
+                _ => Err(ErrorCode::Unimplemented(
+                    "Only parquet format is supported for iceberg table",
+                )),
+            })
+            .collect()
+    }
+
+    /// The data files of the current snapshot in the given metadata file.
+    async fn data_files_of_metadata(
+        &self,
+        path: &str,
+        schema: &TableSchema,
+    ) -> Result<Vec<ParquetDataFile>> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let metadata = TableMetadata::load_file(&op, path).await?;
+        let txn = IcebergTransaction::with_metadata(op, metadata);
+        txn.data_files()
+            .await?
+            .into_iter()
+            .map(|v| {
+                if !v.file_format.eq_ignore_ascii_case("parquet") {
+                    return Err(ErrorCode::Unimplemented(
+                        "Only parquet format is supported for iceberg table",
+                    ));
+                }
+                Ok(ParquetDataFile {
+                    location: txn.metadata().rel_path(&v.file_path)?,
+                    size: v.file_size_in_bytes as u64,
+                    record_count: v.record_count as usize,
+                    stats: get_stats_of_manifest_data_file(schema, &v),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A parquet data file of an iceberg table, the location is relative to the table directory.
+struct ParquetDataFile {
+    location: String,
+    size: u64,
+    record_count: usize,
+    stats: Option<StatisticsOfColumns>,
 }
 
 #[async_trait]
@@ -364,6 +447,61 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _: AppendMode,
+    ) -> Result<()> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let schema = self.schema();
+        pipeline.add_transform(|input, output| {
+            let writer = DataFileWriter::create(ctx.clone(), op.clone(), schema.clone());
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, writer,
+            )))
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        _deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        let op = init_operator(self.get_storage_params()?)?;
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            let sink = CommitSink::create(op.clone(), self.name().to_string(), overwrite);
+            Ok(ProcessorPtr::create(AsyncSinker::create(
+                input,
+                ctx.clone(),
+                sink,
+            )))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn truncate(&self, ctx: Arc<dyn TableContext>, _pipeline: &mut Pipeline) -> Result<()> {
+        self.delete(ctx, None).await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn compact_blocks(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _limit: Option<usize>,
+    ) -> Result<Option<(Partitions, Arc<TableSnapshot>)>> {
+        // The small data files are merged in place, no pipeline is needed.
+        self.compact().await?;
+        Ok(None)
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -0,0 +1,613 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opendal::Operator;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use uuid::Uuid;
+
+use crate::manifest::read_manifest;
+use crate::manifest::read_manifest_list;
+use crate::manifest::write_manifest;
+use crate::manifest::write_manifest_list;
+use crate::manifest::DataFile;
+use crate::manifest::ManifestEntry;
+use crate::manifest::ManifestFile;
+use crate::manifest::ManifestMetadata;
+use crate::manifest::ManifestStatus;
+use crate::manifest::DATA_FILE_CONTENT;
+
+const METADATA_DIR: &str = "metadata/";
+const VERSION_HINT: &str = "metadata/version-hint.text";
+
+/// The current metadata file of an iceberg table.
+///
+/// Both the `v<N>.metadata.json` files tracked by `version-hint.text` and the
+/// `<N>-<uuid>.metadata.json` files are supported.
+pub struct TableMetadata {
+    version: u64,
+    path: String,
+    with_version_hint: bool,
+    json: JsonValue,
+}
+
+impl TableMetadata {
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<Self> {
+        let (version, path, with_version_hint) = match op.read(VERSION_HINT).await {
+            Ok(hint) => {
+                let version = String::from_utf8(hint)?.trim().parse::<u64>()?;
+                (
+                    version,
+                    format!("{METADATA_DIR}v{version}.metadata.json"),
+                    true,
+                )
+            }
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                let (version, path) = Self::latest_metadata_file(op).await?.ok_or_else(|| {
+                    ErrorCode::ReadTableDataError("Iceberg table has no metadata file")
+                })?;
+                (version, path, false)
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        Self::read(op, version, path, with_version_hint).await
+    }
+
+    /// Load the given metadata file, the path is relative to the table directory.
+    #[async_backtrace::framed]
+    pub async fn load_file(op: &Operator, path: &str) -> Result<Self> {
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let version = parse_metadata_version(name).ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!("Invalid iceberg metadata file {path}"))
+        })?;
+        Self::read(op, version, path.to_string(), false).await
+    }
+
+    async fn read(
+        op: &Operator,
+        version: u64,
+        path: String,
+        with_version_hint: bool,
+    ) -> Result<Self> {
+        let data = op.read(&path).await?;
+        let json = serde_json::from_slice(&data)?;
+        Ok(TableMetadata {
+            version,
+            path,
+            with_version_hint,
+            json,
+        })
+    }
+
+    async fn latest_metadata_file(op: &Operator) -> Result<Option<(u64, String)>> {
+        let mut latest: Option<(u64, String)> = None;
+        for entry in op.list(METADATA_DIR).await? {
+            let name = entry.name();
+            let Some(version) = parse_metadata_version(name) else {
+                continue;
+            };
+            if latest.as_ref().map_or(true, |(v, _)| version > *v) {
+                latest = Some((version, format!("{METADATA_DIR}{name}")));
+            }
+        }
+        Ok(latest)
+    }
+
+    pub fn location(&self) -> Result<&str> {
+        self.json["location"]
+            .as_str()
+            .map(|v| v.trim_end_matches('/'))
+            .ok_or_else(|| ErrorCode::ReadTableDataError("Iceberg table metadata lacks location"))
+    }
+
+    /// Convert an absolute path in the metadata to the path relative to the table directory.
+    pub fn rel_path(&self, path: &str) -> Result<String> {
+        let location = self.location()?;
+        path.strip_prefix(location)
+            .map(|v| v.trim_start_matches('/').to_string())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Iceberg file {path} is not in the table location {location}"
+                ))
+            })
+    }
+
+    /// Only unpartitioned tables of format version 2 can be written.
+    pub fn check_writable(&self, table_name: &str) -> Result<()> {
+        let format_version = self.json["format-version"].as_i64().unwrap_or(1);
+        if format_version != 2 {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Iceberg table {table_name} is of format version {format_version}, only format version 2 can be written"
+            )));
+        }
+        let spec_id = self.json["default-spec-id"].as_i64().unwrap_or(0);
+        let partitioned = self.json["partition-specs"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|spec| spec["spec-id"].as_i64() == Some(spec_id))
+            .any(|spec| spec["fields"].as_array().is_some_and(|f| !f.is_empty()));
+        if partitioned {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Writing partitioned iceberg table {table_name} is not supported yet"
+            )));
+        }
+        Ok(())
+    }
+
+    fn current_schema(&self) -> Result<&JsonValue> {
+        let schema_id = self.json["current-schema-id"].as_i64().unwrap_or(0);
+        self.json["schemas"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|schema| schema["schema-id"].as_i64() == Some(schema_id))
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Iceberg table metadata lacks the current schema {schema_id}"
+                ))
+            })
+    }
+
+    /// The field ids of the top level columns in the current schema, by name.
+    pub fn field_ids(&self) -> Result<HashMap<String, i32>> {
+        let fields = self.current_schema()?["fields"]
+            .as_array()
+            .ok_or_else(|| ErrorCode::ReadTableDataError("Iceberg table schema lacks fields"))?;
+        Ok(fields
+            .iter()
+            .filter_map(|f| Some((f["name"].as_str()?.to_string(), f["id"].as_i64()? as i32)))
+            .collect())
+    }
+
+    fn current_snapshot(&self) -> Option<&JsonValue> {
+        let snapshot_id = self.json["current-snapshot-id"].as_i64()?;
+        self.json["snapshots"]
+            .as_array()?
+            .iter()
+            .find(|s| s["snapshot-id"].as_i64() == Some(snapshot_id))
+    }
+}
+
+/// `v<N>.metadata.json` or `<N>-<uuid>.metadata.json`.
+fn parse_metadata_version(name: &str) -> Option<u64> {
+    let name = name.strip_suffix(".metadata.json")?;
+    match name.strip_prefix('v') {
+        Some(version) => version.parse().ok(),
+        None => name.split_once('-')?.0.parse().ok(),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SnapshotOperation {
+    Append,
+    Replace,
+    Overwrite,
+    Delete,
+}
+
+impl SnapshotOperation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SnapshotOperation::Append => "append",
+            SnapshotOperation::Replace => "replace",
+            SnapshotOperation::Overwrite => "overwrite",
+            SnapshotOperation::Delete => "delete",
+        }
+    }
+}
+
+/// Commit a new snapshot of an iceberg table, which adds data files and removes data files
+/// of the current snapshot (copy-on-write).
+///
+/// The new metadata file is only written if no one else has committed since the
+/// transaction began, and an existing metadata file is never overwritten. There is no atomic
+/// create on object storages, so the commits of databend are serialized by the table lock and
+/// the checks only guard against other writers.
+pub struct IcebergTransaction {
+    op: Operator,
+    metadata: TableMetadata,
+    added: Vec<DataFile>,
+    removed: HashSet<String>,
+}
+
+impl IcebergTransaction {
+    #[async_backtrace::framed]
+    pub async fn begin(op: Operator) -> Result<Self> {
+        let metadata = TableMetadata::load(&op).await?;
+        Ok(Self::with_metadata(op, metadata))
+    }
+
+    /// Begin on the given metadata instead of the latest one.
+    pub fn with_metadata(op: Operator, metadata: TableMetadata) -> Self {
+        IcebergTransaction {
+            op,
+            metadata,
+            added: vec![],
+            removed: HashSet::new(),
+        }
+    }
+
+    pub fn metadata(&self) -> &TableMetadata {
+        &self.metadata
+    }
+
+    pub fn add_files(&mut self, files: impl IntoIterator<Item = DataFile>) {
+        self.added.extend(files);
+    }
+
+    pub fn remove_file(&mut self, file_path: String) {
+        self.removed.insert(file_path);
+    }
+
+    async fn manifests(&self) -> Result<Vec<ManifestFile>> {
+        let Some(snapshot) = self.metadata.current_snapshot() else {
+            return Ok(vec![]);
+        };
+        let manifest_list = snapshot["manifest-list"]
+            .as_str()
+            .ok_or_else(|| ErrorCode::ReadTableDataError("Iceberg snapshot lacks manifest-list"))?;
+        let data = self
+            .op
+            .read(&self.metadata.rel_path(manifest_list)?)
+            .await?;
+        read_manifest_list(&data)
+    }
+
+    async fn manifest_entries(&self, manifest: &ManifestFile) -> Result<Vec<ManifestEntry>> {
+        let data = self
+            .op
+            .read(&self.metadata.rel_path(&manifest.manifest_path)?)
+            .await?;
+        read_manifest(&data, manifest)
+    }
+
+    /// The data files of the current snapshot.
+    #[async_backtrace::framed]
+    pub async fn data_files(&self) -> Result<Vec<DataFile>> {
+        let mut files = vec![];
+        for manifest in self.manifests().await? {
+            if manifest.content != DATA_FILE_CONTENT {
+                return Err(ErrorCode::Unimplemented(
+                    "Rewriting iceberg tables with delete files is not supported yet",
+                ));
+            }
+            for entry in self.manifest_entries(&manifest).await? {
+                if entry.status != ManifestStatus::Deleted {
+                    files.push(entry.data_file);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn commit(self, operation: SnapshotOperation) -> Result<()> {
+        if self.added.is_empty() && self.removed.is_empty() {
+            return Ok(());
+        }
+
+        let metadata = &self.metadata;
+        let location = metadata.location()?;
+        let parent = metadata.current_snapshot();
+        let parent_snapshot_id = parent.and_then(|s| s["snapshot-id"].as_i64());
+        let snapshot_id = (Uuid::new_v4().as_u128() as i64) & i64::MAX;
+        let sequence_number = metadata.json["last-sequence-number"].as_i64().unwrap_or(0) + 1;
+        let schema = metadata.current_schema()?;
+        let manifest_metadata = ManifestMetadata {
+            schema: schema.to_string(),
+            schema_id: schema["schema-id"].as_i64().unwrap_or(0),
+            partition_spec_id: metadata.json["default-spec-id"].as_i64().unwrap_or(0) as i32,
+        };
+        let commit_uuid = Uuid::new_v4();
+
+        // Keep the manifests without removed files, rewrite the others.
+        let mut manifests = vec![];
+        let mut removed_files = 0;
+        let mut removed_rows = 0;
+        let mut removed_size = 0;
+        let mut rewritten = 0;
+        for manifest in self.manifests().await? {
+            if self.removed.is_empty() {
+                manifests.push(manifest);
+                continue;
+            }
+            let entries = self.manifest_entries(&manifest).await?;
+            if !entries
+                .iter()
+                .any(|e| self.removed.contains(&e.data_file.file_path))
+            {
+                manifests.push(manifest);
+                continue;
+            }
+            if manifest.content != DATA_FILE_CONTENT {
+                return Err(ErrorCode::Unimplemented(
+                    "Rewriting iceberg tables with delete files is not supported yet",
+                ));
+            }
+
+            let mut kept = vec![];
+            for mut entry in entries {
+                if entry.status == ManifestStatus::Deleted {
+                    continue;
+                }
+                if self.removed.contains(&entry.data_file.file_path) {
+                    removed_files += 1;
+                    removed_rows += entry.data_file.record_count;
+                    removed_size += entry.data_file.file_size_in_bytes;
+                } else if entry.partitioned {
+                    return Err(ErrorCode::Unimplemented(
+                        "Writing partitioned iceberg tables is not supported yet",
+                    ));
+                } else {
+                    entry.status = ManifestStatus::Existing;
+                    kept.push(entry);
+                }
+            }
+            if !kept.is_empty() {
+                let path = format!("{METADATA_DIR}{commit_uuid}-m{rewritten}.avro");
+                rewritten += 1;
+                manifests.push(
+                    self.write_manifest(
+                        &path,
+                        kept,
+                        snapshot_id,
+                        sequence_number,
+                        &manifest_metadata,
+                    )
+                    .await?,
+                );
+            }
+        }
+        if removed_files != self.removed.len() {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Some files to remove are not in the current snapshot of iceberg table {location}, please retry"
+            )));
+        }
+
+        if !self.added.is_empty() {
+            let entries = self
+                .added
+                .iter()
+                .map(|file| ManifestEntry {
+                    status: ManifestStatus::Added,
+                    snapshot_id,
+                    sequence_number,
+                    file_sequence_number: sequence_number,
+                    partitioned: false,
+                    data_file: file.clone(),
+                })
+                .collect();
+            let path = format!("{METADATA_DIR}{commit_uuid}-m{rewritten}.avro");
+            manifests.push(
+                self.write_manifest(
+                    &path,
+                    entries,
+                    snapshot_id,
+                    sequence_number,
+                    &manifest_metadata,
+                )
+                .await?,
+            );
+        }
+
+        let manifest_list_path = format!("{METADATA_DIR}snap-{snapshot_id}-1-{commit_uuid}.avro");
+        let data =
+            write_manifest_list(&manifests, snapshot_id, parent_snapshot_id, sequence_number)?;
+        self.op.write(&manifest_list_path, data).await?;
+
+        // Build the summary from the totals of the parent snapshot.
+        let added_rows: i64 = self.added.iter().map(|f| f.record_count).sum();
+        let added_size: i64 = self.added.iter().map(|f| f.file_size_in_bytes).sum();
+        let mut summary = serde_json::Map::new();
+        summary.insert("operation".to_string(), json!(operation.as_str()));
+        let mut counters = vec![
+            ("added-data-files", self.added.len() as i64),
+            ("added-records", added_rows),
+            ("added-files-size", added_size),
+        ];
+        if removed_files > 0 {
+            counters.extend([
+                ("deleted-data-files", removed_files as i64),
+                ("deleted-records", removed_rows),
+                ("removed-files-size", removed_size),
+            ]);
+        }
+        for (total, added, removed) in [
+            ("total-records", added_rows, removed_rows),
+            ("total-files-size", added_size, removed_size),
+            (
+                "total-data-files",
+                self.added.len() as i64,
+                removed_files as i64,
+            ),
+        ] {
+            let previous = match parent {
+                Some(parent) => parent["summary"][total]
+                    .as_str()
+                    .and_then(|v| v.parse::<i64>().ok()),
+                None => Some(0),
+            };
+            if let Some(previous) = previous {
+                counters.push((total, previous + added - removed));
+            }
+        }
+        for (key, value) in counters {
+            summary.insert(key.to_string(), json!(value.to_string()));
+        }
+
+        let now = Utc::now().timestamp_millis();
+        let mut snapshot = json!({
+            "snapshot-id": snapshot_id,
+            "sequence-number": sequence_number,
+            "timestamp-ms": now,
+            "summary": summary,
+            "manifest-list": format!("{location}/{manifest_list_path}"),
+            "schema-id": manifest_metadata.schema_id,
+        });
+        if let Some(parent_snapshot_id) = parent_snapshot_id {
+            snapshot["parent-snapshot-id"] = json!(parent_snapshot_id);
+        }
+
+        let mut json = metadata.json.clone();
+        let previous_updated = json["last-updated-ms"].as_i64().unwrap_or(now);
+        json["last-sequence-number"] = json!(sequence_number);
+        json["last-updated-ms"] = json!(now);
+        json["current-snapshot-id"] = json!(snapshot_id);
+        json["refs"]["main"] = json!({"snapshot-id": snapshot_id, "type": "branch"});
+        push_json(&mut json, "snapshots", snapshot);
+        push_json(
+            &mut json,
+            "snapshot-log",
+            json!({"timestamp-ms": now, "snapshot-id": snapshot_id}),
+        );
+        push_json(
+            &mut json,
+            "metadata-log",
+            json!({"timestamp-ms": previous_updated, "metadata-file": format!("{location}/{}", metadata.path)}),
+        );
+
+        self.write_metadata(json).await
+    }
+
+    async fn write_manifest(
+        &self,
+        path: &str,
+        entries: Vec<ManifestEntry>,
+        snapshot_id: i64,
+        sequence_number: i64,
+        metadata: &ManifestMetadata,
+    ) -> Result<ManifestFile> {
+        let mut manifest = ManifestFile {
+            manifest_path: format!("{}/{path}", self.metadata.location()?),
+            manifest_length: 0,
+            partition_spec_id: metadata.partition_spec_id,
+            content: DATA_FILE_CONTENT,
+            sequence_number,
+            min_sequence_number: entries
+                .iter()
+                .map(|e| match e.status {
+                    ManifestStatus::Added => sequence_number,
+                    _ => e.sequence_number,
+                })
+                .min()
+                .unwrap_or(sequence_number),
+            added_snapshot_id: snapshot_id,
+            added_files_count: 0,
+            existing_files_count: 0,
+            deleted_files_count: 0,
+            added_rows_count: 0,
+            existing_rows_count: 0,
+            deleted_rows_count: 0,
+        };
+        for entry in entries.iter() {
+            let rows = entry.data_file.record_count;
+            match entry.status {
+                ManifestStatus::Added => {
+                    manifest.added_files_count += 1;
+                    manifest.added_rows_count += rows;
+                }
+                ManifestStatus::Existing => {
+                    manifest.existing_files_count += 1;
+                    manifest.existing_rows_count += rows;
+                }
+                ManifestStatus::Deleted => {
+                    manifest.deleted_files_count += 1;
+                    manifest.deleted_rows_count += rows;
+                }
+            }
+        }
+
+        let data = write_manifest(&entries, metadata)?;
+        manifest.manifest_length = data.len() as i64;
+        self.op.write(path, data).await?;
+        Ok(manifest)
+    }
+
+    async fn write_metadata(&self, json: JsonValue) -> Result<()> {
+        let current = &self.metadata;
+        let version = current.version + 1;
+        let path = match current.with_version_hint {
+            true => format!("{METADATA_DIR}v{version}.metadata.json"),
+            false => format!(
+                "{METADATA_DIR}{version:05}-{}.metadata.json",
+                Uuid::new_v4()
+            ),
+        };
+
+        // Someone else has committed since the transaction began.
+        self.check_latest_version().await?;
+        // Never overwrite the metadata file of another commit.
+        if self.op.is_exist(&path).await? {
+            return Err(self.version_mismatched());
+        }
+
+        let data = serde_json::to_vec_pretty(&json)?;
+        self.op.write(&path, data.clone()).await?;
+
+        // A concurrent commit may have written the same file, or a newer version, meanwhile.
+        if self.op.read(&path).await? != data {
+            return Err(self.version_mismatched());
+        }
+        if !current.with_version_hint {
+            let latest = TableMetadata::latest_metadata_file(&self.op).await?;
+            if latest.map_or(true, |(_, latest)| latest != path) {
+                return Err(self.version_mismatched());
+            }
+            return Ok(());
+        }
+
+        self.check_latest_version().await?;
+        self.op.write(VERSION_HINT, version.to_string()).await?;
+        Ok(())
+    }
+
+    async fn check_latest_version(&self) -> Result<()> {
+        let current = &self.metadata;
+        let latest = match current.with_version_hint {
+            true => String::from_utf8(self.op.read(VERSION_HINT).await?)?
+                .trim()
+                .parse::<u64>()?,
+            false => TableMetadata::latest_metadata_file(&self.op)
+                .await?
+                .map_or(0, |(v, _)| v),
+        };
+        match latest == current.version {
+            true => Ok(()),
+            false => Err(self.version_mismatched()),
+        }
+    }
+
+    fn version_mismatched(&self) -> ErrorCode {
+        let location = self.metadata.location().unwrap_or_default();
+        ErrorCode::TableVersionMismatched(format!(
+            "Iceberg table {location} has been changed by others, please retry"
+        ))
+    }
+}
+
+fn push_json(json: &mut JsonValue, key: &str, value: JsonValue) {
+    match json[key].as_array_mut() {
+        Some(array) => array.push(value),
+        None => json[key] = json!([value]),
+    }
+}
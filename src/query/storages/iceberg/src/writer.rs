@@ -0,0 +1,327 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use async_trait::async_trait;
+use async_trait::unboxed_simple;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::PARQUET_FIELD_ID_META_KEY;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use parquet::format::FileMetaData;
+use uuid::Uuid;
+
+use crate::manifest::DataFile;
+use crate::manifest::DATA_FILE_CONTENT;
+use crate::transaction::IcebergTransaction;
+use crate::transaction::SnapshotOperation;
+use crate::transaction::TableMetadata;
+
+/// The in-memory size of the rows buffered for a data file.
+const MAX_BUFFERED_BYTES: usize = 256 * 1024 * 1024;
+
+/// Where the data files of an iceberg table are written.
+#[derive(Clone)]
+pub struct DataFileLocation {
+    /// The table location in the metadata, data files are referenced by absolute paths.
+    location: String,
+    field_ids: HashMap<String, i32>,
+}
+
+impl DataFileLocation {
+    pub fn create(metadata: &TableMetadata) -> Result<Self> {
+        Ok(DataFileLocation {
+            location: metadata.location()?.to_string(),
+            field_ids: metadata.field_ids()?,
+        })
+    }
+}
+
+/// Write the blocks into a parquet data file under `data/`.
+#[async_backtrace::framed]
+pub async fn write_data_file(
+    op: &Operator,
+    target: &DataFileLocation,
+    schema: &TableSchemaRef,
+    blocks: &[DataBlock],
+) -> Result<DataFile> {
+    let block = DataBlock::concat(blocks)?;
+    let num_rows = block.num_rows();
+    let batch = block.to_record_batch(schema)?;
+
+    // Readers map the parquet columns to the table columns by field ids.
+    let fields = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| match target.field_ids.get(f.name()) {
+            Some(id) => {
+                let mut metadata = f.metadata().clone();
+                metadata.insert(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string());
+                ArrowField::clone(f).with_metadata(metadata)
+            }
+            None => ArrowField::clone(f),
+        })
+        .collect::<Vec<_>>();
+    let arrow_schema = Arc::new(ArrowSchema::new(fields));
+    let batch = batch.with_schema(arrow_schema.clone())?;
+
+    // Write a single row group, so its statistics are the bounds of the file.
+    let props = WriterProperties::builder()
+        .set_compression(Compression::ZSTD(ZstdLevel::default()))
+        .set_max_row_group_size(num_rows.max(1))
+        .build();
+    let mut buf = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut buf, arrow_schema, Some(props))?;
+    writer.write(&batch)?;
+    let file_meta = writer.close()?;
+
+    let name = format!("data/{}.parquet", Uuid::new_v4());
+    let mut data_file = DataFile {
+        content: DATA_FILE_CONTENT,
+        file_path: format!("{}/{name}", target.location),
+        file_format: "PARQUET".to_string(),
+        record_count: num_rows as i64,
+        file_size_in_bytes: buf.len() as i64,
+        ..Default::default()
+    };
+    fill_column_stats(&mut data_file, &file_meta, schema, &target.field_ids);
+    op.write(&name, buf).await?;
+    Ok(data_file)
+}
+
+/// Collect the statistics of the top level columns, the bounds are only taken from the
+/// types whose parquet plain encoding is the iceberg single-value serialization.
+fn fill_column_stats(
+    data_file: &mut DataFile,
+    file_meta: &FileMetaData,
+    schema: &TableSchemaRef,
+    field_ids: &HashMap<String, i32>,
+) {
+    let mut column_sizes = vec![];
+    let mut value_counts = vec![];
+    let mut null_value_counts = vec![];
+    let mut lower_bounds = vec![];
+    let mut upper_bounds = vec![];
+    for row_group in file_meta.row_groups.iter() {
+        for column in row_group.columns.iter() {
+            let Some(meta) = &column.meta_data else {
+                continue;
+            };
+            let [name] = meta.path_in_schema.as_slice() else {
+                continue;
+            };
+            let (Some(id), Ok(field)) = (field_ids.get(name), schema.field_with_name(name)) else {
+                continue;
+            };
+            column_sizes.push((*id, meta.total_compressed_size));
+            value_counts.push((*id, meta.num_values));
+            let Some(stats) = &meta.statistics else {
+                continue;
+            };
+            if let Some(null_count) = stats.null_count {
+                null_value_counts.push((*id, null_count));
+            }
+            let plain_bounds = matches!(
+                field.data_type().remove_nullable(),
+                TableDataType::Boolean
+                    | TableDataType::Number(
+                        NumberDataType::Int32
+                            | NumberDataType::Int64
+                            | NumberDataType::Float32
+                            | NumberDataType::Float64
+                    )
+                    | TableDataType::Date
+                    | TableDataType::Timestamp
+                    | TableDataType::String
+                    | TableDataType::Binary
+            );
+            if plain_bounds && file_meta.row_groups.len() == 1 {
+                if let (Some(min), Some(max)) = (&stats.min_value, &stats.max_value) {
+                    lower_bounds.push((*id, min.clone()));
+                    upper_bounds.push((*id, max.clone()));
+                }
+            }
+        }
+    }
+    data_file.column_sizes = Some(column_sizes);
+    data_file.value_counts = Some(value_counts);
+    data_file.null_value_counts = Some(null_value_counts);
+    data_file.lower_bounds = Some(lower_bounds);
+    data_file.upper_bounds = Some(upper_bounds);
+}
+
+/// The data files written by a [`DataFileWriter`].
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DataFilesMeta {
+    pub files: Vec<DataFile>,
+}
+
+#[typetag::serde(name = "iceberg_data_files_meta")]
+impl BlockMetaInfo for DataFilesMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        DataFilesMeta::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// Buffer the appended blocks into parquet data files.
+pub struct DataFileWriter {
+    ctx: Arc<dyn TableContext>,
+    op: Operator,
+    schema: TableSchemaRef,
+    target: Option<DataFileLocation>,
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    files: Vec<DataFile>,
+}
+
+impl DataFileWriter {
+    pub fn create(ctx: Arc<dyn TableContext>, op: Operator, schema: TableSchemaRef) -> Self {
+        DataFileWriter {
+            ctx,
+            op,
+            schema,
+            target: None,
+            blocks: vec![],
+            buffered_bytes: 0,
+            files: vec![],
+        }
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if self.blocks.is_empty() {
+            return Ok(());
+        }
+        if self.target.is_none() {
+            let metadata = TableMetadata::load(&self.op).await?;
+            self.target = Some(DataFileLocation::create(&metadata)?);
+        }
+        let blocks = std::mem::take(&mut self.blocks);
+        self.buffered_bytes = 0;
+        let target = self.target.as_ref().unwrap();
+        let file = write_data_file(&self.op, target, &self.schema, &blocks).await?;
+        self.files.push(file);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AsyncAccumulatingTransform for DataFileWriter {
+    const NAME: &'static str = "IcebergDataFileWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        if data.is_empty() {
+            return Ok(None);
+        }
+        let progress_values = ProgressValues {
+            rows: data.num_rows(),
+            bytes: data.memory_size(),
+        };
+        self.ctx.get_write_progress().incr(&progress_values);
+
+        self.buffered_bytes += data.memory_size();
+        self.blocks.push(data);
+        if self.buffered_bytes >= MAX_BUFFERED_BYTES {
+            self.flush().await?;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.flush().await?;
+        if self.files.is_empty() {
+            return Ok(None);
+        }
+        let meta = DataFilesMeta {
+            files: std::mem::take(&mut self.files),
+        };
+        Ok(Some(DataBlock::empty_with_meta(Box::new(meta))))
+    }
+}
+
+/// Commit the data files written by the [`DataFileWriter`]s as a new snapshot.
+pub struct CommitSink {
+    op: Operator,
+    table_name: String,
+    overwrite: bool,
+    files: Vec<DataFile>,
+}
+
+impl CommitSink {
+    pub fn create(op: Operator, table_name: String, overwrite: bool) -> Self {
+        CommitSink {
+            op,
+            table_name,
+            overwrite,
+            files: vec![],
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncSink for CommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let mut txn = IcebergTransaction::begin(self.op.clone()).await?;
+        txn.metadata().check_writable(&self.table_name)?;
+
+        let operation = if self.overwrite {
+            for file in txn.data_files().await? {
+                txn.remove_file(file.file_path);
+            }
+            SnapshotOperation::Overwrite
+        } else {
+            SnapshotOperation::Append
+        };
+        txn.add_files(std::mem::take(&mut self.files));
+        txn.commit(operation).await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, mut data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .take_meta()
+            .and_then(DataFilesMeta::downcast_from)
+        {
+            self.files.extend(meta.files);
+        }
+        Ok(false)
+    }
+}
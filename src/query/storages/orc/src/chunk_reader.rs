@@ -0,0 +1,65 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use opendal::Operator;
+use orc_rust::reader::AsyncChunkReader;
+
+/// Reads byte ranges of an ORC file from a stage operator.
+///
+/// The ORC reader fetches the file tail first and then only the streams of the
+/// stripes it's asked to decode, so each call maps to a ranged read.
+#[derive(Clone)]
+pub struct OrcChunkReader {
+    operator: Operator,
+    path: String,
+    size: u64,
+}
+
+impl OrcChunkReader {
+    pub fn new(operator: Operator, path: String, size: u64) -> Self {
+        Self {
+            operator,
+            path,
+            size,
+        }
+    }
+}
+
+impl AsyncChunkReader for OrcChunkReader {
+    fn len(&mut self) -> BoxFuture<'_, std::io::Result<u64>> {
+        let size = self.size;
+        async move { Ok(size) }.boxed()
+    }
+
+    fn get_bytes(
+        &mut self,
+        offset_from_start: u64,
+        length: u64,
+    ) -> BoxFuture<'_, std::io::Result<Bytes>> {
+        async move {
+            let range = offset_from_start..(offset_from_start + length);
+            let buf = self
+                .operator
+                .read_with(&self.path)
+                .range(range)
+                .await
+                .map_err(std::io::Error::from)?;
+            Ok(Bytes::from(buf))
+        }
+        .boxed()
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![allow(clippy::uninlined_format_args)]
+
+mod chunk_reader;
+mod partition;
+mod pruning;
+mod schema;
+mod source;
+mod table;
+
+pub use chunk_reader::OrcChunkReader;
+pub use partition::OrcStripePart;
+pub use pruning::OrcStripePruner;
+pub use schema::infer_orc_schema;
+pub use schema::read_orc_metadata;
+pub use table::OrcTable;
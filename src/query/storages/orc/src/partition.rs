@@ -0,0 +1,64 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// A stripe of an ORC file, which is the unit of parallelism when reading ORC.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct OrcStripePart {
+    pub location: String,
+    pub file_size: u64,
+    pub stripe_index: usize,
+    /// Byte offset of the stripe in the file.
+    pub offset: u64,
+    /// Total length of the index, data and footer of the stripe.
+    pub length: u64,
+    pub num_rows: u64,
+}
+
+#[typetag::serde(name = "orc_stripe_part")]
+impl PartInfo for OrcStripePart {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<OrcStripePart>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.location.hash(&mut s);
+        self.stripe_index.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl OrcStripePart {
+    pub fn from_part(info: &PartInfoPtr) -> Result<&OrcStripePart> {
+        info.as_any()
+            .downcast_ref::<OrcStripePart>()
+            .ok_or_else(|| ErrorCode::Internal("Cannot downcast from PartInfo to OrcStripePart."))
+    }
+}
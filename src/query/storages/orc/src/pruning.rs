@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnId;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use orc_rust::schema::RootDataType;
+use orc_rust::statistics::ColumnStatistics as OrcColumnStatistics;
+use orc_rust::statistics::TypeStatistics;
+use orc_rust::stripe::StripeMetadata;
+
+/// A pruner to skip the stripes of ORC files by the statistics in the file footer.
+pub struct OrcStripePruner {
+    range_pruner: Option<Arc<dyn RangePruner + Send + Sync>>,
+    /// Top level primitive columns referred by the filter: (name, column id, data type).
+    predicate_columns: Vec<(String, ColumnId, TableDataType)>,
+}
+
+impl OrcStripePruner {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        schema: TableSchemaRef,
+        push_down: &Option<PushDownInfo>,
+    ) -> Result<Self> {
+        let filter = push_down.as_ref().and_then(|p| p.filters.as_ref());
+        let Some(filter) = filter else {
+            return Ok(OrcStripePruner {
+                range_pruner: None,
+                predicate_columns: vec![],
+            });
+        };
+
+        let filter_expr = filter.filter.as_expr(&BUILTIN_FUNCTIONS);
+        let predicate_columns = filter_expr
+            .column_refs()
+            .into_keys()
+            .filter_map(|name| {
+                let field = schema
+                    .fields()
+                    .iter()
+                    .find(|f| f.name().eq_ignore_ascii_case(&name))?;
+                let data_type = field.data_type().remove_nullable();
+                // Only the statistics of primitive columns are useful for pruning.
+                if matches!(
+                    data_type,
+                    TableDataType::Number(_) | TableDataType::String | TableDataType::Date
+                ) {
+                    Some((field.name().clone(), field.column_id(), data_type))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        let range_pruner = if predicate_columns.is_empty() {
+            None
+        } else {
+            Some(RangePrunerCreator::try_create(
+                func_ctx,
+                &schema,
+                Some(&filter_expr),
+            )?)
+        };
+
+        Ok(OrcStripePruner {
+            range_pruner,
+            predicate_columns,
+        })
+    }
+
+    /// Returns false if no row of the stripe could match the filter.
+    pub fn should_keep(&self, root: &RootDataType, stripe: &StripeMetadata) -> bool {
+        let Some(pruner) = &self.range_pruner else {
+            return true;
+        };
+        let statistics = stripe.column_statistics();
+        let mut stats: StatisticsOfColumns = HashMap::with_capacity(self.predicate_columns.len());
+        for (name, column_id, data_type) in self.predicate_columns.iter() {
+            let column_index = root
+                .children()
+                .iter()
+                .find(|c| c.name().eq_ignore_ascii_case(name))
+                .map(|c| c.data_type().column_index());
+            let column_stats = column_index
+                .and_then(|i| statistics.get(i))
+                .and_then(|s| convert_column_statistics(s, data_type, stripe.number_of_rows()));
+            if let Some(column_stats) = column_stats {
+                stats.insert(*column_id, column_stats);
+            }
+        }
+        pruner.should_keep(&stats, None)
+    }
+}
+
+fn convert_column_statistics(
+    s: &OrcColumnStatistics,
+    typ: &TableDataType,
+    num_rows: u64,
+) -> Option<ColumnStatistics> {
+    let (min, max) = match (s.type_statistics()?, typ) {
+        (TypeStatistics::Integer { min, max, .. }, TableDataType::Number(number)) => {
+            let (min, max) = (*min, *max);
+            match number {
+                NumberDataType::Int8 => (Scalar::from(min as i8), Scalar::from(max as i8)),
+                NumberDataType::Int16 => (Scalar::from(min as i16), Scalar::from(max as i16)),
+                NumberDataType::Int32 => (Scalar::from(min as i32), Scalar::from(max as i32)),
+                NumberDataType::Int64 => (Scalar::from(min), Scalar::from(max)),
+                _ => return None,
+            }
+        }
+        (TypeStatistics::Double { min, max, .. }, TableDataType::Number(number)) => match number {
+            NumberDataType::Float32 => (Scalar::from(*min as f32), Scalar::from(*max as f32)),
+            NumberDataType::Float64 => (Scalar::from(*min), Scalar::from(*max)),
+            _ => return None,
+        },
+        (TypeStatistics::String { min, max, .. }, TableDataType::String) => {
+            (Scalar::String(min.clone()), Scalar::String(max.clone()))
+        }
+        (TypeStatistics::Date { min, max }, TableDataType::Date) => {
+            (Scalar::Date(*min), Scalar::Date(*max))
+        }
+        _ => return None,
+    };
+    // ORC records the count of non-null values, the rest of the stripe are nulls.
+    let null_count = num_rows.saturating_sub(s.number_of_values());
+    Some(ColumnStatistics::new(min, max, null_count, 0, None))
+}
@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::Array;
+use arrow_array::ArrayRef;
+use arrow_array::RecordBatch;
+use arrow_array::StructArray;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use opendal::Operator;
+use orc_rust::arrow_reader::NaiveStripeDecoder;
+use orc_rust::projection::ProjectionMask;
+use orc_rust::reader::metadata::FileMetadata;
+use orc_rust::stripe::Stripe;
+use parking_lot::Mutex;
+use tokio::sync::OnceCell;
+
+use crate::chunk_reader::OrcChunkReader;
+use crate::partition::OrcStripePart;
+use crate::schema::read_orc_metadata;
+
+pub struct OrcSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    // Used to read orc.
+    operator: Operator,
+    output_schema: DataSchemaRef,
+    batch_size: usize,
+    meta_cache: Arc<OrcMetaCache>,
+    stream: Option<NaiveStripeDecoder>,
+}
+
+impl OrcSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        operator: Operator,
+        output_schema: DataSchemaRef,
+        meta_cache: Arc<OrcMetaCache>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        let batch_size = ctx.get_settings().get_max_block_size()? as usize;
+        Ok(ProcessorPtr::create(Box::new(OrcSource {
+            output,
+            scan_progress,
+            ctx,
+            operator,
+            output_schema,
+            batch_size,
+            meta_cache,
+            stream: None,
+            generated_data: None,
+            is_finished: false,
+        })))
+    }
+
+    /// Read the stripe of `part` and return a decoder of its batches.
+    async fn open_stripe(&self, part: &OrcStripePart) -> Result<NaiveStripeDecoder> {
+        let metadata = self
+            .meta_cache
+            .get(&self.operator, &part.location, part.file_size)
+            .await?;
+
+        // Names in the table schema are lower cased, map them back to the names in the file.
+        let root = metadata.root_data_type();
+        let mut roots = Vec::with_capacity(self.output_schema.num_fields());
+        for field in self.output_schema.fields() {
+            let name = field.name().split(':').next().unwrap();
+            let column = root
+                .children()
+                .iter()
+                .find(|c| c.name().eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    ErrorCode::TableSchemaMismatch(format!(
+                        "Cannot find column {} in orc file {}",
+                        name, part.location
+                    ))
+                })?;
+            if !roots.iter().any(|r: &String| r == column.name()) {
+                roots.push(column.name().to_string());
+            }
+        }
+        let projected = root.project(&ProjectionMask::named_roots(root, &roots));
+        let arrow_schema = Arc::new(projected.create_arrow_schema(&HashMap::new()));
+
+        let info = metadata
+            .stripe_metadatas()
+            .get(part.stripe_index)
+            .ok_or_else(|| {
+                orc_error(
+                    &part.location,
+                    format!("stripe {} does not exist", part.stripe_index),
+                )
+            })?;
+        let mut reader =
+            OrcChunkReader::new(self.operator.clone(), part.location.clone(), part.file_size);
+        let stripe = Stripe::new_async(&mut reader, &metadata, &projected, part.stripe_index, info)
+            .await
+            .map_err(|e| orc_error(&part.location, e))?;
+        NaiveStripeDecoder::new(stripe, arrow_schema, self.batch_size)
+            .map_err(|e| orc_error(&part.location, e))
+    }
+}
+
+/// The metadata of the orc files read by the sources of a scan.
+///
+/// A file is usually split into several stripe partitions, the tail of the file
+/// is read by the first source which opens one of them and shared with the others.
+#[derive(Default)]
+pub struct OrcMetaCache {
+    files: Mutex<HashMap<String, Arc<OnceCell<Arc<FileMetadata>>>>>,
+}
+
+impl OrcMetaCache {
+    async fn get(
+        &self,
+        operator: &Operator,
+        location: &str,
+        size: u64,
+    ) -> Result<Arc<FileMetadata>> {
+        let cell = self
+            .files
+            .lock()
+            .entry(location.to_string())
+            .or_default()
+            .clone();
+        cell.get_or_try_init(|| async {
+            read_orc_metadata(operator, location, Some(size))
+                .await
+                .map(Arc::new)
+        })
+        .await
+        .cloned()
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for OrcSource {
+    fn name(&self) -> String {
+        "OrcSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => Ok(Event::Async),
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                Profile::record_usize_profile(
+                    ProfileStatisticsName::ScanBytes,
+                    data_block.memory_size(),
+                );
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(mut stream) = self.stream.take() {
+            if let Some(batch) = stream.next() {
+                let batch = batch.map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+                self.generated_data = Some(transform_record_batch(&self.output_schema, &batch)?);
+                self.stream = Some(stream);
+            }
+            // else:
+            // The stripe is finished, try to open another one (in next event loop).
+        } else if let Some(part) = self.ctx.get_partition() {
+            let part = OrcStripePart::from_part(&part)?;
+            self.stream = Some(self.open_stripe(part).await?);
+        } else {
+            self.is_finished = true;
+        }
+
+        Ok(())
+    }
+}
+
+/// Transform a [`RecordBatch`] to [`DataBlock`] by the names in the output schema.
+///
+/// Names of inner columns are joined with `:`, e.g. `a:b` is the field `b` of the tuple `a`.
+fn transform_record_batch(schema: &DataSchemaRef, batch: &RecordBatch) -> Result<DataBlock> {
+    if schema.num_fields() == 0 {
+        return Ok(DataBlock::new(vec![], batch.num_rows()));
+    }
+    let mut columns = Vec::with_capacity(schema.num_fields());
+    for field in schema.fields() {
+        columns.push(traverse_column(field, batch)?);
+    }
+    Ok(DataBlock::new_from_columns(columns))
+}
+
+fn traverse_column(field: &DataField, batch: &RecordBatch) -> Result<Column> {
+    let mut names = field.name().split(':');
+    let name = names.next().unwrap();
+    let position = batch
+        .schema()
+        .fields()
+        .iter()
+        .position(|f| f.name().eq_ignore_ascii_case(name))
+        .ok_or_else(|| error_cannot_find_field(field))?;
+    let mut array: ArrayRef = batch.column(position).clone();
+    for name in names {
+        let struct_array = array
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .ok_or_else(|| error_cannot_find_field(field))?;
+        let position = struct_array
+            .fields()
+            .iter()
+            .position(|f| f.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| error_cannot_find_field(field))?;
+        array = struct_array.column(position).clone();
+    }
+    Column::from_arrow_rs(array, field.data_type())
+}
+
+fn error_cannot_find_field(field: &DataField) -> ErrorCode {
+    ErrorCode::TableSchemaMismatch(format!(
+        "Cannot find field {} in the orc file",
+        field.name()
+    ))
+}
+
+fn orc_error(path: &str, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read orc file {}: {}", path, e))
+}
@@ -0,0 +1,309 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow_schema::Schema as ArrowSchema;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::runtime::execute_futures_in_parallel;
+use databend_common_catalog::plan::DataSourceInfo;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::OrcTableInfo;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::query_kind::QueryKind;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::FileStatus;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use opendal::Operator;
+
+use crate::partition::OrcStripePart;
+use crate::pruning::OrcStripePruner;
+use crate::schema::arrow_to_table_schema;
+use crate::schema::orc_to_arrow_schema;
+use crate::schema::read_orc_metadata;
+use crate::source::OrcMetaCache;
+use crate::source::OrcSource;
+
+/// Reads ORC files in a stage, each stripe of the files is a partition.
+pub struct OrcTable {
+    stage_info: StageInfo,
+    files_info: StageFilesInfo,
+    operator: Operator,
+
+    table_info: TableInfo,
+    arrow_schema: ArrowSchema,
+    files_to_read: Option<Vec<StageFileInfo>>,
+    schema_from: String,
+}
+
+impl OrcTable {
+    pub fn from_info(info: &OrcTableInfo) -> Result<Arc<dyn Table>> {
+        let operator = init_stage_operator(&info.stage_info)?;
+
+        Ok(Arc::new(OrcTable {
+            stage_info: info.stage_info.clone(),
+            files_info: info.files_info.clone(),
+            operator,
+            table_info: info.table_info.clone(),
+            arrow_schema: info.arrow_schema.clone(),
+            files_to_read: info.files_to_read.clone(),
+            schema_from: info.schema_from.clone(),
+        }))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn create(
+        stage_info: StageInfo,
+        files_info: StageFilesInfo,
+        files_to_read: Option<Vec<StageFileInfo>>,
+    ) -> Result<Arc<dyn Table>> {
+        let operator = init_stage_operator(&stage_info)?;
+        let first_file = match &files_to_read {
+            Some(files) => files[0].clone(),
+            None => files_info.first_file(&operator).await?,
+        };
+
+        // Infer schema from the first orc file.
+        // Assume all orc files have the same schema.
+        // If not, throw error during reading.
+        let metadata =
+            read_orc_metadata(&operator, &first_file.path, Some(first_file.size)).await?;
+        let arrow_schema = orc_to_arrow_schema(&metadata);
+        let table_info = create_orc_table_info(&arrow_schema, &stage_info)?;
+
+        Ok(Arc::new(OrcTable {
+            stage_info,
+            files_info,
+            operator,
+            table_info,
+            arrow_schema,
+            files_to_read,
+            schema_from: first_file.path,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let thread_num = ctx.get_settings().get_max_threads()? as usize;
+        let files = match &self.files_to_read {
+            Some(files) => files
+                .iter()
+                .map(|f| (f.path.clone(), f.size))
+                .collect::<Vec<_>>(),
+            None => self
+                .files_info
+                .list(&self.operator, thread_num, false, None)
+                .await?
+                .into_iter()
+                .map(|f| (f.path, f.size))
+                .collect::<Vec<_>>(),
+        };
+
+        let pruner = Arc::new(OrcStripePruner::try_create(
+            ctx.get_function_context()?,
+            self.schema(),
+            &push_downs,
+        )?);
+        let copy_status = if matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable) {
+            Some(ctx.get_copy_status())
+        } else {
+            None
+        };
+
+        // Read the footers in parallel, and keep the stripes which may match the filter.
+        let num_files = files.len();
+        let mut tasks = Vec::with_capacity(thread_num);
+        for i in 0..thread_num {
+            let begin = num_files * i / thread_num;
+            let end = num_files * (i + 1) / thread_num;
+            if begin == end {
+                continue;
+            }
+            let files = files[begin..end].to_vec();
+            let operator = self.operator.clone();
+            let pruner = pruner.clone();
+            tasks.push(async move {
+                let mut stats = PartStatistics::default_exact();
+                let mut parts = vec![];
+                for (location, size) in files {
+                    let metadata = read_orc_metadata(&operator, &location, Some(size)).await?;
+                    let root = metadata.root_data_type();
+                    let mut rows_read = 0;
+                    for (stripe_index, stripe) in metadata.stripe_metadatas().iter().enumerate() {
+                        stats.partitions_total += 1;
+                        if !pruner.should_keep(root, stripe) {
+                            continue;
+                        }
+                        let length =
+                            stripe.index_length() + stripe.data_length() + stripe.footer_length();
+                        stats.partitions_scanned += 1;
+                        stats.read_rows += stripe.number_of_rows() as usize;
+                        stats.read_bytes += length as usize;
+                        rows_read += stripe.number_of_rows() as usize;
+                        parts.push(OrcStripePart {
+                            location: location.clone(),
+                            file_size: size,
+                            stripe_index,
+                            offset: stripe.offset(),
+                            length,
+                            num_rows: stripe.number_of_rows(),
+                        });
+                    }
+                    if let Some(copy_status) = &copy_status {
+                        copy_status.add_chunk(&location, FileStatus {
+                            num_rows_loaded: rows_read,
+                            error: None,
+                        });
+                    }
+                }
+                Ok::<_, ErrorCode>((stats, parts))
+            });
+        }
+
+        let (stats, parts) = execute_futures_in_parallel(
+            tasks,
+            thread_num,
+            thread_num * 2,
+            "read-orc-metas-worker".to_owned(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .reduce(|(mut stats_acc, mut parts_acc), (stats, parts)| {
+            stats_acc.merge(&stats);
+            parts_acc.extend(parts);
+            (stats_acc, parts_acc)
+        })
+        .unwrap_or((PartStatistics::default_exact(), vec![]));
+
+        let parts = parts
+            .into_iter()
+            .map(|p| Arc::new(Box::new(p) as Box<dyn PartInfo>))
+            .collect();
+        Ok((stats, Partitions::create(PartitionsShuffleKind::Mod, parts)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for OrcTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    fn has_exact_total_row_count(&self) -> bool {
+        true
+    }
+
+    fn get_data_source_info(&self) -> DataSourceInfo {
+        DataSourceInfo::OrcSource(OrcTableInfo {
+            stage_info: self.stage_info.clone(),
+            files_info: self.files_info.clone(),
+            table_info: self.table_info.clone(),
+            arrow_schema: self.arrow_schema.clone(),
+            files_to_read: self.files_to_read.clone(),
+            schema_from: self.schema_from.clone(),
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let num_threads = std::cmp::min(plan.parts.len(), max_threads).max(1);
+        let output_schema = Arc::new(DataSchema::from(plan.schema().as_ref()));
+        let meta_cache = Arc::new(OrcMetaCache::default());
+
+        pipeline.add_source(
+            |output| {
+                OrcSource::create(
+                    ctx.clone(),
+                    output,
+                    self.operator.clone(),
+                    output_schema.clone(),
+                    meta_cache.clone(),
+                )
+            },
+            num_threads,
+        )
+    }
+
+    fn is_stage_table(&self) -> bool {
+        true
+    }
+}
+
+fn create_orc_table_info(schema: &ArrowSchema, stage_info: &StageInfo) -> Result<TableInfo> {
+    Ok(TableInfo {
+        ident: TableIdent::new(0, 0),
+        desc: "''.'read_orc'".to_string(),
+        name: format!("read_orc({})", stage_info.stage_name),
+        meta: TableMeta {
+            schema: arrow_to_table_schema(schema)?.into(),
+            engine: "SystemReadOrc".to_string(),
+            created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+}
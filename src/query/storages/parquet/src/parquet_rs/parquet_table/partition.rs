@@ -22,6 +22,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table::Table;
@@ -77,6 +78,11 @@ impl ParquetRSTable {
         // If a file size is less than `parquet_fast_read_bytes`,
         // we treat it as a small file and it will be totally loaded into memory.
         let fast_read_bytes = ctx.get_settings().get_parquet_fast_read_bytes()?;
+        // `TABLESAMPLE BLOCK` samples the small files and the row groups of the large files.
+        let sample = push_down
+            .as_ref()
+            .and_then(|p| p.sample.as_ref())
+            .filter(|sample| sample.level == SampleLevel::Block);
         let mut large_files = vec![];
         let mut large_file_indices = vec![];
         let mut small_file_indices = vec![];
@@ -85,7 +91,7 @@ impl ParquetRSTable {
             if size > fast_read_bytes {
                 large_files.push((location, size));
                 large_file_indices.push(index);
-            } else {
+            } else if sample.map_or(true, |sample| sample.sample_part(&location)) {
                 small_files.push((location, size));
                 small_file_indices.push(index);
             }
@@ -156,6 +162,19 @@ impl ParquetRSTable {
             .await?
         };
 
+        if let Some(sample) = sample {
+            partitions
+                .partitions
+                .retain(|part| match ParquetPart::from_part(part) {
+                    Ok(ParquetPart::ParquetRSRowGroup(part)) => {
+                        let offset = part.meta.column(0).file_offset();
+                        sample.sample_part(&format!("{}:{}", part.location, offset))
+                    }
+                    _ => true,
+                });
+            stats.is_exact = false;
+        }
+
         // If there are only row group parts, the `stats` is exact.
         // It will be changed to `false` if there are small files parts.
         if !small_files.is_empty() {
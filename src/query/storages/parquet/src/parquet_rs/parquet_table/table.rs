@@ -202,6 +202,10 @@ impl Table for ParquetRSTable {
         self.read_options.do_prewhere()
     }
 
+    fn support_block_sample(&self) -> bool {
+        true
+    }
+
     fn has_exact_total_row_count(&self) -> bool {
         true
     }
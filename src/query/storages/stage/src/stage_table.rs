@@ -18,10 +18,12 @@ use std::sync::Arc;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::SampleLevel;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
@@ -32,8 +34,10 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::input_formats::SplitInfo;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
+use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use opendal::Operator;
 
@@ -146,20 +150,35 @@ impl Table for StageTable {
     async fn read_partitions(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
         let settings = ctx.get_settings();
         let stage_table_info = &self.table_info;
-        match stage_table_info.stage_info.file_format_params {
+        let sample = push_downs
+            .as_ref()
+            .and_then(|p| p.sample.clone())
+            .filter(|sample| sample.level == SampleLevel::Block);
+        let (statistics, mut partitions) = match stage_table_info.stage_info.file_format_params {
             FileFormatParams::Parquet(_) => {
-                ParquetTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
+                ParquetTableForCopy::do_read_partitions(stage_table_info, ctx, push_downs).await?
             }
             FileFormatParams::Csv(_) if settings.get_enable_new_copy_for_text_formats()? == 1 => {
-                self.read_partitions_simple(ctx, stage_table_info).await
+                self.read_partitions_simple(ctx, stage_table_info).await?
             }
-            _ => self.read_partition_old(&ctx).await,
+            _ => self.read_partition_old(&ctx).await?,
+        };
+        // `TABLESAMPLE BLOCK` samples the files (or the splits of the files).
+        if let Some(sample) = sample {
+            partitions
+                .partitions
+                .retain(|part| sample.sample_part(&sample_key(part)));
         }
+        Ok((statistics, partitions))
+    }
+
+    fn support_block_sample(&self) -> bool {
+        true
     }
 
     fn is_local(&self) -> bool {
@@ -213,3 +232,20 @@ impl Table for StageTable {
         ))
     }
 }
+
+/// The key of a part for `TABLESAMPLE BLOCK`, it must be stable across queries.
+fn sample_key(part: &PartInfoPtr) -> String {
+    if let Some(part) = part.as_any().downcast_ref::<OneFilePartition>() {
+        return part.path.clone();
+    }
+    if let Some(split) = part.as_any().downcast_ref::<SplitInfo>() {
+        return format!("{}:{}", split.file.path, split.seq_in_file);
+    }
+    match ParquetPart::from_part(part) {
+        Ok(ParquetPart::ParquetFiles(part)) => part.files[0].0.clone(),
+        Ok(ParquetPart::ParquetRSRowGroup(part)) => {
+            format!("{}:{}", part.location, part.meta.column(0).file_offset())
+        }
+        Err(_) => part.hash().to_string(),
+    }
+}
@@ -13,10 +13,10 @@
 // limitations under the License.
 
 use std::any::Any;
-use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_base::base::tokio::sync::OnceCell;
 use databend_common_catalog::catalog::StorageDescription;
 use databend_common_catalog::plan::block_id_from_location;
 use databend_common_catalog::plan::DataSourcePlan;
@@ -48,11 +48,10 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::binder::STREAM_COLUMN_FACTORY;
 use databend_common_storages_fuse::io::SegmentsIO;
-use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::operations::collect_incremental_blocks;
 use databend_common_storages_fuse::pruning::FusePruner;
 use databend_common_storages_fuse::FuseTable;
-use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
@@ -79,6 +78,10 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+
+    /// The blocks removed from and added to the source table, they are collected once
+    /// and shared by the planning and the reading of the stream.
+    incremental_blocks: OnceCell<(Vec<Arc<BlockMeta>>, Vec<Arc<BlockMeta>>)>,
 }
 
 impl StreamTable {
@@ -113,6 +116,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            incremental_blocks: OnceCell::new(),
         }))
     }
 
@@ -198,6 +202,36 @@ impl StreamTable {
         &self.table_database
     }
 
+    /// Collects the blocks removed from and added to the source table since the offset of
+    /// the stream. Blocks shared by the two snapshots are excluded, so compacting segments
+    /// does not produce any changes.
+    #[async_backtrace::framed]
+    async fn collect_incremental_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        fuse_table: &FuseTable,
+    ) -> Result<(Vec<Arc<BlockMeta>>, Vec<Arc<BlockMeta>>)> {
+        let blocks = self
+            .incremental_blocks
+            .get_or_try_init(|| async {
+                let fuse_segment_io =
+                    SegmentsIO::create(ctx.clone(), fuse_table.get_operator(), self.schema());
+
+                let latest_snapshot = fuse_table.snapshot_loc().await?;
+                let (_, del_blocks, add_blocks) = collect_incremental_blocks(
+                    ctx,
+                    fuse_segment_io,
+                    fuse_table.get_operator(),
+                    &latest_snapshot,
+                    &self.snapshot_location,
+                )
+                .await?;
+                Ok::<_, ErrorCode>((del_blocks, add_blocks))
+            })
+            .await?;
+        Ok(blocks.clone())
+    }
+
     #[async_backtrace::framed]
     async fn do_read_partitions(
         &self,
@@ -208,18 +242,9 @@ impl StreamTable {
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
-        let fuse_segment_io =
-            SegmentsIO::create(ctx.clone(), fuse_table.get_operator(), self.schema());
-
-        let latest_snapshot = fuse_table.snapshot_loc().await?;
-        let (_, del_blocks, add_blocks) = collect_incremental_blocks(
-            ctx.clone(),
-            fuse_segment_io,
-            fuse_table.get_operator(),
-            &latest_snapshot,
-            &self.snapshot_location,
-        )
-        .await?;
+        let (del_blocks, add_blocks) = self
+            .collect_incremental_blocks(ctx.clone(), fuse_table)
+            .await?;
 
         let change_type = push_downs.as_ref().map_or(ChangeType::Append, |v| {
             v.change_type.clone().unwrap_or(ChangeType::Append)
@@ -334,6 +359,15 @@ impl Table for StreamTable {
         true
     }
 
+    // The blocks and rows are sampled by the source fuse table.
+    fn support_block_sample(&self) -> bool {
+        true
+    }
+
+    fn support_row_sample(&self) -> bool {
+        true
+    }
+
     fn stream_columns(&self) -> Vec<StreamColumn> {
         vec![
             STREAM_COLUMN_FACTORY
@@ -360,27 +394,14 @@ impl Table for StreamTable {
                     return Ok(StreamMode::AppendOnly);
                 }
 
-                let table = self.source_table(ctx).await?;
+                let table = self.source_table(ctx.clone()).await?;
                 let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                let (del_blocks, _) = self.collect_incremental_blocks(ctx, fuse_table).await?;
 
-                let latest_snapshot = fuse_table.read_table_snapshot().await?;
-                if latest_snapshot.is_none() {
-                    return Ok(StreamMode::Standard);
-                }
-                let latest_snapshot = latest_snapshot.unwrap();
-                let latest_segments: HashSet<&Location> =
-                    HashSet::from_iter(&latest_snapshot.segments);
-
-                let (base_snapshot, _) = SnapshotsIO::read_snapshot(
-                    self.snapshot_location.clone().unwrap(),
-                    fuse_table.get_operator(),
-                )
-                .await?;
-                let base_segments = HashSet::from_iter(&base_snapshot.segments);
-
-                // If the base segments are a subset of the latest segments,
-                // then the stream is treated as append only.
-                if base_segments.is_subset(&latest_segments) {
+                // Rows can only be deleted or updated by rewriting or removing the blocks
+                // holding them. If none of the base blocks is gone, the stream is treated
+                // as append only.
+                if del_blocks.is_empty() {
                     Ok(StreamMode::AppendOnly)
                 } else {
                     Ok(StreamMode::Standard)
@@ -423,78 +444,38 @@ impl Table for StreamTable {
     ) -> Result<Option<TableStatistics>> {
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
-        let change_type = change_type.unwrap_or(ChangeType::Append);
-
-        let base_summary = if let Some(snapshot_location) = &self.snapshot_location {
-            let (base_snapshot, _) =
-                SnapshotsIO::read_snapshot(snapshot_location.clone(), fuse_table.get_operator())
-                    .await?;
-            base_snapshot.summary.clone()
-        } else {
+        if self.snapshot_location.is_none() {
             return fuse_table.table_statistics(ctx, None).await;
-        };
-
-        let latest_summary = if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
-            snapshot.summary.clone()
-        } else {
-            return Ok(None);
-        };
+        }
 
-        let num_rows = latest_summary.row_count.abs_diff(base_summary.row_count);
-        let data_size = latest_summary
-            .uncompressed_byte_size
-            .abs_diff(base_summary.uncompressed_byte_size);
-        let data_size_compressed = latest_summary
-            .compressed_byte_size
-            .abs_diff(base_summary.compressed_byte_size);
-        let index_size = latest_summary.index_size.abs_diff(base_summary.index_size);
-        let number_of_blocks = latest_summary
-            .block_count
-            .abs_diff(base_summary.block_count);
-        let max_stats = || {
-            Some(TableStatistics {
-                num_rows: Some(num_rows),
-                data_size: Some(data_size),
-                data_size_compressed: Some(data_size_compressed),
-                index_size: Some(index_size),
-                number_of_blocks: Some(number_of_blocks),
-                number_of_segments: None,
-            })
-        };
-        // The following statistics are predicted, which may have a large bias;
-        // mainly used to determine the join order
-        let min_stats = || {
-            Some(TableStatistics {
-                num_rows: Some(num_rows / 2),
-                data_size: Some(data_size / 2),
-                data_size_compressed: Some(data_size_compressed / 2),
-                index_size: Some(index_size / 2),
-                number_of_blocks: Some(number_of_blocks / 2),
-                number_of_segments: None,
-            })
+        let (del_blocks, add_blocks) = self
+            .collect_incremental_blocks(ctx.clone(), fuse_table)
+            .await?;
+        // The statistics are the upper bound of the changes, the rows of the blocks
+        // rewritten without any changes (e.g. by compaction) are filtered out later.
+        let blocks = match change_type.unwrap_or(ChangeType::Append) {
+            ChangeType::Append | ChangeType::Insert => add_blocks,
+            ChangeType::Delete => del_blocks,
         };
 
-        match change_type {
-            ChangeType::Append => Ok(max_stats()),
-            ChangeType::Insert => {
-                // If the number of rows is greater than the base,
-                // it means that the insertion is more than the deletion.
-                if latest_summary.row_count > base_summary.row_count {
-                    Ok(max_stats())
-                } else {
-                    Ok(min_stats())
-                }
-            }
-            ChangeType::Delete => {
-                // If the number of rows is less than the base,
-                // it means that the deletion is more than the insertion.
-                if latest_summary.row_count < base_summary.row_count {
-                    Ok(max_stats())
-                } else {
-                    Ok(min_stats())
-                }
-            }
+        let mut num_rows = 0;
+        let mut data_size = 0;
+        let mut data_size_compressed = 0;
+        let mut index_size = 0;
+        for block in blocks.iter() {
+            num_rows += block.row_count;
+            data_size += block.block_size;
+            data_size_compressed += block.file_size;
+            index_size += block.bloom_filter_index_size;
         }
+        Ok(Some(TableStatistics {
+            num_rows: Some(num_rows),
+            data_size: Some(data_size),
+            data_size_compressed: Some(data_size_compressed),
+            index_size: Some(index_size),
+            number_of_blocks: Some(blocks.len() as u64),
+            number_of_segments: None,
+        }))
     }
 
     #[async_backtrace::framed]
@@ -45,6 +45,7 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod pipes_table;
 mod processes_table;
 mod processor_profile_table;
 mod queries_queue;
@@ -65,6 +66,7 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
@@ -96,6 +98,8 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use pipes_table::parse_pipes_to_datablock;
+pub use pipes_table::PipesTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
 pub use queries_queue::QueriesQueueTable;
@@ -123,3 +127,4 @@ pub use tracing_table::TracingTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
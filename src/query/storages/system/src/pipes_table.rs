@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::schema::ListPipesReq;
+use databend_common_meta_app::schema::PipeMeta;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::plans::pipe_schema;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub fn parse_pipes_to_datablock(pipes: Vec<PipeMeta>) -> Result<DataBlock> {
+    let mut created_on: Vec<i64> = Vec::with_capacity(pipes.len());
+    let mut name: Vec<String> = Vec::with_capacity(pipes.len());
+    let mut owner: Vec<Option<String>> = Vec::with_capacity(pipes.len());
+    let mut definition: Vec<String> = Vec::with_capacity(pipes.len());
+    let mut auto_ingest: Vec<bool> = Vec::with_capacity(pipes.len());
+    let mut execution_paused: Vec<bool> = Vec::with_capacity(pipes.len());
+    let mut comment: Vec<String> = Vec::with_capacity(pipes.len());
+    let mut updated_on: Vec<Option<i64>> = Vec::with_capacity(pipes.len());
+
+    for pipe in pipes {
+        created_on.push(pipe.created_on.timestamp_micros());
+        name.push(pipe.name);
+        owner.push(pipe.owner);
+        definition.push(pipe.copy_stmt);
+        auto_ingest.push(pipe.auto_ingest);
+        execution_paused.push(pipe.execution_paused);
+        comment.push(pipe.comment);
+        updated_on.push(pipe.updated_on.map(|t| t.timestamp_micros()));
+    }
+
+    Ok(DataBlock::new_from_columns(vec![
+        TimestampType::from_data(created_on),
+        StringType::from_data(name),
+        StringType::from_opt_data(owner),
+        StringType::from_data(definition),
+        BooleanType::from_data(auto_ingest),
+        BooleanType::from_data(execution_paused),
+        StringType::from_data(comment),
+        TimestampType::from_opt_data(updated_on),
+    ]))
+}
+
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_default_catalog()?;
+        let pipes = catalog
+            .list_pipes(ListPipesReq::new(tenant.to_string()))
+            .await?;
+
+        parse_pipes_to_datablock(pipes)
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema =
+            infer_table_schema(&pipe_schema()).expect("failed to parse pipes table schema");
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct WorkloadGroupUsage {
+    running_queries: u64,
+    queued_queries: u64,
+    memory_usage: i64,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let workload_groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+
+        // Usage of the queries on this node, grouped by the workload group of their users.
+        let queued_queries = ctx
+            .get_queued_queries()
+            .into_iter()
+            .filter_map(|process_info| process_info.current_query_id)
+            .collect::<HashSet<_>>();
+        let mut usages: HashMap<String, WorkloadGroupUsage> = HashMap::new();
+        for process_info in ctx.get_processes_info() {
+            let Some(query_id) = &process_info.current_query_id else {
+                continue;
+            };
+            let Some(workload_group) = process_info
+                .user
+                .as_ref()
+                .and_then(|user| user.option.workload_group())
+            else {
+                continue;
+            };
+
+            let usage = usages.entry(workload_group.clone()).or_default();
+            if queued_queries.contains(query_id) {
+                usage.queued_queries += 1;
+            } else {
+                usage.running_queries += 1;
+                usage.memory_usage += process_info.memory_usage;
+            }
+        }
+
+        let mut names = Vec::with_capacity(workload_groups.len());
+        let mut cpu_quotas = Vec::with_capacity(workload_groups.len());
+        let mut memory_quotas = Vec::with_capacity(workload_groups.len());
+        let mut max_concurrencies = Vec::with_capacity(workload_groups.len());
+        let mut queue_timeouts = Vec::with_capacity(workload_groups.len());
+        let mut comments = Vec::with_capacity(workload_groups.len());
+        let mut running_queries = Vec::with_capacity(workload_groups.len());
+        let mut queued_queries = Vec::with_capacity(workload_groups.len());
+        let mut memory_usages = Vec::with_capacity(workload_groups.len());
+        let mut created_on_columns = Vec::with_capacity(workload_groups.len());
+        let mut updated_on_columns = Vec::with_capacity(workload_groups.len());
+        for workload_group in workload_groups {
+            let usage = usages.remove(&workload_group.name).unwrap_or_default();
+            names.push(workload_group.name);
+            cpu_quotas.push(workload_group.cpu_quota);
+            memory_quotas.push(workload_group.memory_quota);
+            max_concurrencies.push(workload_group.max_concurrency);
+            queue_timeouts.push(workload_group.queue_timeout);
+            comments.push(workload_group.comment);
+            running_queries.push(usage.running_queries);
+            queued_queries.push(usage.queued_queries);
+            memory_usages.push(usage.memory_usage);
+            created_on_columns.push(workload_group.create_on.timestamp_micros());
+            updated_on_columns.push(workload_group.update_on.map(|u| u.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(cpu_quotas),
+            UInt64Type::from_data(memory_quotas),
+            UInt64Type::from_data(max_concurrencies),
+            UInt64Type::from_data(queue_timeouts),
+            StringType::from_data(comments),
+            UInt64Type::from_data(running_queries),
+            UInt64Type::from_data(queued_queries),
+            Int64Type::from_data(memory_usages),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_opt_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("cpu_quota", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "memory_quota",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "max_concurrency",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queue_timeout",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("comment", TableDataType::String),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "queued_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("memory_usage", TableDataType::Number(NumberDataType::Int64)),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
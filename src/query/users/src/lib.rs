@@ -28,6 +28,7 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod connection;
 pub mod file_format;
@@ -267,6 +267,32 @@ impl UserApiProvider {
             .map_err(|e| e.add_message_back("(while revoke role from role)"))
     }
 
+    // Bind the role to a workload group, or unbind it if the workload group is None.
+    #[async_backtrace::framed]
+    pub async fn update_role_workload_group(
+        &self,
+        tenant: &NonEmptyString,
+        role: &String,
+        workload_group: Option<String>,
+    ) -> Result<Option<u64>> {
+        if let Some(name) = &workload_group {
+            if !self.exists_workload_group(tenant, name).await? {
+                return Err(ErrorCode::UnknownWorkloadGroup(format!(
+                    "workload group `{}` is not exist",
+                    name
+                )));
+            }
+        }
+
+        let client = self.role_api(tenant);
+        client
+            .update_role_with(role, MatchSeq::GE(1), |ri: &mut RoleInfo| {
+                ri.workload_group = workload_group
+            })
+            .await
+            .map_err(|e| e.add_message_back("(while set role workload group)"))
+    }
+
     // Drop a role by name
     #[async_backtrace::framed]
     pub async fn drop_role(
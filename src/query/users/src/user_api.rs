@@ -33,6 +33,7 @@ use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::tenant::TenantQuota;
@@ -155,6 +156,10 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn workload_group_api(&self, tenant: &NonEmptyString) -> WorkloadGroupMgr {
+        WorkloadGroupMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
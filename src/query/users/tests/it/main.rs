@@ -20,3 +20,4 @@ mod role_mgr;
 mod role_util;
 mod user_mgr;
 mod user_udf;
+mod workload_group;
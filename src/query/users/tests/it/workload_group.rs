@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::PasswordHashMethod;
+use databend_common_meta_app::principal::RoleInfo;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::principal::UserOption;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_types::NonEmptyString;
+use databend_common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_workload_group() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant_name = "test";
+    let tenant = NonEmptyString::new(tenant_name.to_string()).unwrap();
+
+    let user_mgr = UserApiProvider::try_create_simple(conf, &tenant).await?;
+    let username = "test-user1";
+    let hostname = "%";
+    let pwd = "test-pwd";
+
+    let group_name = "etl".to_string();
+
+    // add workload group
+    let workload_group = WorkloadGroup {
+        name: group_name.clone(),
+        cpu_quota: 30,
+        memory_quota: 1024 * 1024 * 1024,
+        max_concurrency: 10,
+        queue_timeout: 60,
+        comment: "".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 4, 15, 12, 0, 9).unwrap(),
+        update_on: None,
+    };
+    user_mgr
+        .add_workload_group(&tenant, workload_group.clone(), &CreateOption::Create)
+        .await?;
+
+    let res = user_mgr
+        .add_workload_group(&tenant, workload_group.clone(), &CreateOption::Create)
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS
+    );
+
+    // add user bound to an unknown workload group
+    let auth_info = AuthInfo::Password {
+        hash_value: Vec::from(pwd),
+        hash_method: PasswordHashMethod::Sha256,
+    };
+
+    let mut user_info = UserInfo::new(username, hostname, auth_info.clone());
+    let option = UserOption::empty().with_workload_group(Some("unknown".to_string()));
+    user_info.update_auth_option(None, Some(option));
+    let res = user_mgr
+        .add_user(&tenant, user_info.clone(), &CreateOption::Create)
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_WORKLOAD_GROUP);
+
+    // add user bound to the workload group
+    let option = UserOption::empty().with_workload_group(Some(group_name.clone()));
+    user_info.update_auth_option(None, Some(option));
+    user_mgr
+        .add_user(&tenant, user_info, &CreateOption::Create)
+        .await?;
+
+    let user = UserIdentity::new(username, hostname);
+    let user_info = user_mgr.get_user(&tenant, user.clone()).await?;
+    assert_eq!(user_info.option.workload_group(), Some(&group_name));
+
+    // update workload group
+    user_mgr
+        .update_workload_group(
+            &tenant,
+            group_name.as_ref(),
+            None,
+            None,
+            Some(20),
+            None,
+            Some("nightly jobs".to_string()),
+            false,
+        )
+        .await?;
+
+    let res = user_mgr.get_workload_group(&tenant, &group_name).await?;
+    assert_eq!(res.cpu_quota, 30);
+    assert_eq!(res.max_concurrency, 20);
+    assert_eq!(res.comment, "nightly jobs");
+    assert!(res.update_on.is_some());
+
+    let res = user_mgr
+        .update_workload_group(&tenant, "unknown", None, None, None, None, None, true)
+        .await?;
+    assert!(res.is_none());
+
+    // drop workload group
+    let res = user_mgr
+        .drop_workload_group(&tenant, group_name.as_ref(), false)
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::WORKLOAD_GROUP_IS_USED_BY_USER
+    );
+
+    user_mgr
+        .drop_user(tenant.clone(), user.clone(), false)
+        .await?;
+
+    // bind a role to the workload group
+    let role_name = "etl_role".to_string();
+    user_mgr
+        .add_role(&tenant, RoleInfo::new(&role_name), false)
+        .await?;
+
+    let res = user_mgr
+        .update_role_workload_group(&tenant, &role_name, Some("unknown".to_string()))
+        .await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::UNKNOWN_WORKLOAD_GROUP);
+
+    user_mgr
+        .update_role_workload_group(&tenant, &role_name, Some(group_name.clone()))
+        .await?;
+    let role_info = user_mgr.get_role(&tenant, role_name.clone()).await?;
+    assert_eq!(role_info.workload_group, Some(group_name.clone()));
+
+    let res = user_mgr
+        .drop_workload_group(&tenant, group_name.as_ref(), false)
+        .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::WORKLOAD_GROUP_IS_USED_BY_USER
+    );
+
+    user_mgr
+        .update_role_workload_group(&tenant, &role_name, None)
+        .await?;
+
+    user_mgr
+        .drop_workload_group(&tenant, group_name.as_ref(), false)
+        .await?;
+    assert!(
+        !user_mgr
+            .exists_workload_group(&tenant, group_name.as_ref())
+            .await?
+    );
+    assert!(user_mgr.get_workload_groups(&tenant).await?.is_empty());
+
+    Ok(())
+}
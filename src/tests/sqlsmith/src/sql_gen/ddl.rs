@@ -200,7 +200,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, vec![])
     }
 }
 
@@ -241,6 +241,10 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("1970-01-01 00:00:00".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            lit: Literal::String("00:00:00".to_string()),
+        },
         TypeName::Binary => Expr::Literal {
             span: None,
             lit: Literal::String("".to_string()),
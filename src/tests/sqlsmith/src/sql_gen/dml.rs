@@ -297,6 +297,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             alias: None,
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         };
@@ -499,6 +500,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             alias: None,
             travel_point: None,
             since_point: None,
+            sample: None,
             pivot: None,
             unpivot: None,
         };
@@ -527,6 +529,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                         inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                         timezone: Tz::UTC,
                         binary_format: Default::default(),
+                        geometry_format: Default::default(),
                     },
                     quote_char: b'\'',
                 };
@@ -784,6 +784,7 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         },
         DataType::Date => TypeName::Date,
         DataType::Timestamp => TypeName::Timestamp,
+        DataType::Interval => TypeName::Interval,
         DataType::String => TypeName::String,
         DataType::Bitmap => TypeName::Bitmap,
         DataType::Variant => TypeName::Variant,
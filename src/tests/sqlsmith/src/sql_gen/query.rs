@@ -477,6 +477,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             // TODO
             travel_point: None,
             since_point: None,
+            sample: None,
             // TODO
             pivot: None,
             // TODO
@@ -670,6 +671,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
         let join = Join {
             op,
             condition,
+            match_condition: None,
             left: Box::new(left_table),
             right: Box::new(right_table),
         };
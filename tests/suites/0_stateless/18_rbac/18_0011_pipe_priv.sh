@@ -0,0 +1,51 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export TEST_USER_PASSWORD="password"
+export TEST_USER_CONNECT="bendsql --user=test-pipe-user --password=password --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "set global enable_experimental_rbac_check=1" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- reset user, roles, pipes and tables'
+echo "drop user if exists 'test-pipe-user'" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists test_pipe_role" | $BENDSQL_CLIENT_CONNECT
+echo "drop pipe if exists p_priv_user" | $BENDSQL_CLIENT_CONNECT
+echo "drop pipe if exists p_priv_admin" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_pipe_priv" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- prepare user, role and table'
+echo "create table default.t_pipe_priv(a int)" | $BENDSQL_CLIENT_CONNECT
+echo "create user 'test-pipe-user' IDENTIFIED BY '$TEST_USER_PASSWORD'" | $BENDSQL_CLIENT_CONNECT
+echo "create role test_pipe_role" | $BENDSQL_CLIENT_CONNECT
+echo "grant role test_pipe_role to 'test-pipe-user'" | $BENDSQL_CLIENT_CONNECT
+echo "create pipe p_priv_admin as copy into default.t_pipe_priv from @~/pipe_priv/ file_format = (type = csv)" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- create pipe needs create on the database'
+echo "set role test_pipe_role; create pipe p_priv_user as copy into default.t_pipe_priv from @~/pipe_priv/ file_format = (type = csv)" | $TEST_USER_CONNECT 2>&1 | cut -d: -f1-3
+echo "grant create on default.* to role test_pipe_role" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- create pipe needs insert on the target table'
+echo "set role test_pipe_role; create pipe p_priv_user as copy into default.t_pipe_priv from @~/pipe_priv/ file_format = (type = csv)" | $TEST_USER_CONNECT 2>&1 | cut -d: -f1-3
+echo "grant insert on default.t_pipe_priv to role test_pipe_role" | $BENDSQL_CLIENT_CONNECT
+echo "set role test_pipe_role; create pipe p_priv_user as copy into default.t_pipe_priv from @~/pipe_priv/ file_format = (type = csv)" | $TEST_USER_CONNECT
+
+echo '-- the owner role can alter, describe and drop its pipe'
+echo "select name, owner from system.pipes where name = 'p_priv_user'" | $BENDSQL_CLIENT_CONNECT
+echo "set role test_pipe_role; alter pipe p_priv_user set pipe_execution_paused = true" | $TEST_USER_CONNECT
+echo "set role test_pipe_role; desc pipe p_priv_user" | $TEST_USER_CONNECT | cut -f2,6
+echo "set role test_pipe_role; drop pipe p_priv_user" | $TEST_USER_CONNECT
+
+echo '-- other roles need super'
+echo "set role test_pipe_role; alter pipe p_priv_admin set pipe_execution_paused = true" | $TEST_USER_CONNECT 2>&1 | cut -d: -f1-3
+echo "set role test_pipe_role; desc pipe p_priv_admin" | $TEST_USER_CONNECT 2>&1 | cut -d: -f1-3
+echo "set role test_pipe_role; drop pipe p_priv_admin" | $TEST_USER_CONNECT 2>&1 | cut -d: -f1-3
+echo "select name from system.pipes where name = 'p_priv_admin'" | $BENDSQL_CLIENT_CONNECT
+
+echo '-- clean up'
+echo "drop pipe if exists p_priv_admin" | $BENDSQL_CLIENT_CONNECT
+echo "drop table if exists default.t_pipe_priv" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists test_pipe_role" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists 'test-pipe-user'" | $BENDSQL_CLIENT_CONNECT
+echo "unset enable_experimental_rbac_check" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,33 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# write to a copy, the table data under tests/data must not be changed.
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_v2_tbl/metadata "$ROOT"/
+
+stmt "drop table if exists test_iceberg_write;"
+
+echo ">>>> create table test_iceberg_write engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg_write engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg_write values (1, 'a'), (2, 'b'), (3, 'c');"
+stmt "insert into test_iceberg_write values (4, 'd'), (5, 'e');"
+query "select * from test_iceberg_write order by id;"
+
+stmt "delete from test_iceberg_write where id = 2 or data = 'e';"
+query "select * from test_iceberg_write order by id;"
+
+stmt "optimize table test_iceberg_write compact;"
+query "select * from test_iceberg_write order by id;"
+
+stmt "insert overwrite test_iceberg_write values (6, 'f');"
+query "select * from test_iceberg_write order by id;"
+
+stmt "delete from test_iceberg_write;"
+query "select count(*) from test_iceberg_write;"
+
+stmt "drop table test_iceberg_write;"
+
+rm -rf "$ROOT"
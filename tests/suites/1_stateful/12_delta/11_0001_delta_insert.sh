@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# write to a copy, the table data under tests/data must not be changed.
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/delta/partitioned/. "$ROOT"/
+
+stmt "drop table if exists test_delta_insert;"
+
+echo ">>>> create table test_delta_insert engine = delta location = 'fs://\${ROOT}/';"
+echo "create table test_delta_insert engine = delta location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_delta_insert values (30, 51, 52, 53, 54, 55), (10, 61, 12, 63, 14, 65), (null, 71, 72, 73, 74, 75);"
+query "select * from test_delta_insert order by c5;"
+query "select c1 from test_delta_insert where p0 = 30 order by c1;"
+query "select c1 from test_delta_insert where p0 is null order by c1;"
+query "select c5 from test_delta_insert where c1 > 50 order by c5;"
+
+stmt "insert overwrite test_delta_insert values (40, 81, 82, 83, 84, 85);"
+query "select * from test_delta_insert order by c5;"
+
+stmt "drop table test_delta_insert;"
+
+rm -rf "$ROOT"